@@ -0,0 +1,782 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Module for working with multiple calendars at once
+
+use crate::buddhist::Buddhist;
+use crate::chinese::{Chinese, ChineseDateInner};
+use crate::coptic::{Coptic, CopticDateInner};
+use crate::ethiopic::{Ethiopic, EthiopicDateInner, EthiopicEraStyle};
+use crate::gregorian::{Gregorian, GregorianDateInner};
+use crate::hebrew::{Hebrew, HebrewDateInner};
+use crate::islamic::{
+    IslamicCivil, IslamicCivilDateInner, IslamicTabular, IslamicTabularDateInner,
+};
+use crate::iso::{Iso, IsoDateInner};
+use crate::japanese::Japanese;
+use crate::julian::{Julian, JulianDateInner};
+use crate::roc::Roc;
+use crate::{types, AsCalendar, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError};
+use alloc::string::ToString;
+use icu_locid::extensions::unicode::{Key, Value};
+use icu_locid::Locale;
+
+/// This is a calendar that encompasses all formattable calendars supported by this crate
+///
+/// This allows for the construction of [`Date`] objects that have their calendar known at runtime.
+///
+/// This can be constructed by calling `.into()` on a concrete calendar type if the calendar type is known at
+/// compile time. When the type is known at runtime, the [`AnyCalendar::new()`] and sibling methods may be used.
+///
+/// [`Date`] can also be converted to [`AnyCalendar`]-compatible ones
+/// via [`Date::to_any()`](crate::Date::to_any()).
+///
+/// Dates from an [`AnyCalendar`] can be converted back into a concrete calendar with
+/// [`Date::try_into_typed()`](crate::Date::try_into_typed()), which fails if the
+/// date is in a different calendar.
+///
+/// The methods of the [`Calendar`] trait cannot fail, so when [`AnyCalendar`] is given a date
+/// of a different calendar, e.g. in [`Date::until()`](crate::Date::until()) with dates of two
+/// calendars, the date is first converted to this calendar. Use
+/// [`Date::try_until()`](crate::Date::try_until()) to get an error for mismatched calendars
+/// instead.
+///
+/// ```rust
+/// use icu_calendar::{AnyCalendar, Date};
+/// use icu_locid::Locale;
+///
+/// let locale: Locale = "en-u-ca-japanese".parse().expect("Failed to parse locale");
+/// let calendar = AnyCalendar::new_for_locale(&locale);
+///
+/// let iso = Date::new_iso_date_from_integers(2, 1, 2020).expect("Failed to construct ISO Date");
+/// let date = iso.to_calendar(calendar);
+///
+/// assert_eq!(date.year().era.0.as_str(), "reiwa");
+/// assert_eq!(date.year().number, 2);
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum AnyCalendar {
+    /// A [`Gregorian`] calendar
+    Gregorian(Gregorian),
+    /// A [`Buddhist`] calendar
+    Buddhist(Buddhist),
+    /// A [`Japanese`] calendar
+    Japanese(Japanese),
+    /// A [`Ethiopic`] calendar, in either era style
+    Ethiopic(Ethiopic),
+    /// An [`Iso`] calendar
+    Iso(Iso),
+    /// A [`Julian`] calendar
+    Julian(Julian),
+    /// A [`Roc`] calendar
+    Roc(Roc),
+    /// A [`Hebrew`] calendar
+    Hebrew(Hebrew),
+    /// A [`Chinese`] calendar
+    Chinese(Chinese),
+    /// A [`Coptic`] calendar
+    Coptic(Coptic),
+    /// An [`IslamicCivil`] calendar
+    IslamicCivil(IslamicCivil),
+    /// An [`IslamicTabular`] calendar
+    IslamicTabular(IslamicTabular),
+}
+
+/// The inner date type for [`AnyCalendar`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum AnyDateInner {
+    /// A date for a [`Gregorian`] calendar
+    Gregorian(GregorianDateInner),
+    /// A date for a [`Buddhist`] calendar
+    Buddhist(IsoDateInner),
+    /// A date for a [`Japanese`] calendar
+    Japanese(IsoDateInner),
+    /// A date for an [`Ethiopic`] calendar
+    Ethiopic(EthiopicDateInner),
+    /// A date for an [`Iso`] calendar
+    Iso(IsoDateInner),
+    /// A date for a [`Julian`] calendar
+    Julian(JulianDateInner),
+    /// A date for a [`Roc`] calendar
+    Roc(IsoDateInner),
+    /// A date for a [`Hebrew`] calendar
+    Hebrew(HebrewDateInner),
+    /// A date for a [`Chinese`] calendar
+    Chinese(ChineseDateInner),
+    /// A date for a [`Coptic`] calendar
+    Coptic(CopticDateInner),
+    /// A date for an [`IslamicCivil`] calendar
+    IslamicCivil(IslamicCivilDateInner),
+    /// A date for an [`IslamicTabular`] calendar
+    IslamicTabular(IslamicTabularDateInner),
+}
+
+/// Runs `$e` with `$c` bound to the concrete calendar and `$d` bound to the concrete date,
+/// when the date belongs to the calendar. Evaluates to `None` otherwise.
+macro_rules! match_cal_and_date {
+    (match ($cal:ident, $date:ident): ($c:ident, $d:ident) => $e:expr) => {
+        match ($cal, $date) {
+            (AnyCalendar::Gregorian(ref $c), AnyDateInner::Gregorian(ref $d)) => Some($e),
+            (AnyCalendar::Buddhist(ref $c), AnyDateInner::Buddhist(ref $d)) => Some($e),
+            (AnyCalendar::Japanese(ref $c), AnyDateInner::Japanese(ref $d)) => Some($e),
+            (AnyCalendar::Ethiopic(ref $c), AnyDateInner::Ethiopic(ref $d)) => Some($e),
+            (AnyCalendar::Iso(ref $c), AnyDateInner::Iso(ref $d)) => Some($e),
+            (AnyCalendar::Julian(ref $c), AnyDateInner::Julian(ref $d)) => Some($e),
+            (AnyCalendar::Roc(ref $c), AnyDateInner::Roc(ref $d)) => Some($e),
+            (AnyCalendar::Hebrew(ref $c), AnyDateInner::Hebrew(ref $d)) => Some($e),
+            (AnyCalendar::Chinese(ref $c), AnyDateInner::Chinese(ref $d)) => Some($e),
+            (AnyCalendar::Coptic(ref $c), AnyDateInner::Coptic(ref $d)) => Some($e),
+            (AnyCalendar::IslamicCivil(ref $c), AnyDateInner::IslamicCivil(ref $d)) => Some($e),
+            (AnyCalendar::IslamicTabular(ref $c), AnyDateInner::IslamicTabular(ref $d)) => Some($e),
+            _ => None,
+        }
+    };
+}
+
+impl Calendar for AnyCalendar {
+    type DateInner = AnyDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> AnyDateInner {
+        match *self {
+            Self::Gregorian(ref c) => AnyDateInner::Gregorian(c.date_from_iso(iso)),
+            Self::Buddhist(ref c) => AnyDateInner::Buddhist(c.date_from_iso(iso)),
+            Self::Japanese(ref c) => AnyDateInner::Japanese(c.date_from_iso(iso)),
+            Self::Ethiopic(ref c) => AnyDateInner::Ethiopic(c.date_from_iso(iso)),
+            Self::Iso(ref c) => AnyDateInner::Iso(c.date_from_iso(iso)),
+            Self::Julian(ref c) => AnyDateInner::Julian(c.date_from_iso(iso)),
+            Self::Roc(ref c) => AnyDateInner::Roc(c.date_from_iso(iso)),
+            Self::Hebrew(ref c) => AnyDateInner::Hebrew(c.date_from_iso(iso)),
+            Self::Chinese(ref c) => AnyDateInner::Chinese(c.date_from_iso(iso)),
+            Self::Coptic(ref c) => AnyDateInner::Coptic(c.date_from_iso(iso)),
+            Self::IslamicCivil(ref c) => AnyDateInner::IslamicCivil(c.date_from_iso(iso)),
+            Self::IslamicTabular(ref c) => AnyDateInner::IslamicTabular(c.date_from_iso(iso)),
+        }
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        // None of the calendars need any state to convert their dates to ISO, so this
+        // works even if the date belongs to a different calendar than `self`
+        match *date {
+            AnyDateInner::Gregorian(ref d) => Gregorian.date_to_iso(d),
+            AnyDateInner::Buddhist(ref d) => Buddhist.date_to_iso(d),
+            AnyDateInner::Japanese(ref d) => Japanese.date_to_iso(d),
+            AnyDateInner::Ethiopic(ref d) => Ethiopic::new().date_to_iso(d),
+            AnyDateInner::Iso(ref d) => Iso.date_to_iso(d),
+            AnyDateInner::Julian(ref d) => Julian.date_to_iso(d),
+            AnyDateInner::Roc(ref d) => Roc.date_to_iso(d),
+            AnyDateInner::Hebrew(ref d) => Hebrew.date_to_iso(d),
+            AnyDateInner::Chinese(ref d) => Chinese.date_to_iso(d),
+            AnyDateInner::Coptic(ref d) => Coptic.date_to_iso(d),
+            AnyDateInner::IslamicCivil(ref d) => IslamicCivil.date_to_iso(d),
+            AnyDateInner::IslamicTabular(ref d) => IslamicTabular.date_to_iso(d),
+        }
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        match_cal_and_date!(match (self, date): (c, d) => c.months_in_year(d))
+            .unwrap_or_else(|| self.months_in_year(&self.convert_date(date)))
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        match_cal_and_date!(match (self, date): (c, d) => c.days_in_year(d))
+            .unwrap_or_else(|| self.days_in_year(&self.convert_date(date)))
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        match_cal_and_date!(match (self, date): (c, d) => c.days_in_month(d))
+            .unwrap_or_else(|| self.days_in_month(&self.convert_date(date)))
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        if !self.owns_date(date) {
+            *date = self.convert_date(date);
+        }
+        match (self, date) {
+            (Self::Gregorian(c), AnyDateInner::Gregorian(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::Buddhist(c), AnyDateInner::Buddhist(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::Japanese(c), AnyDateInner::Japanese(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::Ethiopic(c), AnyDateInner::Ethiopic(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::Iso(c), AnyDateInner::Iso(ref mut d)) => c.offset_date(d, offset.cast_unit()),
+            (Self::Julian(c), AnyDateInner::Julian(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::Roc(c), AnyDateInner::Roc(ref mut d)) => c.offset_date(d, offset.cast_unit()),
+            (Self::Hebrew(c), AnyDateInner::Hebrew(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::Chinese(c), AnyDateInner::Chinese(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::Coptic(c), AnyDateInner::Coptic(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::IslamicCivil(c), AnyDateInner::IslamicCivil(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::IslamicTabular(c), AnyDateInner::IslamicTabular(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            // The date was converted to this calendar above
+            _ => unreachable!(),
+        }
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        let date1 = self.convert_date(date1);
+        let date2 = self.convert_date(date2);
+        macro_rules! until {
+            ($($variant:ident),+) => {
+                match (self, &date1, &date2) {
+                    $(
+                        (Self::$variant(c), AnyDateInner::$variant(d1), AnyDateInner::$variant(d2)) => {
+                            c.until(d1, d2, largest_unit, smallest_unit).cast_unit()
+                        }
+                    )+
+                    // Both dates were converted to this calendar above
+                    _ => unreachable!(),
+                }
+            };
+        }
+        until!(
+            Gregorian,
+            Buddhist,
+            Japanese,
+            Ethiopic,
+            Iso,
+            Julian,
+            Roc,
+            Hebrew,
+            Chinese,
+            Coptic,
+            IslamicCivil,
+            IslamicTabular
+        )
+    }
+
+    fn debug_name() -> &'static str {
+        "AnyCalendar"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(self.kind())
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        match_cal_and_date!(match (self, date): (c, d) => c.year(d))
+            .unwrap_or_else(|| self.year(&self.convert_date(date)))
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        match_cal_and_date!(match (self, date): (c, d) => c.month(d))
+            .unwrap_or_else(|| self.month(&self.convert_date(date)))
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        match_cal_and_date!(match (self, date): (c, d) => c.day_of_month(d))
+            .unwrap_or_else(|| self.day_of_month(&self.convert_date(date)))
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        match_cal_and_date!(match (self, date): (c, d) => c.day_of_year_info(d))
+            .unwrap_or_else(|| self.day_of_year_info(&self.convert_date(date)))
+    }
+}
+
+impl AnyCalendar {
+    /// Constructs an AnyCalendar for a given calendar kind
+    pub fn new(kind: AnyCalendarKind) -> Self {
+        match kind {
+            AnyCalendarKind::Gregorian => AnyCalendar::Gregorian(Gregorian),
+            AnyCalendarKind::Buddhist => AnyCalendar::Buddhist(Buddhist),
+            AnyCalendarKind::Japanese => AnyCalendar::Japanese(Japanese),
+            AnyCalendarKind::Ethiopic => AnyCalendar::Ethiopic(Ethiopic::new()),
+            AnyCalendarKind::EthiopicAmeteAlem => {
+                AnyCalendar::Ethiopic(Ethiopic::new_with_era_style(EthiopicEraStyle::AmeteAlem))
+            }
+            AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
+            AnyCalendarKind::Julian => AnyCalendar::Julian(Julian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+        }
+    }
+
+    /// Constructs an AnyCalendar for a given calendar kind, as specified by the `-u-ca`
+    /// keyword of `locale`
+    ///
+    /// Falls back to the Gregorian calendar if the locale does not specify a calendar,
+    /// or specifies a calendar that is not supported.
+    pub fn new_for_locale(locale: &Locale) -> Self {
+        Self::new(AnyCalendarKind::from_locale(locale).unwrap_or(AnyCalendarKind::Gregorian))
+    }
+
+    /// The [`AnyCalendarKind`] corresponding to the calendar contained within this [`AnyCalendar`]
+    pub fn kind(&self) -> AnyCalendarKind {
+        match *self {
+            Self::Gregorian(_) => AnyCalendarKind::Gregorian,
+            Self::Buddhist(_) => AnyCalendarKind::Buddhist,
+            Self::Japanese(_) => AnyCalendarKind::Japanese,
+            Self::Ethiopic(ref e) => match e.era_style() {
+                EthiopicEraStyle::AmeteMihret => AnyCalendarKind::Ethiopic,
+                EthiopicEraStyle::AmeteAlem => AnyCalendarKind::EthiopicAmeteAlem,
+            },
+            Self::Iso(_) => AnyCalendarKind::Iso,
+            Self::Julian(_) => AnyCalendarKind::Julian,
+            Self::Roc(_) => AnyCalendarKind::Roc,
+            Self::Hebrew(_) => AnyCalendarKind::Hebrew,
+            Self::Chinese(_) => AnyCalendarKind::Chinese,
+            Self::Coptic(_) => AnyCalendarKind::Coptic,
+            Self::IslamicCivil(_) => AnyCalendarKind::IslamicCivil,
+            Self::IslamicTabular(_) => AnyCalendarKind::IslamicTabular,
+        }
+    }
+
+    /// The debug name of the calendar contained within this [`AnyCalendar`]
+    pub fn calendar_name(&self) -> &'static str {
+        match *self {
+            Self::Gregorian(_) => Gregorian::debug_name(),
+            Self::Buddhist(_) => Buddhist::debug_name(),
+            Self::Japanese(_) => Japanese::debug_name(),
+            Self::Ethiopic(_) => Ethiopic::debug_name(),
+            Self::Iso(_) => Iso::debug_name(),
+            Self::Julian(_) => Julian::debug_name(),
+            Self::Roc(_) => Roc::debug_name(),
+            Self::Hebrew(_) => Hebrew::debug_name(),
+            Self::Chinese(_) => Chinese::debug_name(),
+            Self::Coptic(_) => Coptic::debug_name(),
+            Self::IslamicCivil(_) => IslamicCivil::debug_name(),
+            Self::IslamicTabular(_) => IslamicTabular::debug_name(),
+        }
+    }
+
+    /// Whether `date` belongs to this calendar
+    fn owns_date(&self, date: &AnyDateInner) -> bool {
+        match_cal_and_date!(match (self, date): (_c, _d) => ()).is_some()
+    }
+
+    /// Converts `date` to a date in this calendar, if it belongs to a different one
+    fn convert_date(&self, date: &AnyDateInner) -> AnyDateInner {
+        if self.owns_date(date) {
+            date.clone()
+        } else {
+            self.date_from_iso(self.date_to_iso(date))
+        }
+    }
+}
+
+/// Convenient type for selecting the kind of AnyCalendar to construct
+#[non_exhaustive]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AnyCalendarKind {
+    /// The kind of a [`Gregorian`] calendar
+    Gregorian,
+    /// The kind of a [`Buddhist`] calendar
+    Buddhist,
+    /// The kind of a [`Japanese`] calendar
+    Japanese,
+    /// The kind of an [`Ethiopic`] calendar, with Amete Mihret era
+    Ethiopic,
+    /// The kind of an [`Ethiopic`] calendar, with Amete Alem era
+    EthiopicAmeteAlem,
+    /// The kind of an [`Iso`] calendar
+    Iso,
+    /// The kind of a [`Julian`] calendar
+    Julian,
+    /// The kind of a [`Roc`] calendar
+    Roc,
+    /// The kind of a [`Hebrew`] calendar
+    Hebrew,
+    /// The kind of a [`Chinese`] calendar
+    Chinese,
+    /// The kind of a [`Coptic`] calendar
+    Coptic,
+    /// The kind of an [`IslamicCivil`] calendar
+    IslamicCivil,
+    /// The kind of an [`IslamicTabular`] calendar
+    IslamicTabular,
+}
+
+impl AnyCalendarKind {
+    /// Construct from a BCP-47 string
+    ///
+    /// Only canonical values are accepted, e.g. `"ethioaa"` but not its alias
+    /// `"ethiopic-amete-alem"`. Returns None if the calendar is unknown
+    pub fn from_bcp47_string(x: &str) -> Option<Self> {
+        Some(match x {
+            "gregory" => AnyCalendarKind::Gregorian,
+            "buddhist" => AnyCalendarKind::Buddhist,
+            "japanese" => AnyCalendarKind::Japanese,
+            "ethiopic" => AnyCalendarKind::Ethiopic,
+            "ethioaa" => AnyCalendarKind::EthiopicAmeteAlem,
+            "iso8601" => AnyCalendarKind::Iso,
+            "roc" => AnyCalendarKind::Roc,
+            "hebrew" => AnyCalendarKind::Hebrew,
+            "chinese" => AnyCalendarKind::Chinese,
+            "coptic" => AnyCalendarKind::Coptic,
+            "islamic-civil" => AnyCalendarKind::IslamicCivil,
+            "islamic-tbla" => AnyCalendarKind::IslamicTabular,
+            _ => return None,
+        })
+    }
+
+    /// Construct from a BCP-47 [`Value`]
+    ///
+    /// Returns None if the calendar is unknown
+    pub fn from_bcp47(x: &Value) -> Option<Self> {
+        Self::from_bcp47_string(&x.to_string())
+    }
+
+    /// Convert to a BCP-47 string
+    ///
+    /// Returns None for the [`Julian`] calendar, which does not have a BCP-47 identifier
+    pub fn as_bcp47_string(self) -> Option<&'static str> {
+        Some(match self {
+            AnyCalendarKind::Gregorian => "gregory",
+            AnyCalendarKind::Buddhist => "buddhist",
+            AnyCalendarKind::Japanese => "japanese",
+            AnyCalendarKind::Ethiopic => "ethiopic",
+            AnyCalendarKind::EthiopicAmeteAlem => "ethioaa",
+            AnyCalendarKind::Iso => "iso8601",
+            AnyCalendarKind::Julian => return None,
+            AnyCalendarKind::Roc => "roc",
+            AnyCalendarKind::Hebrew => "hebrew",
+            AnyCalendarKind::Chinese => "chinese",
+            AnyCalendarKind::Coptic => "coptic",
+            AnyCalendarKind::IslamicCivil => "islamic-civil",
+            AnyCalendarKind::IslamicTabular => "islamic-tbla",
+        })
+    }
+
+    /// Extract the calendar kind from the `-u-ca` keyword of a [`Locale`]
+    ///
+    /// Returns None if the locale does not specify a calendar, or specifies one that
+    /// is not supported
+    ///
+    /// ```rust
+    /// use icu_calendar::AnyCalendarKind;
+    /// use icu_locid::Locale;
+    ///
+    /// let locale: Locale = "th-u-ca-buddhist".parse().expect("Failed to parse locale");
+    /// assert_eq!(AnyCalendarKind::from_locale(&locale), Some(AnyCalendarKind::Buddhist));
+    ///
+    /// let locale: Locale = "th".parse().expect("Failed to parse locale");
+    /// assert_eq!(AnyCalendarKind::from_locale(&locale), None);
+    /// ```
+    pub fn from_locale(locale: &Locale) -> Option<Self> {
+        let key = Key::from_bytes(b"ca").ok()?;
+        locale
+            .get_unicode_extension(&key)
+            .and_then(Self::from_bcp47)
+    }
+}
+
+/// Trait for calendars that may be converted to [`AnyCalendar`]
+pub trait IntoAnyCalendar: Calendar + Clone + Sized {
+    /// Convert this calendar into an [`AnyCalendar`], moving it
+    fn to_any(self) -> AnyCalendar;
+    /// Convert an [`AnyCalendar`] into this calendar, if it contains this kind of calendar
+    ///
+    /// Returns the original calendar otherwise
+    fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar>;
+    /// Convert a date for this calendar into an [`AnyDateInner`]
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner;
+    /// Convert an [`AnyDateInner`] into a date for this calendar, if it is one
+    fn date_from_any(d: AnyDateInner) -> Option<Self::DateInner>;
+}
+
+macro_rules! impl_into_any_calendar {
+    ($($variant:ident),+) => {
+        $(
+            impl IntoAnyCalendar for $variant {
+                fn to_any(self) -> AnyCalendar {
+                    AnyCalendar::$variant(self)
+                }
+                fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar> {
+                    if let AnyCalendar::$variant(cal) = any {
+                        Ok(cal)
+                    } else {
+                        Err(any)
+                    }
+                }
+                fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+                    AnyDateInner::$variant(*d)
+                }
+                fn date_from_any(d: AnyDateInner) -> Option<Self::DateInner> {
+                    if let AnyDateInner::$variant(d) = d {
+                        Some(d)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl From<$variant> for AnyCalendar {
+                fn from(c: $variant) -> AnyCalendar {
+                    c.to_any()
+                }
+            }
+        )+
+    };
+}
+
+impl_into_any_calendar!(
+    Gregorian,
+    Buddhist,
+    Japanese,
+    Ethiopic,
+    Iso,
+    Julian,
+    Roc,
+    Hebrew,
+    Chinese,
+    Coptic,
+    IslamicCivil,
+    IslamicTabular
+);
+
+impl<C: IntoAnyCalendar> Date<C> {
+    /// Type-erase the date, converting it to a date for [`AnyCalendar`]
+    pub fn to_any(&self) -> Date<AnyCalendar> {
+        let cal = self.calendar();
+        Date::from_raw(cal.date_to_any(self.inner()), cal.clone().to_any())
+    }
+}
+
+impl Date<AnyCalendar> {
+    /// Convert a date for [`AnyCalendar`] back into a date for a concrete calendar
+    ///
+    /// Returns [`DateTimeError::MismatchedCalendars`] if the date is not in the calendar `C`.
+    /// Use [`Date::to_calendar()`] to convert the date to a different calendar instead.
+    ///
+    /// ```rust
+    /// use icu_calendar::{AnyCalendar, AnyCalendarKind, Date, DateTimeError};
+    /// use icu_calendar::{buddhist::Buddhist, hebrew::Hebrew};
+    ///
+    /// let iso = Date::new_iso_date_from_integers(2, 1, 2020).expect("Failed to construct ISO Date");
+    /// let date = iso.to_calendar(AnyCalendar::new(AnyCalendarKind::Buddhist));
+    ///
+    /// assert!(date.clone().try_into_typed::<Buddhist>().is_ok());
+    /// assert!(matches!(
+    ///     date.try_into_typed::<Hebrew>(),
+    ///     Err(DateTimeError::MismatchedCalendars("Buddhist", "Hebrew"))
+    /// ));
+    /// ```
+    pub fn try_into_typed<C: IntoAnyCalendar>(self) -> Result<Date<C>, DateTimeError> {
+        let name = self.calendar().calendar_name();
+        let inner = self.inner().clone();
+        let cal = C::from_any(self.calendar().clone())
+            .map_err(|_| DateTimeError::MismatchedCalendars(name, C::debug_name()))?;
+        let inner = C::date_from_any(inner)
+            .ok_or_else(|| DateTimeError::MismatchedCalendars(name, C::debug_name()))?;
+        Ok(Date::from_raw(inner, cal))
+    }
+
+    /// Calculate the duration between `other - self`, like [`Date::until()`]
+    ///
+    /// Returns [`DateTimeError::MismatchedCalendars`] if `other` is in a different calendar
+    /// than `self`, instead of converting it to the calendar of `self`.
+    ///
+    /// ```rust
+    /// use icu_calendar::{Date, DateDurationUnit, DateTimeError};
+    ///
+    /// let date1 = Date::new_gregorian_date_from_integers(11, 9, 2021).unwrap().to_any();
+    /// let date2 = Date::new_gregorian_date_from_integers(1, 10, 2021).unwrap().to_any();
+    /// let coptic = Date::new_coptic_date_from_integers(1, 1, 1738).unwrap().to_any();
+    ///
+    /// let duration = date1
+    ///     .try_until(&date2, DateDurationUnit::Years, DateDurationUnit::Days)
+    ///     .expect("Both dates are Gregorian");
+    /// assert_eq!(duration.days, 20);
+    /// assert!(matches!(
+    ///     date1.try_until(&coptic, DateDurationUnit::Years, DateDurationUnit::Days),
+    ///     Err(DateTimeError::MismatchedCalendars("Coptic", "Gregorian"))
+    /// ));
+    /// ```
+    pub fn try_until<B: AsCalendar<Calendar = AnyCalendar>>(
+        &self,
+        other: &Date<B>,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> Result<DateDuration<AnyCalendar>, DateTimeError> {
+        let calendar = self.calendar();
+        if !calendar.owns_date(other.inner()) {
+            return Err(DateTimeError::MismatchedCalendars(
+                other.calendar().calendar_name(),
+                calendar.calendar_name(),
+            ));
+        }
+        Ok(self.until(other, largest_unit, smallest_unit))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn single_test_roundtrip(calendar: &AnyCalendar, era: &str, year: i32, month: u8, day: u8) {
+        let iso = Date::new_iso_date_from_integers(day, month, year).unwrap();
+        let date = iso.to_calendar(calendar.clone());
+        assert_eq!(
+            date.to_iso(),
+            iso,
+            "{:?} {}-{}-{}",
+            calendar,
+            year,
+            month,
+            day
+        );
+        assert_eq!(date.year().era.0.as_str(), era, "{:?}", calendar);
+    }
+
+    #[test]
+    fn test_any_construction() {
+        let cases = [
+            (AnyCalendarKind::Gregorian, "ce"),
+            (AnyCalendarKind::Buddhist, "be"),
+            (AnyCalendarKind::Japanese, "reiwa"),
+            (AnyCalendarKind::Ethiopic, "incar"),
+            (AnyCalendarKind::EthiopicAmeteAlem, "mundi"),
+            (AnyCalendarKind::Iso, "default"),
+            (AnyCalendarKind::Julian, "ce"),
+            (AnyCalendarKind::Roc, "roc"),
+            (AnyCalendarKind::Hebrew, "am"),
+            (AnyCalendarKind::Chinese, "chinese"),
+            (AnyCalendarKind::Coptic, "ad"),
+            (AnyCalendarKind::IslamicCivil, "ah"),
+            (AnyCalendarKind::IslamicTabular, "ah"),
+        ];
+        for &(kind, era) in &cases {
+            let calendar = AnyCalendar::new(kind);
+            assert_eq!(calendar.kind(), kind);
+            single_test_roundtrip(&calendar, era, 2021, 6, 15);
+        }
+    }
+
+    #[test]
+    fn test_any_from_locale() {
+        let cases = [
+            ("en", AnyCalendarKind::Gregorian),
+            ("en-u-ca-gregory", AnyCalendarKind::Gregorian),
+            ("th-u-ca-buddhist", AnyCalendarKind::Buddhist),
+            ("ja-u-ca-japanese", AnyCalendarKind::Japanese),
+            ("am-u-ca-ethiopic", AnyCalendarKind::Ethiopic),
+            ("am-u-ca-ethioaa", AnyCalendarKind::EthiopicAmeteAlem),
+            ("he-u-ca-hebrew", AnyCalendarKind::Hebrew),
+            ("ar-u-ca-islamic-civil", AnyCalendarKind::IslamicCivil),
+            ("ar-u-ca-islamic-tbla", AnyCalendarKind::IslamicTabular),
+            ("zh-u-ca-chinese", AnyCalendarKind::Chinese),
+            ("zh-TW-u-hc-h12-ca-roc", AnyCalendarKind::Roc),
+            // Unsupported calendars fall back to Gregorian
+            ("ar-u-ca-islamic-umalqura", AnyCalendarKind::Gregorian),
+        ];
+        for &(locale, kind) in &cases {
+            let locale: Locale = locale.parse().unwrap();
+            assert_eq!(
+                AnyCalendar::new_for_locale(&locale).kind(),
+                kind,
+                "{:?}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_any_from_bcp47_string() {
+        for &value in &[
+            "gregory",
+            "buddhist",
+            "japanese",
+            "ethiopic",
+            "ethioaa",
+            "iso8601",
+            "roc",
+            "hebrew",
+            "chinese",
+            "coptic",
+            "islamic-civil",
+            "islamic-tbla",
+        ] {
+            let kind = AnyCalendarKind::from_bcp47_string(value).unwrap();
+            assert_eq!(kind.as_bcp47_string(), Some(value));
+        }
+        // Aliases are not canonical BCP-47 values
+        assert_eq!(
+            AnyCalendarKind::from_bcp47_string("ethiopic-amete-alem"),
+            None
+        );
+        assert_eq!(AnyCalendarKind::from_bcp47_string("islamicc"), None);
+    }
+
+    #[test]
+    fn test_any_typed_conversion() {
+        let hebrew =
+            Date::new_hebrew_date(1, types::MonthCode(tinystr::tinystr8!("M05L")), 5784).unwrap();
+        let any = hebrew.to_any();
+        assert_eq!(any.calendar().kind(), AnyCalendarKind::Hebrew);
+        assert_eq!(any.year(), hebrew.year());
+        assert_eq!(any.month(), hebrew.month());
+
+        let typed = any.clone().try_into_typed::<Hebrew>().unwrap();
+        assert_eq!(typed, hebrew);
+
+        match any.try_into_typed::<Gregorian>() {
+            Err(DateTimeError::MismatchedCalendars(found, expected)) => {
+                assert_eq!(found, "Hebrew");
+                assert_eq!(expected, "Gregorian");
+            }
+            other => panic!("Expected mismatched calendars, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_any_mismatched_arithmetic() {
+        let gregorian = Date::new_gregorian_date_from_integers(11, 9, 2021)
+            .unwrap()
+            .to_any();
+        let coptic = Date::new_coptic_date_from_integers(1, 1, 1738)
+            .unwrap()
+            .to_any();
+
+        for (date1, date2) in &[(&gregorian, &coptic), (&coptic, &gregorian)] {
+            match date1.try_until(date2, DateDurationUnit::Years, DateDurationUnit::Days) {
+                Err(DateTimeError::MismatchedCalendars(found, expected)) => {
+                    assert_eq!(found, date2.calendar().calendar_name());
+                    assert_eq!(expected, date1.calendar().calendar_name());
+                }
+                other => panic!("Expected mismatched calendars, got {:?}", other),
+            }
+
+            // `until` converts the other date to the calendar of the first one. Both dates
+            // are the same day, so there is no difference between them.
+            let difference = date1.until(date2, DateDurationUnit::Years, DateDurationUnit::Days);
+            assert_eq!(
+                (difference.years, difference.months, difference.days),
+                (0, 0, 0)
+            );
+        }
+
+        // Arithmetic on a date is performed in the calendar of the date
+        let later = coptic.added(DateDuration::new(0, 12, 0, 0));
+        assert_eq!(later.calendar().kind(), AnyCalendarKind::Coptic);
+        assert_eq!(later.month().number, 13);
+    }
+}
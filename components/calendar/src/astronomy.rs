@@ -0,0 +1,368 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Astronomical calculations needed by the astronomical calendars, following chapter 14
+//! ("Time and Astronomy") of Calendrical Calculations.
+//!
+//! Times are represented as "moments": fractional fixed dates, in Universal Time unless
+//! otherwise noted.
+
+use crate::iso::Iso;
+
+/// A fractional fixed date, such that the integer part is the fixed date
+pub type Moment = f64;
+
+/// Noon on January 1st, 2000 (ISO)
+const J2000: Moment = 730120.5;
+
+/// The mean time between new moons, in days
+pub const MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+
+/// The mean time between winter solstices, in days
+pub const MEAN_TROPICAL_YEAR: f64 = 365.242189;
+
+/// `x mod y` for real numbers, with the same sign as `y`
+pub fn modulo(x: f64, y: f64) -> f64 {
+    x - y * libm::floor(x / y)
+}
+
+fn sin_degrees(degrees: f64) -> f64 {
+    libm::sin(degrees.to_radians())
+}
+
+fn cos_degrees(degrees: f64) -> f64 {
+    libm::cos(degrees.to_radians())
+}
+
+/// Evaluate the polynomial with the given coefficients (in order of increasing degree)
+fn poly(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+// The difference between dynamical time and universal time, in days
+//
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L3884-L3952
+fn ephemeris_correction(moment: Moment) -> f64 {
+    let year = Iso::iso_year_from_fixed(libm::floor(moment) as i64);
+    let year_f = year as f64;
+    if (2051..=2150).contains(&year) {
+        let x = (year_f - 1820.0) / 100.0;
+        (-20.0 + 32.0 * x * x + 0.5628 * (2150.0 - year_f)) / 86400.0
+    } else if (2006..=2050).contains(&year) {
+        poly(year_f - 2000.0, &[62.92, 0.32217, 0.005589]) / 86400.0
+    } else if (1987..=2005).contains(&year) {
+        poly(
+            year_f - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        ) / 86400.0
+    } else if (1800..=1986).contains(&year) {
+        // Julian centuries between July 1st of the year and January 1st, 1900
+        let c = (Iso::fixed_from_iso_integers(year, 7, 1)
+            - Iso::fixed_from_iso_integers(1900, 1, 1)) as f64
+            / 36525.0;
+        if year >= 1900 {
+            poly(
+                c,
+                &[
+                    -0.00002, 0.000297, 0.025184, -0.181133, 0.553040, -0.861938, 0.677066,
+                    -0.212591,
+                ],
+            )
+        } else {
+            poly(
+                c,
+                &[
+                    -0.000009, 0.003844, 0.083563, 0.865736, 4.867575, 15.845535, 31.332267,
+                    38.291999, 28.316289, 11.636204, 2.043794,
+                ],
+            )
+        }
+    } else if (1700..=1799).contains(&year) {
+        poly(
+            year_f - 1700.0,
+            &[8.118780842, -0.005092142, 0.003336121, -0.0000266484],
+        ) / 86400.0
+    } else if (1600..=1699).contains(&year) {
+        poly(year_f - 1600.0, &[120.0, -0.9808, -0.01532, 0.000140272128]) / 86400.0
+    } else if (500..=1599).contains(&year) {
+        poly(
+            (year_f - 1000.0) / 100.0,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        ) / 86400.0
+    } else if (-499..=499).contains(&year) {
+        poly(
+            year_f / 100.0,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        ) / 86400.0
+    } else {
+        let x = (year_f - 1820.0) / 100.0;
+        (-20.0 + 32.0 * x * x) / 86400.0
+    }
+}
+
+fn dynamical_from_universal(moment: Moment) -> Moment {
+    moment + ephemeris_correction(moment)
+}
+
+fn universal_from_dynamical(moment: Moment) -> Moment {
+    moment - ephemeris_correction(moment)
+}
+
+/// Julian centuries (in dynamical time) since J2000
+fn julian_centuries(moment: Moment) -> f64 {
+    (dynamical_from_universal(moment) - J2000) / 36525.0
+}
+
+// (coefficient, addend, multiplier) for the periodic terms of the solar longitude
+const SOLAR_LONGITUDE_TERMS: [(f64, f64, f64); 49] = [
+    (403406.0, 270.54861, 0.9287892),
+    (195207.0, 340.19128, 35999.1376958),
+    (119433.0, 63.91854, 35999.4089666),
+    (112392.0, 331.26220, 35998.7287385),
+    (3891.0, 317.843, 71998.20261),
+    (2819.0, 86.631, 71998.4403),
+    (1721.0, 240.052, 36000.35726),
+    (660.0, 310.26, 71997.4812),
+    (350.0, 247.23, 32964.4678),
+    (334.0, 260.87, -19.4410),
+    (314.0, 297.82, 445267.1117),
+    (268.0, 343.14, 45036.8840),
+    (242.0, 166.79, 3.1008),
+    (234.0, 81.53, 22518.4434),
+    (158.0, 3.50, -19.9739),
+    (132.0, 132.75, 65928.9345),
+    (129.0, 182.95, 9038.0293),
+    (114.0, 162.03, 3034.7684),
+    (99.0, 29.8, 33718.148),
+    (93.0, 266.4, 3034.448),
+    (86.0, 249.2, -2280.773),
+    (78.0, 157.6, 29929.992),
+    (72.0, 257.8, 31556.493),
+    (68.0, 185.1, 149.588),
+    (64.0, 69.9, 9037.750),
+    (46.0, 8.0, 107997.405),
+    (38.0, 197.1, -4444.176),
+    (37.0, 250.4, 151.771),
+    (32.0, 65.3, 67555.316),
+    (29.0, 162.7, 31556.080),
+    (28.0, 341.5, -4561.540),
+    (27.0, 291.6, 107996.706),
+    (27.0, 98.5, 1221.655),
+    (25.0, 146.7, 62894.167),
+    (24.0, 110.0, 31437.369),
+    (21.0, 5.2, 14578.298),
+    (21.0, 342.6, -31931.757),
+    (20.0, 230.9, 34777.243),
+    (18.0, 256.1, 1221.999),
+    (17.0, 45.3, 62894.511),
+    (14.0, 242.9, -4442.039),
+    (13.0, 115.2, 107997.909),
+    (13.0, 151.8, 119.066),
+    (13.0, 285.3, 16859.071),
+    (12.0, 53.3, -4.578),
+    (10.0, 126.6, 26895.292),
+    (10.0, 205.7, -39.127),
+    (10.0, 85.9, 12297.536),
+    (10.0, 146.1, 90073.778),
+];
+
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L4103-L4111
+fn nutation(moment: Moment) -> f64 {
+    let c = julian_centuries(moment);
+    let a = poly(c, &[124.90, -1934.134, 0.002063]);
+    let b = poly(c, &[201.11, 72001.5377, 0.00057]);
+    -0.004778 * sin_degrees(a) - 0.0003667 * sin_degrees(b)
+}
+
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L4113-L4119
+fn aberration(moment: Moment) -> f64 {
+    let c = julian_centuries(moment);
+    0.0000974 * cos_degrees(177.63 + 35999.01848 * c) - 0.005575
+}
+
+/// The longitude of the sun at the given moment, in degrees
+///
+/// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L4036-L4101
+pub fn solar_longitude(moment: Moment) -> f64 {
+    let c = julian_centuries(moment);
+    let sum: f64 = SOLAR_LONGITUDE_TERMS
+        .iter()
+        .map(|&(x, y, z)| x * sin_degrees(y + z * c))
+        .sum();
+    let lambda = 282.7771834 + 36000.76953744 * c + 0.000005729577951308232 * sum;
+    modulo(lambda + aberration(moment) + nutation(moment), 360.0)
+}
+
+/// An estimate of the last moment before `moment` at which the solar longitude
+/// was `longitude`
+///
+/// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L4419-L4431
+pub fn estimate_prior_solar_longitude(longitude: f64, moment: Moment) -> Moment {
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let tau = moment - rate * modulo(solar_longitude(moment) - longitude, 360.0);
+    let delta = modulo(solar_longitude(tau) - longitude + 180.0, 360.0) - 180.0;
+    let estimate = tau - rate * delta;
+    if estimate < moment {
+        estimate
+    } else {
+        moment
+    }
+}
+
+// (v, w, x, y, z) for the periodic terms of the new moon correction
+const NEW_MOON_TERMS: [(f64, f64, f64, f64, f64); 24] = [
+    (-0.40720, 0.0, 0.0, 1.0, 0.0),
+    (0.17241, 1.0, 1.0, 0.0, 0.0),
+    (0.01608, 0.0, 0.0, 2.0, 0.0),
+    (0.01039, 0.0, 0.0, 0.0, 2.0),
+    (0.00739, 1.0, -1.0, 1.0, 0.0),
+    (-0.00514, 1.0, 1.0, 1.0, 0.0),
+    (0.00208, 2.0, 2.0, 0.0, 0.0),
+    (-0.00111, 0.0, 0.0, 1.0, -2.0),
+    (-0.00057, 0.0, 0.0, 1.0, 2.0),
+    (0.00056, 1.0, 1.0, 2.0, 0.0),
+    (-0.00042, 0.0, 0.0, 3.0, 0.0),
+    (0.00042, 1.0, 1.0, 0.0, 2.0),
+    (0.00038, 1.0, 1.0, 0.0, -2.0),
+    (-0.00024, 1.0, -1.0, 2.0, 0.0),
+    (-0.00007, 0.0, 2.0, 1.0, 0.0),
+    (0.00004, 0.0, 0.0, 2.0, -2.0),
+    (0.00004, 0.0, 3.0, 0.0, 0.0),
+    (0.00003, 0.0, 1.0, 1.0, -2.0),
+    (0.00003, 0.0, 0.0, 2.0, 2.0),
+    (-0.00003, 0.0, 1.0, 1.0, 2.0),
+    (0.00003, 0.0, -1.0, 1.0, 2.0),
+    (-0.00002, 0.0, -1.0, 1.0, -2.0),
+    (-0.00002, 0.0, 1.0, 3.0, 0.0),
+    (0.00002, 0.0, 0.0, 4.0, 0.0),
+];
+
+// (i, j, l) for the additional planetary corrections of the new moon
+const NEW_MOON_ADDITIONAL_TERMS: [(f64, f64, f64); 13] = [
+    (251.88, 0.016321, 0.000165),
+    (251.83, 26.651886, 0.000164),
+    (349.42, 36.412478, 0.000126),
+    (84.66, 18.206239, 0.000110),
+    (141.74, 53.303771, 0.000062),
+    (207.14, 2.453732, 0.000060),
+    (154.84, 7.306860, 0.000056),
+    (34.52, 27.261239, 0.000047),
+    (207.19, 0.121824, 0.000042),
+    (291.34, 1.844379, 0.000040),
+    (161.72, 24.198154, 0.000037),
+    (239.56, 25.513099, 0.000035),
+    (331.55, 3.592518, 0.000023),
+];
+
+/// The moment of the `n`th new moon after (or before) the new moon of January 11th, 1 CE
+///
+/// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L4288-L4377
+pub fn nth_new_moon(n: i64) -> Moment {
+    // The number of new moons between January 11th, 1 CE and J2000
+    let k = (n - 24724) as f64;
+    let c = k / 1236.85;
+    let approx = J2000
+        + poly(
+            c,
+            &[
+                5.09766,
+                MEAN_SYNODIC_MONTH * 1236.85,
+                0.00015437,
+                -0.000000150,
+                0.00000000073,
+            ],
+        );
+    let e = poly(c, &[1.0, -0.002516, -0.0000074]);
+    let solar_anomaly = poly(c, &[2.5534, 1236.85 * 29.10535670, -0.0000014, -0.00000011]);
+    let lunar_anomaly = poly(
+        c,
+        &[
+            201.5643,
+            385.81693528 * 1236.85,
+            0.0107582,
+            0.00001238,
+            -0.000000058,
+        ],
+    );
+    let moon_argument = poly(
+        c,
+        &[
+            160.7108,
+            390.67050284 * 1236.85,
+            -0.0016118,
+            -0.00000227,
+            0.000000011,
+        ],
+    );
+    let omega = poly(c, &[124.7746, -1.56375588 * 1236.85, 0.0020672, 0.00000215]);
+    let correction = -0.00017 * sin_degrees(omega)
+        + NEW_MOON_TERMS
+            .iter()
+            .map(|&(v, w, x, y, z)| {
+                v * libm::pow(e, w)
+                    * sin_degrees(x * solar_anomaly + y * lunar_anomaly + z * moon_argument)
+            })
+            .sum::<f64>();
+    let extra = 0.000325 * sin_degrees(poly(c, &[299.77, 132.8475848, -0.009173]));
+    let additional: f64 = NEW_MOON_ADDITIONAL_TERMS
+        .iter()
+        .map(|&(i, j, l)| l * sin_degrees(i + j * k))
+        .sum();
+    universal_from_dynamical(approx + correction + extra + additional)
+}
+
+/// The index of the new moon closest to the given moment, such that it can be passed
+/// to [`nth_new_moon()`]
+fn approx_new_moon_index(moment: Moment) -> i64 {
+    libm::round((moment - nth_new_moon(0)) / MEAN_SYNODIC_MONTH) as i64
+}
+
+/// The moment of the first new moon at or after `moment`
+pub fn new_moon_at_or_after(moment: Moment) -> Moment {
+    // True new moons differ from mean new moons by less than a day, so this only
+    // needs to search a step or two in either direction
+    let mut n = approx_new_moon_index(moment);
+    while nth_new_moon(n) < moment {
+        n += 1;
+    }
+    while nth_new_moon(n - 1) >= moment {
+        n -= 1;
+    }
+    nth_new_moon(n)
+}
+
+/// The moment of the last new moon before `moment`
+pub fn new_moon_before(moment: Moment) -> Moment {
+    let mut n = approx_new_moon_index(moment);
+    while nth_new_moon(n) >= moment {
+        n -= 1;
+    }
+    while nth_new_moon(n + 1) < moment {
+        n += 1;
+    }
+    nth_new_moon(n)
+}
@@ -0,0 +1,155 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Buddhist calendar
+
+use crate::iso::{Iso, IsoDateInner};
+use crate::{
+    types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+use tinystr::tinystr8;
+
+/// The number of years the Buddhist Era is ahead of C.E. by
+///
+/// (1 AD = 544 BE)
+const BUDDHIST_ERA_OFFSET: i32 = 543;
+
+/// The Thai Buddhist Calendar
+///
+/// The Buddhist calendar shares its months and days with the ISO calendar, but counts
+/// years in a single era, "be" (Buddhist Era), starting at ISO year -542.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Buddhist;
+
+impl Calendar for Buddhist {
+    type DateInner = IsoDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> IsoDateInner {
+        *iso.inner()
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Date::from_raw(*date, Iso)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        Iso.months_in_year(date)
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        Iso.days_in_year(date)
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        Iso.days_in_month(date)
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> u8 {
+        Iso.day_of_week(date)
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        Iso.offset_date(date, offset.cast_unit())
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        Iso.until(date1, date2, largest_unit, smallest_unit)
+            .cast_unit()
+    }
+
+    fn debug_name() -> &'static str {
+        "Buddhist"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Buddhist)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        iso_year_as_buddhist(date.year.0)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        Iso.month(date)
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        Iso.day_of_month(date)
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: Iso::day_of_year(*date),
+            days_in_year: Iso.days_in_year(date),
+            prev_year: iso_year_as_buddhist(date.year.0 - 1),
+            days_in_prev_year: Iso::days_in_year_direct(date.year.0 - 1),
+            next_year: iso_year_as_buddhist(date.year.0 + 1),
+        }
+    }
+}
+
+impl Date<Buddhist> {
+    /// Construct a new Buddhist Date.
+    ///
+    /// Years are specified as BE years.
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    ///
+    /// let date_buddhist = Date::new_buddhist_date_from_integers(1, 1, 1970)
+    ///     .expect("Failed to initialize Buddhist Date instance.");
+    ///
+    /// assert_eq!(date_buddhist.year().number, 1970);
+    /// assert_eq!(date_buddhist.month().number, 1);
+    /// assert_eq!(date_buddhist.day_of_month().0, 1);
+    /// assert_eq!(date_buddhist.to_iso().year().number, 1427);
+    /// ```
+    pub fn new_buddhist_date_from_integers(
+        day: u8,
+        month: u8,
+        year: i32,
+    ) -> Result<Date<Buddhist>, DateTimeError> {
+        Ok(
+            Date::new_iso_date_from_integers(day, month, year - BUDDHIST_ERA_OFFSET)?
+                .to_calendar(Buddhist),
+        )
+    }
+}
+
+fn iso_year_as_buddhist(year: i32) -> types::Year {
+    types::Year {
+        era: types::Era(tinystr8!("be")),
+        number: year + BUDDHIST_ERA_OFFSET,
+        related_iso: year,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_buddhist_iso_conversion() {
+        let iso = Date::new_iso_date_from_integers(14, 4, 2021).unwrap();
+        let buddhist = iso.to_calendar(Buddhist);
+        assert_eq!(buddhist.year().era, types::Era(tinystr8!("be")));
+        assert_eq!(buddhist.year().number, 2564);
+        assert_eq!(buddhist.year().related_iso, 2021);
+        assert_eq!(buddhist.month().number, 4);
+        assert_eq!(buddhist.day_of_month().0, 14);
+        assert_eq!(
+            Date::new_buddhist_date_from_integers(14, 4, 2564).unwrap(),
+            buddhist
+        );
+
+        // 1 BE is 543 BCE, i.e. ISO year -542
+        let buddhist = Date::new_buddhist_date_from_integers(1, 1, 1).unwrap();
+        assert_eq!(buddhist.to_iso().year().number, -542);
+    }
+}
@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use crate::{Date, DateDuration, DateDurationUnit, Iso};
+use crate::{types, AnyCalendarKind, Date, DateDuration, DateDurationUnit, Iso};
 
 /// A calendar implementation
 ///
@@ -40,9 +40,16 @@ pub trait Calendar {
     // fn week_of_year(&self, date: &Self::DateInner) -> u8;
 
     /// Add `offset` to `date`
+    ///
+    /// Years and months should be added first, constraining the day (and month, for
+    /// leap months) to the closest one that exists, and then weeks and days.
     fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>);
 
-    /// Calculate `date2 - date` as a duration
+    /// Calculate `date2 - date1` as a duration
+    ///
+    /// The duration should be balanced so that no unit is larger than `largest_unit`,
+    /// and units smaller than `smallest_unit` should be truncated. The duration is
+    /// negative if `date2` is before `date1`.
     fn until(
         &self,
         date1: &Self::DateInner,
@@ -53,5 +60,24 @@ pub trait Calendar {
 
     /// Obtain a name for the calendar for debug printing
     fn debug_name() -> &'static str;
+
+    /// The kind of this calendar, if it is one of the calendars of [`AnyCalendar`]
+    ///
+    /// [`AnyCalendar`]: crate::AnyCalendar
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        None
+    }
+
+    /// The calendar-specific year represented by `date`
+    fn year(&self, date: &Self::DateInner) -> types::Year;
+
+    /// The calendar-specific month represented by `date`
+    fn month(&self, date: &Self::DateInner) -> types::Month;
+
+    /// The calendar-specific day-of-month represented by `date`
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth;
+
+    /// Information of the day of the year
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo;
     // fn since(&self, from: &Date<Self>, to: &Date<Self>) -> Duration<Self>, Error;
 }
@@ -0,0 +1,267 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Shared arithmetic for calendars that represent dates as a year, an ordinal
+//! month, and a day of the month.
+
+use crate::{types, Calendar, DateDuration};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use tinystr::{tinystr8, TinyStr8};
+
+/// Calendar-specific rules needed by [`ArithmeticDate`]
+pub trait CalendarArithmetic: Calendar {
+    /// The number of days in the given ordinal month of the given year
+    fn month_days(year: i32, month: u8) -> u8;
+    /// The number of months in the given year
+    fn months_for_every_year(year: i32) -> u8;
+    /// Whether the given year is a leap year
+    fn is_leap_year(year: i32) -> bool;
+
+    /// The number of days in the given year
+    fn days_in_provided_year(year: i32) -> u32 {
+        (1..=Self::months_for_every_year(year))
+            .map(|month| Self::month_days(year, month) as u32)
+            .sum()
+    }
+}
+
+/// A date represented as a year, an ordinal month (1-indexed), and a day of the month
+/// (1-indexed), following the rules of the calendar `C`.
+pub struct ArithmeticDate<C: CalendarArithmetic> {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    marker: PhantomData<C>,
+}
+
+impl<C: CalendarArithmetic> ArithmeticDate<C> {
+    #[inline]
+    pub fn new(year: i32, month: u8, day: u8) -> Self {
+        ArithmeticDate {
+            year,
+            month,
+            day,
+            marker: PhantomData,
+        }
+    }
+
+    /// Construct a date after checking that the month and day are in range for the year
+    pub fn new_checked(year: i32, month: u8, day: u8) -> Option<Self> {
+        if month == 0 || month > C::months_for_every_year(year) {
+            return None;
+        }
+        if day == 0 || day > C::month_days(year, month) {
+            return None;
+        }
+        Some(Self::new(year, month, day))
+    }
+
+    /// Construct a date from a year and a 1-indexed day of that year
+    pub fn from_year_and_day_of_year(year: i32, day_of_year: u32) -> Self {
+        let mut remaining = day_of_year;
+        let mut month = 1;
+        loop {
+            let month_days = C::month_days(year, month) as u32;
+            if remaining <= month_days || month == C::months_for_every_year(year) {
+                return Self::new(year, month, remaining as u8);
+            }
+            remaining -= month_days;
+            month += 1;
+        }
+    }
+
+    #[inline]
+    pub fn months_in_year(&self) -> u8 {
+        C::months_for_every_year(self.year)
+    }
+
+    #[inline]
+    pub fn days_in_year(&self) -> u32 {
+        C::days_in_provided_year(self.year)
+    }
+
+    #[inline]
+    pub fn days_in_month(&self) -> u8 {
+        C::month_days(self.year, self.month)
+    }
+
+    /// The 1-indexed day of the year
+    pub fn day_of_year(&self) -> u32 {
+        (1..self.month)
+            .map(|month| C::month_days(self.year, month) as u32)
+            .sum::<u32>()
+            + self.day as u32
+    }
+
+    /// Add `month_offset` ordinal months to the date, without changing the day
+    pub fn offset_months(&mut self, mut month_offset: i32) {
+        while month_offset != 0 {
+            let year_months = C::months_for_every_year(self.year) as i32;
+            let month = self.month as i32;
+            if month + month_offset > year_months {
+                month_offset -= year_months - month + 1;
+                self.year += 1;
+                self.month = 1;
+            } else if month + month_offset < 1 {
+                month_offset += month;
+                self.year -= 1;
+                self.month = C::months_for_every_year(self.year);
+            } else {
+                self.month = (month + month_offset) as u8;
+                month_offset = 0;
+            }
+        }
+    }
+
+    /// Add `day_offset` days to the date
+    pub fn offset_days(&mut self, mut day_offset: i32) {
+        // Normalize date to beginning of month
+        day_offset += self.day as i32 - 1;
+        self.day = 1;
+
+        while day_offset != 0 {
+            if day_offset < 0 {
+                self.offset_months(-1);
+                let month_days = self.days_in_month() as i32;
+                if -day_offset > month_days {
+                    day_offset += month_days;
+                } else {
+                    // Add 1 since we are subtracting from the first day of the
+                    // *next* month
+                    self.day = (1 + month_days + day_offset) as u8;
+                    day_offset = 0;
+                }
+            } else {
+                let month_days = self.days_in_month() as i32;
+                if day_offset >= month_days {
+                    self.offset_months(1);
+                    day_offset -= month_days;
+                } else {
+                    self.day += day_offset as u8;
+                    day_offset = 0;
+                }
+            }
+        }
+    }
+
+    /// Clamp the day to the number of days in the month
+    #[inline]
+    pub fn constrain_day(&mut self) {
+        self.day = self.day.min(self.days_in_month());
+    }
+
+    /// Add `offset` to the date
+    ///
+    /// Years and months are added first, constraining the day to the resulting month,
+    /// and then weeks and days are added.
+    pub fn offset_date(&mut self, offset: DateDuration<C>) {
+        self.year += offset.years;
+        // The month may not exist in the new year, e.g. a leap month
+        let year_months = C::months_for_every_year(self.year);
+        if self.month > year_months {
+            self.month = year_months;
+        }
+        self.offset_months(offset.months);
+        self.constrain_day();
+        self.offset_days(offset.days + offset.weeks * 7);
+    }
+
+    /// The month code for the ordinal month of this date, assuming the calendar
+    /// has no leap months
+    #[inline]
+    pub fn solar_month(&self) -> types::Month {
+        types::Month {
+            number: self.month as u32,
+            code: month_code_for_ordinal(self.month),
+        }
+    }
+}
+
+/// The month code for a month in a calendar without leap months, e.g. "M01" for the
+/// first month of the year
+pub fn month_code_for_ordinal(month: u8) -> types::MonthCode {
+    let code = match month {
+        1 => tinystr8!("M01"),
+        2 => tinystr8!("M02"),
+        3 => tinystr8!("M03"),
+        4 => tinystr8!("M04"),
+        5 => tinystr8!("M05"),
+        6 => tinystr8!("M06"),
+        7 => tinystr8!("M07"),
+        8 => tinystr8!("M08"),
+        9 => tinystr8!("M09"),
+        10 => tinystr8!("M10"),
+        11 => tinystr8!("M11"),
+        12 => tinystr8!("M12"),
+        13 => tinystr8!("M13"),
+        _ => unreachable!("Calendars have at most 13 months"),
+    };
+    types::MonthCode(code)
+}
+
+/// The month code for the given month number, e.g. "M05" for the fifth month, or "M05L"
+/// for the leap month following it
+pub fn month_code(number: u8, is_leap: bool) -> types::MonthCode {
+    let digits = [b'M', b'0' + number / 10, b'0' + number % 10, b'L'];
+    let len = if is_leap { 4 } else { 3 };
+    types::MonthCode(TinyStr8::from_bytes(&digits[..len]).expect("Month codes are ASCII"))
+}
+
+/// Parse a month code into its month number and whether it designates a leap month,
+/// e.g. "M05L" into `(5, true)`
+pub fn parse_month_code(code: &types::MonthCode) -> Option<(u8, bool)> {
+    let bytes = code.0.as_bytes();
+    let is_leap = match bytes {
+        [b'M', _, _] => false,
+        [b'M', _, _, b'L'] => true,
+        _ => return None,
+    };
+    let (tens, ones) = (bytes[1], bytes[2]);
+    if !tens.is_ascii_digit() || !ones.is_ascii_digit() {
+        return None;
+    }
+    let number = (tens - b'0') * 10 + (ones - b'0');
+    if number == 0 {
+        return None;
+    }
+    Some((number, is_leap))
+}
+
+// Manual impls since the derives would place unnecessary bounds on `C`
+
+impl<C: CalendarArithmetic> Copy for ArithmeticDate<C> {}
+
+impl<C: CalendarArithmetic> Clone for ArithmeticDate<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: CalendarArithmetic> PartialEq for ArithmeticDate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.year == other.year && self.month == other.month && self.day == other.day
+    }
+}
+
+impl<C: CalendarArithmetic> Eq for ArithmeticDate<C> {}
+
+impl<C: CalendarArithmetic> Hash for ArithmeticDate<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.year.hash(state);
+        self.month.hash(state);
+        self.day.hash(state);
+    }
+}
+
+impl<C: CalendarArithmetic> fmt::Debug for ArithmeticDate<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("ArithmeticDate")
+            .field("year", &self.year)
+            .field("month", &self.month)
+            .field("day", &self.day)
+            .finish()
+    }
+}
@@ -0,0 +1,576 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Chinese calendar
+
+use crate::astronomy::{self, Moment, MEAN_SYNODIC_MONTH};
+use crate::calendar_arithmetic::{month_code, parse_month_code};
+use crate::iso::Iso;
+use crate::{
+    duration, types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+use tinystr::tinystr8;
+
+/// The number of years the Chinese extended year is ahead of the ISO year, counting
+/// from the traditional accession of the Yellow Emperor in 2637 BCE
+const CHINESE_YEAR_OFFSET: i32 = 2637;
+
+/// The Chinese Calendar
+///
+/// The Chinese calendar is an astronomical lunisolar calendar. Each month starts on the
+/// day of a new moon, and a leap month is inserted after any month that contains no
+/// major solar term in years that would otherwise have thirteen months, all as observed
+/// in Beijing.
+///
+/// Years are counted in the "chinese" era, as the extended year used by ICU (e.g. 4658
+/// for the year starting in 2021). Months are identified by the month codes "M01" through
+/// "M12", with a leap month having the code of the preceding month followed by "L", for
+/// example "M04L" for the leap month in 2020. Ordinal month numbers count months from
+/// the start of the year, including any leap month.
+///
+/// The calculations follow chapter 19 of Calendrical Calculations, and are most accurate
+/// for dates within a few centuries of the present.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+pub struct Chinese;
+
+/// The inner date type used for representing Date<Chinese>
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ChineseDateInner {
+    /// The extended year
+    year: i32,
+    /// The 1-indexed ordinal month, which may be a leap month
+    month: u8,
+    /// The 1-indexed day of the month
+    day: u8,
+    /// Cached information about `year`
+    year_info: ChineseYearInfo,
+}
+
+/// The astronomical information needed to work with the days of a single Chinese year,
+/// computed once per year since it is expensive to compute
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+struct ChineseYearInfo {
+    /// The fixed date of the first day of the year
+    new_year: i64,
+    /// The number of months in the year, 12 or 13
+    months: u8,
+    /// Bit `n` is set if the ordinal month `n + 1` has 30 days rather than 29
+    long_months: u16,
+    /// The ordinal month of the leap month, if any
+    leap_month: Option<u8>,
+}
+
+impl ChineseYearInfo {
+    /// Compute the year information for the year starting on the fixed date `new_year`
+    fn compute(new_year: i64) -> Self {
+        // The next new year is between 353 and 385 days away
+        let next_new_year = Chinese::new_year_on_or_before(new_year + 390);
+        let mut months = 0;
+        let mut long_months = 0;
+        let mut leap_month = None;
+        let mut month_start = new_year;
+        while month_start < next_new_year {
+            let next_month_start = Chinese::new_moon_on_or_after(month_start + 1);
+            if next_month_start - month_start == 30 {
+                long_months |= 1 << months;
+            }
+            months += 1;
+            // Only years with thirteen months have a leap month, and it is the first
+            // month without a major solar term in its leap sui (solstice-to-solstice year)
+            if leap_month.is_none()
+                && months > 1
+                && next_new_year - new_year > 365
+                && Chinese::no_major_solar_term(month_start)
+                && Chinese::is_in_leap_sui_before_leap_month(month_start)
+            {
+                leap_month = Some(months);
+            }
+            month_start = next_month_start;
+        }
+        ChineseYearInfo {
+            new_year,
+            months,
+            long_months,
+            leap_month,
+        }
+    }
+
+    /// Compute the year information for the given extended year
+    fn for_year(year: i32) -> Self {
+        let iso_year = year - CHINESE_YEAR_OFFSET;
+        // The new year is always in January or February
+        let mid_year = Iso::fixed_from_iso_integers(iso_year, 6, 1);
+        Self::compute(Chinese::new_year_on_or_before(mid_year))
+    }
+
+    fn days_in_month(&self, month: u8) -> u8 {
+        if self.long_months & (1 << (month - 1)) != 0 {
+            30
+        } else {
+            29
+        }
+    }
+
+    fn days_in_year(&self) -> u32 {
+        (1..=self.months)
+            .map(|month| self.days_in_month(month) as u32)
+            .sum()
+    }
+
+    /// The number of days in the year before the start of the given ordinal month
+    fn days_before_month(&self, month: u8) -> u32 {
+        (1..month)
+            .map(|month| self.days_in_month(month) as u32)
+            .sum()
+    }
+
+    /// The month number and whether it is a leap month, which make up the month code
+    fn month_number_and_leap(&self, month: u8) -> (u8, bool) {
+        match self.leap_month {
+            Some(leap) if month == leap => (month - 1, true),
+            Some(leap) if month > leap => (month - 1, false),
+            _ => (month, false),
+        }
+    }
+
+    /// The inverse of [`ChineseYearInfo::month_number_and_leap()`], returning `None` for
+    /// months that do not exist in this year
+    fn ordinal_for_month(&self, number: u8, is_leap: bool) -> Option<u8> {
+        if !(1..=12).contains(&number) {
+            return None;
+        }
+        match self.leap_month {
+            Some(leap) if is_leap && number + 1 == leap => Some(leap),
+            _ if is_leap => None,
+            Some(leap) if number >= leap => Some(number + 1),
+            _ => Some(number),
+        }
+    }
+}
+
+impl Calendar for Chinese {
+    type DateInner = ChineseDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> ChineseDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::chinese_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_chinese = Chinese::fixed_from_chinese(date);
+        Iso::iso_from_fixed(fixed_chinese)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.year_info.months
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.year_info.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.year_info.days_in_month(date.month)
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        if offset.years != 0 {
+            // Keep the same month when changing years, even though its ordinal may differ
+            let (number, is_leap) = date.year_info.month_number_and_leap(date.month);
+            date.year += offset.years;
+            date.year_info = ChineseYearInfo::for_year(date.year);
+            date.month = date
+                .year_info
+                .ordinal_for_month(number, is_leap)
+                // Leap months that do not exist in the new year become the regular month
+                .or_else(|| date.year_info.ordinal_for_month(number, false))
+                .unwrap_or(number);
+        }
+
+        let mut months = offset.months;
+        while months != 0 {
+            let month = date.month as i32 + months;
+            if month > date.year_info.months as i32 {
+                months -= date.year_info.months as i32 - date.month as i32 + 1;
+                date.year += 1;
+                date.year_info = ChineseYearInfo::for_year(date.year);
+                date.month = 1;
+            } else if month < 1 {
+                months += date.month as i32;
+                date.year -= 1;
+                date.year_info = ChineseYearInfo::for_year(date.year);
+                date.month = date.year_info.months;
+            } else {
+                date.month = month as u8;
+                months = 0;
+            }
+        }
+
+        // Constrain the day to the new month, which may be shorter
+        date.day = date.day.min(date.year_info.days_in_month(date.month));
+        let days = offset.days + offset.weeks * 7;
+        if days != 0 {
+            *date = Self::chinese_from_fixed(Self::fixed_from_chinese(date) + days as i64);
+        }
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        duration::until(self, date1, date2, largest_unit, smallest_unit)
+    }
+
+    fn debug_name() -> &'static str {
+        "Chinese"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Chinese)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        Self::year_as_chinese(date.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        let (number, is_leap) = date.year_info.month_number_and_leap(date.month);
+        types::Month {
+            number: date.month as u32,
+            code: month_code(number, is_leap),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: date.year_info.days_before_month(date.month) + date.day as u32,
+            days_in_year: date.year_info.days_in_year(),
+            prev_year: Self::year_as_chinese(date.year - 1),
+            days_in_prev_year: ChineseYearInfo::for_year(date.year - 1).days_in_year(),
+            next_year: Self::year_as_chinese(date.year + 1),
+        }
+    }
+}
+
+impl Chinese {
+    /// Construct a new Chinese Calendar
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn year_as_chinese(year: i32) -> types::Year {
+        types::Year {
+            era: types::Era(tinystr8!("chinese")),
+            number: year,
+            related_iso: year - CHINESE_YEAR_OFFSET,
+        }
+    }
+
+    /// The offset of Beijing time from Universal Time, in days
+    ///
+    /// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5180-L5187
+    fn zone(moment: Moment) -> f64 {
+        if Iso::iso_year_from_fixed(libm::floor(moment) as i64) < 1929 {
+            // The local mean time of Beijing (116°25' E)
+            1397.0 / 180.0 / 24.0
+        } else {
+            8.0 / 24.0
+        }
+    }
+
+    /// The moment of midnight at the start of the given day in Beijing, in Universal Time
+    fn midnight_in_china(date: i64) -> Moment {
+        let moment = date as Moment;
+        moment - Self::zone(moment)
+    }
+
+    /// The fixed date in Beijing at the given moment
+    fn fixed_in_china(moment: Moment) -> i64 {
+        libm::floor(moment + Self::zone(moment)) as i64
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5220-L5230
+    fn winter_solstice_on_or_before(date: i64) -> i64 {
+        let approx =
+            astronomy::estimate_prior_solar_longitude(270.0, Self::midnight_in_china(date + 1));
+        let mut day = libm::floor(approx) as i64 - 1;
+        while astronomy::solar_longitude(Self::midnight_in_china(day + 1)) < 270.0 {
+            day += 1;
+        }
+        day
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5232-L5238
+    fn new_moon_on_or_after(date: i64) -> i64 {
+        Self::fixed_in_china(astronomy::new_moon_at_or_after(Self::midnight_in_china(
+            date,
+        )))
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5240-L5246
+    fn new_moon_before(date: i64) -> i64 {
+        Self::fixed_in_china(astronomy::new_moon_before(Self::midnight_in_china(date)))
+    }
+
+    /// The index of the last major solar term (a multiple of 30° of solar longitude)
+    /// before the given date
+    ///
+    /// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5196-L5204
+    fn current_major_solar_term(date: i64) -> i64 {
+        let longitude = astronomy::solar_longitude(Self::midnight_in_china(date));
+        (2 + libm::floor(longitude / 30.0) as i64 - 1).rem_euclid(12) + 1
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5248-L5253
+    fn no_major_solar_term(date: i64) -> bool {
+        Self::current_major_solar_term(date)
+            == Self::current_major_solar_term(Self::new_moon_on_or_after(date + 1))
+    }
+
+    /// Whether there is a month without a major solar term in the range of months
+    /// starting on `start` and `end`, inclusive
+    ///
+    /// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5285-L5293
+    fn prior_leap_month(start: i64, mut end: i64) -> bool {
+        while end >= start {
+            if Self::no_major_solar_term(end) {
+                return true;
+            }
+            end = Self::new_moon_before(end);
+        }
+        false
+    }
+
+    /// Whether the month starting on `date` is in a sui (the period between two winter
+    /// solstices) with a leap month, with no earlier month in the sui being a leap month
+    fn is_in_leap_sui_before_leap_month(date: i64) -> bool {
+        let s1 = Self::winter_solstice_on_or_before(date);
+        let s2 = Self::winter_solstice_on_or_before(s1 + 370);
+        let m12 = Self::new_moon_on_or_after(s1 + 1);
+        let next_m11 = Self::new_moon_before(s2 + 1);
+        let is_leap_sui = libm::round((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH) as i64 == 12;
+        is_leap_sui && !Self::prior_leap_month(m12, Self::new_moon_before(date))
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5255-L5271
+    fn new_year_in_sui(date: i64) -> i64 {
+        let s1 = Self::winter_solstice_on_or_before(date);
+        let s2 = Self::winter_solstice_on_or_before(s1 + 370);
+        let m12 = Self::new_moon_on_or_after(s1 + 1);
+        let m13 = Self::new_moon_on_or_after(m12 + 1);
+        let next_m11 = Self::new_moon_before(s2 + 1);
+        let is_leap_sui = libm::round((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH) as i64 == 12;
+        if is_leap_sui && (Self::no_major_solar_term(m12) || Self::no_major_solar_term(m13)) {
+            Self::new_moon_on_or_after(m13 + 1)
+        } else {
+            m13
+        }
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5273-L5283
+    fn new_year_on_or_before(date: i64) -> i64 {
+        let new_year = Self::new_year_in_sui(date);
+        if date >= new_year {
+            new_year
+        } else {
+            Self::new_year_in_sui(date - 180)
+        }
+    }
+
+    fn fixed_from_chinese(date: &ChineseDateInner) -> i64 {
+        date.year_info.new_year
+            + date.year_info.days_before_month(date.month) as i64
+            + date.day as i64
+            - 1
+    }
+
+    fn chinese_from_fixed(date: i64) -> ChineseDateInner {
+        let year_info = ChineseYearInfo::compute(Self::new_year_on_or_before(date));
+        // The new year is always in the ISO year that the Chinese year is related to
+        let year = Iso::iso_year_from_fixed(year_info.new_year) + CHINESE_YEAR_OFFSET;
+        let mut remaining = (date - year_info.new_year) as u32;
+        let mut month = 1;
+        while remaining >= year_info.days_in_month(month) as u32 {
+            remaining -= year_info.days_in_month(month) as u32;
+            month += 1;
+        }
+        ChineseDateInner {
+            year,
+            month,
+            day: remaining as u8 + 1,
+            year_info,
+        }
+    }
+}
+
+impl Date<Chinese> {
+    /// Construct a new Chinese Date from a day, a month code, and an extended year.
+    ///
+    /// ```rust
+    /// use icu_calendar::{types::MonthCode, Date};
+    /// use tinystr::tinystr8;
+    ///
+    /// // The first day of the leap fourth month of the year starting in 2020
+    /// let date_chinese = Date::new_chinese_date(1, MonthCode(tinystr8!("M04L")), 4657)
+    ///     .expect("Failed to initialize Chinese Date instance.");
+    ///
+    /// assert_eq!(date_chinese.year().related_iso, 2020);
+    /// assert_eq!(date_chinese.month().number, 5);
+    /// assert_eq!(date_chinese.day_of_month().0, 1);
+    ///
+    /// let date_iso = date_chinese.to_iso();
+    /// assert_eq!(date_iso.month().number, 5);
+    /// assert_eq!(date_iso.day_of_month().0, 23);
+    ///
+    /// // There was no leap fourth month in the following year
+    /// assert!(Date::new_chinese_date(1, MonthCode(tinystr8!("M04L")), 4658).is_err());
+    /// ```
+    pub fn new_chinese_date(
+        day: u8,
+        month: types::MonthCode,
+        year: i32,
+    ) -> Result<Date<Chinese>, DateTimeError> {
+        let (number, is_leap) = parse_month_code(&month)
+            .filter(|&(number, _)| number <= 12)
+            .ok_or_else(|| DateTimeError::UnknownMonthCode(month.0, Chinese::debug_name()))?;
+        let year_info = ChineseYearInfo::for_year(year);
+        let month = year_info
+            .ordinal_for_month(number, is_leap)
+            .ok_or(DateTimeError::OutOfRange)?;
+        if day == 0 || day > year_info.days_in_month(month) {
+            return Err(DateTimeError::OutOfRange);
+        }
+        Ok(Date::from_raw(
+            ChineseDateInner {
+                year,
+                month,
+                day,
+                year_info,
+            },
+            Chinese,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn code(s: &str) -> types::MonthCode {
+        types::MonthCode(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_chinese_new_year() {
+        // (related ISO year, ISO month, ISO day) of the first day of the year
+        let cases = [
+            (1990, 1, 27),
+            (2000, 2, 5),
+            (2010, 2, 14),
+            (2017, 1, 28),
+            (2020, 1, 25),
+            (2021, 2, 12),
+            (2022, 2, 1),
+            (2023, 1, 22),
+            (2024, 2, 10),
+            (2025, 1, 29),
+        ];
+        for &(year, month, day) in &cases {
+            let iso = Date::new_iso_date_from_integers(day, month, year).unwrap();
+            let chinese = iso.to_calendar(Chinese);
+            assert_eq!(chinese.year().related_iso, year, "ISO {}", year);
+            assert_eq!(chinese.month().code, code("M01"), "ISO {}", year);
+            assert_eq!(chinese.day_of_month().0, 1, "ISO {}", year);
+            assert_eq!(chinese.to_iso(), iso);
+
+            let prev_day = iso.clone().added(DateDuration::new(0, 0, 0, -1));
+            let chinese = prev_day.to_calendar(Chinese);
+            assert_eq!(chinese.year().related_iso, year - 1, "ISO {}", year);
+            assert_eq!(chinese.month().code, code("M12"), "ISO {}", year);
+        }
+    }
+
+    #[test]
+    fn test_chinese_leap_months() {
+        // (related ISO year, leap month code, ISO month and day it starts on)
+        let cases = [
+            (2001, "M04L", 5, 23),
+            (2004, "M02L", 3, 21),
+            (2006, "M07L", 8, 24),
+            (2009, "M05L", 6, 23),
+            (2012, "M04L", 5, 21),
+            (2014, "M09L", 10, 24),
+            (2017, "M06L", 7, 23),
+            (2020, "M04L", 5, 23),
+            (2023, "M02L", 3, 22),
+            (2025, "M06L", 7, 25),
+            // A leap month after the eleventh month, which is famously tricky
+            (2033, "M11L", 12, 22),
+        ];
+        for &(year, leap_code, month, day) in &cases {
+            let chinese =
+                Date::new_chinese_date(1, code(leap_code), year + CHINESE_YEAR_OFFSET).unwrap();
+            let iso = Date::new_iso_date_from_integers(day, month, year).unwrap();
+            assert_eq!(chinese.to_iso(), iso, "{} {}", year, leap_code);
+            assert_eq!(iso.to_calendar(Chinese), chinese, "{} {}", year, leap_code);
+            assert_eq!(chinese.months_in_year(), 13, "{}", year);
+        }
+
+        let chinese = Date::new_iso_date_from_integers(1, 6, 2021)
+            .unwrap()
+            .to_calendar(Chinese);
+        assert_eq!(chinese.months_in_year(), 12);
+    }
+
+    #[test]
+    fn test_chinese_month_codes() {
+        let year_info = ChineseYearInfo::for_year(2020 + CHINESE_YEAR_OFFSET);
+        assert_eq!(year_info.leap_month, Some(5));
+        let codes: Vec<_> = (1..=13)
+            .map(|month| {
+                let (number, is_leap) = year_info.month_number_and_leap(month);
+                month_code(number, is_leap)
+            })
+            .collect();
+        assert_eq!(codes[3], code("M04"));
+        assert_eq!(codes[4], code("M04L"));
+        assert_eq!(codes[5], code("M05"));
+        assert_eq!(codes[12], code("M12"));
+        for month in 1..=13 {
+            let (number, is_leap) = year_info.month_number_and_leap(month);
+            assert_eq!(year_info.ordinal_for_month(number, is_leap), Some(month));
+        }
+        assert_eq!(year_info.ordinal_for_month(5, true), None);
+    }
+
+    #[test]
+    fn test_chinese_offset() {
+        // Adding a year keeps the month code, and leap months become regular months
+        let date = Date::new_chinese_date(10, code("M04L"), 4657).unwrap();
+        let expected = Date::new_chinese_date(10, code("M04"), 4658).unwrap();
+        assert_eq!(date.added(DateDuration::new(1, 0, 0, 0)), expected);
+
+        // Adding months counts the leap month
+        let date = Date::new_chinese_date(1, code("M04"), 4657).unwrap();
+        let expected = Date::new_chinese_date(1, code("M05"), 4657).unwrap();
+        assert_eq!(date.added(DateDuration::new(0, 2, 0, 0)), expected);
+
+        // Adding months across the end of the year
+        let date = Date::new_chinese_date(1, code("M12"), 4657).unwrap();
+        let expected = Date::new_chinese_date(1, code("M02"), 4658).unwrap();
+        assert_eq!(date.clone().added(DateDuration::new(0, 2, 0, 0)), expected);
+        assert_eq!(expected.added(DateDuration::new(0, -2, 0, 0)), date);
+
+        // Adding days
+        let date = Date::new_chinese_date(1, code("M01"), 4658).unwrap();
+        let expected = Date::new_iso_date_from_integers(13, 2, 2021).unwrap();
+        assert_eq!(date.added(DateDuration::new(0, 0, 0, 1)).to_iso(), expected);
+    }
+}
@@ -0,0 +1,243 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Coptic calendar
+
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::{
+    duration, types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+use tinystr::tinystr8;
+
+// The fixed date of 1 Thout, year 1 AM, which is August 29th, 284 CE in the Julian calendar
+//
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1978-L1981
+pub(crate) const COPTIC_EPOCH: i64 = 103605;
+
+/// The Coptic Calendar
+///
+/// The Coptic calendar has twelve months of thirty days, followed by an epagomenal month
+/// of five days, or six days in leap years. Every fourth year is a leap year.
+///
+/// Years are counted from the accession of Diocletian in 284 CE. Years from then onwards
+/// are in the "ad" (Anno Diocletiani) era, and earlier years are counted backwards in the
+/// "bd" (Before Diocletian) era.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+pub struct Coptic;
+
+/// The inner date type used for representing Date<Coptic>
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct CopticDateInner(ArithmeticDate<Coptic>);
+
+impl CalendarArithmetic for Coptic {
+    fn month_days(year: i32, month: u8) -> u8 {
+        if (1..=12).contains(&month) {
+            30
+        } else if month == 13 {
+            if Self::is_leap_year(year) {
+                6
+            } else {
+                5
+            }
+        } else {
+            0
+        }
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        13
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2010-L2013
+    fn is_leap_year(year: i32) -> bool {
+        year.rem_euclid(4) == 3
+    }
+}
+
+impl Calendar for Coptic {
+    type DateInner = CopticDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> CopticDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        CopticDateInner(Self::coptic_from_fixed(fixed_iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(Self::fixed_from_coptic(date.0))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        duration::until(self, date1, date2, largest_unit, smallest_unit)
+    }
+
+    fn debug_name() -> &'static str {
+        "Coptic"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Coptic)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        year_as_coptic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.0.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_coptic(date.0.year - 1),
+            days_in_prev_year: Coptic::days_in_provided_year(date.0.year - 1),
+            next_year: year_as_coptic(date.0.year + 1),
+        }
+    }
+}
+
+impl Coptic {
+    /// Construct a new Coptic Calendar
+    pub fn new() -> Self {
+        Self
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2015-L2024
+    pub(crate) fn fixed_from_coptic(date: ArithmeticDate<Coptic>) -> i64 {
+        let year = date.year as i64;
+        COPTIC_EPOCH - 1 + 365 * (year - 1) + year.div_euclid(4) + date.day_of_year() as i64
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2026-L2038
+    pub(crate) fn coptic_from_fixed(date: i64) -> ArithmeticDate<Coptic> {
+        let year = (4 * (date - COPTIC_EPOCH) + 1463).div_euclid(1461) as i32;
+        let new_year = Self::fixed_from_coptic(ArithmeticDate::new(year, 1, 1));
+        ArithmeticDate::from_year_and_day_of_year(year, (date - new_year + 1) as u32)
+    }
+}
+
+impl Date<Coptic> {
+    /// Construct a new Coptic Date.
+    ///
+    /// Years are specified in the "ad" era, with year 0 being 1 BD (Before Diocletian).
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    ///
+    /// let date_coptic = Date::new_coptic_date_from_integers(1, 1, 1738)
+    ///     .expect("Failed to initialize Coptic Date instance.");
+    ///
+    /// assert_eq!(date_coptic.year().era.0.as_str(), "ad");
+    /// assert_eq!(date_coptic.year().number, 1738);
+    ///
+    /// let date_iso = date_coptic.to_iso();
+    /// assert_eq!(date_iso.year().number, 2021);
+    /// assert_eq!(date_iso.month().number, 9);
+    /// assert_eq!(date_iso.day_of_month().0, 11);
+    /// ```
+    pub fn new_coptic_date_from_integers(
+        day: u8,
+        month: u8,
+        year: i32,
+    ) -> Result<Date<Coptic>, DateTimeError> {
+        ArithmeticDate::new_checked(year, month, day)
+            .map(|inner| Date::from_raw(CopticDateInner(inner), Coptic))
+            .ok_or(DateTimeError::OutOfRange)
+    }
+}
+
+fn year_as_coptic(year: i32) -> types::Year {
+    // The Coptic year starts in August or September of the ISO year
+    let related_iso = year + 283;
+    if year > 0 {
+        types::Year {
+            era: types::Era(tinystr8!("ad")),
+            number: year,
+            related_iso,
+        }
+    } else {
+        types::Year {
+            era: types::Era(tinystr8!("bd")),
+            number: 1 - year,
+            related_iso,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_coptic_iso_conversion() {
+        // (coptic day, month, year), (iso day, month, year)
+        let cases = [
+            ((1, 1, 1), (29, 8, 284)),
+            ((5, 13, 1737), (10, 9, 2021)),
+            ((1, 1, 1738), (11, 9, 2021)),
+            ((6, 13, 1735), (11, 9, 2019)),
+            ((1, 1, 1736), (12, 9, 2019)),
+        ];
+        for &((cd, cm, cy), (id, im, iy)) in &cases {
+            let coptic = Date::new_coptic_date_from_integers(cd, cm, cy).unwrap();
+            let iso = Date::new_iso_date_from_integers(id, im, iy).unwrap();
+            assert_eq!(coptic.to_iso(), iso, "Coptic {}-{}-{}", cy, cm, cd);
+            assert_eq!(iso.to_calendar(Coptic), coptic, "ISO {}-{}-{}", iy, im, id);
+        }
+    }
+
+    #[test]
+    fn test_coptic_eras() {
+        let date = Date::new_coptic_date_from_integers(1, 1, 0).unwrap();
+        assert_eq!(date.year().era, types::Era(tinystr8!("bd")));
+        assert_eq!(date.year().number, 1);
+        let date = Date::new_coptic_date_from_integers(1, 1, 1).unwrap();
+        assert_eq!(date.year().era, types::Era(tinystr8!("ad")));
+        assert_eq!(date.year().number, 1);
+    }
+
+    #[test]
+    fn test_coptic_arithmetic() {
+        let date = Date::new_coptic_date_from_integers(30, 12, 1735).unwrap();
+        assert_eq!(date.days_in_year(), 366);
+        let expected = Date::new_coptic_date_from_integers(1, 1, 1736).unwrap();
+        assert_eq!(date.added(DateDuration::new(0, 0, 0, 7)), expected);
+
+        let date = Date::new_coptic_date_from_integers(1, 13, 1737).unwrap();
+        assert_eq!(date.days_in_month(), 5);
+        let expected = Date::new_coptic_date_from_integers(1, 2, 1738).unwrap();
+        assert_eq!(date.clone().added(DateDuration::new(0, 2, 0, 0)), expected);
+
+        let difference = expected.until(&date, DateDurationUnit::Months, DateDurationUnit::Days);
+        assert_eq!(difference.years, 0);
+        assert_eq!(difference.months, -2);
+    }
+}
@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use crate::{Calendar, DateDuration, DateDurationUnit, Iso};
+use crate::{types, Calendar, DateDuration, DateDurationUnit, DateTimeError, Iso, Overflow};
 use core::fmt;
 
 /// Types that contain a calendar
@@ -26,7 +26,7 @@ impl<C: Calendar> AsCalendar for C {
 
 /// A date for a given calendar
 ///
-/// This can work with wrappers arount [`Calendar`] types,
+/// This can work with wrappers around [`Calendar`] types,
 /// e.g. `Rc<C>`, via the [`AsCalendar`] trait
 pub struct Date<A: AsCalendar> {
     inner: <A::Calendar as Calendar>::DateInner,
@@ -47,6 +47,12 @@ impl<A: AsCalendar> Date<A> {
         self.calendar.as_calendar().date_to_iso(self.inner())
     }
 
+    /// Convert the Date to a date in a different calendar
+    #[inline]
+    pub fn to_calendar<A2: AsCalendar>(&self, calendar: A2) -> Date<A2> {
+        Date::new_from_iso(self.to_iso(), calendar)
+    }
+
     /// The number of months in the year of this date
     #[inline]
     pub fn months_in_year(&self) -> u8 {
@@ -74,6 +80,9 @@ impl<A: AsCalendar> Date<A> {
     }
 
     /// Add a `duration` to this date, mutating it
+    ///
+    /// Dates that do not exist after adding the years and months of the `duration`
+    /// are constrained, see [`Overflow::Constrain`].
     #[inline]
     pub fn add(&mut self, duration: DateDuration<A::Calendar>) {
         self.calendar
@@ -88,7 +97,71 @@ impl<A: AsCalendar> Date<A> {
         self
     }
 
+    /// Add a `duration` to this date, mutating it, handling dates that do not exist
+    /// after adding the years and months of the `duration` according to `overflow`
+    ///
+    /// With [`Overflow::Reject`], this returns an error and leaves the date unchanged
+    /// when the day of the month, or a leap month, does not exist.
+    ///
+    /// ```rust
+    /// use icu_calendar::{Date, DateDuration, Overflow};
+    ///
+    /// let mut date = Date::new_iso_date_from_integers(31, 1, 2021).unwrap();
+    /// let one_month = DateDuration::new(0, 1, 0, 0);
+    /// assert!(date
+    ///     .try_add_with_overflow(one_month, Overflow::Reject)
+    ///     .is_err());
+    /// assert_eq!(date.day_of_month().0, 31);
+    ///
+    /// date.try_add_with_overflow(one_month, Overflow::Constrain)
+    ///     .expect("Constraining never fails");
+    /// assert_eq!(date.month().number, 2);
+    /// assert_eq!(date.day_of_month().0, 28);
+    /// ```
+    pub fn try_add_with_overflow(
+        &mut self,
+        duration: DateDuration<A::Calendar>,
+        overflow: Overflow,
+    ) -> Result<(), DateTimeError> {
+        let calendar = self.calendar.as_calendar();
+        if overflow == Overflow::Reject {
+            let mut intermediate = self.inner.clone();
+            calendar.offset_date(
+                &mut intermediate,
+                DateDuration::new(duration.years, duration.months, 0, 0),
+            );
+            let day_constrained =
+                calendar.day_of_month(&intermediate) != calendar.day_of_month(&self.inner);
+            // Changing only the year keeps the month, unless it is a leap month that does
+            // not exist in the new year
+            let month_constrained = duration.months == 0
+                && calendar.month(&intermediate).code != calendar.month(&self.inner).code;
+            if day_constrained || month_constrained {
+                return Err(DateTimeError::OutOfRange);
+            }
+        }
+        calendar.offset_date(&mut self.inner, duration);
+        Ok(())
+    }
+
     /// Calculating the duration between `other - self`
+    ///
+    /// The duration is balanced so that no unit is larger than `largest_unit`, and units
+    /// smaller than `smallest_unit` are truncated, following the `until` method of the
+    /// [Temporal proposal](https://tc39.es/proposal-temporal/).
+    ///
+    /// ```rust
+    /// use icu_calendar::{Date, DateDurationUnit};
+    ///
+    /// let date1 = Date::new_iso_date_from_integers(31, 1, 2019).unwrap();
+    /// let date2 = Date::new_iso_date_from_integers(1, 3, 2019).unwrap();
+    ///
+    /// let duration = date1.until(&date2, DateDurationUnit::Months, DateDurationUnit::Days);
+    /// assert_eq!((duration.months, duration.days), (1, 1));
+    ///
+    /// let duration = date2.until(&date1, DateDurationUnit::Weeks, DateDurationUnit::Days);
+    /// assert_eq!((duration.weeks, duration.days), (-4, -1));
+    /// ```
     #[inline]
     pub fn until<B: AsCalendar<Calendar = A::Calendar>>(
         &self,
@@ -101,6 +174,30 @@ impl<A: AsCalendar> Date<A> {
             .until(self.inner(), other.inner(), largest_unit, smallest_unit)
     }
 
+    /// The calendar-specific year represented by `self`
+    #[inline]
+    pub fn year(&self) -> types::Year {
+        self.calendar.as_calendar().year(&self.inner)
+    }
+
+    /// The calendar-specific month represented by `self`
+    #[inline]
+    pub fn month(&self) -> types::Month {
+        self.calendar.as_calendar().month(&self.inner)
+    }
+
+    /// The calendar-specific day-of-month represented by `self`
+    #[inline]
+    pub fn day_of_month(&self) -> types::DayOfMonth {
+        self.calendar.as_calendar().day_of_month(&self.inner)
+    }
+
+    /// Information about the day of the year for `self`
+    #[inline]
+    pub fn day_of_year_info(&self) -> types::DayOfYearInfo {
+        self.calendar.as_calendar().day_of_year_info(&self.inner)
+    }
+
     /// Construct a date from raw values for a given calendar. This does not check any
     /// invariants for the date and calendar, and should only be called by calendar implementations.
     ///
@@ -116,6 +213,12 @@ impl<A: AsCalendar> Date<A> {
     pub fn inner(&self) -> &<A::Calendar as Calendar>::DateInner {
         &self.inner
     }
+
+    /// Get a reference to the contained calendar
+    #[inline]
+    pub fn calendar(&self) -> &A::Calendar {
+        self.calendar.as_calendar()
+    }
 }
 
 impl<C, A, B> PartialEq<Date<B>> for Date<A>
@@ -0,0 +1,242 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::types::{IsoHour, IsoMinute, IsoSecond, NanoSecond};
+use crate::{AsCalendar, Calendar, Date, DateDuration, DateTimeError, Iso};
+use core::convert::TryInto;
+use core::fmt;
+
+/// A time of day, according to ISO-8601 conventions
+///
+/// This is always indexed from midnight, regardless of calendar system.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Time {
+    /// 0-based hour.
+    pub hour: IsoHour,
+
+    /// 0-based minute.
+    pub minute: IsoMinute,
+
+    /// 0-based second.
+    pub second: IsoSecond,
+
+    /// Fractional second, in nanoseconds.
+    pub nanosecond: NanoSecond,
+}
+
+const MINUTES_IN_DAY: i64 = 24 * 60;
+
+impl Time {
+    /// Construct a new [`Time`] from already validated components
+    pub const fn new(
+        hour: IsoHour,
+        minute: IsoMinute,
+        second: IsoSecond,
+        nanosecond: NanoSecond,
+    ) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        }
+    }
+
+    /// Construct a new [`Time`], validating the inputs
+    ///
+    /// ```rust
+    /// use icu_calendar::Time;
+    ///
+    /// let time = Time::try_new(13, 37, 0, 0).expect("Failed to construct Time.");
+    /// assert_eq!(u8::from(time.hour), 13);
+    ///
+    /// assert!(Time::try_new(13, 61, 0, 0).is_err());
+    /// ```
+    pub fn try_new(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Result<Self, DateTimeError> {
+        Ok(Self {
+            hour: hour.try_into()?,
+            minute: minute.try_into()?,
+            second: second.try_into()?,
+            nanosecond: nanosecond.try_into()?,
+        })
+    }
+
+    /// Add `hours` to this time, returning the number of days by which the time
+    /// rolled over
+    ///
+    /// ```rust
+    /// use icu_calendar::Time;
+    ///
+    /// let mut time = Time::try_new(22, 30, 0, 0).expect("Failed to construct Time.");
+    /// assert_eq!(time.add_hours(3), 1);
+    /// assert_eq!(time, Time::try_new(1, 30, 0, 0).unwrap());
+    /// ```
+    pub fn add_hours(&mut self, hours: i32) -> i32 {
+        self.add_minutes_i64(hours as i64 * 60)
+    }
+
+    /// Add `minutes` to this time, returning the number of days by which the time
+    /// rolled over
+    ///
+    /// Subtracting minutes, with a negative `minutes`, may roll the time back to a
+    /// previous day, in which case the returned number of days is negative.
+    pub fn add_minutes(&mut self, minutes: i32) -> i32 {
+        self.add_minutes_i64(minutes as i64)
+    }
+
+    fn add_minutes_i64(&mut self, minutes: i64) -> i32 {
+        let total = u8::from(self.hour) as i64 * 60 + u8::from(self.minute) as i64 + minutes;
+        let minute_of_day = total.rem_euclid(MINUTES_IN_DAY);
+        self.hour = IsoHour::new_unchecked((minute_of_day / 60) as u8);
+        self.minute = IsoMinute::new_unchecked((minute_of_day % 60) as u8);
+        total.div_euclid(MINUTES_IN_DAY) as i32
+    }
+}
+
+/// A date and time for a given calendar
+///
+/// This can work with wrappers around [`Calendar`] types,
+/// e.g. `Rc<C>`, via the [`AsCalendar`] trait, much like
+/// [`Date`].
+pub struct DateTime<A: AsCalendar> {
+    /// The date
+    pub date: Date<A>,
+    /// The time
+    pub time: Time,
+}
+
+impl<A: AsCalendar> DateTime<A> {
+    /// Construct a DateTime for a given [`Date`] and [`Time`]
+    pub fn new(date: Date<A>, time: Time) -> Self {
+        DateTime { date, time }
+    }
+
+    /// Construct a datetime from an ISO datetime and some calendar representation
+    #[inline]
+    pub fn new_from_iso(iso: DateTime<Iso>, calendar: A) -> Self {
+        let date = Date::new_from_iso(iso.date, calendar);
+        DateTime {
+            date,
+            time: iso.time,
+        }
+    }
+
+    /// Convert the DateTime to an ISO DateTime
+    #[inline]
+    pub fn to_iso(&self) -> DateTime<Iso> {
+        DateTime {
+            date: self.date.to_iso(),
+            time: self.time,
+        }
+    }
+
+    /// Convert the DateTime to a DateTime in a different calendar
+    #[inline]
+    pub fn to_calendar<A2: AsCalendar>(&self, calendar: A2) -> DateTime<A2> {
+        DateTime {
+            date: self.date.to_calendar(calendar),
+            time: self.time,
+        }
+    }
+
+    /// Add `hours` to this datetime, moving to a different day if necessary
+    ///
+    /// ```rust
+    /// use icu_calendar::DateTime;
+    ///
+    /// let mut datetime = DateTime::new_iso_datetime_from_integers(2021, 12, 31, 22, 30, 0)
+    ///     .expect("Failed to construct DateTime.");
+    /// datetime.add_hours(3);
+    ///
+    /// assert_eq!(datetime.date.year().number, 2022);
+    /// assert_eq!(datetime.date.month().number, 1);
+    /// assert_eq!(datetime.date.day_of_month().0, 1);
+    /// assert_eq!(u8::from(datetime.time.hour), 1);
+    /// ```
+    pub fn add_hours(&mut self, hours: i32) {
+        let days = self.time.add_hours(hours);
+        self.add_days(days);
+    }
+
+    /// Add `minutes` to this datetime, moving to a different day if necessary
+    pub fn add_minutes(&mut self, minutes: i32) {
+        let days = self.time.add_minutes(minutes);
+        self.add_days(days);
+    }
+
+    fn add_days(&mut self, days: i32) {
+        if days != 0 {
+            self.date.add(DateDuration::new(0, 0, 0, days));
+        }
+    }
+}
+
+impl<C, A, B> PartialEq<DateTime<B>> for DateTime<A>
+where
+    C: Calendar,
+    A: AsCalendar<Calendar = C>,
+    B: AsCalendar<Calendar = C>,
+{
+    fn eq(&self, other: &DateTime<B>) -> bool {
+        self.date == other.date && self.time == other.time
+    }
+}
+
+impl<A: AsCalendar> fmt::Debug for DateTime<A>
+where
+    Date<A>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("DateTime")
+            .field("date", &self.date)
+            .field("time", &self.time)
+            .finish()
+    }
+}
+
+impl<A: AsCalendar + Clone> Clone for DateTime<A> {
+    fn clone(&self) -> Self {
+        Self {
+            date: self.date.clone(),
+            time: self.time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_time_rollover() {
+        let mut time = Time::try_new(23, 59, 30, 0).unwrap();
+        assert_eq!(time.add_minutes(1), 1);
+        assert_eq!(time, Time::try_new(0, 0, 30, 0).unwrap());
+        assert_eq!(time.add_minutes(-1), -1);
+        assert_eq!(time, Time::try_new(23, 59, 30, 0).unwrap());
+        assert_eq!(time.add_hours(-48), -2);
+        assert_eq!(time, Time::try_new(23, 59, 30, 0).unwrap());
+        assert_eq!(time.add_hours(0), 0);
+    }
+
+    #[test]
+    fn test_datetime_rollover() {
+        let mut datetime =
+            DateTime::new_gregorian_datetime_from_integers(2020, 2, 28, 23, 0, 0).unwrap();
+        datetime.add_hours(2);
+        let expected =
+            DateTime::new_gregorian_datetime_from_integers(2020, 2, 29, 1, 0, 0).unwrap();
+        assert_eq!(datetime, expected);
+
+        datetime.add_minutes(-2 * 24 * 60 - 61);
+        let expected =
+            DateTime::new_gregorian_datetime_from_integers(2020, 2, 26, 23, 59, 0).unwrap();
+        assert_eq!(datetime, expected);
+    }
+}
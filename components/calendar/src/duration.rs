@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use crate::Calendar;
+use crate::{Calendar, Iso};
 use core::fmt;
 use core::marker::PhantomData;
 
@@ -35,6 +35,25 @@ pub enum DateDurationUnit {
     Days,
 }
 
+/// How to handle a date that does not exist after adding years or months, e.g. one
+/// month after January 31st
+///
+/// This follows the `overflow` option of the [Temporal proposal](https://tc39.es/proposal-temporal/).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Overflow {
+    /// Move to the closest date that exists, e.g. the last day of the month, or the
+    /// regular month when a leap month does not exist in the new year
+    Constrain,
+    /// Produce an error
+    Reject,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Overflow::Constrain
+    }
+}
+
 impl<C: Calendar + ?Sized> Default for DateDuration<C> {
     fn default() -> Self {
         Self {
@@ -64,6 +83,119 @@ impl<C: Calendar + ?Sized> DateDuration<C> {
             marker: PhantomData,
         }
     }
+
+    /// Cast the duration to a duration of a different calendar, keeping the same
+    /// field values
+    ///
+    /// This is useful for calendars that share their arithmetic with another calendar
+    #[inline]
+    pub fn cast_unit<C2: Calendar + ?Sized>(self) -> DateDuration<C2> {
+        DateDuration {
+            years: self.years,
+            months: self.months,
+            weeks: self.weeks,
+            days: self.days,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Calculate `date2 - date1` for any calendar, following the
+/// [Temporal proposal](https://tc39.es/proposal-temporal/#sec-temporal-differenceisodate)
+///
+/// Years and months are counted by adding them to `date1` with [`Overflow::Constrain`] for
+/// as long as the result does not pass `date2`, and the remainder is counted in weeks and
+/// days. Units smaller than `smallest_unit` are truncated towards zero. The duration is
+/// negative when `date2` is before `date1`.
+pub(crate) fn until<C: Calendar>(
+    calendar: &C,
+    date1: &C::DateInner,
+    date2: &C::DateInner,
+    mut largest_unit: DateDurationUnit,
+    smallest_unit: DateDurationUnit,
+) -> DateDuration<C> {
+    // The largest unit must be at least as large as the smallest unit
+    largest_unit = match (largest_unit, smallest_unit) {
+        (_, DateDurationUnit::Years) => DateDurationUnit::Years,
+        (DateDurationUnit::Weeks, DateDurationUnit::Months)
+        | (DateDurationUnit::Days, DateDurationUnit::Months) => DateDurationUnit::Months,
+        (DateDurationUnit::Days, DateDurationUnit::Weeks) => DateDurationUnit::Weeks,
+        (largest, _) => largest,
+    };
+
+    let fixed = |date: &C::DateInner| Iso::fixed_from_iso(*calendar.date_to_iso(date).inner());
+    let offset = |years: i32, months: i32| {
+        let mut date = date1.clone();
+        calendar.offset_date(&mut date, DateDuration::new(years, months, 0, 0));
+        date
+    };
+    let end = fixed(date2);
+    let sign = (end - fixed(date1)).signum() as i32;
+
+    let mut duration = DateDuration::default();
+    let start = match largest_unit {
+        DateDurationUnit::Years | DateDurationUnit::Months if sign != 0 => {
+            let passes_end =
+                |years, months| (fixed(&offset(years, months)) - end) * sign as i64 > 0;
+            let years = if largest_unit == DateDurationUnit::Years {
+                largest_fitting_offset(sign, |years| passes_end(years, 0))
+            } else {
+                0
+            };
+            let months = largest_fitting_offset(sign, |months| passes_end(years, months));
+            duration.years = years;
+            duration.months = months;
+            fixed(&offset(years, months))
+        }
+        _ => fixed(date1),
+    };
+    duration.days = (end - start) as i32;
+    if largest_unit == DateDurationUnit::Weeks {
+        duration.weeks = duration.days / 7;
+        duration.days %= 7;
+    }
+
+    match smallest_unit {
+        DateDurationUnit::Years => {
+            duration.months = 0;
+            duration.weeks = 0;
+            duration.days = 0;
+        }
+        DateDurationUnit::Months => {
+            duration.weeks = 0;
+            duration.days = 0;
+        }
+        DateDurationUnit::Weeks => {
+            duration.weeks += duration.days / 7;
+            duration.days = 0;
+        }
+        DateDurationUnit::Days => {}
+    }
+    duration
+}
+
+/// Find the largest offset, in the direction of `sign`, for which `passes_end` is false
+///
+/// `passes_end` must be false for 0 and monotonic, so the offset can be found by doubling
+/// it until it passes the end and then bisecting, which avoids stepping through every
+/// month of long durations.
+fn largest_fitting_offset(sign: i32, passes_end: impl Fn(i32) -> bool) -> i32 {
+    let mut fitting = 0;
+    let mut step = 1;
+    while !passes_end((fitting + step) * sign) {
+        fitting += step;
+        step *= 2;
+    }
+    let mut passing = fitting + step;
+    while passing - fitting > 1 {
+        let middle = fitting + (passing - fitting) / 2;
+        if passes_end(middle * sign) {
+            passing = middle;
+        } else {
+            fitting = middle;
+        }
+    }
+    fitting * sign
 }
 
 impl<C: Calendar> fmt::Debug for DateDuration<C> {
@@ -76,3 +208,294 @@ impl<C: Calendar> fmt::Debug for DateDuration<C> {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::MonthCode;
+    use crate::{Chinese, Coptic, Date, Hebrew, IslamicCivil, Julian};
+    use tinystr::tinystr8;
+
+    fn iso(year: i32, month: u8, day: u8) -> Date<Iso> {
+        Date::new_iso_date_from_integers(day, month, year).unwrap()
+    }
+
+    fn fields<C: Calendar>(duration: DateDuration<C>) -> (i32, i32, i32, i32) {
+        (
+            duration.years,
+            duration.months,
+            duration.weeks,
+            duration.days,
+        )
+    }
+
+    // Cases modeled on the Temporal test suite for `PlainDate.prototype.until`:
+    // (date1, date2, [duration with each largest unit, from years to days])
+    #[allow(clippy::type_complexity)]
+    const UNTIL_CASES: &[((i32, u8, u8), (i32, u8, u8), [(i32, i32, i32, i32); 4])] = &[
+        (
+            (2020, 2, 1),
+            (2021, 2, 1),
+            [(1, 0, 0, 0), (0, 12, 0, 0), (0, 0, 52, 2), (0, 0, 0, 366)],
+        ),
+        (
+            (1969, 7, 24),
+            (1969, 10, 5),
+            [(0, 2, 0, 11), (0, 2, 0, 11), (0, 0, 10, 3), (0, 0, 0, 73)],
+        ),
+        (
+            (1969, 7, 24),
+            (1996, 3, 3),
+            [
+                (26, 7, 0, 8),
+                (0, 319, 0, 8),
+                (0, 0, 1388, 3),
+                (0, 0, 0, 9719),
+            ],
+        ),
+        // Adding months constrains the day, so the remaining days count from the end of
+        // the shorter month
+        (
+            (2019, 1, 31),
+            (2019, 3, 1),
+            [(0, 1, 0, 1), (0, 1, 0, 1), (0, 0, 4, 1), (0, 0, 0, 29)],
+        ),
+        (
+            (2019, 3, 1),
+            (2019, 1, 31),
+            [
+                (0, -1, 0, -1),
+                (0, -1, 0, -1),
+                (0, 0, -4, -1),
+                (0, 0, 0, -29),
+            ],
+        ),
+        (
+            (2019, 1, 31),
+            (2019, 6, 30),
+            [(0, 5, 0, 0), (0, 5, 0, 0), (0, 0, 21, 3), (0, 0, 0, 150)],
+        ),
+        (
+            (2019, 6, 30),
+            (2019, 1, 31),
+            [
+                (0, -4, 0, -28),
+                (0, -4, 0, -28),
+                (0, 0, -21, -3),
+                (0, 0, 0, -150),
+            ],
+        ),
+        (
+            (2020, 1, 31),
+            (2020, 2, 29),
+            [(0, 1, 0, 0), (0, 1, 0, 0), (0, 0, 4, 1), (0, 0, 0, 29)],
+        ),
+        (
+            (2020, 3, 31),
+            (2020, 2, 29),
+            [(0, -1, 0, 0), (0, -1, 0, 0), (0, 0, -4, -3), (0, 0, 0, -31)],
+        ),
+        // Leap days
+        (
+            (2020, 2, 29),
+            (2021, 2, 28),
+            [(1, 0, 0, 0), (0, 12, 0, 0), (0, 0, 52, 1), (0, 0, 0, 365)],
+        ),
+        (
+            (2021, 2, 28),
+            (2020, 2, 29),
+            [
+                (0, -11, 0, -28),
+                (0, -11, 0, -28),
+                (0, 0, -52, -1),
+                (0, 0, 0, -365),
+            ],
+        ),
+        (
+            (2020, 2, 29),
+            (2024, 2, 28),
+            [
+                (3, 11, 0, 30),
+                (0, 47, 0, 30),
+                (0, 0, 208, 4),
+                (0, 0, 0, 1460),
+            ],
+        ),
+        (
+            (2019, 1, 8),
+            (2021, 9, 7),
+            [
+                (2, 7, 0, 30),
+                (0, 31, 0, 30),
+                (0, 0, 139, 0),
+                (0, 0, 0, 973),
+            ],
+        ),
+        (
+            (2021, 9, 7),
+            (2019, 1, 8),
+            [
+                (-2, -7, 0, -30),
+                (0, -31, 0, -30),
+                (0, 0, -139, 0),
+                (0, 0, 0, -973),
+            ],
+        ),
+        (
+            (2021, 1, 15),
+            (2019, 12, 20),
+            [
+                (-1, 0, 0, -26),
+                (0, -12, 0, -26),
+                (0, 0, -56, 0),
+                (0, 0, 0, -392),
+            ],
+        ),
+        (
+            (2021, 9, 7),
+            (2021, 9, 7),
+            [(0, 0, 0, 0), (0, 0, 0, 0), (0, 0, 0, 0), (0, 0, 0, 0)],
+        ),
+    ];
+
+    const UNITS: [DateDurationUnit; 4] = [
+        DateDurationUnit::Years,
+        DateDurationUnit::Months,
+        DateDurationUnit::Weeks,
+        DateDurationUnit::Days,
+    ];
+
+    #[test]
+    fn test_until_largest_unit() {
+        for &(date1, date2, expected) in UNTIL_CASES {
+            let date1 = iso(date1.0, date1.1, date1.2);
+            let date2 = iso(date2.0, date2.1, date2.2);
+            for (&largest_unit, &expected) in UNITS.iter().zip(expected.iter()) {
+                let duration = date1.until(&date2, largest_unit, DateDurationUnit::Days);
+                assert_eq!(
+                    fields(duration),
+                    expected,
+                    "{:?} until {:?} in {:?}",
+                    date1,
+                    date2,
+                    largest_unit
+                );
+                // Adding the duration back always reaches the later date
+                assert_eq!(date1.clone().added(duration), date2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_until_smallest_unit() {
+        let earlier = iso(2019, 1, 8);
+        let later = iso(2021, 9, 7);
+        let cases = [
+            (DateDurationUnit::Years, (2, 0, 0, 0)),
+            (DateDurationUnit::Months, (2, 7, 0, 0)),
+            (DateDurationUnit::Weeks, (2, 7, 4, 0)),
+            (DateDurationUnit::Days, (2, 7, 0, 30)),
+        ];
+        for &(smallest_unit, (years, months, weeks, days)) in &cases {
+            let duration = earlier.until(&later, DateDurationUnit::Years, smallest_unit);
+            assert_eq!(fields(duration), (years, months, weeks, days));
+            // Truncation is towards zero, so negative durations mirror positive ones
+            let duration = later.until(&earlier, DateDurationUnit::Years, smallest_unit);
+            assert_eq!(fields(duration), (-years, -months, -weeks, -days));
+        }
+
+        // The largest unit is never smaller than the smallest unit
+        let duration = earlier.until(&later, DateDurationUnit::Days, DateDurationUnit::Months);
+        assert_eq!(fields(duration), (0, 31, 0, 0));
+        let duration = earlier.until(&later, DateDurationUnit::Days, DateDurationUnit::Weeks);
+        assert_eq!(fields(duration), (0, 0, 139, 0));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let one_month = DateDuration::new(0, 1, 0, 0);
+        let one_year = DateDuration::new(1, 0, 0, 0);
+
+        let mut date = iso(2021, 1, 31);
+        assert!(date
+            .try_add_with_overflow(one_month, Overflow::Reject)
+            .is_err());
+        assert_eq!(date, iso(2021, 1, 31));
+        date.try_add_with_overflow(one_month, Overflow::Constrain)
+            .unwrap();
+        assert_eq!(date, iso(2021, 2, 28));
+        // Days are added after constraining
+        assert_eq!(
+            iso(2021, 1, 31).added(DateDuration::new(0, 1, 0, 1)),
+            iso(2021, 3, 1)
+        );
+        assert_eq!(
+            iso(2020, 3, 31).added(DateDuration::new(0, -1, 0, 0)),
+            iso(2020, 2, 29)
+        );
+
+        let mut date = iso(2020, 2, 29);
+        assert!(date
+            .try_add_with_overflow(one_year, Overflow::Reject)
+            .is_err());
+        date.try_add_with_overflow(DateDuration::new(4, 0, 0, 0), Overflow::Reject)
+            .unwrap();
+        assert_eq!(date, iso(2024, 2, 29));
+        date.try_add_with_overflow(DateDuration::new(-1, 0, 0, 0), Overflow::Constrain)
+            .unwrap();
+        assert_eq!(date, iso(2023, 2, 28));
+
+        // Adar I only exists in leap years
+        let adar_i = MonthCode(tinystr8!("M05L"));
+        let mut date = Date::new_hebrew_date(1, adar_i.clone(), 5782).unwrap();
+        assert!(date
+            .try_add_with_overflow(one_year.cast_unit(), Overflow::Reject)
+            .is_err());
+        date.try_add_with_overflow(one_year.cast_unit(), Overflow::Constrain)
+            .unwrap();
+        assert_eq!(date.month().code, MonthCode(tinystr8!("M06")));
+        // Adding months moves through the ordinal months, so Adar I is never constrained
+        let mut date = Date::new_hebrew_date(1, adar_i, 5782).unwrap();
+        date.try_add_with_overflow(DateDuration::new(1, 1, 0, 0), Overflow::Reject)
+            .unwrap();
+        assert_eq!(date.month().code, MonthCode(tinystr8!("M07")));
+    }
+
+    fn check_round_trip<C: Calendar + Copy>(dates: &[Date<Iso>], calendar: C) {
+        for date1 in dates {
+            for date2 in dates {
+                let sign = (Iso::fixed_from_iso(*date2.inner())
+                    - Iso::fixed_from_iso(*date1.inner()))
+                .signum() as i32;
+                let date1 = date1.to_calendar(calendar);
+                let date2 = date2.to_calendar(calendar);
+                for &largest_unit in &UNITS {
+                    let duration = date1.until(&date2, largest_unit, DateDurationUnit::Days);
+                    let message = (date1.to_iso(), date2.to_iso(), largest_unit);
+                    for &field in &[duration.years, duration.months, duration.weeks] {
+                        assert!(field * sign >= 0, "{:?}", message);
+                    }
+                    assert!(date1.clone().added(duration) == date2, "{:?}", message);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_until_round_trip() {
+        let dates = [
+            iso(2019, 1, 31),
+            iso(2019, 9, 30),
+            iso(2020, 2, 29),
+            iso(2020, 3, 21),
+            iso(2021, 9, 11),
+            iso(2022, 3, 2),
+        ];
+        check_round_trip(&dates, Iso);
+        check_round_trip(&dates, Julian);
+        check_round_trip(&dates, Coptic);
+        check_round_trip(&dates, IslamicCivil);
+        check_round_trip(&dates, Hebrew);
+        check_round_trip(&dates, Chinese);
+    }
+}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use displaydoc::Display;
+use tinystr::TinyStr8;
 
 #[cfg(feature = "std")]
 impl std::error::Error for DateTimeError {}
@@ -33,9 +34,53 @@ pub enum DateTimeError {
     /// The time zone offset was invalid.
     #[displaydoc("Failed to parse time-zone offset")]
     InvalidTimeZoneOffset,
+    /// The era code was not recognized by the calendar.
+    #[displaydoc("No era named {0} for calendar {1}")]
+    UnknownEra(TinyStr8, &'static str),
+    /// The month code was not recognized by the calendar.
+    #[displaydoc("No month code named {0} for calendar {1}")]
+    UnknownMonthCode(TinyStr8, &'static str),
+    /// A date was used with a calendar that it does not belong to.
+    #[displaydoc("Attempted to use a date from the {0} calendar with the {1} calendar")]
+    MismatchedCalendars(&'static str, &'static str),
     /// Out of range
     // TODO(Manishearth) turn this into a proper variant
     OutOfRange,
+    /// The input ended before a complete date or time was parsed.
+    #[displaydoc("Unexpected end of input")]
+    UnexpectedEnd,
+    /// The input contained a character that is not allowed at its position.
+    #[displaydoc("Unexpected character {found:?} at index {index}")]
+    UnexpectedCharacter {
+        /// The byte index of the character in the input
+        index: usize,
+        /// The character that was found
+        found: char,
+    },
+    /// A parsed field was outside of its valid range.
+    #[displaydoc("{field} must be between {min}-{max}, found {value}")]
+    FieldOutOfRange {
+        /// The name of the field
+        field: &'static str,
+        /// The value that was found
+        value: u32,
+        /// The minimum value
+        min: u32,
+        /// The maximum value
+        max: u32,
+    },
+    /// The extended year -000000 was used, which ISO 8601 does not allow.
+    #[displaydoc("The year -000000 is not allowed")]
+    NegativeZeroYear,
+    /// The calendar of a `u-ca` annotation was not recognized.
+    #[displaydoc("Unknown calendar in the annotation at index {0}")]
+    UnknownCalendar(usize),
+    /// An annotation marked as critical with `!` was not recognized.
+    #[displaydoc("Unknown critical annotation at index {0}")]
+    UnknownCriticalAnnotation(usize),
+    /// An annotation was repeated with one of its occurrences marked as critical.
+    #[displaydoc("Conflicting annotation at index {0}")]
+    ConflictingAnnotation(usize),
 }
 
 impl From<core::num::ParseIntError> for DateTimeError {
@@ -0,0 +1,297 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Ethiopic calendar
+
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::coptic::{Coptic, COPTIC_EPOCH};
+use crate::iso::Iso;
+use crate::{
+    duration, types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+use tinystr::tinystr8;
+
+// The fixed date of 1 Meskerem, year 1 of the Incarnation, which is August 29th, 8 CE in
+// the Julian calendar
+//
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2048-L2051
+const ETHIOPIC_EPOCH: i64 = 2796;
+
+/// The number of years the Amete Alem era is ahead of the Amete Mihret era
+const AMETE_ALEM_OFFSET: i32 = 5500;
+
+/// The era style used by an [`Ethiopic`] calendar
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum EthiopicEraStyle {
+    /// Years are counted in the "incar" (Amete Mihret, Year of Mercy) era, which starts in
+    /// 8 CE. Earlier years are counted in the "mundi" (Amete Alem) era.
+    AmeteMihret,
+    /// Years are counted in the "mundi" (Amete Alem, Year of the World) era, which starts
+    /// 5500 years before the Amete Mihret era.
+    AmeteAlem,
+}
+
+/// The Ethiopic Calendar
+///
+/// The Ethiopic calendar has the same structure as the [`Coptic`] calendar: twelve months
+/// of thirty days, followed by an epagomenal month of five days, or six days in leap years.
+/// Its years are counted from a different epoch, in one of two era styles; see
+/// [`EthiopicEraStyle`].
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+pub struct Ethiopic {
+    amete_alem: bool,
+}
+
+/// The inner date type used for representing Date<Ethiopic>
+///
+/// The year is always stored as an Amete Mihret year, regardless of the era style.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct EthiopicDateInner(ArithmeticDate<Ethiopic>);
+
+impl CalendarArithmetic for Ethiopic {
+    fn month_days(year: i32, month: u8) -> u8 {
+        Coptic::month_days(year, month)
+    }
+
+    fn months_for_every_year(year: i32) -> u8 {
+        Coptic::months_for_every_year(year)
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        // Amete Mihret years and Coptic years are 276 years apart, so they have the same
+        // leap years
+        Coptic::is_leap_year(year)
+    }
+}
+
+impl Calendar for Ethiopic {
+    type DateInner = EthiopicDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> EthiopicDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::ethiopic_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(Self::fixed_from_ethiopic(date.0))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        duration::until(self, date1, date2, largest_unit, smallest_unit)
+    }
+
+    fn debug_name() -> &'static str {
+        "Ethiopic"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        match self.era_style() {
+            EthiopicEraStyle::AmeteMihret => Some(AnyCalendarKind::Ethiopic),
+            EthiopicEraStyle::AmeteAlem => Some(AnyCalendarKind::EthiopicAmeteAlem),
+        }
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        self.year_as_ethiopic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.0.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: self.year_as_ethiopic(date.0.year - 1),
+            days_in_prev_year: Ethiopic::days_in_provided_year(date.0.year - 1),
+            next_year: self.year_as_ethiopic(date.0.year + 1),
+        }
+    }
+}
+
+impl Ethiopic {
+    /// Construct a new Ethiopic Calendar using the Amete Mihret era style
+    pub fn new() -> Self {
+        Self { amete_alem: false }
+    }
+
+    /// Construct a new Ethiopic Calendar with the given era style
+    pub fn new_with_era_style(era_style: EthiopicEraStyle) -> Self {
+        Self {
+            amete_alem: era_style == EthiopicEraStyle::AmeteAlem,
+        }
+    }
+
+    /// The era style of this calendar
+    pub fn era_style(&self) -> EthiopicEraStyle {
+        if self.amete_alem {
+            EthiopicEraStyle::AmeteAlem
+        } else {
+            EthiopicEraStyle::AmeteMihret
+        }
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2053-L2059
+    fn fixed_from_ethiopic(date: ArithmeticDate<Ethiopic>) -> i64 {
+        let coptic = ArithmeticDate::new(date.year, date.month, date.day);
+        ETHIOPIC_EPOCH + Coptic::fixed_from_coptic(coptic) - COPTIC_EPOCH
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2061-L2064
+    fn ethiopic_from_fixed(date: i64) -> EthiopicDateInner {
+        let coptic = Coptic::coptic_from_fixed(date + COPTIC_EPOCH - ETHIOPIC_EPOCH);
+        EthiopicDateInner(ArithmeticDate::new(coptic.year, coptic.month, coptic.day))
+    }
+
+    fn year_as_ethiopic(&self, year: i32) -> types::Year {
+        // The Ethiopic year starts in August or September of the ISO year
+        let related_iso = year + 7;
+        if self.amete_alem || year <= 0 {
+            types::Year {
+                era: types::Era(tinystr8!("mundi")),
+                number: year + AMETE_ALEM_OFFSET,
+                related_iso,
+            }
+        } else {
+            types::Year {
+                era: types::Era(tinystr8!("incar")),
+                number: year,
+                related_iso,
+            }
+        }
+    }
+}
+
+impl Date<Ethiopic> {
+    /// Construct a new Ethiopic Date.
+    ///
+    /// Years are specified in the era of the given era style.
+    ///
+    /// ```rust
+    /// use icu_calendar::ethiopic::EthiopicEraStyle;
+    /// use icu_calendar::Date;
+    ///
+    /// let date_ethiopic =
+    ///     Date::new_ethiopic_date_from_integers(EthiopicEraStyle::AmeteMihret, 1, 1, 2014)
+    ///         .expect("Failed to initialize Ethiopic Date instance.");
+    ///
+    /// assert_eq!(date_ethiopic.year().era.0.as_str(), "incar");
+    /// assert_eq!(date_ethiopic.year().number, 2014);
+    ///
+    /// let date_iso = date_ethiopic.to_iso();
+    /// assert_eq!(date_iso.year().number, 2021);
+    /// assert_eq!(date_iso.month().number, 9);
+    /// assert_eq!(date_iso.day_of_month().0, 11);
+    /// ```
+    pub fn new_ethiopic_date_from_integers(
+        era_style: EthiopicEraStyle,
+        day: u8,
+        month: u8,
+        year: i32,
+    ) -> Result<Date<Ethiopic>, DateTimeError> {
+        let year = match era_style {
+            EthiopicEraStyle::AmeteMihret => year,
+            EthiopicEraStyle::AmeteAlem => year - AMETE_ALEM_OFFSET,
+        };
+        ArithmeticDate::new_checked(year, month, day)
+            .map(|inner| {
+                Date::from_raw(
+                    EthiopicDateInner(inner),
+                    Ethiopic::new_with_era_style(era_style),
+                )
+            })
+            .ok_or(DateTimeError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ethiopic_iso_conversion() {
+        // (ethiopic day, month, year), (iso day, month, year)
+        let cases = [
+            ((1, 1, 1), (27, 8, 8)),
+            ((5, 13, 2013), (10, 9, 2021)),
+            ((1, 1, 2014), (11, 9, 2021)),
+            ((6, 13, 2011), (11, 9, 2019)),
+            ((1, 1, 2012), (12, 9, 2019)),
+        ];
+        for &((ed, em, ey), (id, im, iy)) in &cases {
+            let ethiopic =
+                Date::new_ethiopic_date_from_integers(EthiopicEraStyle::AmeteMihret, ed, em, ey)
+                    .unwrap();
+            let iso = Date::new_iso_date_from_integers(id, im, iy).unwrap();
+            assert_eq!(ethiopic.to_iso(), iso, "Ethiopic {}-{}-{}", ey, em, ed);
+            assert_eq!(iso.to_calendar(Ethiopic::new()), ethiopic);
+        }
+    }
+
+    #[test]
+    fn test_ethiopic_eras() {
+        let amete_alem = Ethiopic::new_with_era_style(EthiopicEraStyle::AmeteAlem);
+        let iso = Date::new_iso_date_from_integers(11, 9, 2021).unwrap();
+
+        let date = iso.to_calendar(Ethiopic::new());
+        assert_eq!(date.year().era, types::Era(tinystr8!("incar")));
+        assert_eq!(date.year().number, 2014);
+
+        let date = iso.to_calendar(amete_alem);
+        assert_eq!(date.year().era, types::Era(tinystr8!("mundi")));
+        assert_eq!(date.year().number, 7514);
+        let constructed =
+            Date::new_ethiopic_date_from_integers(EthiopicEraStyle::AmeteAlem, 1, 1, 7514).unwrap();
+        assert_eq!(date, constructed);
+
+        // Years before the Incarnation are always in the Amete Alem era
+        let date =
+            Date::new_ethiopic_date_from_integers(EthiopicEraStyle::AmeteMihret, 1, 1, 0).unwrap();
+        assert_eq!(date.year().era, types::Era(tinystr8!("mundi")));
+        assert_eq!(date.year().number, 5500);
+    }
+
+    #[test]
+    fn test_ethiopic_arithmetic() {
+        let date =
+            Date::new_ethiopic_date_from_integers(EthiopicEraStyle::AmeteMihret, 30, 12, 2011)
+                .unwrap();
+        let expected =
+            Date::new_ethiopic_date_from_integers(EthiopicEraStyle::AmeteMihret, 1, 1, 2012)
+                .unwrap();
+        assert_eq!(date.clone().added(DateDuration::new(0, 0, 1, 0)), expected);
+
+        let difference = expected.until(&date, DateDurationUnit::Years, DateDurationUnit::Days);
+        // One month back is the first day of the epagomenal month
+        assert_eq!(difference.years, 0);
+        assert_eq!(difference.months, -1);
+        assert_eq!(difference.days, -1);
+    }
+}
@@ -0,0 +1,275 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Gregorian calendar
+
+use crate::iso::{Iso, IsoDateInner, IsoDay, IsoMonth, IsoYear};
+use crate::{
+    types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTime,
+    DateTimeError, Time,
+};
+use core::convert::TryInto;
+use tinystr::tinystr8;
+
+/// The Gregorian Calendar
+///
+/// The Gregorian calendar shares its months and days with the ISO calendar, but counts
+/// years in two eras: "ce" (Common Era, AD) and "bce" (Before Common Era, BC). There is
+/// no year zero, so ISO year 0 is 1 BCE.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Gregorian;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+/// The inner date type used for representing Date<Gregorian>
+pub struct GregorianDateInner(IsoDateInner);
+
+impl Calendar for Gregorian {
+    type DateInner = GregorianDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> GregorianDateInner {
+        GregorianDateInner(*iso.inner())
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Date::from_raw(date.0, Iso)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        Iso.months_in_year(&date.0)
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        Iso.days_in_year(&date.0)
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        Iso.days_in_month(&date.0)
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> u8 {
+        Iso.day_of_week(&date.0)
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        Iso.offset_date(&mut date.0, offset.cast_unit())
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        Iso.until(&date1.0, &date2.0, largest_unit, smallest_unit)
+            .cast_unit()
+    }
+
+    fn debug_name() -> &'static str {
+        "Gregorian"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Gregorian)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        year_as_gregorian(date.0.year.0)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        Iso.month(&date.0)
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        Iso.day_of_month(&date.0)
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: Iso::day_of_year(date.0),
+            days_in_year: Iso.days_in_year(&date.0),
+            prev_year: year_as_gregorian(date.0.year.0 - 1),
+            days_in_prev_year: Iso::days_in_year_direct(date.0.year.0 - 1),
+            next_year: year_as_gregorian(date.0.year.0 + 1),
+        }
+    }
+}
+
+impl Date<Gregorian> {
+    /// Construct a new Gregorian Date.
+    ///
+    /// Years are specified as ISO years, i.e. year 0 is 1 BCE.
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    /// use icu_calendar::iso::IsoYear;
+    /// use std::convert::TryInto;
+    ///
+    /// let iso_year = IsoYear(1970);
+    /// let iso_month = 1.try_into().expect("Failed to create IsoMonth instance.");
+    /// let iso_day = 2.try_into().expect("Failed to create IsoDay instance.");
+    ///
+    /// let date_gregorian = Date::new_gregorian_date(iso_day, iso_month, iso_year)
+    ///     .expect("Failed to initialize Gregorian Date instance.");
+    ///
+    /// assert_eq!(date_gregorian.year().number, 1970);
+    /// assert_eq!(date_gregorian.month().number, 1);
+    /// assert_eq!(date_gregorian.day_of_month().0, 2);
+    /// ```
+    pub fn new_gregorian_date(
+        day: IsoDay,
+        month: IsoMonth,
+        year: IsoYear,
+    ) -> Result<Date<Gregorian>, DateTimeError> {
+        Ok(Date::new_iso_date(day, month, year)?.to_calendar(Gregorian))
+    }
+
+    /// Construct a new Gregorian date from integers.
+    ///
+    /// Years are specified as ISO years, i.e. year 0 is 1 BCE.
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    ///
+    /// let date_gregorian = Date::new_gregorian_date_from_integers(5, 1, -43)
+    ///     .expect("Failed to initialize Gregorian Date instance.");
+    ///
+    /// assert_eq!(date_gregorian.year().era.0.as_str(), "bce");
+    /// assert_eq!(date_gregorian.year().number, 44);
+    /// ```
+    pub fn new_gregorian_date_from_integers(
+        day: u8,
+        month: u8,
+        year: i32,
+    ) -> Result<Date<Gregorian>, DateTimeError> {
+        Self::new_gregorian_date(day.try_into()?, month.try_into()?, year.into())
+    }
+}
+
+impl DateTime<Gregorian> {
+    /// Construct a new Gregorian datetime from integers.
+    ///
+    /// Years are specified as ISO years, i.e. year 0 is 1 BCE.
+    ///
+    /// ```rust
+    /// use icu_calendar::DateTime;
+    ///
+    /// let datetime_gregorian = DateTime::new_gregorian_datetime_from_integers(1970, 1, 2, 13, 1, 0)
+    ///     .expect("Failed to initialize Gregorian DateTime instance.");
+    ///
+    /// assert_eq!(datetime_gregorian.date.year().number, 1970);
+    /// assert_eq!(datetime_gregorian.date.month().number, 1);
+    /// assert_eq!(datetime_gregorian.date.day_of_month().0, 2);
+    /// assert_eq!(u8::from(datetime_gregorian.time.hour), 13);
+    /// assert_eq!(u8::from(datetime_gregorian.time.minute), 1);
+    /// assert_eq!(u8::from(datetime_gregorian.time.second), 0);
+    /// ```
+    pub fn new_gregorian_datetime_from_integers(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Gregorian>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_gregorian_date_from_integers(day, month, year)?,
+            time: Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+/// Convert a proleptic ISO year into a Gregorian year with an era.
+///
+/// ISO years greater than zero are in the "ce" era, and all others are in the "bce" era,
+/// such that ISO year 0 is 1 BCE.
+pub fn year_as_gregorian(iso_year: i32) -> types::Year {
+    if iso_year > 0 {
+        types::Year {
+            era: types::Era(tinystr8!("ce")),
+            number: iso_year,
+            related_iso: iso_year,
+        }
+    } else {
+        types::Year {
+            era: types::Era(tinystr8!("bce")),
+            number: 1 - iso_year,
+            related_iso: iso_year,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_year_as_gregorian() {
+        assert_eq!(
+            year_as_gregorian(2020),
+            types::Year {
+                era: types::Era(tinystr8!("ce")),
+                number: 2020,
+                related_iso: 2020,
+            }
+        );
+        assert_eq!(
+            year_as_gregorian(1),
+            types::Year {
+                era: types::Era(tinystr8!("ce")),
+                number: 1,
+                related_iso: 1,
+            }
+        );
+        assert_eq!(
+            year_as_gregorian(0),
+            types::Year {
+                era: types::Era(tinystr8!("bce")),
+                number: 1,
+                related_iso: 0,
+            }
+        );
+        assert_eq!(
+            year_as_gregorian(-1),
+            types::Year {
+                era: types::Era(tinystr8!("bce")),
+                number: 2,
+                related_iso: -1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_iso_round_trip() {
+        for &(day, month, year) in &[(1, 1, 1), (31, 12, 0), (29, 2, 2020), (15, 10, 1582)] {
+            let iso = Date::new_iso_date_from_integers(day, month, year).unwrap();
+            let gregorian = iso.to_calendar(Gregorian);
+            assert_eq!(gregorian.day_of_month().0, day as u32);
+            assert_eq!(gregorian.month().number, month as u32);
+            assert_eq!(gregorian.year().related_iso, year);
+            assert_eq!(gregorian.to_iso(), iso);
+        }
+    }
+
+    #[test]
+    fn test_day_of_year_info() {
+        let date = Date::new_gregorian_date_from_integers(1, 1, 1).unwrap();
+        let info = date.day_of_year_info();
+        assert_eq!(info.day_of_year, 1);
+        assert_eq!(info.days_in_year, 365);
+        assert_eq!(info.prev_year.era, types::Era(tinystr8!("bce")));
+        assert_eq!(info.prev_year.number, 1);
+        assert_eq!(info.next_year.number, 2);
+
+        let date = Date::new_gregorian_date_from_integers(31, 12, 2020).unwrap();
+        assert_eq!(date.day_of_year_info().day_of_year, 366);
+    }
+
+    #[test]
+    fn test_offset() {
+        let date = Date::new_gregorian_date_from_integers(31, 12, 2020).unwrap();
+        let expected = Date::new_gregorian_date_from_integers(1, 1, 2021).unwrap();
+        assert_eq!(date.added(DateDuration::new(0, 0, 0, 1)), expected);
+    }
+}
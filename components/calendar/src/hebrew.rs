@@ -0,0 +1,402 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Hebrew calendar
+
+use crate::calendar_arithmetic::{
+    month_code, parse_month_code, ArithmeticDate, CalendarArithmetic,
+};
+use crate::iso::Iso;
+use crate::{
+    duration, types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+use tinystr::tinystr8;
+
+// The fixed date of 1 Tishri, year 1 AM, which is October 7th, 3761 BCE in the Julian calendar
+//
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2206-L2209
+const HEBREW_EPOCH: i64 = -1373427;
+
+/// The Hebrew Calendar
+///
+/// The Hebrew calendar is a lunisolar calendar with years counted in the "am" (Anno Mundi)
+/// era. Years begin with the month of Tishri, and leap years insert a thirty-day month,
+/// Adar I, before the month of Adar (known as Adar II in leap years).
+///
+/// Months are identified by the month codes "M01" (Tishri) through "M12" (Elul), with
+/// Adar I being the leap month "M05L". Ordinal month numbers count months from the start
+/// of the year, so they refer to different months in leap and common years.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+pub struct Hebrew;
+
+/// The inner date type used for representing Date<Hebrew>
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct HebrewDateInner(ArithmeticDate<Hebrew>);
+
+impl CalendarArithmetic for Hebrew {
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2282-L2299
+    fn month_days(year: i32, month: u8) -> u8 {
+        // Months in the order used by Calendrical Calculations, starting from Nisan
+        match Self::biblical_month(year, month) {
+            // Iyyar, Tammuz, Elul, Tevet, Adar II
+            2 | 4 | 6 | 10 | 13 => 29,
+            // Adar in common years
+            12 if !Self::is_leap_year(year) => 29,
+            // Heshvan, which is long in years of 355 or 385 days
+            8 if Self::days_in_provided_year(year) % 10 != 5 => 29,
+            // Kislev, which is short in years of 353 or 383 days
+            9 if Self::days_in_provided_year(year) % 10 == 3 => 29,
+            _ => 30,
+        }
+    }
+
+    fn months_for_every_year(year: i32) -> u8 {
+        if Self::is_leap_year(year) {
+            13
+        } else {
+            12
+        }
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2226-L2230
+    fn is_leap_year(year: i32) -> bool {
+        (7 * year as i64 + 1).rem_euclid(19) < 7
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        (Self::new_year(year + 1) - Self::new_year(year)) as u32
+    }
+}
+
+impl Calendar for Hebrew {
+    type DateInner = HebrewDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> HebrewDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::hebrew_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_hebrew = Hebrew::fixed_from_hebrew(date.0);
+        Iso::iso_from_fixed(fixed_hebrew)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        if offset.years != 0 {
+            // Keep the same month when changing years, even though its ordinal may differ
+            let (number, is_leap) = Self::month_number_and_leap(date.0.year, date.0.month);
+            date.0.year += offset.years;
+            date.0.month = Self::ordinal_for_month(date.0.year, number, is_leap)
+                // Adar I only exists in leap years, use Adar in common years
+                .unwrap_or_else(|| Self::ordinal_for_month(date.0.year, 6, false).unwrap_or(6));
+        }
+        date.0.offset_months(offset.months);
+        date.0.constrain_day();
+        date.0.offset_days(offset.days + offset.weeks * 7);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        duration::until(self, date1, date2, largest_unit, smallest_unit)
+    }
+
+    fn debug_name() -> &'static str {
+        "Hebrew"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Hebrew)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        Self::year_as_hebrew(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        let (number, is_leap) = Self::month_number_and_leap(date.0.year, date.0.month);
+        types::Month {
+            number: date.0.month as u32,
+            code: month_code(number, is_leap),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.0.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: Self::year_as_hebrew(date.0.year - 1),
+            days_in_prev_year: Self::days_in_provided_year(date.0.year - 1),
+            next_year: Self::year_as_hebrew(date.0.year + 1),
+        }
+    }
+}
+
+impl Hebrew {
+    /// Construct a new Hebrew Calendar
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn year_as_hebrew(year: i32) -> types::Year {
+        types::Year {
+            era: types::Era(tinystr8!("am")),
+            number: year,
+            related_iso: Iso::iso_year_from_fixed(Self::new_year(year)),
+        }
+    }
+
+    /// Convert an ordinal month (starting from Tishri) into the month numbering used by
+    /// Calendrical Calculations, which starts from Nisan and places Adar II last
+    fn biblical_month(year: i32, month: u8) -> u8 {
+        if month <= 6 {
+            // Tishri through Adar (I)
+            month + 6
+        } else if Self::is_leap_year(year) {
+            if month == 7 {
+                // Adar II
+                13
+            } else {
+                month - 7
+            }
+        } else {
+            month - 6
+        }
+    }
+
+    /// The month number and whether it is a leap month, which make up the month code
+    fn month_number_and_leap(year: i32, month: u8) -> (u8, bool) {
+        if !Self::is_leap_year(year) || month < 6 {
+            (month, false)
+        } else if month == 6 {
+            (5, true)
+        } else {
+            (month - 1, false)
+        }
+    }
+
+    /// The inverse of [`Hebrew::month_number_and_leap()`], returning `None` for months
+    /// that do not exist in the given year
+    fn ordinal_for_month(year: i32, number: u8, is_leap: bool) -> Option<u8> {
+        match (number, is_leap) {
+            (1..=5, false) => Some(number),
+            (5, true) if Self::is_leap_year(year) => Some(6),
+            (6..=12, false) if Self::is_leap_year(year) => Some(number + 1),
+            (6..=12, false) => Some(number),
+            _ => None,
+        }
+    }
+
+    // The number of days elapsed from the epoch to the molad (mean new moon) of Tishri,
+    // delayed such that the year does not start on a Sunday, Wednesday or Friday
+    //
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2301-L2311
+    fn calendar_elapsed_days(year: i32) -> i64 {
+        let months_elapsed = (235 * year as i64 - 234).div_euclid(19);
+        let parts_elapsed = 12084 + 13753 * months_elapsed;
+        let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+        if (3 * (days + 1)).rem_euclid(7) < 3 {
+            days + 1
+        } else {
+            days
+        }
+    }
+
+    // Delays needed to keep years within the allowed lengths
+    //
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2313-L2324
+    fn year_length_correction(year: i32) -> i64 {
+        let ny0 = Self::calendar_elapsed_days(year - 1);
+        let ny1 = Self::calendar_elapsed_days(year);
+        let ny2 = Self::calendar_elapsed_days(year + 1);
+        if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    // The fixed date of 1 Tishri of the given year
+    //
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2326-L2331
+    fn new_year(year: i32) -> i64 {
+        HEBREW_EPOCH + Self::calendar_elapsed_days(year) + Self::year_length_correction(year)
+    }
+
+    fn fixed_from_hebrew(date: ArithmeticDate<Hebrew>) -> i64 {
+        Self::new_year(date.year) + date.day_of_year() as i64 - 1
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2368-L2388
+    fn hebrew_from_fixed(date: i64) -> HebrewDateInner {
+        // The average length of a year, 35975351/98496 days
+        let approx = ((date - HEBREW_EPOCH) * 98496).div_euclid(35975351) as i32 + 1;
+        // The last year starting on or before the date
+        let mut year = approx - 1;
+        while Self::new_year(year + 1) <= date {
+            year += 1;
+        }
+        let day_of_year = (date - Self::new_year(year) + 1) as u32;
+        HebrewDateInner(ArithmeticDate::from_year_and_day_of_year(year, day_of_year))
+    }
+}
+
+impl Date<Hebrew> {
+    /// Construct a new Hebrew Date from a day, a month code, and a year in the "am" era.
+    ///
+    /// ```rust
+    /// use icu_calendar::{types::MonthCode, Date};
+    /// use tinystr::tinystr8;
+    ///
+    /// // 1 Adar I 5782
+    /// let date_hebrew = Date::new_hebrew_date(1, MonthCode(tinystr8!("M05L")), 5782)
+    ///     .expect("Failed to initialize Hebrew Date instance.");
+    ///
+    /// assert_eq!(date_hebrew.year().number, 5782);
+    /// assert_eq!(date_hebrew.month().number, 6);
+    /// assert_eq!(date_hebrew.day_of_month().0, 1);
+    ///
+    /// let date_iso = date_hebrew.to_iso();
+    /// assert_eq!(date_iso.year().number, 2022);
+    /// assert_eq!(date_iso.month().number, 2);
+    /// assert_eq!(date_iso.day_of_month().0, 2);
+    ///
+    /// // 5783 is not a leap year
+    /// assert!(Date::new_hebrew_date(1, MonthCode(tinystr8!("M05L")), 5783).is_err());
+    /// ```
+    pub fn new_hebrew_date(
+        day: u8,
+        month: types::MonthCode,
+        year: i32,
+    ) -> Result<Date<Hebrew>, DateTimeError> {
+        let (number, is_leap) = parse_month_code(&month)
+            .filter(|&(number, _)| number <= 12)
+            .ok_or_else(|| DateTimeError::UnknownMonthCode(month.0, Hebrew::debug_name()))?;
+        let ordinal =
+            Hebrew::ordinal_for_month(year, number, is_leap).ok_or(DateTimeError::OutOfRange)?;
+        ArithmeticDate::new_checked(year, ordinal, day)
+            .map(|inner| Date::from_raw(HebrewDateInner(inner), Hebrew))
+            .ok_or(DateTimeError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn code(s: &str) -> types::MonthCode {
+        types::MonthCode(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_hebrew_iso_conversion() {
+        // (hebrew day, month code, year), (iso day, month, year)
+        let cases = [
+            ((1, "M01", 5782), (7, 9, 2021)),
+            ((1, "M05L", 5782), (2, 2, 2022)),
+            ((1, "M06", 5782), (4, 3, 2022)),
+            ((15, "M07", 5782), (16, 4, 2022)),
+            ((1, "M01", 5783), (26, 9, 2022)),
+            ((14, "M07", 5781), (27, 3, 2021)),
+            ((1, "M01", 5784), (16, 9, 2023)),
+            ((1, "M01", 1), (7, 9, -3760)),
+        ];
+        for &((hd, hm, hy), (id, im, iy)) in &cases {
+            let hebrew = Date::new_hebrew_date(hd, code(hm), hy).unwrap();
+            let iso = Date::new_iso_date_from_integers(id, im, iy).unwrap();
+            assert_eq!(hebrew.to_iso(), iso, "Hebrew {}-{}-{}", hy, hm, hd);
+            assert_eq!(iso.to_calendar(Hebrew), hebrew, "ISO {}-{}-{}", iy, im, id);
+            assert_eq!(hebrew.month().code, code(hm));
+        }
+    }
+
+    #[test]
+    fn test_hebrew_year_lengths() {
+        // (year, days in year, months in year)
+        let cases = [
+            (5781, 353, 12),
+            (5782, 384, 13),
+            (5783, 355, 12),
+            (5784, 383, 13),
+            (5785, 355, 12),
+        ];
+        for &(year, days, months) in &cases {
+            let date = Date::new_hebrew_date(1, code("M01"), year).unwrap();
+            assert_eq!(date.days_in_year(), days, "Year {}", year);
+            assert_eq!(date.months_in_year(), months, "Year {}", year);
+            let sum: u32 = (1..=months)
+                .map(|month| Hebrew::month_days(year, month) as u32)
+                .sum();
+            assert_eq!(sum, days, "Year {}", year);
+        }
+    }
+
+    #[test]
+    fn test_hebrew_month_codes() {
+        // Leap year
+        let codes: Vec<_> = (1..=13)
+            .map(|month| {
+                let (number, is_leap) = Hebrew::month_number_and_leap(5782, month);
+                month_code(number, is_leap)
+            })
+            .collect();
+        assert_eq!(codes[4], code("M05"));
+        assert_eq!(codes[5], code("M05L"));
+        assert_eq!(codes[6], code("M06"));
+        assert_eq!(codes[12], code("M12"));
+
+        // Common year
+        let (number, is_leap) = Hebrew::month_number_and_leap(5783, 6);
+        assert_eq!(month_code(number, is_leap), code("M06"));
+
+        assert!(matches!(
+            Date::new_hebrew_date(1, code("M13"), 5782),
+            Err(DateTimeError::UnknownMonthCode(..))
+        ));
+    }
+
+    #[test]
+    fn test_hebrew_offset() {
+        // Adding a year keeps the month code, even when the ordinal month changes
+        let date = Date::new_hebrew_date(15, code("M07"), 5781).unwrap();
+        let expected = Date::new_hebrew_date(15, code("M07"), 5782).unwrap();
+        assert_eq!(date.added(DateDuration::new(1, 0, 0, 0)), expected);
+
+        // Adar I becomes Adar in a common year
+        let date = Date::new_hebrew_date(10, code("M05L"), 5782).unwrap();
+        let expected = Date::new_hebrew_date(10, code("M06"), 5783).unwrap();
+        assert_eq!(date.added(DateDuration::new(1, 0, 0, 0)), expected);
+
+        // Adding months counts the leap month
+        let date = Date::new_hebrew_date(1, code("M05"), 5782).unwrap();
+        let expected = Date::new_hebrew_date(1, code("M06"), 5782).unwrap();
+        assert_eq!(date.added(DateDuration::new(0, 2, 0, 0)), expected);
+
+        // Adding days across the end of the year
+        let date = Date::new_hebrew_date(29, code("M12"), 5782).unwrap();
+        let expected = Date::new_hebrew_date(1, code("M01"), 5783).unwrap();
+        assert_eq!(date.added(DateDuration::new(0, 0, 0, 1)), expected);
+    }
+}
@@ -0,0 +1,383 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the tabular Islamic calendars
+//!
+//! The tabular Islamic calendars approximate the observational Islamic calendar with
+//! alternating months of 30 and 29 days, and a leap day at the end of 11 years in
+//! every 30 year cycle. They only differ in their epoch:
+//!
+//! - [`IslamicCivil`] ("islamic-civil") uses the civil epoch of Friday, July 16th, 622 CE
+//!   (Julian)
+//! - [`IslamicTabular`] ("islamic-tbla") uses the astronomical epoch of Thursday,
+//!   July 15th, 622 CE (Julian)
+
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::{
+    duration, types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+use tinystr::tinystr8;
+
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2066-L2069
+const ISLAMIC_CIVIL_EPOCH: i64 = 227015;
+const ISLAMIC_ASTRONOMICAL_EPOCH: i64 = ISLAMIC_CIVIL_EPOCH - 1;
+
+/// The tabular Islamic Calendar with the civil (Friday) epoch
+///
+/// Years are counted in the "ah" (Anno Hegirae) era.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+pub struct IslamicCivil;
+
+/// The tabular Islamic Calendar with the astronomical (Thursday) epoch
+///
+/// Years are counted in the "ah" (Anno Hegirae) era.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+pub struct IslamicTabular;
+
+/// The inner date type used for representing Date<IslamicCivil>
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicCivilDateInner(ArithmeticDate<IslamicCivil>);
+
+/// The inner date type used for representing Date<IslamicTabular>
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicTabularDateInner(ArithmeticDate<IslamicTabular>);
+
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2076-L2078
+fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * year as i64).rem_euclid(30) < 11
+}
+
+fn month_days(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 9 | 11 => 30,
+        2 | 4 | 6 | 8 | 10 => 29,
+        12 if is_leap_year(year) => 30,
+        12 => 29,
+        _ => 0,
+    }
+}
+
+// The fixed date of the first day of the given year
+//
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2080-L2093
+fn new_year(epoch: i64, year: i32) -> i64 {
+    let year = year as i64;
+    epoch + (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+}
+
+fn fixed_from_islamic<C: CalendarArithmetic>(epoch: i64, date: ArithmeticDate<C>) -> i64 {
+    new_year(epoch, date.year) + date.day_of_year() as i64 - 1
+}
+
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2095-L2112
+fn islamic_from_fixed<C: CalendarArithmetic>(epoch: i64, date: i64) -> ArithmeticDate<C> {
+    let year = (30 * (date - epoch) + 10646).div_euclid(10631) as i32;
+    let day_of_year = (date - new_year(epoch, year) + 1) as u32;
+    ArithmeticDate::from_year_and_day_of_year(year, day_of_year)
+}
+
+fn year_as_islamic(epoch: i64, year: i32) -> types::Year {
+    types::Year {
+        era: types::Era(tinystr8!("ah")),
+        number: year,
+        related_iso: Iso::iso_year_from_fixed(new_year(epoch, year)),
+    }
+}
+
+fn day_of_year_info<C: CalendarArithmetic>(
+    epoch: i64,
+    date: &ArithmeticDate<C>,
+) -> types::DayOfYearInfo {
+    types::DayOfYearInfo {
+        day_of_year: date.day_of_year(),
+        days_in_year: date.days_in_year(),
+        prev_year: year_as_islamic(epoch, date.year - 1),
+        days_in_prev_year: C::days_in_provided_year(date.year - 1),
+        next_year: year_as_islamic(epoch, date.year + 1),
+    }
+}
+
+impl CalendarArithmetic for IslamicCivil {
+    fn month_days(year: i32, month: u8) -> u8 {
+        month_days(year, month)
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        is_leap_year(year)
+    }
+}
+
+impl CalendarArithmetic for IslamicTabular {
+    fn month_days(year: i32, month: u8) -> u8 {
+        month_days(year, month)
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        is_leap_year(year)
+    }
+}
+
+impl Calendar for IslamicCivil {
+    type DateInner = IslamicCivilDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> IslamicCivilDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        IslamicCivilDateInner(islamic_from_fixed(ISLAMIC_CIVIL_EPOCH, fixed_iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(fixed_from_islamic(ISLAMIC_CIVIL_EPOCH, date.0))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        duration::until(self, date1, date2, largest_unit, smallest_unit)
+    }
+
+    fn debug_name() -> &'static str {
+        "Islamic (civil)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicCivil)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        year_as_islamic(ISLAMIC_CIVIL_EPOCH, date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.0.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        day_of_year_info(ISLAMIC_CIVIL_EPOCH, &date.0)
+    }
+}
+
+impl Calendar for IslamicTabular {
+    type DateInner = IslamicTabularDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> IslamicTabularDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        IslamicTabularDateInner(islamic_from_fixed(ISLAMIC_ASTRONOMICAL_EPOCH, fixed_iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(fixed_from_islamic(ISLAMIC_ASTRONOMICAL_EPOCH, date.0))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        duration::until(self, date1, date2, largest_unit, smallest_unit)
+    }
+
+    fn debug_name() -> &'static str {
+        "Islamic (tabular)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicTabular)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        year_as_islamic(ISLAMIC_ASTRONOMICAL_EPOCH, date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.0.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        day_of_year_info(ISLAMIC_ASTRONOMICAL_EPOCH, &date.0)
+    }
+}
+
+impl IslamicCivil {
+    /// Construct a new Islamic Calendar with the civil epoch
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl IslamicTabular {
+    /// Construct a new Islamic Calendar with the astronomical epoch
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Date<IslamicCivil> {
+    /// Construct a new tabular Islamic Date with the civil epoch.
+    ///
+    /// Years are specified as AH years.
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_civil_date_from_integers(1, 1, 1443)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().era.0.as_str(), "ah");
+    /// assert_eq!(date_islamic.year().number, 1443);
+    ///
+    /// let date_iso = date_islamic.to_iso();
+    /// assert_eq!(date_iso.year().number, 2021);
+    /// assert_eq!(date_iso.month().number, 8);
+    /// assert_eq!(date_iso.day_of_month().0, 10);
+    /// ```
+    pub fn new_islamic_civil_date_from_integers(
+        day: u8,
+        month: u8,
+        year: i32,
+    ) -> Result<Date<IslamicCivil>, DateTimeError> {
+        ArithmeticDate::new_checked(year, month, day)
+            .map(|inner| Date::from_raw(IslamicCivilDateInner(inner), IslamicCivil))
+            .ok_or(DateTimeError::OutOfRange)
+    }
+}
+
+impl Date<IslamicTabular> {
+    /// Construct a new tabular Islamic Date with the astronomical epoch.
+    ///
+    /// Years are specified as AH years.
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_tabular_date_from_integers(1, 1, 1443)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// let date_iso = date_islamic.to_iso();
+    /// assert_eq!(date_iso.year().number, 2021);
+    /// assert_eq!(date_iso.month().number, 8);
+    /// assert_eq!(date_iso.day_of_month().0, 9);
+    /// ```
+    pub fn new_islamic_tabular_date_from_integers(
+        day: u8,
+        month: u8,
+        year: i32,
+    ) -> Result<Date<IslamicTabular>, DateTimeError> {
+        ArithmeticDate::new_checked(year, month, day)
+            .map(|inner| Date::from_raw(IslamicTabularDateInner(inner), IslamicTabular))
+            .ok_or(DateTimeError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_islamic_iso_conversion() {
+        // (islamic day, month, year), (civil iso day, month, year)
+        let cases = [
+            ((1, 1, 1), (19, 7, 622)),
+            ((1, 9, 1442), (13, 4, 2021)),
+            ((30, 12, 1442), (9, 8, 2021)),
+            ((1, 1, 1443), (10, 8, 2021)),
+        ];
+        for &((hd, hm, hy), (id, im, iy)) in &cases {
+            let civil = Date::new_islamic_civil_date_from_integers(hd, hm, hy).unwrap();
+            let iso = Date::new_iso_date_from_integers(id, im, iy).unwrap();
+            assert_eq!(civil.to_iso(), iso, "AH {}-{}-{}", hy, hm, hd);
+            assert_eq!(
+                iso.to_calendar(IslamicCivil),
+                civil,
+                "ISO {}-{}-{}",
+                iy,
+                im,
+                id
+            );
+
+            // The astronomical epoch is a day earlier
+            let tabular = Date::new_islamic_tabular_date_from_integers(hd, hm, hy).unwrap();
+            let iso = iso.added(DateDuration::new(0, 0, 0, -1));
+            assert_eq!(tabular.to_iso(), iso, "AH {}-{}-{}", hy, hm, hd);
+            assert_eq!(iso.to_calendar(IslamicTabular), tabular);
+        }
+    }
+
+    #[test]
+    fn test_islamic_leap_years() {
+        let leap_years: Vec<i32> = (1..=30).filter(|&year| is_leap_year(year)).collect();
+        assert_eq!(leap_years, [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29]);
+        let date = Date::new_islamic_civil_date_from_integers(1, 1, 1442).unwrap();
+        assert_eq!(date.days_in_year(), 355);
+        let date = Date::new_islamic_civil_date_from_integers(1, 1, 1443).unwrap();
+        assert_eq!(date.days_in_year(), 354);
+        assert!(Date::new_islamic_civil_date_from_integers(30, 12, 1443).is_err());
+    }
+
+    #[test]
+    fn test_islamic_arithmetic() {
+        // 1442 is a leap year, so Dhu al-Hijjah has 30 days
+        let date = Date::new_islamic_civil_date_from_integers(29, 11, 1442).unwrap();
+        let expected = Date::new_islamic_civil_date_from_integers(1, 1, 1443).unwrap();
+        let later = date.clone().added(DateDuration::new(0, 0, 0, 32));
+        assert_eq!(later, expected);
+
+        let difference = date.until(&expected, DateDurationUnit::Years, DateDurationUnit::Days);
+        assert_eq!(difference.years, 0);
+        assert_eq!(difference.months, 1);
+        assert_eq!(difference.days, 2);
+    }
+}
@@ -4,8 +4,14 @@
 
 //! This module contains types and implementations for the ISO calendar
 
-use crate::{Calendar, Date, DateDuration, DateDurationUnit, DateTimeError};
+use crate::calendar_arithmetic::month_code_for_ordinal;
+use crate::{
+    duration, types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTime,
+    DateTimeError, Time,
+};
 use core::convert::{TryFrom, TryInto};
+use tinystr::tinystr8;
+use types::{IsoHour, IsoMinute, IsoSecond, NanoSecond};
 
 #[derive(Copy, Clone, Debug, Default)]
 /// The ISO Calendar
@@ -65,29 +71,24 @@ impl From<IsoYear> for i32 {
     }
 }
 
+/// The fixed day of the Unix epoch, January 1st, 1970
+const UNIX_EPOCH_FIXED: i64 = 719163;
+const SECONDS_IN_DAY: i64 = 24 * 60 * 60;
+
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 /// The inner date type used for representing Date<Iso>
 pub struct IsoDateInner {
-    day: IsoDay,
-    month: IsoMonth,
-    year: IsoYear,
+    pub(crate) day: IsoDay,
+    pub(crate) month: IsoMonth,
+    pub(crate) year: IsoYear,
 }
 
 impl IsoDateInner {
     fn add_months(&mut self, months: i32) {
         // Get a zero-indexed new month
         let new_month = (self.month.0 as i32 - 1) + months;
-        if new_month >= 0 {
-            self.year.0 += new_month / 12;
-            self.month.0 = ((new_month % 12) + 1) as u8;
-        } else {
-            // subtract full years
-            self.year.0 -= (-new_month) / 12;
-            // subtract a partial year
-            self.year.0 -= 1;
-            // adding 13 since months are 1-indexed
-            self.month.0 = (13 + (new_month % 12)) as u8
-        }
+        self.year.0 += new_month.div_euclid(12);
+        self.month.0 = (new_month.rem_euclid(12) + 1) as u8;
     }
 }
 
@@ -106,11 +107,7 @@ impl Calendar for Iso {
     }
 
     fn days_in_year(&self, date: &Self::DateInner) -> u32 {
-        if Self::is_leap_year(date.year) {
-            366
-        } else {
-            365
-        }
+        Self::days_in_year_direct(date.year.0)
     }
 
     fn days_in_month(&self, date: &Self::DateInner) -> u8 {
@@ -118,51 +115,9 @@ impl Calendar for Iso {
     }
 
     fn day_of_week(&self, date: &Self::DateInner) -> u8 {
-        // TODO (Manishearth) share code with icu_datetime
-
-        // For the purposes of the calculation here, Monday is 0, Sunday is 6
-        // ISO has Monday=1, Sunday=7, which we transform in the last step
-
-        // The days of the week are the same every 400 years
-        // so we normalize to the nearest multiple of 400
-        let years_since_400 = date.year.0 % 400;
-        let leap_years_since_400 = years_since_400 / 4 - years_since_400 / 100;
-        // The number of days to the current year
-        let days_to_current_year = 365 * years_since_400 + leap_years_since_400;
-        // The weekday offset from January 1 this year and January 1 2000
-        let year_offset = days_to_current_year % 7;
-
-        // Corresponding months from
-        // https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week#Corresponding_months
-        let month_offset = if Self::is_leap_year(date.year) {
-            match date.month.0 {
-                1 | 4 | 7 => 0,
-                10 => 1,
-                5 => 2,
-                2 | 8 => 3,
-                3 | 11 => 4,
-                6 => 5,
-                9 | 12 => 6,
-                _ => unreachable!(),
-            }
-        } else {
-            match date.month.0 {
-                1 | 10 => 0,
-                5 => 1,
-                8 => 2,
-                2 | 3 | 11 => 3,
-                6 => 4,
-                9 | 12 => 5,
-                4 | 7 => 6,
-                _ => unreachable!(),
-            }
-        };
-
-        let january_1_2000 = 5; // Saturday
-        let day_offset = (january_1_2000 + year_offset + month_offset + date.day.0 as i32) % 7;
-
-        // We calculated in a zero-indexed fashion, but ISO specifies one-indexed
-        (day_offset + 1) as u8
+        // January 1, 1 CE (fixed day 1) is a Monday, so fixed days are ISO weekdays
+        // modulo 7
+        ((Self::fixed_from_iso(*date) - 1).rem_euclid(7) + 1) as u8
     }
 
     fn offset_date(&self, date: &mut Self::DateInner, mut offset: DateDuration<Self>) {
@@ -170,6 +125,9 @@ impl Calendar for Iso {
         date.add_months(offset.months);
         offset.months = 0;
 
+        // Constrain the day to the new month, e.g. February 29th in a common year
+        date.day.0 = date.day.0.min(self.days_in_month(date));
+
         offset.days += offset.weeks * 7;
 
         // Normalize date to beginning of month
@@ -190,7 +148,7 @@ impl Calendar for Iso {
                 }
             } else {
                 let month_days = self.days_in_month(date);
-                if offset.days > month_days as i32 {
+                if offset.days >= month_days as i32 {
                     date.add_months(1);
                     offset.days -= month_days as i32;
                 } else {
@@ -201,27 +159,48 @@ impl Calendar for Iso {
         }
     }
 
-    #[allow(clippy::field_reassign_with_default)] // it's more clear this way
     fn until(
         &self,
         date1: &Self::DateInner,
         date2: &Self::DateInner,
-        _largest_unit: DateDurationUnit,
-        _smallest_unit: DateDurationUnit,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
     ) -> DateDuration<Self> {
-        let mut difference = DateDuration::default();
-        // TODO (Manishearth) handle the unit bounds and rounding behavior
-        // (perhaps share code with icu_datetime)
-        difference.years = date1.year.0 - date2.year.0;
-        difference.months = date1.month.0 as i32 - date2.month.0 as i32;
-        difference.days = date1.day.0 as i32 - date2.day.0 as i32;
-
-        difference
+        duration::until(self, date1, date2, largest_unit, smallest_unit)
     }
 
     fn debug_name() -> &'static str {
         "ISO"
     }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Iso)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        Self::year_as_iso(date.year.0)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        types::Month {
+            number: date.month.0.into(),
+            code: month_code_for_ordinal(date.month.0),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.day.0.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: Self::day_of_year(*date),
+            days_in_year: self.days_in_year(date),
+            prev_year: Self::year_as_iso(date.year.0 - 1),
+            days_in_prev_year: Self::days_in_year_direct(date.year.0 - 1),
+            next_year: Self::year_as_iso(date.year.0 + 1),
+        }
+    }
 }
 
 impl Date<Iso> {
@@ -233,7 +212,7 @@ impl Date<Iso> {
     ) -> Result<Date<Iso>, DateTimeError> {
         if day.0 > 28 {
             let bound = Iso::days_in_month(year, month);
-            if day.0 < bound {
+            if day.0 > bound {
                 return Err(DateTimeError::OutOfRange);
             }
         }
@@ -249,6 +228,137 @@ impl Date<Iso> {
     ) -> Result<Date<Iso>, DateTimeError> {
         Self::new_iso_date(day.try_into()?, month.try_into()?, year.into())
     }
+
+    /// The fixed day number of this date, i.e. the number of days since the start of the
+    /// ISO calendar ("Rata Die"), such that January 1, 1 CE is day 1.
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    ///
+    /// let date = Date::new_iso_date_from_integers(1, 1, 1970)
+    ///     .expect("Failed to initialize ISO Date instance.");
+    /// assert_eq!(date.to_fixed(), 719163);
+    /// assert_eq!(Date::from_fixed(719163).unwrap(), date);
+    /// ```
+    pub fn to_fixed(&self) -> i64 {
+        Iso::fixed_from_iso(*self.inner())
+    }
+
+    /// Construct an ISO date from its fixed day number ("Rata Die"), such that January 1,
+    /// 1 CE is day 1.
+    ///
+    /// Returns [`DateTimeError::OutOfRange`] if the year of the date does not fit in an `i32`.
+    pub fn from_fixed(fixed: i64) -> Result<Date<Iso>, DateTimeError> {
+        if fixed < Iso::fixed_from_iso_integers(i32::MIN, 1, 1)
+            || fixed > Iso::fixed_from_iso_integers(i32::MAX, 12, 31)
+        {
+            return Err(DateTimeError::OutOfRange);
+        }
+        Ok(Iso::iso_from_fixed(fixed))
+    }
+}
+
+impl DateTime<Iso> {
+    /// Construct a new ISO datetime from integers
+    ///
+    /// ```rust
+    /// use icu_calendar::DateTime;
+    ///
+    /// let datetime_iso = DateTime::new_iso_datetime_from_integers(1970, 1, 2, 13, 1, 0)
+    ///     .expect("Failed to initialize ISO DateTime instance.");
+    ///
+    /// assert_eq!(datetime_iso.date.year().number, 1970);
+    /// assert_eq!(datetime_iso.date.month().number, 1);
+    /// assert_eq!(datetime_iso.date.day_of_month().0, 2);
+    /// assert_eq!(u8::from(datetime_iso.time.hour), 13);
+    /// assert_eq!(u8::from(datetime_iso.time.minute), 1);
+    /// assert_eq!(u8::from(datetime_iso.time.second), 0);
+    /// ```
+    pub fn new_iso_datetime_from_integers(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Iso>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_iso_date_from_integers(day, month, year)?,
+            time: Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+
+    /// The number of seconds between the Unix epoch (1970-01-01T00:00:00) and this
+    /// datetime, which is taken to be in UTC. Leap seconds are not counted.
+    ///
+    /// ```rust
+    /// use icu_calendar::DateTime;
+    ///
+    /// let datetime = DateTime::new_iso_datetime_from_integers(2000, 1, 1, 0, 0, 0)
+    ///     .expect("Failed to initialize ISO DateTime instance.");
+    /// assert_eq!(datetime.to_unix_seconds(), 946684800);
+    /// assert_eq!(DateTime::from_unix_seconds(946684800).unwrap(), datetime);
+    /// ```
+    pub fn to_unix_seconds(&self) -> i64 {
+        let days = self.date.to_fixed() - UNIX_EPOCH_FIXED;
+        days * SECONDS_IN_DAY
+            + u8::from(self.time.hour) as i64 * 3600
+            + u8::from(self.time.minute) as i64 * 60
+            + u8::from(self.time.second) as i64
+    }
+
+    /// Construct a datetime from the number of seconds since the Unix epoch
+    /// (1970-01-01T00:00:00), in UTC.
+    ///
+    /// Returns [`DateTimeError::OutOfRange`] if the year of the datetime does not fit in
+    /// an `i32`.
+    pub fn from_unix_seconds(seconds: i64) -> Result<DateTime<Iso>, DateTimeError> {
+        Self::from_unix_seconds_and_nanos(seconds, 0)
+    }
+
+    /// The number of milliseconds between the Unix epoch (1970-01-01T00:00:00) and this
+    /// datetime, which is taken to be in UTC. Leap seconds are not counted.
+    ///
+    /// Any fraction of a millisecond is truncated. Returns `None` if the number of
+    /// milliseconds does not fit in an `i64`, which is the case for datetimes more than
+    /// about 292 million years away from the epoch.
+    pub fn to_unix_milliseconds(&self) -> Option<i64> {
+        let seconds = self.to_unix_seconds();
+        let millis = (u32::from(self.time.nanosecond) / 1_000_000) as i64;
+        if seconds < 0 {
+            // Avoid overflowing for timestamps close to i64::MIN
+            (seconds + 1).checked_mul(1000)?.checked_add(millis - 1000)
+        } else {
+            seconds.checked_mul(1000)?.checked_add(millis)
+        }
+    }
+
+    /// Construct a datetime from the number of milliseconds since the Unix epoch
+    /// (1970-01-01T00:00:00), in UTC.
+    ///
+    /// Returns [`DateTimeError::OutOfRange`] if the year of the datetime does not fit in
+    /// an `i32`.
+    pub fn from_unix_milliseconds(milliseconds: i64) -> Result<DateTime<Iso>, DateTimeError> {
+        Self::from_unix_seconds_and_nanos(
+            milliseconds.div_euclid(1000),
+            milliseconds.rem_euclid(1000) as u32 * 1_000_000,
+        )
+    }
+
+    fn from_unix_seconds_and_nanos(
+        seconds: i64,
+        nanosecond: u32,
+    ) -> Result<DateTime<Iso>, DateTimeError> {
+        let date = Date::from_fixed(seconds.div_euclid(SECONDS_IN_DAY) + UNIX_EPOCH_FIXED)?;
+        let second_of_day = seconds.rem_euclid(SECONDS_IN_DAY);
+        let time = Time::new(
+            IsoHour::new_unchecked((second_of_day / 3600) as u8),
+            IsoMinute::new_unchecked((second_of_day % 3600 / 60) as u8),
+            IsoSecond::new_unchecked((second_of_day % 60) as u8),
+            NanoSecond::new_unchecked(nanosecond),
+        );
+        Ok(DateTime { date, time })
+    }
 }
 
 impl Iso {
@@ -262,8 +372,17 @@ impl Iso {
         year.0 % 4 == 0 && (year.0 % 400 == 0 || year.0 % 100 != 0)
     }
 
+    /// The number of days in the given ISO year
+    pub(crate) fn days_in_year_direct(year: i32) -> u32 {
+        if Self::is_leap_year(IsoYear(year)) {
+            366
+        } else {
+            365
+        }
+    }
+
     /// Count the number of days in a given month/year combo
-    fn days_in_month(year: IsoYear, month: IsoMonth) -> u8 {
+    pub(crate) fn days_in_month(year: IsoYear, month: IsoMonth) -> u8 {
         match month.0 {
             4 | 6 | 9 | 11 => 30,
             2 if Self::is_leap_year(year) => 29,
@@ -271,12 +390,174 @@ impl Iso {
             _ => 31,
         }
     }
+
+    /// Count the number of days in the given year prior to the start of the given month
+    fn days_before_month(year: IsoYear, month: IsoMonth) -> u16 {
+        (1..month.0)
+            .map(|m| Self::days_in_month(year, IsoMonth(m)) as u16)
+            .sum()
+    }
+
+    /// The 1-indexed day of the year for the given date
+    pub(crate) fn day_of_year(date: IsoDateInner) -> u32 {
+        Self::days_before_month(date.year, date.month) as u32 + date.day.0 as u32
+    }
+
+    /// The number of days since the start of the ISO calendar ("Rata Die"), such that
+    /// January 1, 1 CE is day 1.
+    ///
+    /// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1167-L1189
+    pub(crate) fn fixed_from_iso(date: IsoDateInner) -> i64 {
+        let prev_year = date.year.0 as i64 - 1;
+        // Calculate days per year
+        let mut fixed: i64 = 365 * prev_year;
+        // Adjust for leap year logic
+        fixed += prev_year.div_euclid(4) - prev_year.div_euclid(100) + prev_year.div_euclid(400);
+        // Days of current year
+        fixed += Self::days_before_month(date.year, date.month) as i64;
+        // Days passed in current month
+        fixed + date.day.0 as i64
+    }
+
+    /// Like [`Iso::fixed_from_iso()`], for callers that already know that the date is valid
+    pub(crate) fn fixed_from_iso_integers(year: i32, month: u8, day: u8) -> i64 {
+        Self::fixed_from_iso(IsoDateInner {
+            day: IsoDay(day),
+            month: IsoMonth(month),
+            year: IsoYear(year),
+        })
+    }
+
+    /// The ISO year containing the given fixed day
+    pub(crate) fn iso_year_from_fixed(date: i64) -> i32 {
+        let d0 = date - 1;
+        let n400 = d0.div_euclid(146097);
+        let d1 = d0.rem_euclid(146097);
+        let n100 = d1.div_euclid(36524);
+        let d2 = d1.rem_euclid(36524);
+        let n4 = d2.div_euclid(1461);
+        let d3 = d2.rem_euclid(1461);
+        let n1 = d3.div_euclid(365);
+        let year = 400 * n400 + 100 * n100 + 4 * n4 + n1;
+        if n100 == 4 || n1 == 4 {
+            year as i32
+        } else {
+            (year + 1) as i32
+        }
+    }
+
+    /// The inverse of [`Iso::fixed_from_iso()`]
+    ///
+    /// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1191-L1217
+    pub(crate) fn iso_from_fixed(date: i64) -> Date<Iso> {
+        let year = IsoYear(Self::iso_year_from_fixed(date));
+        let jan_1 = Self::fixed_from_iso(IsoDateInner {
+            day: IsoDay(1),
+            month: IsoMonth(1),
+            year,
+        });
+        // The day of the year, 0-indexed
+        let mut remaining = (date - jan_1) as u16;
+        let mut month = IsoMonth(1);
+        loop {
+            let month_days = Self::days_in_month(year, month) as u16;
+            if remaining < month_days || month.0 == 12 {
+                break;
+            }
+            remaining -= month_days;
+            month.0 += 1;
+        }
+        let day = IsoDay(remaining as u8 + 1);
+        Date::from_raw(IsoDateInner { day, month, year }, Iso)
+    }
+
+    fn year_as_iso(year: i32) -> types::Year {
+        types::Year {
+            era: types::Era(tinystr8!("default")),
+            number: year,
+            related_iso: year,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_fixed() {
+        // (fixed, (day, month, year))
+        let cases = [
+            (1, (1, 1, 1)),
+            (0, (31, 12, 0)),
+            (-365, (1, 1, 0)),
+            (-305, (1, 3, 0)),
+            (-366, (31, 12, -1)),
+            (-719527, (1, 1, -1969)),
+            (719163, (1, 1, 1970)),
+            (738153, (29, 12, 2021)),
+        ];
+        for &(fixed, (d, m, y)) in &cases {
+            let date = Date::new_iso_date_from_integers(d, m, y).unwrap();
+            assert_eq!(date.to_fixed(), fixed, "{:?}", date);
+            assert_eq!(Date::from_fixed(fixed).unwrap(), date, "{}", fixed);
+        }
+    }
+
+    #[test]
+    fn test_fixed_round_trip() {
+        let min = Date::new_iso_date_from_integers(1, 1, i32::MIN).unwrap();
+        let max = Date::new_iso_date_from_integers(31, 12, i32::MAX).unwrap();
+        assert_eq!(Date::from_fixed(min.to_fixed()).unwrap(), min);
+        assert_eq!(Date::from_fixed(max.to_fixed()).unwrap(), max);
+        assert!(Date::from_fixed(min.to_fixed() - 1).is_err());
+        assert!(Date::from_fixed(max.to_fixed() + 1).is_err());
+
+        // Every day around the epochs, and a sample of days across the whole range
+        let step = (max.to_fixed() - min.to_fixed()) / 100_000;
+        let days =
+            (-200_000..200_000).chain((min.to_fixed()..=max.to_fixed()).step_by(step as usize));
+        for fixed in days {
+            let date = Date::from_fixed(fixed).unwrap();
+            assert_eq!(date.to_fixed(), fixed, "{:?}", date);
+        }
+    }
+
+    #[test]
+    fn test_unix_epoch() {
+        // (unix seconds, (year, month, day, hour, minute, second))
+        let cases = [
+            (0, (1970, 1, 1, 0, 0, 0)),
+            (-1, (1969, 12, 31, 23, 59, 59)),
+            (951782400, (2000, 2, 29, 0, 0, 0)),
+            (1640995199, (2021, 12, 31, 23, 59, 59)),
+            (-62135596800, (1, 1, 1, 0, 0, 0)),
+            (-62167219200, (0, 1, 1, 0, 0, 0)),
+            (-377736739200, (-10000, 1, 1, 0, 0, 0)),
+        ];
+        for &(seconds, (y, mo, d, h, mi, s)) in &cases {
+            let datetime = DateTime::new_iso_datetime_from_integers(y, mo, d, h, mi, s).unwrap();
+            assert_eq!(datetime.to_unix_seconds(), seconds, "{:?}", datetime);
+            assert_eq!(DateTime::from_unix_seconds(seconds).unwrap(), datetime);
+            assert_eq!(datetime.to_unix_milliseconds(), Some(seconds * 1000));
+        }
+
+        let datetime = DateTime::from_unix_milliseconds(-1).unwrap();
+        assert_eq!(datetime.to_unix_seconds(), -1);
+        assert_eq!(u32::from(datetime.time.nanosecond), 999_000_000);
+        assert_eq!(datetime.to_unix_milliseconds(), Some(-1));
+
+        assert!(DateTime::from_unix_seconds(i64::MAX).is_err());
+        assert!(DateTime::from_unix_seconds(i64::MIN).is_err());
+        // All millisecond timestamps fit in the supported range
+        for &ms in &[i64::MIN, i64::MAX] {
+            let datetime = DateTime::from_unix_milliseconds(ms).unwrap();
+            assert_eq!(datetime.to_unix_milliseconds(), Some(ms));
+        }
+        let max = DateTime::new_iso_datetime_from_integers(i32::MAX, 12, 31, 23, 59, 59).unwrap();
+        assert_eq!(max.to_unix_milliseconds(), None);
+    }
+
     #[test]
     fn test_day_of_week() {
         // June 23, 2021 is a Wednesday
@@ -293,6 +574,27 @@ mod test {
                 .day_of_week(),
             3
         );
+        // Dec 26, 2020 was a Saturday
+        assert_eq!(
+            Date::new_iso_date_from_integers(26, 12, 2020)
+                .unwrap()
+                .day_of_week(),
+            6
+        );
+        // Jan 1, 2000 was a Saturday
+        assert_eq!(
+            Date::new_iso_date_from_integers(1, 1, 2000)
+                .unwrap()
+                .day_of_week(),
+            6
+        );
+        // Jan 1, 1 BCE was a Saturday
+        assert_eq!(
+            Date::new_iso_date_from_integers(1, 1, 0)
+                .unwrap()
+                .day_of_week(),
+            6
+        );
     }
 
     fn simple_subtract(a: &Date<Iso>, b: &Date<Iso>) -> DateDuration<Iso> {
@@ -326,4 +628,46 @@ mod test {
             .added(simple_subtract(&today_minus_5000, &today));
         assert_eq!(offset, today_minus_5000);
     }
+
+    #[test]
+    fn test_offset_months() {
+        let date = Date::new_iso_date_from_integers(15, 1, 2021).unwrap();
+        let cases = [
+            (1, (15, 2, 2021)),
+            (12, (15, 1, 2022)),
+            (-1, (15, 12, 2020)),
+            (-12, (15, 1, 2020)),
+            (-13, (15, 12, 2019)),
+            (-24, (15, 1, 2019)),
+        ];
+        for &(months, (day, month, year)) in &cases {
+            let offset = date.clone().added(DateDuration::new(0, months, 0, 0));
+            let expected = Date::new_iso_date_from_integers(day, month, year).unwrap();
+            assert_eq!(offset, expected, "{} months", months);
+            assert_eq!(offset.month().number, month as u32);
+        }
+    }
+
+    #[test]
+    fn test_until() {
+        let date = |day, month, year| Date::new_iso_date_from_integers(day, month, year).unwrap();
+        let start = date(15, 1, 2020);
+        let cases = [
+            (date(5, 3, 2021), DateDurationUnit::Years, (1, 1, 0, 18)),
+            (date(5, 3, 2021), DateDurationUnit::Months, (0, 13, 0, 18)),
+            (date(5, 3, 2021), DateDurationUnit::Weeks, (0, 0, 59, 2)),
+            (date(5, 3, 2021), DateDurationUnit::Days, (0, 0, 0, 415)),
+            (date(30, 12, 2019), DateDurationUnit::Years, (0, 0, 0, -16)),
+            (date(15, 11, 2018), DateDurationUnit::Years, (-1, -2, 0, 0)),
+        ];
+        for (end, largest_unit, (years, months, weeks, days)) in IntoIterator::into_iter(cases) {
+            assert_eq!(
+                start.until(&end, largest_unit, DateDurationUnit::Days),
+                DateDuration::new(years, months, weeks, days),
+                "{:?} until {:?}",
+                start,
+                end
+            );
+        }
+    }
 }
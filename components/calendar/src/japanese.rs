@@ -0,0 +1,240 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Japanese calendar
+
+use crate::gregorian::year_as_gregorian;
+use crate::iso::{Iso, IsoDateInner};
+use crate::{
+    types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+use tinystr::{tinystr8, TinyStr8};
+
+/// The Japanese Calendar
+///
+/// The Japanese calendar shares its months and days with the ISO calendar, but counts
+/// years within eras that begin with the accession of each emperor. Only the modern eras
+/// (from Meiji onwards) are supported; dates before the start of the Meiji era use the
+/// Gregorian "ce" and "bce" eras.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Japanese;
+
+/// The start dates of the modern Japanese eras, as (ISO year, month, day, era code),
+/// in chronological order
+const ERAS: [(i32, u8, u8, &str); 5] = [
+    (1868, 9, 8, "meiji"),
+    (1912, 7, 30, "taisho"),
+    (1926, 12, 25, "showa"),
+    (1989, 1, 8, "heisei"),
+    (2019, 5, 1, "reiwa"),
+];
+
+impl Calendar for Japanese {
+    type DateInner = IsoDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> IsoDateInner {
+        *iso.inner()
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Date::from_raw(*date, Iso)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        Iso.months_in_year(date)
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        Iso.days_in_year(date)
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        Iso.days_in_month(date)
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> u8 {
+        Iso.day_of_week(date)
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        Iso.offset_date(date, offset.cast_unit())
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        Iso.until(date1, date2, largest_unit, smallest_unit)
+            .cast_unit()
+    }
+
+    fn debug_name() -> &'static str {
+        "Japanese"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Japanese)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        year_as_japanese(date.year.0, date.month.into(), date.day.into())
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        Iso.month(date)
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        Iso.day_of_month(date)
+    }
+
+    /// The previous and next years are computed for the same day of the year, which
+    /// matters for years in which an era begins.
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let (year, month, day): (i32, u8, u8) = (date.year.0, date.month.into(), date.day.into());
+        types::DayOfYearInfo {
+            day_of_year: Iso::day_of_year(*date),
+            days_in_year: Iso.days_in_year(date),
+            prev_year: year_as_japanese(year - 1, month, day),
+            days_in_prev_year: Iso::days_in_year_direct(year - 1),
+            next_year: year_as_japanese(year + 1, month, day),
+        }
+    }
+}
+
+impl Date<Japanese> {
+    /// Construct a new Japanese Date.
+    ///
+    /// Years are specified as years within the given era. The eras "ce" and "bce" may be
+    /// used for dates before the start of the Meiji era.
+    ///
+    /// ```rust
+    /// use icu_calendar::{types::Era, Date};
+    /// use tinystr::tinystr8;
+    ///
+    /// let date_japanese = Date::new_japanese_date(Era(tinystr8!("heisei")), 31, 4, 30)
+    ///     .expect("Failed to initialize Japanese Date instance.");
+    ///
+    /// assert_eq!(date_japanese.to_iso().year().number, 2019);
+    ///
+    /// let next_day = Date::new_japanese_date(Era(tinystr8!("reiwa")), 1, 5, 1)
+    ///     .expect("Failed to initialize Japanese Date instance.");
+    /// assert_eq!(next_day.year().era.0.as_str(), "reiwa");
+    /// assert_eq!(next_day.year().number, 1);
+    ///
+    /// // The Heisei era ended on April 30th, 2019
+    /// assert!(Date::new_japanese_date(Era(tinystr8!("heisei")), 31, 5, 1).is_err());
+    /// ```
+    pub fn new_japanese_date(
+        era: types::Era,
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<Japanese>, DateTimeError> {
+        let iso_year = match era.0.as_str() {
+            "ce" => year,
+            "bce" => 1 - year,
+            code => {
+                let &(start_year, _, _, _) = ERAS
+                    .iter()
+                    .find(|&&(_, _, _, era_code)| era_code == code)
+                    .ok_or_else(|| DateTimeError::UnknownEra(era.0, Japanese::debug_name()))?;
+                start_year + year - 1
+            }
+        };
+        let date = Date::new_iso_date_from_integers(day, month, iso_year)?.to_calendar(Japanese);
+        // Check that the date actually falls within the given era
+        let actual = date.year();
+        if actual.era != era || actual.number != year {
+            return Err(DateTimeError::OutOfRange);
+        }
+        Ok(date)
+    }
+}
+
+/// Convert an ISO date into a Japanese year with an era
+fn year_as_japanese(iso_year: i32, month: u8, day: u8) -> types::Year {
+    let era = ERAS
+        .iter()
+        .rev()
+        .find(|&&(year, m, d, _)| (iso_year, month, day) >= (year, m, d));
+    match era {
+        Some(&(start_year, _, _, code)) => types::Year {
+            era: types::Era(era_code(code)),
+            number: iso_year - start_year + 1,
+            related_iso: iso_year,
+        },
+        None => year_as_gregorian(iso_year),
+    }
+}
+
+// tinystr8! cannot be used in consts, so the era codes are constructed here
+fn era_code(code: &str) -> TinyStr8 {
+    match code {
+        "meiji" => tinystr8!("meiji"),
+        "taisho" => tinystr8!("taisho"),
+        "showa" => tinystr8!("showa"),
+        "heisei" => tinystr8!("heisei"),
+        "reiwa" => tinystr8!("reiwa"),
+        _ => unreachable!("Unknown Japanese era {}", code),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_japanese_eras() {
+        // (iso day, month, year), (era, era year)
+        let cases = [
+            ((1, 5, 2019), ("reiwa", 1)),
+            ((30, 4, 2019), ("heisei", 31)),
+            ((8, 1, 1989), ("heisei", 1)),
+            ((7, 1, 1989), ("showa", 64)),
+            ((25, 12, 1926), ("showa", 1)),
+            ((24, 12, 1926), ("taisho", 15)),
+            ((30, 7, 1912), ("taisho", 1)),
+            ((8, 9, 1868), ("meiji", 1)),
+            ((7, 9, 1868), ("ce", 1868)),
+        ];
+        for &((day, month, year), (era, number)) in &cases {
+            let iso = Date::new_iso_date_from_integers(day, month, year).unwrap();
+            let japanese = iso.to_calendar(Japanese);
+            assert_eq!(
+                japanese.year().era.0.as_str(),
+                era,
+                "{}-{}-{}",
+                year,
+                month,
+                day
+            );
+            assert_eq!(japanese.year().number, number, "{}-{}-{}", year, month, day);
+
+            let era = types::Era(era.parse().unwrap());
+            let constructed = Date::new_japanese_date(era, number, month, day).unwrap();
+            assert_eq!(constructed, japanese);
+        }
+    }
+
+    #[test]
+    fn test_japanese_constructor_errors() {
+        assert!(matches!(
+            Date::new_japanese_date(types::Era(tinystr8!("edo")), 1, 1, 1),
+            Err(DateTimeError::UnknownEra(..))
+        ));
+        // Showa 1 began on December 25th
+        assert!(matches!(
+            Date::new_japanese_date(types::Era(tinystr8!("showa")), 1, 12, 24),
+            Err(DateTimeError::OutOfRange)
+        ));
+        // Taisho ended in its 15th year
+        assert!(matches!(
+            Date::new_japanese_date(types::Era(tinystr8!("taisho")), 16, 1, 1),
+            Err(DateTimeError::OutOfRange)
+        ));
+    }
+}
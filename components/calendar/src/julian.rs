@@ -0,0 +1,237 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Julian calendar
+
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::gregorian::year_as_gregorian;
+use crate::iso::Iso;
+use crate::{
+    duration, types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+
+// Julian epoch is equivalent to fixed_from_iso of December 30th of 0 year
+// 1st Jan of 1st year Julian is equivalent to December 30th of 0th year of ISO year
+const JULIAN_EPOCH: i64 = -1;
+
+/// The Julian Calendar
+///
+/// The Julian calendar has the same months as the Gregorian calendar, but every fourth
+/// year is a leap year, without exceptions for century years. Years are numbered the
+/// same way as ISO years, so year 0 is 1 BCE.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+pub struct Julian;
+
+/// The inner date type used for representing Date<Julian>
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct JulianDateInner(ArithmeticDate<Julian>);
+
+impl CalendarArithmetic for Julian {
+    fn month_days(year: i32, month: u8) -> u8 {
+        match month {
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            _ => 0,
+        }
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        year.rem_euclid(4) == 0
+    }
+}
+
+impl Calendar for Julian {
+    type DateInner = JulianDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> JulianDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::julian_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_julian = Julian::fixed_from_julian(date.0);
+        Iso::iso_from_fixed(fixed_julian)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        duration::until(self, date1, date2, largest_unit, smallest_unit)
+    }
+
+    fn debug_name() -> &'static str {
+        "Julian"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Julian)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        year_as_gregorian(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.0.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_gregorian(date.0.year - 1),
+            days_in_prev_year: Julian::days_in_provided_year(date.0.year - 1),
+            next_year: year_as_gregorian(date.0.year + 1),
+        }
+    }
+}
+
+impl Julian {
+    /// Construct a new Julian Calendar
+    pub fn new() -> Self {
+        Self
+    }
+
+    // "Fixed" is a day count representation of calendars staring from Jan 1st of year 1 of the Georgian Calendar.
+    //
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1689-L1709
+    fn fixed_from_julian(date: ArithmeticDate<Julian>) -> i64 {
+        let prev_year = date.year as i64 - 1;
+        JULIAN_EPOCH - 1 + 365 * prev_year + prev_year.div_euclid(4) + date.day_of_year() as i64
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1711-L1738
+    fn julian_from_fixed(date: i64) -> JulianDateInner {
+        let year = (4 * (date - JULIAN_EPOCH) + 1464).div_euclid(1461) as i32;
+        let jan_1 = Self::fixed_from_julian(ArithmeticDate::new(year, 1, 1));
+        let day_of_year = (date - jan_1 + 1) as u32;
+        JulianDateInner(ArithmeticDate::from_year_and_day_of_year(year, day_of_year))
+    }
+}
+
+impl Date<Julian> {
+    /// Construct a new Julian Date.
+    ///
+    /// Years are specified as ISO years, i.e. year 0 is 1 BCE.
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    ///
+    /// let date_julian = Date::new_julian_date_from_integers(5, 10, 1582)
+    ///     .expect("Failed to initialize Julian Date instance.");
+    ///
+    /// assert_eq!(date_julian.year().number, 1582);
+    /// assert_eq!(date_julian.month().number, 10);
+    /// assert_eq!(date_julian.day_of_month().0, 5);
+    ///
+    /// // The day after the last Julian day observed in the Papal States
+    /// let date_iso = date_julian.to_iso();
+    /// assert_eq!(date_iso.day_of_month().0, 15);
+    /// ```
+    pub fn new_julian_date_from_integers(
+        day: u8,
+        month: u8,
+        year: i32,
+    ) -> Result<Date<Julian>, DateTimeError> {
+        ArithmeticDate::new_checked(year, month, day)
+            .map(|inner| Date::from_raw(JulianDateInner(inner), Julian))
+            .ok_or(DateTimeError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_julian_iso_conversion() {
+        // (julian day, month, year), (iso day, month, year)
+        let cases = [
+            ((1, 1, 1), (30, 12, 0)),
+            ((5, 10, 1582), (15, 10, 1582)),
+            ((29, 2, 1900), (13, 3, 1900)),
+            ((1, 1, 2021), (14, 1, 2021)),
+            ((31, 12, 0), (29, 12, 0)),
+            ((1, 3, -100), (27, 2, -100)),
+        ];
+        for &((jd, jm, jy), (id, im, iy)) in &cases {
+            let julian = Date::new_julian_date_from_integers(jd, jm, jy).unwrap();
+            let iso = Date::new_iso_date_from_integers(id, im, iy).unwrap();
+            assert_eq!(julian.to_iso(), iso, "Julian {}-{}-{}", jy, jm, jd);
+            assert_eq!(iso.to_calendar(Julian), julian, "ISO {}-{}-{}", iy, im, id);
+        }
+    }
+
+    #[test]
+    fn test_julian_leap_years() {
+        assert!(Julian::is_leap_year(1900));
+        assert!(Julian::is_leap_year(0));
+        assert!(Julian::is_leap_year(-4));
+        assert!(!Julian::is_leap_year(2021));
+        assert!(Date::new_julian_date_from_integers(29, 2, 1900).is_ok());
+        assert!(Date::new_julian_date_from_integers(29, 2, 1901).is_err());
+    }
+
+    #[test]
+    fn test_julian_offset() {
+        let date = Date::new_julian_date_from_integers(28, 2, 1900).unwrap();
+        let expected = Date::new_julian_date_from_integers(1, 3, 1900).unwrap();
+        assert_eq!(date.clone().added(DateDuration::new(0, 0, 0, 2)), expected);
+        assert_eq!(expected.added(DateDuration::new(0, 0, 0, -2)), date);
+
+        let date = Date::new_julian_date_from_integers(15, 11, 2020).unwrap();
+        let expected = Date::new_julian_date_from_integers(15, 2, 2021).unwrap();
+        assert_eq!(date.added(DateDuration::new(0, 3, 0, 0)), expected);
+    }
+
+    #[test]
+    fn test_julian_until() {
+        let date = Date::new_julian_date_from_integers(28, 2, 1900).unwrap();
+        let end = Date::new_julian_date_from_integers(1, 3, 1900).unwrap();
+        assert_eq!(
+            date.until(&end, DateDurationUnit::Years, DateDurationUnit::Days),
+            DateDuration::new(0, 0, 0, 2)
+        );
+
+        let date = Date::new_julian_date_from_integers(15, 11, 2020).unwrap();
+        let end = Date::new_julian_date_from_integers(15, 2, 2021).unwrap();
+        assert_eq!(
+            date.until(&end, DateDurationUnit::Years, DateDurationUnit::Days),
+            DateDuration::new(0, 3, 0, 0)
+        );
+        assert_eq!(
+            end.until(&date, DateDurationUnit::Years, DateDurationUnit::Days),
+            DateDuration::new(0, -3, 0, 0)
+        );
+    }
+}
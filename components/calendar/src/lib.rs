@@ -10,20 +10,60 @@
 //! The [`types`] module has a lot of common types for dealing with dates and times.
 //!
 //! [`Calendar`] is a trait that allows one to define custom calendars, and [`Date`]
-//! can represent dates for arbitrary calendars.
+//! can represent dates for arbitrary calendars. [`Time`] represents a time of day, and
+//! [`DateTime`] combines a [`Date`] with a [`Time`]. These can be parsed from ISO 8601,
+//! RFC 3339, and IXDTF strings, see [`ParsedDateTime`].
 //!
-//! The [`iso`] module contains an implementation for the ISO calendar.
+//! The [`iso`] module contains an implementation for the ISO calendar, and the
+//! [`gregorian`] module contains an implementation for the Gregorian calendar. The
+//! [`julian`], [`buddhist`], [`roc`], and [`japanese`] modules contain implementations
+//! for calendars that share their structure with the Gregorian calendar. The [`hebrew`]
+//! and [`chinese`] modules contain implementations for lunisolar calendars, which have
+//! leap months. The [`islamic`], [`coptic`], and [`ethiopic`] modules contain
+//! implementations for other arithmetic calendars.
+//!
+//! [`AnyCalendar`] can be used when the calendar is only known at runtime, for example
+//! when it is selected by the `-u-ca` keyword of a [`Locale`](icu_locid::Locale).
 extern crate alloc;
 
+pub mod any_calendar;
+mod astronomy;
+pub mod buddhist;
 mod calendar;
+mod calendar_arithmetic;
+pub mod chinese;
+pub mod coptic;
 mod date;
+mod datetime;
 mod duration;
 mod error;
+pub mod ethiopic;
+pub mod gregorian;
+pub mod hebrew;
+pub mod islamic;
 pub mod iso;
+pub mod japanese;
+pub mod julian;
+mod parse;
+pub mod roc;
 pub mod types;
+pub mod week;
 
+pub use any_calendar::{AnyCalendar, AnyCalendarKind};
+pub use buddhist::Buddhist;
 pub use calendar::Calendar;
+pub use chinese::Chinese;
+pub use coptic::Coptic;
 pub use date::{AsCalendar, Date};
-pub use duration::{DateDuration, DateDurationUnit};
+pub use datetime::{DateTime, Time};
+pub use duration::{DateDuration, DateDurationUnit, Overflow};
 pub use error::DateTimeError;
+pub use ethiopic::Ethiopic;
+pub use gregorian::Gregorian;
+pub use hebrew::Hebrew;
+pub use islamic::{IslamicCivil, IslamicTabular};
 pub use iso::Iso;
+pub use japanese::Japanese;
+pub use julian::Julian;
+pub use parse::ParsedDateTime;
+pub use roc::Roc;
@@ -0,0 +1,660 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of ISO 8601, [RFC 3339], and [IXDTF] strings
+//!
+//! [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+//! [IXDTF]: https://datatracker.ietf.org/doc/draft-ietf-sedate-datetime-extended/
+
+use crate::iso::IsoYear;
+use crate::types::GmtOffset;
+use crate::{AnyCalendar, AnyCalendarKind, Date, DateTime, DateTimeError, Iso, Time};
+use alloc::string::{String, ToString};
+use core::convert::TryInto;
+use core::str::FromStr;
+
+/// A date and time parsed from an ISO 8601, RFC 3339, or IXDTF string, along with its
+/// UTC offset and annotations
+///
+/// The following syntax is supported:
+///
+/// - Dates in the extended (`2021-09-14`) or basic (`20210914`) format, with four digit
+///   years or signed six digit years (`+002021-09-14`)
+/// - An optional time, separated by `T` or a space, with optional minutes, seconds, and
+///   up to nine digits of fractional seconds (`T13:37:02.123`, `T133702,123`). A leap
+///   second is constrained to the previous second.
+/// - An optional UTC offset after the time (`Z`, `-07`, `+0530`, `+05:30`)
+/// - Annotations in brackets: a time zone (`[America/Los_Angeles]`), followed by keys and
+///   values such as the calendar (`[u-ca=hebrew]`). Unknown annotations are ignored, unless
+///   they are marked as critical with `!` (`[!x-foo=bar]`).
+///
+/// ```rust
+/// use icu_calendar::{AnyCalendarKind, ParsedDateTime};
+///
+/// let parsed: ParsedDateTime = "2021-09-14T13:37:02.5+02:00[Europe/Paris][u-ca=hebrew]"
+///     .parse()
+///     .expect("Failed to parse a datetime.");
+///
+/// assert_eq!(parsed.datetime.date.year().number, 2021);
+/// assert_eq!(u8::from(parsed.datetime.time.hour), 13);
+/// assert_eq!(u32::from(parsed.datetime.time.nanosecond), 500_000_000);
+/// assert_eq!(parsed.offset.map(|offset| offset.raw_offset_seconds()), Some(7200));
+/// assert_eq!(parsed.time_zone.as_deref(), Some("Europe/Paris"));
+/// assert_eq!(parsed.calendar, Some(AnyCalendarKind::Hebrew));
+///
+/// let hebrew = parsed.to_any();
+/// assert_eq!(hebrew.date.year().number, 5782);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedDateTime {
+    /// The date and time, which is midnight if the string only contained a date
+    pub datetime: DateTime<Iso>,
+    /// The UTC offset, if any
+    pub offset: Option<GmtOffset>,
+    /// The time zone annotation, if any, which is either a time zone name or a UTC offset
+    pub time_zone: Option<String>,
+    /// The calendar of the `u-ca` annotation, if any
+    pub calendar: Option<AnyCalendarKind>,
+}
+
+impl ParsedDateTime {
+    /// Convert the parsed datetime to the calendar of its `u-ca` annotation, or the ISO
+    /// calendar if it has none
+    pub fn to_any(&self) -> DateTime<AnyCalendar> {
+        let kind = self.calendar.unwrap_or(AnyCalendarKind::Iso);
+        self.datetime.to_calendar(AnyCalendar::new(kind))
+    }
+}
+
+impl FromStr for ParsedDateTime {
+    type Err = DateTimeError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(input);
+        let date = parser.date()?;
+        let mut time = Time::default();
+        let mut offset = None;
+        if parser.eat(b'T') || parser.eat(b't') || parser.eat(b' ') {
+            time = parser.time()?;
+            offset = parser.offset()?;
+        }
+        let (time_zone, calendar) = parser.annotations()?;
+        parser.finish()?;
+        Ok(Self {
+            datetime: DateTime::new(date, time),
+            offset,
+            time_zone,
+            calendar,
+        })
+    }
+}
+
+impl FromStr for Date<Iso> {
+    type Err = DateTimeError;
+
+    /// Parse an ISO date from a string, see [`ParsedDateTime`] for the supported syntax
+    ///
+    /// Any time, offset, and annotations are validated, but ignored.
+    ///
+    /// ```rust
+    /// use icu_calendar::{Date, Iso};
+    ///
+    /// let date: Date<Iso> = "2021-09-14".parse().expect("Failed to parse a date.");
+    /// assert_eq!(date, Date::new_iso_date_from_integers(14, 9, 2021).unwrap());
+    ///
+    /// let date: Date<Iso> = "-000001-01-01".parse().expect("Failed to parse a date.");
+    /// assert_eq!(date.year().number, -1);
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input
+            .parse::<ParsedDateTime>()
+            .map(|parsed| parsed.datetime.date)
+    }
+}
+
+impl FromStr for DateTime<Iso> {
+    type Err = DateTimeError;
+
+    /// Parse an ISO datetime from a string, see [`ParsedDateTime`] for the supported syntax
+    ///
+    /// Any offset and annotations are validated, but ignored.
+    ///
+    /// ```rust
+    /// use icu_calendar::{DateTime, Iso};
+    ///
+    /// let datetime: DateTime<Iso> = "2021-09-14T13:37:02"
+    ///     .parse()
+    ///     .expect("Failed to parse a datetime.");
+    /// let expected = DateTime::new_iso_datetime_from_integers(2021, 9, 14, 13, 37, 2).unwrap();
+    /// assert_eq!(datetime, expected);
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input
+            .parse::<ParsedDateTime>()
+            .map(|parsed| parsed.datetime)
+    }
+}
+
+impl FromStr for Time {
+    type Err = DateTimeError;
+
+    /// Parse a time of day from a string, optionally starting with `T`
+    ///
+    /// ```rust
+    /// use icu_calendar::Time;
+    ///
+    /// let time: Time = "13:37:02.25".parse().expect("Failed to parse a time.");
+    /// assert_eq!(time, Time::try_new(13, 37, 2, 250_000_000).unwrap());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(input);
+        let _ = parser.eat(b'T') || parser.eat(b't');
+        let time = parser.time()?;
+        parser.finish()?;
+        Ok(time)
+    }
+}
+
+/// Parse a UTC offset such as `Z`, `-07`, `+0530`, or `+05:30:15`, returning
+/// [`DateTimeError::InvalidTimeZoneOffset`] if the string does not start like an offset
+pub(crate) fn parse_offset(input: &str) -> Result<GmtOffset, DateTimeError> {
+    let mut parser = Parser::new(input);
+    let offset = parser
+        .offset()?
+        .ok_or(DateTimeError::InvalidTimeZoneOffset)?;
+    parser.finish()?;
+    Ok(offset)
+}
+
+/// A cursor over the input, which is always at a character boundary
+struct Parser<'a> {
+    input: &'a str,
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, index: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.index).copied()
+    }
+
+    fn peek_digit(&self) -> bool {
+        self.peek().map_or(false, |byte| byte.is_ascii_digit())
+    }
+
+    /// The error for the character at the current position
+    fn error(&self) -> DateTimeError {
+        match self.input[self.index..].chars().next() {
+            Some(found) => DateTimeError::UnexpectedCharacter {
+                index: self.index,
+                found,
+            },
+            None => DateTimeError::UnexpectedEnd,
+        }
+    }
+
+    /// Consume `byte` if it is next
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), DateTimeError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn finish(&self) -> Result<(), DateTimeError> {
+        if self.index == self.input.len() {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Consume a sign, which may be the Unicode minus sign
+    fn sign(&mut self) -> Option<i32> {
+        if self.eat(b'+') {
+            Some(1)
+        } else if self.eat(b'-') {
+            Some(-1)
+        } else if self.input[self.index..].starts_with('\u{2212}') {
+            self.index += '\u{2212}'.len_utf8();
+            Some(-1)
+        } else {
+            None
+        }
+    }
+
+    /// Consume exactly `count` digits
+    fn digits(&mut self, count: usize) -> Result<u32, DateTimeError> {
+        let mut value = 0;
+        for _ in 0..count {
+            match self.peek() {
+                Some(byte @ b'0'..=b'9') => {
+                    value = value * 10 + (byte - b'0') as u32;
+                    self.index += 1;
+                }
+                _ => return Err(self.error()),
+            }
+        }
+        Ok(value)
+    }
+
+    fn date(&mut self) -> Result<Date<Iso>, DateTimeError> {
+        let year = match self.sign() {
+            Some(sign) => {
+                let year = self.digits(6)? as i32;
+                if sign < 0 && year == 0 {
+                    return Err(DateTimeError::NegativeZeroYear);
+                }
+                sign * year
+            }
+            None => self.digits(4)? as i32,
+        };
+        // The separators must be used consistently
+        let extended = self.eat(b'-');
+        let month = self.digits(2)?;
+        if extended {
+            self.expect(b'-')?;
+        }
+        let day = self.digits(2)?;
+
+        check_range("Month", month, 1, 12)?;
+        let month = (month as u8).try_into()?;
+        let days_in_month = Iso::days_in_month(IsoYear(year), month);
+        check_range("Day", day, 1, days_in_month as u32)?;
+        Date::new_iso_date((day as u8).try_into()?, month, IsoYear(year))
+    }
+
+    /// Parse hours, with optional minutes and seconds, in the extended or basic format
+    fn hours_minutes_seconds(&mut self) -> Result<(u32, u32, Option<u32>), DateTimeError> {
+        let hour = self.digits(2)?;
+        let extended = self.eat(b':');
+        if !extended && !self.peek_digit() {
+            return Ok((hour, 0, None));
+        }
+        let minute = self.digits(2)?;
+        let has_second = if extended {
+            self.eat(b':')
+        } else {
+            self.peek_digit()
+        };
+        let second = if has_second {
+            Some(self.digits(2)?)
+        } else {
+            None
+        };
+        Ok((hour, minute, second))
+    }
+
+    fn time(&mut self) -> Result<Time, DateTimeError> {
+        let (hour, minute, second) = self.hours_minutes_seconds()?;
+        let mut nanosecond = 0;
+        if second.is_some() && (self.eat(b'.') || self.eat(b',')) {
+            let start = self.index;
+            while self.peek_digit() {
+                if self.index - start == 9 {
+                    return Err(self.error());
+                }
+                nanosecond = nanosecond * 10 + (self.input.as_bytes()[self.index] - b'0') as u32;
+                self.index += 1;
+            }
+            let digits = self.index - start;
+            if digits == 0 {
+                return Err(self.error());
+            }
+            nanosecond *= 10u32.pow(9 - digits as u32);
+        }
+
+        check_range("Hour", hour, 0, 23)?;
+        check_range("Minute", minute, 0, 59)?;
+        let second = second.unwrap_or(0);
+        check_range("Second", second, 0, 60)?;
+        // Leap seconds are constrained to the previous second
+        let second = second.min(59);
+        Time::try_new(hour as u8, minute as u8, second as u8, nanosecond)
+    }
+
+    /// Parse a UTC offset, if there is one
+    fn offset(&mut self) -> Result<Option<GmtOffset>, DateTimeError> {
+        if self.eat(b'Z') || self.eat(b'z') {
+            return GmtOffset::try_new(0).map(Some);
+        }
+        let sign = match self.sign() {
+            Some(sign) => sign,
+            None => return Ok(None),
+        };
+        let (hours, minutes, seconds) = self.hours_minutes_seconds()?;
+        check_range("Offset hour", hours, 0, 23)?;
+        check_range("Offset minute", minutes, 0, 59)?;
+        let seconds = seconds.unwrap_or(0);
+        check_range("Offset second", seconds, 0, 59)?;
+        GmtOffset::try_new(sign * (hours * 3600 + minutes * 60 + seconds) as i32).map(Some)
+    }
+
+    /// Parse the bracketed annotations, returning the time zone and the calendar
+    fn annotations(&mut self) -> Result<(Option<String>, Option<AnyCalendarKind>), DateTimeError> {
+        let mut time_zone = None;
+        let mut calendar = None;
+        let mut calendar_critical = false;
+        let mut first = true;
+        while self.peek() == Some(b'[') {
+            let start = self.index;
+            self.index += 1;
+            let critical = self.eat(b'!');
+            let content_start = self.index;
+            let content_end = match self.input[self.index..].find(']') {
+                Some(len) => self.index + len,
+                None => {
+                    self.index = self.input.len();
+                    return Err(DateTimeError::UnexpectedEnd);
+                }
+            };
+            let content = &self.input[content_start..content_end];
+
+            // Only the first annotation may be a time zone
+            if first && !content.contains('=') {
+                self.time_zone_annotation(content_end)?;
+                time_zone = Some(content.to_string());
+            } else {
+                let (key, value) = self.key_value_annotation(content_end)?;
+                if key == "u-ca" {
+                    if calendar.is_some() {
+                        if critical || calendar_critical {
+                            return Err(DateTimeError::ConflictingAnnotation(start));
+                        }
+                    } else {
+                        calendar = Some(
+                            AnyCalendarKind::from_bcp47_string(value)
+                                .ok_or(DateTimeError::UnknownCalendar(start))?,
+                        );
+                        calendar_critical = critical;
+                    }
+                } else if critical {
+                    return Err(DateTimeError::UnknownCriticalAnnotation(start));
+                }
+            }
+            self.expect(b']')?;
+            first = false;
+        }
+        Ok((time_zone, calendar))
+    }
+
+    /// Validate a time zone name, e.g. `America/Los_Angeles`, or a UTC offset
+    fn time_zone_annotation(&mut self, end: usize) -> Result<(), DateTimeError> {
+        if self.sign().is_some() {
+            let (hours, minutes, _) = self.hours_minutes_seconds()?;
+            check_range("Offset hour", hours, 0, 23)?;
+            check_range("Offset minute", minutes, 0, 59)?;
+        } else {
+            if self.index == end {
+                return Err(self.error());
+            }
+            while self.index < end {
+                match self.peek() {
+                    Some(byte)
+                        if byte.is_ascii_alphanumeric()
+                            || matches!(byte, b'/' | b'_' | b'-' | b'+' | b'.') =>
+                    {
+                        self.index += 1
+                    }
+                    _ => return Err(self.error()),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate an annotation such as `u-ca=hebrew`, returning its key and value
+    fn key_value_annotation(&mut self, end: usize) -> Result<(&'a str, &'a str), DateTimeError> {
+        let key_start = self.index;
+        if !matches!(self.peek(), Some(b'a'..=b'z' | b'_')) {
+            return Err(self.error());
+        }
+        self.index += 1;
+        while matches!(self.peek(), Some(b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-')) {
+            self.index += 1;
+        }
+        let key = &self.input[key_start..self.index];
+        self.expect(b'=')?;
+
+        // The value is made of alphanumeric components, separated by hyphens
+        let value_start = self.index;
+        loop {
+            let component_start = self.index;
+            while self
+                .peek()
+                .map_or(false, |byte| byte.is_ascii_alphanumeric())
+            {
+                self.index += 1;
+            }
+            if self.index == component_start {
+                return Err(self.error());
+            }
+            if self.index == end || !self.eat(b'-') {
+                break;
+            }
+        }
+        Ok((key, &self.input[value_start..self.index]))
+    }
+}
+
+fn check_range(field: &'static str, value: u32, min: u32, max: u32) -> Result<(), DateTimeError> {
+    if (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(DateTimeError::FieldOutOfRange {
+            field,
+            value,
+            min,
+            max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> DateTime<Iso> {
+        DateTime::new(
+            Date::new_iso_date_from_integers(day, month, year).unwrap(),
+            Time::try_new(hour, minute, second, nanosecond).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_parse_datetime() {
+        let cases = [
+            ("2021-09-14", datetime(2021, 9, 14, 0, 0, 0, 0)),
+            ("20210914", datetime(2021, 9, 14, 0, 0, 0, 0)),
+            ("+002021-09-14", datetime(2021, 9, 14, 0, 0, 0, 0)),
+            ("-000001-12-31", datetime(-1, 12, 31, 0, 0, 0, 0)),
+            ("\u{2212}271821-04-20", datetime(-271821, 4, 20, 0, 0, 0, 0)),
+            ("+000000-02-29", datetime(0, 2, 29, 0, 0, 0, 0)),
+            ("2021-09-14T13", datetime(2021, 9, 14, 13, 0, 0, 0)),
+            ("2021-09-14T13:37", datetime(2021, 9, 14, 13, 37, 0, 0)),
+            ("2021-09-14t13:37:02", datetime(2021, 9, 14, 13, 37, 2, 0)),
+            ("2021-09-14 13:37:02", datetime(2021, 9, 14, 13, 37, 2, 0)),
+            ("20210914T133702", datetime(2021, 9, 14, 13, 37, 2, 0)),
+            (
+                "2021-09-14T13:37:02.1",
+                datetime(2021, 9, 14, 13, 37, 2, 100_000_000),
+            ),
+            (
+                "2021-09-14T13:37:02,000001",
+                datetime(2021, 9, 14, 13, 37, 2, 1000),
+            ),
+            (
+                "2021-09-14T13:37:02.123456789",
+                datetime(2021, 9, 14, 13, 37, 2, 123_456_789),
+            ),
+            (
+                "2016-12-31T23:59:60Z",
+                datetime(2016, 12, 31, 23, 59, 59, 0),
+            ),
+        ];
+        for (input, expected) in &cases {
+            let parsed: ParsedDateTime = input.parse().expect(input);
+            assert_eq!(&parsed.datetime, expected, "{}", input);
+            let datetime: DateTime<Iso> = input.parse().unwrap();
+            assert_eq!(&datetime, expected, "{}", input);
+            let date: Date<Iso> = input.parse().unwrap();
+            assert_eq!(date, expected.date, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_offset() {
+        let cases = [
+            ("2021-09-14T13:37Z", Some(0)),
+            ("2021-09-14T13:37z", Some(0)),
+            ("2021-09-14T13:37:02-07", Some(-7 * 3600)),
+            ("2021-09-14T13:37:02.5+0530", Some(5 * 3600 + 30 * 60)),
+            ("2021-09-14T13:37:02+05:30", Some(5 * 3600 + 30 * 60)),
+            (
+                "2021-09-14T13:37:02\u{2212}03:30:15",
+                Some(-(3 * 3600 + 30 * 60 + 15)),
+            ),
+            ("2021-09-14T13:37:02-00:00", Some(0)),
+            ("2021-09-14T13:37:02", None),
+            ("2021-09-14", None),
+        ];
+        for (input, expected) in &cases {
+            let parsed: ParsedDateTime = input.parse().expect(input);
+            let offset = parsed.offset.map(|offset| offset.raw_offset_seconds());
+            assert_eq!(offset, *expected, "{}", input);
+        }
+
+        let offset: GmtOffset = "-09:30".parse().unwrap();
+        assert_eq!(offset.raw_offset_seconds(), -(9 * 3600 + 30 * 60));
+        assert!(matches!(
+            "09:30".parse::<GmtOffset>(),
+            Err(DateTimeError::InvalidTimeZoneOffset)
+        ));
+        assert!(matches!(
+            "+09:3x".parse::<GmtOffset>(),
+            Err(DateTimeError::UnexpectedCharacter { index: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_annotations() {
+        let parsed: ParsedDateTime = "2021-09-14T13:37:02-07:00[America/Los_Angeles]"
+            .parse()
+            .unwrap();
+        assert_eq!(parsed.time_zone.as_deref(), Some("America/Los_Angeles"));
+        assert_eq!(parsed.calendar, None);
+
+        let parsed: ParsedDateTime = "2021-09-14[u-ca=islamic-civil]".parse().unwrap();
+        assert_eq!(parsed.time_zone, None);
+        assert_eq!(parsed.calendar, Some(AnyCalendarKind::IslamicCivil));
+        assert_eq!(parsed.to_any().date.year().number, 1443);
+
+        let parsed: ParsedDateTime = "2021-09-14T00:00[-03:00][!u-ca=coptic][x-foo=bar-baz]"
+            .parse()
+            .unwrap();
+        assert_eq!(parsed.time_zone.as_deref(), Some("-03:00"));
+        assert_eq!(parsed.calendar, Some(AnyCalendarKind::Coptic));
+
+        // The first calendar is used, unless one of them is critical
+        let parsed: ParsedDateTime = "2021-09-14[u-ca=hebrew][u-ca=chinese]".parse().unwrap();
+        assert_eq!(parsed.calendar, Some(AnyCalendarKind::Hebrew));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use DateTimeError::*;
+        let unexpected = |index, found| UnexpectedCharacter { index, found };
+        let out_of_range = |field, value, min, max| FieldOutOfRange {
+            field,
+            value,
+            min,
+            max,
+        };
+        let cases = [
+            ("", UnexpectedEnd),
+            ("2021", UnexpectedEnd),
+            ("2021-09", UnexpectedEnd),
+            ("202-09-14", unexpected(3, '-')),
+            ("2021-0914", unexpected(7, '1')),
+            ("202109-14", unexpected(6, '-')),
+            ("+2021-09-14", unexpected(5, '-')),
+            ("-000000-01-01", NegativeZeroYear),
+            ("2021-13-01", out_of_range("Month", 13, 1, 12)),
+            ("2021-00-01", out_of_range("Month", 0, 1, 12)),
+            ("2021-02-29", out_of_range("Day", 29, 1, 28)),
+            ("2021-09-14T", UnexpectedEnd),
+            ("2021-09-14T1", UnexpectedEnd),
+            ("2021-09-14T24:00", out_of_range("Hour", 24, 0, 23)),
+            ("2021-09-14T13:60", out_of_range("Minute", 60, 0, 59)),
+            ("2021-09-14T13:37:61", out_of_range("Second", 61, 0, 60)),
+            ("2021-09-14T13:3702", unexpected(16, '0')),
+            ("2021-09-14T13:37:02.", UnexpectedEnd),
+            ("2021-09-14T13:37:02.1234567891", unexpected(29, '1')),
+            (
+                "2021-09-14T13:37+24:00",
+                out_of_range("Offset hour", 24, 0, 23),
+            ),
+            (
+                "2021-09-14T13:37+15:00",
+                Overflow {
+                    field: "GmtOffset",
+                    max: 14 * 60 * 60,
+                },
+            ),
+            ("2021-09-14Z", unexpected(10, 'Z')),
+            ("2021-09-14T13:37:02x", unexpected(19, 'x')),
+            ("2021-09-14[America/Los_Angeles", UnexpectedEnd),
+            ("2021-09-14[]", unexpected(11, ']')),
+            ("2021-09-14[u-ca=]", unexpected(16, ']')),
+            ("2021-09-14[u-ca=hebrew-]", unexpected(23, ']')),
+            ("2021-09-14[u-ca=hebrew][UTC]", unexpected(24, 'U')),
+            ("2021-09-14[u-ca=julian]", UnknownCalendar(10)),
+            ("2021-09-14[u-ca=islamicc]", UnknownCalendar(10)),
+            ("2021-09-14[!x-foo=bar]", UnknownCriticalAnnotation(10)),
+            (
+                "2021-09-14[u-ca=hebrew][!u-ca=hebrew]",
+                ConflictingAnnotation(23),
+            ),
+        ];
+        for (input, expected) in &cases {
+            let error = input.parse::<ParsedDateTime>().unwrap_err();
+            assert_eq!(
+                format!("{:?}", error),
+                format!("{:?}", expected),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_time() {
+        let time: Time = "T13:37".parse().unwrap();
+        assert_eq!(time, Time::try_new(13, 37, 0, 0).unwrap());
+        let time: Time = "133702.5".parse().unwrap();
+        assert_eq!(time, Time::try_new(13, 37, 2, 500_000_000).unwrap());
+        assert!("13:37Z".parse::<Time>().is_err());
+    }
+}
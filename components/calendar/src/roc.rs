@@ -0,0 +1,171 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Republic of China calendar
+
+use crate::iso::{Iso, IsoDateInner};
+use crate::{
+    types, AnyCalendarKind, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError,
+};
+use tinystr::tinystr8;
+
+/// Year 1 of the Republic of China calendar is ISO year 1912
+const ROC_ERA_OFFSET: i32 = 1911;
+
+/// The Republic of China (Minguo) Calendar
+///
+/// The ROC calendar shares its months and days with the ISO calendar, but counts years
+/// from the founding of the Republic of China in 1912. Years from 1912 onwards are in
+/// the "roc" era, and earlier years are counted backwards in the "broc" (Before ROC) era,
+/// such that ISO year 1911 is 1 Before ROC.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Roc;
+
+impl Calendar for Roc {
+    type DateInner = IsoDateInner;
+    fn date_from_iso(&self, iso: Date<Iso>) -> IsoDateInner {
+        *iso.inner()
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Date::from_raw(*date, Iso)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        Iso.months_in_year(date)
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        Iso.days_in_year(date)
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        Iso.days_in_month(date)
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> u8 {
+        Iso.day_of_week(date)
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        Iso.offset_date(date, offset.cast_unit())
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        Iso.until(date1, date2, largest_unit, smallest_unit)
+            .cast_unit()
+    }
+
+    fn debug_name() -> &'static str {
+        "ROC"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Roc)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::Year {
+        iso_year_as_roc(date.year.0)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::Month {
+        Iso.month(date)
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        Iso.day_of_month(date)
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        types::DayOfYearInfo {
+            day_of_year: Iso::day_of_year(*date),
+            days_in_year: Iso.days_in_year(date),
+            prev_year: iso_year_as_roc(date.year.0 - 1),
+            days_in_prev_year: Iso::days_in_year_direct(date.year.0 - 1),
+            next_year: iso_year_as_roc(date.year.0 + 1),
+        }
+    }
+}
+
+impl Date<Roc> {
+    /// Construct a new Republic of China Date.
+    ///
+    /// Years are specified as extended ROC years, i.e. year 1 is ISO year 1912 and
+    /// year 0 is ISO year 1911 (1 Before ROC).
+    ///
+    /// ```rust
+    /// use icu_calendar::Date;
+    ///
+    /// let date_roc = Date::new_roc_date_from_integers(10, 10, 110)
+    ///     .expect("Failed to initialize ROC Date instance.");
+    ///
+    /// assert_eq!(date_roc.year().era.0.as_str(), "roc");
+    /// assert_eq!(date_roc.year().number, 110);
+    /// assert_eq!(date_roc.to_iso().year().number, 2021);
+    /// ```
+    pub fn new_roc_date_from_integers(
+        day: u8,
+        month: u8,
+        year: i32,
+    ) -> Result<Date<Roc>, DateTimeError> {
+        Ok(Date::new_iso_date_from_integers(day, month, year + ROC_ERA_OFFSET)?.to_calendar(Roc))
+    }
+}
+
+fn iso_year_as_roc(year: i32) -> types::Year {
+    let roc_year = year - ROC_ERA_OFFSET;
+    if roc_year > 0 {
+        types::Year {
+            era: types::Era(tinystr8!("roc")),
+            number: roc_year,
+            related_iso: year,
+        }
+    } else {
+        types::Year {
+            era: types::Era(tinystr8!("broc")),
+            number: 1 - roc_year,
+            related_iso: year,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_roc_eras() {
+        // (iso year, era, era year)
+        let cases = [
+            (2021, "roc", 110),
+            (1912, "roc", 1),
+            (1911, "broc", 1),
+            (1900, "broc", 12),
+        ];
+        for &(iso_year, era, number) in &cases {
+            let iso = Date::new_iso_date_from_integers(1, 1, iso_year).unwrap();
+            let roc = iso.to_calendar(Roc);
+            assert_eq!(roc.year().era.0.as_str(), era, "ISO year {}", iso_year);
+            assert_eq!(roc.year().number, number, "ISO year {}", iso_year);
+            assert_eq!(roc.year().related_iso, iso_year);
+        }
+    }
+
+    #[test]
+    fn test_roc_constructor() {
+        let roc = Date::new_roc_date_from_integers(1, 1, 0).unwrap();
+        assert_eq!(
+            roc.to_iso(),
+            Date::new_iso_date_from_integers(1, 1, 1911).unwrap()
+        );
+        assert!(Date::new_roc_date_from_integers(29, 2, 110).is_err());
+        assert!(Date::new_roc_date_from_integers(29, 2, 109).is_ok());
+    }
+}
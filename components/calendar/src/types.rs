@@ -62,13 +62,27 @@ pub struct DayOfYearInfo {
     pub days_in_year: u32,
     /// The previous year.
     pub prev_year: Year,
+    /// The number of days in the previous year.
+    pub days_in_prev_year: u32,
     /// The next year.
     pub next_year: Year,
 }
 
 /// A day number in a month. Usually 1-based.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct DayOfMonth(pub u32);
 
+/// The occurrence of a weekday within its month, e.g. 2 for the second Tuesday of a month.
+/// Usually 1-based.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DayOfWeekInMonth(pub u32);
+
+impl From<DayOfMonth> for DayOfWeekInMonth {
+    fn from(day_of_month: DayOfMonth) -> Self {
+        DayOfWeekInMonth(1 + ((day_of_month.0 - 1) / 7))
+    }
+}
+
 /// A week number in a month. Usually 1-based.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct WeekOfMonth(pub u32);
@@ -77,18 +91,18 @@ pub struct WeekOfMonth(pub u32);
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct WeekOfYear(pub u32);
 
-/// This macro defines a struct for 0-based date fields: hours, minutes, and seconds. Each
-/// unit is bounded by a range. The traits implemented here will return a Result on
+/// This macro defines a struct for 0-based date fields: hours, minutes, seconds, and
+/// nanoseconds. Each unit is bounded by a range. The traits implemented here will return a Result on
 /// whether or not the unit is in range from the given input.
 macro_rules! dt_unit {
-    ($name:ident, $value:expr, $docs:expr) => {
+    ($name:ident, $storage:ident, $value:expr, $docs:expr) => {
         #[doc=$docs]
         #[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
-        pub struct $name(u8);
+        pub struct $name($storage);
 
         impl $name {
             /// Do not validate the numeric input for this component.
-            pub const fn new_unchecked(input: u8) -> Self {
+            pub const fn new_unchecked(input: $storage) -> Self {
                 Self(input)
             }
         }
@@ -97,7 +111,7 @@ macro_rules! dt_unit {
             type Err = DateTimeError;
 
             fn from_str(input: &str) -> Result<Self, Self::Err> {
-                let val: u8 = input.parse()?;
+                let val: $storage = input.parse()?;
                 if val > $value {
                     Err(DateTimeError::Overflow {
                         field: "$name",
@@ -109,10 +123,10 @@ macro_rules! dt_unit {
             }
         }
 
-        impl TryFrom<u8> for $name {
+        impl TryFrom<$storage> for $name {
             type Error = DateTimeError;
 
-            fn try_from(input: u8) -> Result<Self, Self::Error> {
+            fn try_from(input: $storage) -> Result<Self, Self::Error> {
                 if input > $value {
                     Err(DateTimeError::Overflow {
                         field: "$name",
@@ -134,12 +148,12 @@ macro_rules! dt_unit {
                         max: $value,
                     })
                 } else {
-                    Ok(Self(input as u8))
+                    Ok(Self(input as $storage))
                 }
             }
         }
 
-        impl From<$name> for u8 {
+        impl From<$name> for $storage {
             fn from(input: $name) -> Self {
                 input.0
             }
@@ -151,18 +165,18 @@ macro_rules! dt_unit {
             }
         }
 
-        impl Add<u8> for $name {
+        impl Add<$storage> for $name {
             type Output = Self;
 
-            fn add(self, other: u8) -> Self {
+            fn add(self, other: $storage) -> Self {
                 Self(self.0 + other)
             }
         }
 
-        impl Sub<u8> for $name {
+        impl Sub<$storage> for $name {
             type Output = Self;
 
-            fn sub(self, other: u8) -> Self {
+            fn sub(self, other: $storage) -> Self {
                 Self(self.0 - other)
             }
         }
@@ -171,22 +185,32 @@ macro_rules! dt_unit {
 
 dt_unit!(
     IsoHour,
+    u8,
     24,
     "An ISO-8601 hour component, for use with the [`IsoTimeInput`]."
 );
 
 dt_unit!(
     IsoMinute,
+    u8,
     60,
     "An ISO-8601 minute component, for use with the [`IsoTimeInput`]."
 );
 
 dt_unit!(
     IsoSecond,
+    u8,
     61,
     "An ISO-8601 second component, for use with the [`IsoTimeInput`]."
 );
 
+dt_unit!(
+    NanoSecond,
+    u32,
+    999_999_999,
+    "A fractional second component, stored as nanoseconds."
+);
+
 // TODO(#485): Improve FractionalSecond.
 /// A placeholder for fractional seconds support. See [Issue #485](https://github.com/unicode-org/icu4x/issues/485)
 /// for tracking the support of this feature.
@@ -201,7 +225,7 @@ pub enum FractionalSecond {
 }
 
 /// The GMT offset in seconds for a [`MockTimeZone`](crate::mock::time_zone::MockTimeZone).
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct GmtOffset(i32);
 
 impl GmtOffset {
@@ -261,6 +285,7 @@ impl FromStr for GmtOffset {
     /// e.g. +05
     /// e.g. +0500
     /// e.g. +05:00
+    /// e.g. +05:00:30
     ///
     /// # Examples
     ///
@@ -273,37 +298,7 @@ impl FromStr for GmtOffset {
     /// let offset3: GmtOffset = "-09:30".parse().expect("Failed to parse a GMT offset.");
     /// ```
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let offset_sign;
-        match input.chars().next() {
-            Some('+') => offset_sign = 1,
-            /* ASCII  */ Some('-') => offset_sign = -1,
-            /* U+2212 */ Some('−') => offset_sign = -1,
-            Some('Z') => return Ok(Self(0)),
-            _ => return Err(DateTimeError::InvalidTimeZoneOffset),
-        };
-
-        let seconds = match input.chars().count() {
-            /* ±hh */
-            3 => {
-                let hour: u8 = input[1..3].parse()?;
-                offset_sign * (hour as i32 * 60 * 60)
-            }
-            /* ±hhmm */
-            5 => {
-                let hour: u8 = input[1..3].parse()?;
-                let minute: u8 = input[3..5].parse()?;
-                offset_sign * (hour as i32 * 60 * 60 + minute as i32 * 60)
-            }
-            /* ±hh:mm */
-            6 => {
-                let hour: u8 = input[1..3].parse()?;
-                let minute: u8 = input[4..6].parse()?;
-                offset_sign * (hour as i32 * 60 * 60 + minute as i32 * 60)
-            }
-            _ => panic!("Invalid time-zone designator"),
-        };
-
-        Self::try_new(seconds)
+        crate::parse::parse_offset(input)
     }
 }
 
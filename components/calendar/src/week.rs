@@ -0,0 +1,260 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Functions for computing week numbers, following the rules for the week elements of
+//! [UTS 35](https://unicode.org/reports/tr35/tr35-dates.html#Date_Patterns_Week_Elements).
+
+use crate::types::{DayOfMonth, DayOfYearInfo, IsoWeekday, WeekOfMonth, WeekOfYear};
+
+/// The number of days in a week
+const DAYS_IN_WEEK: i32 = 7;
+
+/// Calculates week-of-month and week-of-year information for a locale.
+///
+/// Weeks are counted using the first day of the week and the minimal number of days
+/// that a week must have in a month or year in order to be counted as its first week,
+/// as given by the CLDR `weekData` for a region.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeekCalculator {
+    /// The first day of a week.
+    pub first_weekday: IsoWeekday,
+    /// The minimal number of days of a week that must fall in a month or year for the
+    /// week to be counted as the first week of that month or year.
+    pub min_week_days: u8,
+}
+
+/// The month or year that a week belongs to, relative to the month or year of the date
+/// whose week was calculated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelativeUnit {
+    /// The week belongs to the previous year.
+    Previous,
+    /// The week belongs to the current year.
+    Current,
+    /// The week belongs to the next year.
+    Next,
+}
+
+/// The week of the year that a date falls in, as calculated by a [`WeekCalculator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeekOf {
+    /// The 1-based week number.
+    pub week: u32,
+    /// The year that the week belongs to.
+    pub unit: RelativeUnit,
+}
+
+impl WeekCalculator {
+    /// The rules of ISO-8601: weeks start on Monday, and the first week of a year is the
+    /// one containing at least four of its days (i.e. its first Thursday).
+    pub const ISO_8601: Self = Self {
+        first_weekday: IsoWeekday::Monday,
+        min_week_days: 4,
+    };
+
+    /// Construct a new [`WeekCalculator`]
+    pub const fn new(first_weekday: IsoWeekday, min_week_days: u8) -> Self {
+        Self {
+            first_weekday,
+            min_week_days,
+        }
+    }
+
+    /// The 0-based position of `weekday` in a week, e.g. 0 for the first day of the week.
+    fn weekday_index(&self, weekday: IsoWeekday) -> i32 {
+        (weekday as i32 - self.first_weekday as i32).rem_euclid(DAYS_IN_WEEK)
+    }
+
+    /// Returns the 1-based position of `weekday` in a week, e.g. 1 for the first day of the
+    /// week. This is the local day of week used by the `e` and `c` pattern fields.
+    ///
+    /// ```rust
+    /// use icu_calendar::types::IsoWeekday;
+    /// use icu_calendar::week::WeekCalculator;
+    ///
+    /// let us = WeekCalculator::new(IsoWeekday::Sunday, 1);
+    ///
+    /// assert_eq!(us.local_day_of_week(IsoWeekday::Sunday), 1);
+    /// assert_eq!(us.local_day_of_week(IsoWeekday::Monday), 2);
+    /// assert_eq!(WeekCalculator::ISO_8601.local_day_of_week(IsoWeekday::Monday), 1);
+    /// assert_eq!(WeekCalculator::ISO_8601.local_day_of_week(IsoWeekday::Sunday), 7);
+    /// ```
+    pub fn local_day_of_week(&self, weekday: IsoWeekday) -> u32 {
+        self.weekday_index(weekday) as u32 + 1
+    }
+
+    /// Returns the 1-based day of a month or year on which its first week begins,
+    /// given the 1-based `day` of that month or year on which `weekday` falls.
+    ///
+    /// The result is less than 1 if the first week begins in the previous month or year,
+    /// and `day` may be less than 1 to refer to days before the start of the period.
+    fn first_week_start(&self, day: i32, weekday: IsoWeekday) -> i32 {
+        let first_day_index = (self.weekday_index(weekday) - (day - 1)).rem_euclid(DAYS_IN_WEEK);
+        let days_in_first_week = DAYS_IN_WEEK - first_day_index;
+        if days_in_first_week >= self.min_week_days as i32 {
+            1 - first_day_index
+        } else {
+            1 + days_in_first_week
+        }
+    }
+
+    /// Returns the week of the month that a day falls in.
+    ///
+    /// Days before the first week of the month are in week 0.
+    ///
+    /// ```rust
+    /// use icu_calendar::types::{DayOfMonth, IsoWeekday, WeekOfMonth};
+    /// use icu_calendar::week::WeekCalculator;
+    ///
+    /// // 2021-05-01 is a Saturday
+    /// let day = DayOfMonth(1);
+    /// let iso = WeekCalculator::ISO_8601;
+    /// let us = WeekCalculator::new(IsoWeekday::Sunday, 1);
+    ///
+    /// assert_eq!(iso.week_of_month(day, IsoWeekday::Saturday), WeekOfMonth(0));
+    /// assert_eq!(us.week_of_month(day, IsoWeekday::Saturday), WeekOfMonth(1));
+    /// ```
+    pub fn week_of_month(&self, day_of_month: DayOfMonth, weekday: IsoWeekday) -> WeekOfMonth {
+        let day = day_of_month.0 as i32;
+        let start = self.first_week_start(day, weekday);
+        if day < start {
+            WeekOfMonth(0)
+        } else {
+            WeekOfMonth(((day - start) / DAYS_IN_WEEK + 1) as u32)
+        }
+    }
+
+    /// Returns the week of the year that a day falls in, which may be the last week of
+    /// the previous year or the first week of the next year.
+    pub fn week_of_year(&self, day_of_year_info: &DayOfYearInfo, weekday: IsoWeekday) -> WeekOf {
+        let day = day_of_year_info.day_of_year as i32;
+        let start = self.first_week_start(day, weekday);
+        if day < start {
+            let day_in_prev_year = day + day_of_year_info.days_in_prev_year as i32;
+            let prev_start = self.first_week_start(day_in_prev_year, weekday);
+            return WeekOf {
+                week: ((day_in_prev_year - prev_start) / DAYS_IN_WEEK + 1) as u32,
+                unit: RelativeUnit::Previous,
+            };
+        }
+
+        let day_in_next_year = day - day_of_year_info.days_in_year as i32;
+        if day_in_next_year >= self.first_week_start(day_in_next_year, weekday) {
+            return WeekOf {
+                week: 1,
+                unit: RelativeUnit::Next,
+            };
+        }
+
+        WeekOf {
+            week: ((day - start) / DAYS_IN_WEEK + 1) as u32,
+            unit: RelativeUnit::Current,
+        }
+    }
+}
+
+impl WeekOf {
+    /// The week number as a [`WeekOfYear`]
+    pub fn week_of_year(&self) -> WeekOfYear {
+        WeekOfYear(self.week)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Date;
+
+    const US: WeekCalculator = WeekCalculator::new(IsoWeekday::Sunday, 1);
+
+    fn week_of_year(calculator: &WeekCalculator, year: i32, month: u8, day: u8) -> WeekOf {
+        let date = Date::new_iso_date_from_integers(day, month, year).unwrap();
+        calculator.week_of_year(
+            &date.day_of_year_info(),
+            IsoWeekday::from(date.day_of_week() as usize),
+        )
+    }
+
+    fn week_of_month(calculator: &WeekCalculator, year: i32, month: u8, day: u8) -> u32 {
+        let date = Date::new_iso_date_from_integers(day, month, year).unwrap();
+        calculator
+            .week_of_month(
+                date.day_of_month(),
+                IsoWeekday::from(date.day_of_week() as usize),
+            )
+            .0
+    }
+
+    #[test]
+    fn test_week_of_year_iso() {
+        use RelativeUnit::*;
+        let cases = [
+            // 2021-01-01 is a Friday, so it is in the last week of 2020
+            ((2021, 1, 1), (53, Previous)),
+            ((2021, 1, 3), (53, Previous)),
+            ((2021, 1, 4), (1, Current)),
+            ((2020, 12, 31), (53, Current)),
+            // 2019-12-30 is the Monday of the week containing 2020-01-02
+            ((2019, 12, 29), (52, Current)),
+            ((2019, 12, 30), (1, Next)),
+            ((2020, 1, 1), (1, Current)),
+            ((2021, 12, 31), (52, Current)),
+            ((2023, 1, 1), (52, Previous)),
+        ];
+        for &((y, m, d), (week, unit)) in &cases {
+            assert_eq!(
+                week_of_year(&WeekCalculator::ISO_8601, y, m, d),
+                WeekOf { week, unit },
+                "{}-{}-{}",
+                y,
+                m,
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn test_week_of_year_us() {
+        use RelativeUnit::*;
+        let cases = [
+            // Any week containing January 1st is the first week of the year
+            ((2021, 1, 1), (1, Current)),
+            ((2021, 1, 2), (1, Current)),
+            ((2021, 1, 3), (2, Current)),
+            ((2020, 12, 26), (52, Current)),
+            ((2020, 12, 27), (1, Next)),
+            ((2020, 12, 31), (1, Next)),
+            ((2022, 12, 31), (53, Current)),
+        ];
+        for &((y, m, d), (week, unit)) in &cases {
+            assert_eq!(
+                week_of_year(&US, y, m, d),
+                WeekOf { week, unit },
+                "{}-{}-{}",
+                y,
+                m,
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        // 2021-03-01 is a Monday, 2021-05-01 is a Saturday
+        let cases = [
+            ((2021, 3, 1), 1, 1),
+            ((2021, 3, 6), 1, 1),
+            ((2021, 3, 7), 1, 2),
+            ((2021, 3, 8), 2, 2),
+            ((2021, 3, 31), 5, 5),
+            ((2021, 5, 1), 0, 1),
+            ((2021, 5, 2), 0, 2),
+            ((2021, 5, 3), 1, 2),
+        ];
+        for &((y, m, d), iso, us) in &cases {
+            assert_eq!(week_of_month(&WeekCalculator::ISO_8601, y, m, d), iso);
+            assert_eq!(week_of_month(&US, y, m, d), us);
+        }
+    }
+}
@@ -4,63 +4,9 @@
 
 //! Assorted functions to help with date calculations.
 
-use crate::date::{Era, IsoWeekday, Year};
+use crate::date::IsoWeekday;
 use crate::pattern::{Pattern, TimeGranularity};
 
-use tinystr::tinystr8;
-
-pub fn iso_year_to_gregorian(iso_year: i32) -> Year {
-    if iso_year > 0 {
-        Year {
-            era: Era(tinystr8!("ce")),
-            number: iso_year,
-            related_iso: iso_year,
-        }
-    } else {
-        Year {
-            era: Era(tinystr8!("bce")),
-            number: -iso_year + 1,
-            related_iso: iso_year,
-        }
-    }
-}
-
-#[test]
-fn test_iso_year_to_gregorian() {
-    assert_eq!(
-        iso_year_to_gregorian(2020),
-        Year {
-            era: Era(tinystr8!("ce")),
-            number: 2020,
-            related_iso: 2020,
-        }
-    );
-    assert_eq!(
-        iso_year_to_gregorian(1),
-        Year {
-            era: Era(tinystr8!("ce")),
-            number: 1,
-            related_iso: 1,
-        }
-    );
-    assert_eq!(
-        iso_year_to_gregorian(0),
-        Year {
-            era: Era(tinystr8!("bce")),
-            number: 1,
-            related_iso: 0,
-        }
-    );
-    assert_eq!(
-        iso_year_to_gregorian(-1),
-        Year {
-            era: Era(tinystr8!("bce")),
-            number: 2,
-            related_iso: -1,
-        }
-    );
-}
-
 /// Temporary simplified function to get the day of the week
 /// month and day are both zero-indexed.
 ///
@@ -81,6 +27,79 @@ fn test_iso_date_to_weekday() {
     assert_eq!(IsoWeekday::Wednesday, iso_date_to_weekday(-379, 1, 2));
 }
 
+/// Returns the zero-indexed quarter of the year that the one-indexed `month` is in, or `None`
+/// if the month is out of range.
+///
+/// The thirteenth month of the Coptic and Ethiopic calendars, which only has five or six days,
+/// and the last month of leap years of the Hebrew calendar are in the fourth quarter.
+pub fn quarter_of_month(month: u32) -> Option<u32> {
+    match month {
+        1..=12 => Some((month - 1) / 3),
+        13 => Some(3),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_quarter_of_month() {
+    assert_eq!(Some(0), quarter_of_month(1));
+    assert_eq!(Some(0), quarter_of_month(3));
+    assert_eq!(Some(1), quarter_of_month(4));
+    assert_eq!(Some(3), quarter_of_month(12));
+    assert_eq!(Some(3), quarter_of_month(13));
+    assert_eq!(None, quarter_of_month(0));
+    assert_eq!(None, quarter_of_month(14));
+}
+
+/// Returns the number of days from the Unix epoch, January 1, 1970, to the given ISO date.
+/// month and day are both one-indexed.
+pub fn iso_date_to_epoch_days(year: i64, month: u8, day: u8) -> i64 {
+    debug_assert!((1..=12).contains(&month));
+    // Count the years from March so that the leap day is the last day of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    // 719468 is the number of days from March 1, 0 to January 1, 1970.
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns the ISO year of the given number of days from the Unix epoch, January 1, 1970.
+pub fn epoch_days_to_iso_year(days: i64) -> i64 {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Years are counted from March, so January and February belong to the next year.
+    let year = era * 400 + year_of_era;
+    if day_of_year >= 306 {
+        year + 1
+    } else {
+        year
+    }
+}
+
+#[test]
+fn test_epoch_days() {
+    assert_eq!(0, iso_date_to_epoch_days(1970, 1, 1));
+    assert_eq!(-1, iso_date_to_epoch_days(1969, 12, 31));
+    assert_eq!(18_686, iso_date_to_epoch_days(2021, 2, 28));
+    assert_eq!(11_016, iso_date_to_epoch_days(2000, 2, 29));
+    assert_eq!(-719_528, iso_date_to_epoch_days(0, 1, 1));
+
+    assert_eq!(1970, epoch_days_to_iso_year(0));
+    assert_eq!(1969, epoch_days_to_iso_year(-1));
+    assert_eq!(2021, epoch_days_to_iso_year(18_686));
+    assert_eq!(2000, epoch_days_to_iso_year(11_016));
+    assert_eq!(2000, epoch_days_to_iso_year(11_322));
+    assert_eq!(2001, epoch_days_to_iso_year(11_323));
+    assert_eq!(0, epoch_days_to_iso_year(-719_528));
+}
+
 /// Returns [`true`] if the most granular time being displayed will align with
 /// the top of the hour, otherwise returns [`false`].
 /// e.g. `12:00:00` is at the top of the hour for any display granularity.
@@ -5,6 +5,12 @@
 //! A collection of utilities for representing and working with dates as an input to
 //! formatting operations.
 
+use crate::error::DateTimeFormatError;
+use crate::provider::day_period_rules::{DayPeriodRulesV1, FlexibleDayPeriod};
+pub use crate::provider::time_zones::TimeZoneBcp47Id;
+use crate::provider::week_data::WeekDataV1;
+use icu_calendar::week::{RelativeUnit, WeekCalculator};
+use icu_calendar::{AnyCalendar, AnyCalendarKind, AsCalendar, Calendar, Date, DateTime, Iso, Time};
 use icu_locid::Locale;
 use tinystr::TinyStr8;
 
@@ -34,6 +40,29 @@ pub trait DateInput {
 
     /// Gets information on the position of the day within the year.
     fn day_of_year_info(&self) -> Option<DayOfYearInfo>;
+
+    /// Gets the fixed day number ("Rata Die") of the date, such that January 1, 1 CE of the
+    /// ISO calendar is day 1.
+    fn fixed_day(&self) -> Option<i64> {
+        None
+    }
+
+    /// Gets the calendar system of the date, if known.
+    ///
+    /// Dates of a calendar other than that of the formatter are converted to it with their
+    /// [`DateInput::fixed_day`], so that they are formatted with symbols and patterns of the
+    /// matching calendar.
+    fn calendar_kind(&self) -> Option<AnyCalendarKind> {
+        None
+    }
+
+    /// Gets the number of months in the year of the date, if known.
+    ///
+    /// This is used to select the names that months only have in leap years, such as Adar II
+    /// in the Hebrew calendar.
+    fn months_in_year(&self) -> Option<u8> {
+        None
+    }
 }
 
 /// Representation of a time of day according to ISO-8601 conventions. Always indexed from
@@ -66,9 +95,8 @@ pub trait TimeZoneInput {
     /// The GMT offset in Nanoseconds.
     fn gmt_offset(&self) -> GmtOffset;
 
-    /// The IANA time-zone identifier.
-    /// TODO(#606) switch this to BCP-47 identifier.
-    fn time_zone_id(&self) -> Option<&str>;
+    /// The BCP-47 time-zone identifier, e.g. "uslax" for America/Los_Angeles.
+    fn time_zone_id(&self) -> Option<&TimeZoneBcp47Id>;
 
     /// The metazone identifier.
     /// TODO(#528) switch to a compact, stable ID.
@@ -95,51 +123,364 @@ pub trait LocalizedDateTimeInput<T: DateTimeInput> {
 
     /// The year number according to week numbering.
     ///
-    /// For example, December 31, 2020 is part of the first week of 2021.
-    fn year_week(&self) -> Year;
+    /// For example, December 31, 2020 is part of the first week of 2021 in `en-US`.
+    fn year_week(&self) -> Option<Year>;
 
     /// The week of the month according to UTS 35.
-    fn week_of_month(&self) -> WeekOfMonth;
+    fn week_of_month(&self) -> Option<WeekOfMonth>;
 
     /// The week number of the year.
     ///
-    /// For example, December 31, 2020 is part of the first week of 2021.
-    fn week_of_year(&self) -> WeekOfYear;
+    /// For example, December 31, 2020 is part of the first week of 2021 in `en-US`.
+    fn week_of_year(&self) -> Option<WeekOfYear>;
+
+    /// The 1-based position of the weekday in the week.
+    ///
+    /// For example, Monday is day 2 of the week in `en-US`, where weeks start on Sunday.
+    fn local_day_of_week(&self) -> Option<u32>;
+
+    /// The flexible day period of the time, such as "in the morning", according to the day
+    /// period rules of the locale.
+    ///
+    /// Day periods at a single point in time, such as noon, are only chosen if the displayed
+    /// time is `is_top_of_hour`.
+    fn flexible_day_period(&self, is_top_of_hour: bool) -> Option<FlexibleDayPeriod>;
+}
+
+impl<A: AsCalendar> DateInput for Date<A> {
+    fn year(&self) -> Option<Year> {
+        Some(self.year())
+    }
+
+    fn month(&self) -> Option<Month> {
+        Some(self.month())
+    }
+
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        Some(self.day_of_month())
+    }
+
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        Some(IsoWeekday::from(self.day_of_week() as usize))
+    }
+
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        Some(self.day_of_year_info())
+    }
+
+    fn fixed_day(&self) -> Option<i64> {
+        Some(self.to_iso().to_fixed())
+    }
+
+    fn calendar_kind(&self) -> Option<AnyCalendarKind> {
+        self.calendar().any_calendar_kind()
+    }
+
+    fn months_in_year(&self) -> Option<u8> {
+        Some(self.months_in_year())
+    }
+}
+
+/// A [`Date`] does not contain any time information, so formatting a pattern that
+/// requires a time field will return an error.
+impl<A: AsCalendar> IsoTimeInput for Date<A> {
+    fn hour(&self) -> Option<IsoHour> {
+        None
+    }
+
+    fn minute(&self) -> Option<IsoMinute> {
+        None
+    }
+
+    fn second(&self) -> Option<IsoSecond> {
+        None
+    }
+
+    fn fraction(&self) -> Option<FractionalSecond> {
+        None
+    }
+}
+
+impl<A: AsCalendar> DateInput for DateTime<A> {
+    fn year(&self) -> Option<Year> {
+        Some(self.date.year())
+    }
+
+    fn month(&self) -> Option<Month> {
+        Some(self.date.month())
+    }
+
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        Some(self.date.day_of_month())
+    }
+
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        Some(IsoWeekday::from(self.date.day_of_week() as usize))
+    }
+
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        Some(self.date.day_of_year_info())
+    }
+
+    fn fixed_day(&self) -> Option<i64> {
+        Some(self.date.to_iso().to_fixed())
+    }
+
+    fn calendar_kind(&self) -> Option<AnyCalendarKind> {
+        self.date.calendar().any_calendar_kind()
+    }
+
+    fn months_in_year(&self) -> Option<u8> {
+        Some(self.date.months_in_year())
+    }
+}
+
+impl<A: AsCalendar> IsoTimeInput for DateTime<A> {
+    fn hour(&self) -> Option<IsoHour> {
+        Some(self.time.hour)
+    }
+
+    fn minute(&self) -> Option<IsoMinute> {
+        Some(self.time.minute)
+    }
+
+    fn second(&self) -> Option<IsoSecond> {
+        Some(self.time.second)
+    }
+
+    fn fraction(&self) -> Option<FractionalSecond> {
+        Some(FractionalSecond::Nanosecond(self.time.nanosecond.into()))
+    }
+}
+
+impl IsoTimeInput for Time {
+    fn hour(&self) -> Option<IsoHour> {
+        Some(self.hour)
+    }
+
+    fn minute(&self) -> Option<IsoMinute> {
+        Some(self.minute)
+    }
+
+    fn second(&self) -> Option<IsoSecond> {
+        Some(self.second)
+    }
+
+    fn fraction(&self) -> Option<FractionalSecond> {
+        Some(FractionalSecond::Nanosecond(self.nanosecond.into()))
+    }
+}
+
+/// A [`Time`] does not contain any date information, so formatting a pattern that
+/// requires a date field will return an error.
+impl DateInput for Time {
+    fn year(&self) -> Option<Year> {
+        None
+    }
+
+    fn month(&self) -> Option<Month> {
+        None
+    }
+
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        None
+    }
+
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        None
+    }
+
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        None
+    }
+}
+
+/// Computes the week of the year that `datetime` falls in, along with the year that
+/// the week belongs to.
+fn year_week<T: DateInput>(
+    datetime: &T,
+    calendar: Option<&WeekCalculator>,
+) -> Option<(Year, WeekOfYear)> {
+    let calendar = calendar.expect("Expect week data to be present");
+    let day_of_year_info = datetime.day_of_year_info()?;
+    let week_of = calendar.week_of_year(&day_of_year_info, datetime.iso_weekday()?);
+    let year = match week_of.unit {
+        RelativeUnit::Previous => day_of_year_info.prev_year,
+        RelativeUnit::Current => datetime.year()?,
+        RelativeUnit::Next => day_of_year_info.next_year,
+    };
+    Some((year, week_of.week_of_year()))
+}
+
+fn week_of_month<T: DateInput>(
+    datetime: &T,
+    calendar: Option<&WeekCalculator>,
+) -> Option<WeekOfMonth> {
+    let calendar = calendar.expect("Expect week data to be present");
+    Some(calendar.week_of_month(datetime.day_of_month()?, datetime.iso_weekday()?))
+}
+
+fn flexible_day_period<T: IsoTimeInput>(
+    datetime: &T,
+    day_period_rules: Option<&DayPeriodRulesV1>,
+    is_top_of_hour: bool,
+) -> Option<FlexibleDayPeriod> {
+    let day_period_rules = day_period_rules.expect("Expect day period rules to be present");
+    day_period_rules.get_period(
+        datetime.hour()?.into(),
+        datetime.minute().map(u8::from).unwrap_or(0),
+        is_top_of_hour,
+    )
+}
+
+fn local_day_of_week<T: DateInput>(datetime: &T, calendar: Option<&WeekCalculator>) -> Option<u32> {
+    let calendar = calendar.expect("Expect week data to be present");
+    Some(calendar.local_day_of_week(datetime.iso_weekday()?))
+}
+
+/// An input whose date is converted to the calendar of the formatter, if it is a date of a
+/// different calendar. The time and time zone are those of the input.
+pub(crate) struct DateTimeInputInCalendar<'data, T> {
+    data: &'data T,
+    converted: Option<Date<AnyCalendar>>,
+}
+
+impl<'data, T: DateInput> DateTimeInputInCalendar<'data, T> {
+    /// Converts `data` to the `calendar` of the formatter, unless it is already formatted with
+    /// the data of that calendar. Inputs that don't know their calendar are assumed to match.
+    ///
+    /// Returns [`DateTimeFormatError::MismatchedCalendar`] if the date needs to be converted,
+    /// but has no fixed day number to convert it with.
+    pub fn new(data: &'data T, calendar: AnyCalendarKind) -> Result<Self, DateTimeFormatError> {
+        let converted = match data.calendar_kind() {
+            Some(kind) if crate::datetime::data_calendar(kind) != Some(calendar) => {
+                let iso = data
+                    .fixed_day()
+                    .and_then(|fixed_day| Date::<Iso>::from_fixed(fixed_day).ok())
+                    .ok_or(DateTimeFormatError::MismatchedCalendar(calendar, kind))?;
+                Some(Date::new_from_iso(iso, AnyCalendar::new(calendar)))
+            }
+            _ => None,
+        };
+        Ok(Self { data, converted })
+    }
+
+    fn date(&self) -> &dyn DateInput {
+        match &self.converted {
+            Some(date) => date,
+            None => self.data,
+        }
+    }
+}
+
+impl<'data, T: DateInput> DateInput for DateTimeInputInCalendar<'data, T> {
+    fn year(&self) -> Option<Year> {
+        self.date().year()
+    }
 
-    /// TODO(#487): Implement flexible day periods.
-    fn flexible_day_period(&self);
+    fn month(&self) -> Option<Month> {
+        self.date().month()
+    }
+
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        self.date().day_of_month()
+    }
+
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        self.date().iso_weekday()
+    }
+
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        self.date().day_of_year_info()
+    }
+
+    fn fixed_day(&self) -> Option<i64> {
+        self.date().fixed_day()
+    }
+
+    fn calendar_kind(&self) -> Option<AnyCalendarKind> {
+        self.date().calendar_kind()
+    }
+
+    fn months_in_year(&self) -> Option<u8> {
+        self.date().months_in_year()
+    }
+}
+
+impl<'data, T: IsoTimeInput> IsoTimeInput for DateTimeInputInCalendar<'data, T> {
+    fn hour(&self) -> Option<IsoHour> {
+        self.data.hour()
+    }
+
+    fn minute(&self) -> Option<IsoMinute> {
+        self.data.minute()
+    }
+
+    fn second(&self) -> Option<IsoSecond> {
+        self.data.second()
+    }
+
+    fn fraction(&self) -> Option<FractionalSecond> {
+        self.data.fraction()
+    }
+}
+
+impl<'data, T: TimeZoneInput> TimeZoneInput for DateTimeInputInCalendar<'data, T> {
+    fn gmt_offset(&self) -> GmtOffset {
+        self.data.gmt_offset()
+    }
+
+    fn time_zone_id(&self) -> Option<&TimeZoneBcp47Id> {
+        self.data.time_zone_id()
+    }
+
+    fn metazone_id(&self) -> Option<&str> {
+        self.data.metazone_id()
+    }
+
+    fn time_variant(&self) -> Option<&TinyStr8> {
+        self.data.time_variant()
+    }
 }
 
 pub(crate) struct DateTimeInputWithLocale<'data, T: DateTimeInput> {
     data: &'data T,
-    _first_weekday: u8,
-    _anchor_weekday: u8,
+    calendar: Option<WeekCalculator>,
+    day_period_rules: Option<&'data DayPeriodRulesV1>,
 }
 
 impl<'data, T: DateTimeInput> DateTimeInputWithLocale<'data, T> {
-    pub fn new(data: &'data T, _locale: &Locale) -> Self {
+    pub fn new(
+        data: &'data T,
+        week_data: Option<&WeekDataV1>,
+        day_period_rules: Option<&'data DayPeriodRulesV1>,
+        _locale: &Locale,
+    ) -> Self {
         Self {
             data,
-            // TODO(#488): Implement week calculations.
-            _first_weekday: 1,
-            _anchor_weekday: 4,
+            calendar: week_data.map(WeekCalculator::from),
+            day_period_rules,
         }
     }
 }
 
 pub(crate) struct ZonedDateTimeInputWithLocale<'data, T: ZonedDateTimeInput> {
     data: &'data T,
-    _first_weekday: u8,
-    _anchor_weekday: u8,
+    calendar: Option<WeekCalculator>,
+    day_period_rules: Option<&'data DayPeriodRulesV1>,
 }
 
 impl<'data, T: ZonedDateTimeInput> ZonedDateTimeInputWithLocale<'data, T> {
-    pub fn new(data: &'data T, _locale: &Locale) -> Self {
+    pub fn new(
+        data: &'data T,
+        week_data: Option<&WeekDataV1>,
+        day_period_rules: Option<&'data DayPeriodRulesV1>,
+        _locale: &Locale,
+    ) -> Self {
         Self {
             data,
-            // TODO(#488): Implement week calculations.
-            _first_weekday: 1,
-            _anchor_weekday: 4,
+            calendar: week_data.map(WeekCalculator::from),
+            day_period_rules,
         }
     }
 }
@@ -149,20 +490,24 @@ impl<'data, T: DateTimeInput> LocalizedDateTimeInput<T> for DateTimeInputWithLoc
         self.data
     }
 
-    fn year_week(&self) -> Year {
-        todo!("#488")
+    fn year_week(&self) -> Option<Year> {
+        year_week(self.data, self.calendar.as_ref()).map(|(year, _)| year)
     }
 
-    fn week_of_month(&self) -> WeekOfMonth {
-        todo!("#488")
+    fn week_of_month(&self) -> Option<WeekOfMonth> {
+        week_of_month(self.data, self.calendar.as_ref())
     }
 
-    fn week_of_year(&self) -> WeekOfYear {
-        todo!("#488")
+    fn week_of_year(&self) -> Option<WeekOfYear> {
+        year_week(self.data, self.calendar.as_ref()).map(|(_, week)| week)
     }
 
-    fn flexible_day_period(&self) {
-        todo!("#487")
+    fn local_day_of_week(&self) -> Option<u32> {
+        local_day_of_week(self.data, self.calendar.as_ref())
+    }
+
+    fn flexible_day_period(&self, is_top_of_hour: bool) -> Option<FlexibleDayPeriod> {
+        flexible_day_period(self.data, self.day_period_rules, is_top_of_hour)
     }
 }
 
@@ -173,19 +518,23 @@ impl<'data, T: ZonedDateTimeInput> LocalizedDateTimeInput<T>
         self.data
     }
 
-    fn year_week(&self) -> Year {
-        todo!("#488")
+    fn year_week(&self) -> Option<Year> {
+        year_week(self.data, self.calendar.as_ref()).map(|(year, _)| year)
+    }
+
+    fn week_of_month(&self) -> Option<WeekOfMonth> {
+        week_of_month(self.data, self.calendar.as_ref())
     }
 
-    fn week_of_month(&self) -> WeekOfMonth {
-        todo!("#488")
+    fn week_of_year(&self) -> Option<WeekOfYear> {
+        year_week(self.data, self.calendar.as_ref()).map(|(_, week)| week)
     }
 
-    fn week_of_year(&self) -> WeekOfYear {
-        todo!("#488")
+    fn local_day_of_week(&self) -> Option<u32> {
+        local_day_of_week(self.data, self.calendar.as_ref())
     }
 
-    fn flexible_day_period(&self) {
-        todo!("#487")
+    fn flexible_day_period(&self, is_top_of_hour: bool) -> Option<FlexibleDayPeriod> {
+        flexible_day_period(self.data, self.day_period_rules, is_top_of_hour)
     }
 }
@@ -0,0 +1,727 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The collection of code that is needed for formatting intervals between two DateTimes.
+//! Central to this is the [`DateIntervalFormat`].
+
+use crate::{
+    date::DateTimeInput,
+    datetime::DateTimeFormat,
+    fields::{Field, FieldSymbol},
+    format::{date_interval::FormattedDateInterval, datetime},
+    options::DateTimeFormatOptions,
+    pattern::{Pattern, PatternItem},
+    provider::{
+        self,
+        day_period_rules::DayPeriodRulesV1Marker,
+        gregory::{
+            patterns::PatternV1, DateIntervalPatternsV1, DateIntervalPatternsV1Marker,
+            DatePatternsV1, DatePatternsV1Marker, DateSymbolsV1Marker,
+        },
+        week_data::WeekDataV1Marker,
+    },
+    skeleton::{self, BestSkeleton, Skeleton},
+    DateTimeFormatError, FormattedDateTime,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem::{discriminant, Discriminant};
+use icu_calendar::AnyCalendarKind;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// [`DateIntervalFormat`] formats the interval between two dates, such as "Jan 3 – 7, 2022"
+/// or "10:00 – 11:30 AM", using the CLDR interval patterns of the locale.
+///
+/// The fields of the dates are selected with the same [`DateTimeFormatOptions`] as for
+/// [`DateTimeFormat`]. When formatting, the greatest field that differs between the two dates
+/// selects the interval pattern, so that the fields that both dates share are only shown once.
+/// If the locale has no interval pattern for the fields, both dates are formatted in full and
+/// combined with the fallback pattern of the locale, e.g. "{0} – {1}". If the dates only differ
+/// in fields that are not shown, a single date is formatted.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::Locale;
+/// use icu::locid::macros::langid;
+/// use icu::datetime::{DateIntervalFormat, options::components};
+/// use icu::datetime::mock::datetime::MockDateTime;
+///
+/// let locale: Locale = langid!("en").into();
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let options = components::Bag {
+///     year: Some(components::Numeric::Numeric),
+///     month: Some(components::Month::Short),
+///     day: Some(components::Numeric::Numeric),
+///     ..Default::default()
+/// };
+/// let dif = DateIntervalFormat::try_new(locale, &provider, &options.into())
+///     .expect("Failed to create DateIntervalFormat instance.");
+///
+/// let from: MockDateTime = "2022-01-03T10:00:00".parse().expect("Failed to parse date.");
+/// let to: MockDateTime = "2022-01-07T11:30:00".parse().expect("Failed to parse date.");
+///
+/// assert_eq!(dif.format_to_string(&from, &to), "Jan 3 – 7, 2022");
+/// ```
+pub struct DateIntervalFormat<'data> {
+    pub(super) date_time_format: DateTimeFormat<'data>,
+    pub(super) patterns: IntervalPatterns,
+}
+
+impl<'data> DateIntervalFormat<'data> {
+    /// Constructor that takes a selected [`Locale`], reference to a [`DataProvider`] and
+    /// a list of options, then collects all data necessary to format intervals between date
+    /// and time values into the given locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateIntervalFormat, DateTimeFormatOptions};
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let locale: Locale = langid!("en").into();
+    ///
+    /// let provider = InvariantDataProvider;
+    ///
+    /// let options = DateTimeFormatOptions::default();
+    ///
+    /// let dif = DateIntervalFormat::try_new(locale, &provider, &options);
+    ///
+    /// assert_eq!(dif.is_ok(), true);
+    /// ```
+    pub fn try_new<
+        T: Into<Locale>,
+        D: DataProvider<'data, DateSymbolsV1Marker>
+            + DataProvider<'data, DatePatternsV1Marker>
+            + DataProvider<'data, DateIntervalPatternsV1Marker>
+            + DataProvider<'data, WeekDataV1Marker>
+            + DataProvider<'data, DayPeriodRulesV1Marker>
+            + DataProvider<'data, DecimalSymbolsV1Marker>
+            + ?Sized,
+    >(
+        locale: T,
+        data_provider: &D,
+        options: &DateTimeFormatOptions,
+    ) -> Result<Self, DateTimeFormatError> {
+        let locale = locale.into();
+
+        // There are only interval patterns for the Gregorian calendar.
+        let calendar = DateTimeFormat::resolve_calendar(&locale)?;
+        if calendar != AnyCalendarKind::Gregorian {
+            return Err(DateTimeFormatError::UnsupportedCalendar(calendar));
+        }
+
+        let mut date_time_format = DateTimeFormat::try_new(locale.clone(), data_provider, options)?;
+
+        let patterns_data: DataPayload<'_, DatePatternsV1Marker> = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::DATE_PATTERNS_V1,
+                    options: DateTimeFormat::calendar_options(&locale, calendar),
+                },
+            })?
+            .take_payload()?;
+
+        let interval_patterns_data: DataPayload<'_, DateIntervalPatternsV1Marker> = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::GREGORY_DATE_INTERVAL_PATTERNS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.clone().into()),
+                    },
+                },
+            })?
+            .take_payload()?;
+
+        let patterns = IntervalPatterns::try_new(
+            &date_time_format.pattern,
+            patterns_data.get(),
+            interval_patterns_data.get(),
+        )?;
+
+        // The interval patterns have the same types of fields as the pattern of the
+        // DateTimeFormat, so the week data and day period rules are already loaded if they are
+        // needed. However, a field could be numeric in one pattern, and text in the other.
+        let requires_data = patterns
+            .analyze()
+            .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
+
+        if requires_data && date_time_format.symbols.is_none() {
+            date_time_format.symbols = Some(
+                data_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::DATE_SYMBOLS_V1,
+                            options: DateTimeFormat::calendar_options(&locale, calendar),
+                        },
+                    })?
+                    .take_payload()?,
+            );
+        }
+
+        Ok(Self {
+            date_time_format,
+            patterns,
+        })
+    }
+
+    /// Takes two [`DateTimeInput`] implementers and returns an instance of a
+    /// [`FormattedDateInterval`] that contains all information necessary to display the
+    /// formatted interval between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateIntervalFormat, options::length};
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = icu_testdata::get_provider();
+    /// let options = length::Bag {
+    ///     date: None,
+    ///     time: Some(length::Time::Short),
+    ///     ..Default::default()
+    /// };
+    /// let dif = DateIntervalFormat::try_new(locale, &provider, &options.into())
+    ///     .expect("Failed to create DateIntervalFormat instance.");
+    ///
+    /// let from = MockDateTime::try_new(2022, 1, 3, 10, 0, 0)
+    ///     .expect("Failed to construct DateTime.");
+    /// let to = MockDateTime::try_new(2022, 1, 3, 11, 30, 0)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// let formatted_interval = dif.format(&from, &to);
+    ///
+    /// assert_eq!(formatted_interval.to_string(), "10:00 – 11:30 AM");
+    /// ```
+    pub fn format<'l, T>(&'l self, from: &'l T, to: &'l T) -> FormattedDateInterval<'l, 'data, T>
+    where
+        T: DateTimeInput,
+    {
+        match self.patterns.get(from, to) {
+            Some(interval_pattern) => FormattedDateInterval {
+                first: self.format_with_pattern(&interval_pattern.first, from),
+                second: Some(self.format_with_pattern(&interval_pattern.second, to)),
+            },
+            None => FormattedDateInterval {
+                first: self.date_time_format.format(from),
+                second: None,
+            },
+        }
+    }
+
+    /// Takes a mutable reference to anything that implements [`Write`](std::fmt::Write) trait
+    /// and two [`DateTimeInput`] implementers and populates the buffer with the formatted
+    /// interval between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateIntervalFormat, DateTimeFormatOptions};
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    /// use icu_provider::inv::InvariantDataProvider;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = InvariantDataProvider;
+    /// # let options = DateTimeFormatOptions::default();
+    /// let dif = DateIntervalFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create DateIntervalFormat instance.");
+    ///
+    /// let from = MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    /// let to = MockDateTime::try_new(2020, 9, 4, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// let mut buffer = String::new();
+    /// dif.format_to_write(&mut buffer, &from, &to)
+    ///     .expect("Failed to write to a buffer.");
+    ///
+    /// let _ = format!("Interval: {}", buffer);
+    /// ```
+    pub fn format_to_write<T>(
+        &self,
+        w: &mut impl core::fmt::Write,
+        from: &T,
+        to: &T,
+    ) -> core::fmt::Result
+    where
+        T: DateTimeInput,
+    {
+        self.format(from, to).write_to(w)
+    }
+
+    /// Takes two [`DateTimeInput`] implementers and returns the interval between them
+    /// formatted as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateIntervalFormat, DateTimeFormatOptions};
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    /// use icu_provider::inv::InvariantDataProvider;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = InvariantDataProvider;
+    /// # let options = DateTimeFormatOptions::default();
+    /// let dif = DateIntervalFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create DateIntervalFormat instance.");
+    ///
+    /// let from = MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    /// let to = MockDateTime::try_new(2020, 9, 4, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// let _ = dif.format_to_string(&from, &to);
+    /// ```
+    pub fn format_to_string<T>(&self, from: &T, to: &T) -> String
+    where
+        T: DateTimeInput,
+    {
+        let mut s = String::new();
+        self.format_to_write(&mut s, from, to)
+            .expect("Failed to write to a String.");
+        s
+    }
+
+    fn format_with_pattern<'l, T>(
+        &'l self,
+        pattern: &'l Pattern,
+        value: &'l T,
+    ) -> FormattedDateTime<'l, 'data, T>
+    where
+        T: DateTimeInput,
+    {
+        let date_time_format = &self.date_time_format;
+        FormattedDateTime {
+            pattern,
+            symbols: date_time_format.symbols.as_ref().map(|s| s.get()),
+            week_data: date_time_format.week_data.as_ref().map(|w| w.get()),
+            day_period_rules: date_time_format.day_period_rules.as_ref().map(|d| d.get()),
+            fixed_decimal_format: &date_time_format.fixed_decimal_format,
+            datetime: value,
+            locale: &date_time_format.locale,
+            calendar: date_time_format.calendar,
+        }
+    }
+}
+
+/// The fields that can differ between two dates, ordered from the greatest to the smallest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Difference {
+    Era,
+    Year,
+    Month,
+    Day,
+    DayPeriod,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl Difference {
+    /// Finds the greatest field that differs between two dates, if any.
+    fn between<T: DateTimeInput>(from: &T, to: &T) -> Option<Self> {
+        let (from_year, to_year) = (from.year(), to.year());
+        let (from_hour, to_hour) = (from.hour().map(u8::from), to.hour().map(u8::from));
+        if from_year.as_ref().map(|year| &year.era) != to_year.as_ref().map(|year| &year.era) {
+            Some(Self::Era)
+        } else if from_year.map(|year| year.number) != to_year.map(|year| year.number) {
+            Some(Self::Year)
+        } else if from.month() != to.month() {
+            Some(Self::Month)
+        } else if from.day_of_month() != to.day_of_month() {
+            Some(Self::Day)
+        } else if from_hour.map(|hour| hour < 12) != to_hour.map(|hour| hour < 12) {
+            Some(Self::DayPeriod)
+        } else if from_hour != to_hour {
+            Some(Self::Hour)
+        } else if from.minute() != to.minute() {
+            Some(Self::Minute)
+        } else if from.second() != to.second() {
+            Some(Self::Second)
+        } else {
+            None
+        }
+    }
+
+    /// The difference that a field shows, or `None` for fields that do not show a difference
+    /// by themselves, such as time zones.
+    fn for_symbol(symbol: FieldSymbol) -> Option<Self> {
+        match symbol {
+            FieldSymbol::Era => Some(Self::Era),
+            FieldSymbol::Year(_) => Some(Self::Year),
+            FieldSymbol::Quarter(_) | FieldSymbol::Month(_) => Some(Self::Month),
+            FieldSymbol::Week(_) | FieldSymbol::Day(_) | FieldSymbol::Weekday(_) => Some(Self::Day),
+            FieldSymbol::DayPeriod(_) => Some(Self::DayPeriod),
+            FieldSymbol::Hour(_) => Some(Self::Hour),
+            FieldSymbol::Minute => Some(Self::Minute),
+            FieldSymbol::Second(_) => Some(Self::Second),
+            FieldSymbol::TimeZone(_) => None,
+        }
+    }
+}
+
+/// An interval pattern, split into the part that is formatted with the first date, and the
+/// part that is formatted with the second date.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct IntervalPattern {
+    pub first: Pattern,
+    pub second: Pattern,
+}
+
+impl From<&Pattern> for IntervalPattern {
+    /// Splits the pattern at the first field that repeats a type of field, e.g. "MMM d – d, y"
+    /// is split into "MMM d – " and "d, y".
+    fn from(pattern: &Pattern) -> Self {
+        let mut seen: Vec<Discriminant<FieldSymbol>> = Vec::new();
+        let items = pattern.items();
+        let index = items
+            .iter()
+            .position(|item| match item {
+                PatternItem::Field(field) => {
+                    let symbol = discriminant(&field.symbol);
+                    if seen.contains(&symbol) {
+                        true
+                    } else {
+                        seen.push(symbol);
+                        false
+                    }
+                }
+                PatternItem::Literal(_) => false,
+            })
+            .unwrap_or(items.len());
+        Self {
+            first: items[..index].iter().cloned().collect(),
+            second: items[index..].iter().cloned().collect(),
+        }
+    }
+}
+
+/// The interval patterns for the fields of a [`DateIntervalFormat`], for each [`Difference`].
+/// Differences without an interval pattern in the data use the fallback pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct IntervalPatterns {
+    /// The smallest difference shown by the fields. Dates that differ only in smaller fields
+    /// are formatted as a single date.
+    smallest: Option<Difference>,
+    /// Without a day period field, a change from am to pm is a difference in the hour.
+    has_day_period: bool,
+    era: IntervalPattern,
+    year: IntervalPattern,
+    month: IntervalPattern,
+    day: IntervalPattern,
+    day_period: IntervalPattern,
+    hour: IntervalPattern,
+    minute: IntervalPattern,
+    fallback: IntervalPattern,
+}
+
+impl IntervalPatterns {
+    /// Selects the interval patterns for the fields of a pattern.
+    ///
+    /// The interval patterns are those of the skeleton with the same fields. If there is none,
+    /// and the fields contain both a date and a time, the date is formatted once and combined
+    /// with the interval patterns of the time when only the time differs. All other
+    /// differences use the fallback pattern.
+    fn try_new(
+        pattern: &Pattern,
+        patterns: &DatePatternsV1,
+        interval_patterns: &DateIntervalPatternsV1,
+    ) -> Result<Self, DateTimeFormatError> {
+        let requested_skeleton = Skeleton::from(pattern);
+        let fields = requested_skeleton.as_slice();
+        let hour_symbol = pattern.items().iter().find_map(|item| match item {
+            PatternItem::Field(Field {
+                symbol: symbol @ FieldSymbol::Hour(_),
+                ..
+            }) => Some(*symbol),
+            _ => None,
+        });
+
+        let fallback = IntervalPattern::from(&Pattern::from_bytes_combination(
+            &interval_patterns.fallback,
+            pattern.clone(),
+            pattern.clone(),
+        )?);
+
+        // Adjusts the lengths of the fields of an interval pattern to the requested fields.
+        let get_pattern = |interval_pattern: &Option<PatternV1>, fields: &[Field]| {
+            interval_pattern.as_ref().map(|interval_pattern| {
+                let mut interval_pattern =
+                    skeleton::adjust_field_lengths(&interval_pattern.0, fields);
+                // The hour cycle of the pattern may have been changed by the preferences.
+                if let Some(hour_symbol) = hour_symbol {
+                    for item in interval_pattern.items_mut() {
+                        if let PatternItem::Field(Field {
+                            symbol: symbol @ FieldSymbol::Hour(_),
+                            ..
+                        }) = item
+                        {
+                            *symbol = hour_symbol;
+                        }
+                    }
+                }
+                interval_pattern
+            })
+        };
+
+        let mut result = Self {
+            smallest: fields
+                .iter()
+                .filter_map(|field| Difference::for_symbol(field.symbol))
+                .max(),
+            has_day_period: pattern.items().iter().any(|item| {
+                matches!(
+                    item,
+                    PatternItem::Field(Field {
+                        symbol: FieldSymbol::DayPeriod(_),
+                        ..
+                    })
+                )
+            }),
+            era: fallback.clone(),
+            year: fallback.clone(),
+            month: fallback.clone(),
+            day: fallback.clone(),
+            day_period: fallback.clone(),
+            hour: fallback.clone(),
+            minute: fallback.clone(),
+            fallback,
+        };
+
+        if let Some(skeleton_patterns) =
+            skeleton::get_best_interval_patterns(&interval_patterns.skeletons, fields)
+        {
+            let set = |interval_pattern: &mut IntervalPattern, data: &Option<PatternV1>| {
+                if let Some(pattern) = get_pattern(data, fields) {
+                    *interval_pattern = IntervalPattern::from(&pattern);
+                }
+            };
+            set(&mut result.era, &skeleton_patterns.era);
+            set(&mut result.year, &skeleton_patterns.year);
+            set(&mut result.month, &skeleton_patterns.month);
+            set(&mut result.day, &skeleton_patterns.day);
+            set(&mut result.day_period, &skeleton_patterns.day_period);
+            set(&mut result.hour, &skeleton_patterns.hour);
+            set(&mut result.minute, &skeleton_patterns.minute);
+            return Ok(result);
+        }
+
+        let skeleton::FieldsByType { date, time } = skeleton::group_fields_by_type(fields);
+        if date.is_empty() || time.is_empty() {
+            return Ok(result);
+        }
+        let time_patterns =
+            match skeleton::get_best_interval_patterns(&interval_patterns.skeletons, &time) {
+                Some(time_patterns) => time_patterns,
+                None => return Ok(result),
+            };
+        let date_pattern = match skeleton::get_best_available_format_pattern(
+            &patterns.datetime.skeletons,
+            &date,
+            false,
+        ) {
+            BestSkeleton::AllFieldsMatch(pattern) | BestSkeleton::MissingOrExtraFields(pattern) => {
+                pattern
+            }
+            BestSkeleton::NoMatch => return Ok(result),
+        };
+        let length_pattern =
+            skeleton::get_length_pattern_for_fields(&patterns.datetime.length_patterns, fields);
+
+        let set = |interval_pattern: &mut IntervalPattern,
+                   data: &Option<PatternV1>|
+         -> Result<(), DateTimeFormatError> {
+            if let Some(pattern) = get_pattern(data, &time) {
+                *interval_pattern = IntervalPattern::from(&Pattern::from_bytes_combination(
+                    length_pattern,
+                    date_pattern.clone(),
+                    pattern,
+                )?);
+            }
+            Ok(())
+        };
+        set(&mut result.day_period, &time_patterns.day_period)?;
+        set(&mut result.hour, &time_patterns.hour)?;
+        set(&mut result.minute, &time_patterns.minute)?;
+        Ok(result)
+    }
+
+    /// Selects the interval pattern for two dates, or `None` if they should be formatted as a
+    /// single date.
+    fn get<T: DateTimeInput>(&self, from: &T, to: &T) -> Option<&IntervalPattern> {
+        let difference = match Difference::between(from, to)? {
+            Difference::DayPeriod if !self.has_day_period => Difference::Hour,
+            difference => difference,
+        };
+        if difference > self.smallest? {
+            return None;
+        }
+        Some(match difference {
+            Difference::Era => &self.era,
+            Difference::Year => &self.year,
+            Difference::Month => &self.month,
+            Difference::Day => &self.day,
+            Difference::DayPeriod => &self.day_period,
+            Difference::Hour => &self.hour,
+            Difference::Minute => &self.minute,
+            Difference::Second => &self.fallback,
+        })
+    }
+
+    /// Checks the fields of all of the interval patterns, see [`datetime::analyze_pattern`].
+    fn analyze(&self) -> Result<bool, &Field> {
+        let mut requires_data = false;
+        for interval_pattern in [
+            &self.era,
+            &self.year,
+            &self.month,
+            &self.day,
+            &self.day_period,
+            &self.hour,
+            &self.minute,
+            &self.fallback,
+        ]
+        .iter()
+        {
+            requires_data |= datetime::analyze_pattern(&interval_pattern.first, false)?;
+            requires_data |= datetime::analyze_pattern(&interval_pattern.second, false)?;
+        }
+        Ok(requires_data)
+    }
+}
+
+#[cfg(all(test, feature = "provider_serde"))]
+mod tests {
+    use super::*;
+    use crate::mock::datetime::MockDateTime;
+    use crate::options::{components, length};
+    use icu_locid_macros::langid;
+
+    #[test]
+    fn test_split() {
+        let split = |pattern: &str| {
+            let interval_pattern =
+                IntervalPattern::from(&Pattern::from_bytes(pattern).expect("Failed to parse"));
+            (
+                interval_pattern.first.to_string(),
+                interval_pattern.second.to_string(),
+            )
+        };
+        assert_eq!(split("MMM d – d, y"), ("MMM d – ".into(), "d, y".into()));
+        assert_eq!(split("h:mm – h:mm a"), ("h:mm – ".into(), "h:mm a".into()));
+        // Stand-alone and format months are the same type of field.
+        assert_eq!(split("LLL – MMM y"), ("LLL – ".into(), "MMM y".into()));
+    }
+
+    #[test]
+    fn test_difference() {
+        let difference = |from: &str, to: &str| {
+            let from: MockDateTime = from.parse().expect("Failed to parse");
+            let to: MockDateTime = to.parse().expect("Failed to parse");
+            Difference::between(&from, &to)
+        };
+        let from = "2022-01-03T10:00:00";
+        assert_eq!(
+            difference(from, "2023-01-03T10:00:00"),
+            Some(Difference::Year)
+        );
+        assert_eq!(
+            difference(from, "2022-02-03T10:00:00"),
+            Some(Difference::Month)
+        );
+        assert_eq!(
+            difference(from, "2022-01-07T10:00:00"),
+            Some(Difference::Day)
+        );
+        assert_eq!(
+            difference(from, "2022-01-03T13:00:00"),
+            Some(Difference::DayPeriod)
+        );
+        assert_eq!(
+            difference(from, "2022-01-03T11:00:00"),
+            Some(Difference::Hour)
+        );
+        assert_eq!(
+            difference(from, "2022-01-03T10:30:00"),
+            Some(Difference::Minute)
+        );
+        assert_eq!(
+            difference(from, "2022-01-03T10:00:01"),
+            Some(Difference::Second)
+        );
+        assert_eq!(difference(from, from), None);
+    }
+
+    #[test]
+    fn test_format() {
+        let provider = icu_testdata::get_provider();
+        let format = |options: &DateTimeFormatOptions, from: &str, to: &str| {
+            let dif = DateIntervalFormat::try_new(langid!("en"), &provider, options)
+                .expect("Failed to create DateIntervalFormat");
+            let from: MockDateTime = from.parse().expect("Failed to parse");
+            let to: MockDateTime = to.parse().expect("Failed to parse");
+            dif.format_to_string(&from, &to)
+        };
+
+        let date: DateTimeFormatOptions = components::Bag {
+            year: Some(components::Numeric::Numeric),
+            month: Some(components::Month::Short),
+            day: Some(components::Numeric::Numeric),
+            ..Default::default()
+        }
+        .into();
+        let time: DateTimeFormatOptions = length::Bag {
+            date: None,
+            time: Some(length::Time::Short),
+            ..Default::default()
+        }
+        .into();
+        let date_time: DateTimeFormatOptions = length::Bag {
+            date: Some(length::Date::Medium),
+            time: Some(length::Time::Short),
+            ..Default::default()
+        }
+        .into();
+
+        let from = "2022-01-03T10:00:00";
+        let cases = [
+            (&date, "2023-02-07T11:30:00", "Jan 3, 2022 – Feb 7, 2023"),
+            (&date, "2022-02-07T11:30:00", "Jan 3 – Feb 7, 2022"),
+            (&date, "2022-01-07T11:30:00", "Jan 3 – 7, 2022"),
+            // The dates only differ in fields that are not shown.
+            (&date, "2022-01-03T11:30:00", "Jan 3, 2022"),
+            (&time, "2022-01-03T11:30:00", "10:00 – 11:30 AM"),
+            (&time, "2022-01-03T13:30:00", "10:00 AM – 1:30 PM"),
+            // There is no interval pattern for a difference in the day.
+            (&time, "2022-01-07T11:30:00", "10:00 AM – 11:30 AM"),
+            (&time, "2022-01-03T10:00:30", "10:00 AM"),
+            // The date is shown once when only the time differs.
+            (
+                &date_time,
+                "2022-01-03T11:30:00",
+                "Jan 3, 2022, 10:00 – 11:30 AM",
+            ),
+            (
+                &date_time,
+                "2022-01-07T11:30:00",
+                "Jan 3, 2022, 10:00 AM – Jan 7, 2022, 11:30 AM",
+            ),
+        ];
+        for (options, to, expected) in cases.iter() {
+            assert_eq!(format(options, from, to), *expected, "{:?}", options);
+        }
+    }
+}
@@ -6,14 +6,25 @@
 //! Central to this is the [`DateTimeFormat`].
 
 use crate::{
+    date::{DateTimeInputInCalendar, DateTimeInputWithLocale},
     format::datetime,
-    options::DateTimeFormatOptions,
+    options::{preferences, DateTimeFormatOptions},
+    parse::{self, DateTimeParseError, ParsedDateTime},
     provider::{
+        day_period_rules::DayPeriodRulesV1Marker,
         gregory::{DatePatternsV1Marker, DateSymbolsV1Marker},
         helpers::DateTimePatterns,
+        week_data::WeekDataV1Marker,
     },
 };
+use alloc::borrow::Cow;
 use alloc::string::String;
+use icu_calendar::AnyCalendarKind;
+use icu_decimal::{
+    options::{FixedDecimalFormatOptions, GroupingStrategy},
+    provider::DecimalSymbolsV1Marker,
+    FixedDecimalFormat,
+};
 use icu_locid::Locale;
 use icu_provider::prelude::*;
 
@@ -65,12 +76,41 @@ pub struct DateTimeFormat<'data> {
     pub(super) locale: Locale,
     pub(super) pattern: Pattern,
     pub(super) symbols: Option<DataPayload<'data, DateSymbolsV1Marker>>,
+    pub(super) week_data: Option<DataPayload<'data, WeekDataV1Marker>>,
+    pub(super) day_period_rules: Option<DataPayload<'data, DayPeriodRulesV1Marker>>,
+    pub(super) fixed_decimal_format: FixedDecimalFormat<'data>,
+    pub(super) calendar: AnyCalendarKind,
+}
+
+/// The calendar whose symbols and patterns are used to format dates of the `kind` calendar,
+/// or `None` if there is no such data.
+///
+/// The ISO and Julian calendars share the names of months and eras with the Gregorian
+/// calendar, so they are formatted with its data.
+pub(crate) fn data_calendar(kind: AnyCalendarKind) -> Option<AnyCalendarKind> {
+    match kind {
+        AnyCalendarKind::Iso | AnyCalendarKind::Julian => Some(AnyCalendarKind::Gregorian),
+        // TODO: Formatting Chinese dates requires cyclic year names and leap month
+        // patterns, which are not supported yet.
+        AnyCalendarKind::Chinese => None,
+        kind => Some(kind),
+    }
 }
 
 impl<'data> DateTimeFormat<'data> {
     /// Constructor that takes a selected [`Locale`], reference to a [`DataProvider`] and
     /// a list of options, then collects all data necessary to format date and time values into the given locale.
     ///
+    /// The symbols and patterns are those of the calendar selected with the `-u-ca` keyword
+    /// of the locale, or of the Gregorian calendar if there is none. Dates of a different
+    /// calendar are converted to it before they are formatted.
+    ///
+    /// The hour cycle is the one set in the preferences of the `options`, otherwise the one
+    /// selected with the `-u-hc` keyword of the locale, otherwise the preferred hour cycle of
+    /// the locale. Numbers are rendered in the numbering system selected with the `-u-nu`
+    /// keyword of the locale, or in the default numbering system of the locale if there is
+    /// none.
+    ///
     /// # Examples
     ///
     /// ```
@@ -94,6 +134,9 @@ impl<'data> DateTimeFormat<'data> {
         T: Into<Locale>,
         D: DataProvider<'data, DateSymbolsV1Marker>
             + DataProvider<'data, DatePatternsV1Marker>
+            + DataProvider<'data, WeekDataV1Marker>
+            + DataProvider<'data, DayPeriodRulesV1Marker>
+            + DataProvider<'data, DecimalSymbolsV1Marker>
             + ?Sized,
     >(
         locale: T,
@@ -101,23 +144,22 @@ impl<'data> DateTimeFormat<'data> {
         options: &DateTimeFormatOptions,
     ) -> Result<Self, DateTimeFormatError> {
         let locale = locale.into();
+        let calendar = Self::resolve_calendar(&locale)?;
+        let options = Self::resolve_options(&locale, options);
 
         let patterns_data: icu_provider::DataPayload<'_, provider::gregory::DatePatternsV1Marker> =
             data_provider
                 .load_payload(&DataRequest {
                     resource_path: ResourcePath {
-                        key: provider::key::GREGORY_DATE_PATTERNS_V1,
-                        options: ResourceOptions {
-                            variant: None,
-                            langid: Some(locale.clone().into()),
-                        },
+                        key: provider::key::DATE_PATTERNS_V1,
+                        options: Self::calendar_options(&locale, calendar),
                     },
                 })?
                 .take_payload()?;
 
         let pattern = patterns_data
             .get()
-            .get_pattern_for_options(options)?
+            .get_pattern_for_options(&options)?
             .unwrap_or_default();
 
         let requires_data = datetime::analyze_pattern(&pattern, false)
@@ -128,7 +170,22 @@ impl<'data> DateTimeFormat<'data> {
                 data_provider
                     .load_payload(&DataRequest {
                         resource_path: ResourcePath {
-                            key: provider::key::GREGORY_DATE_SYMBOLS_V1,
+                            key: provider::key::DATE_SYMBOLS_V1,
+                            options: Self::calendar_options(&locale, calendar),
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        let week_data = if datetime::requires_week_data(&pattern) {
+            Some(
+                data_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::WEEK_DATA_V1,
                             options: ResourceOptions {
                                 variant: None,
                                 langid: Some(locale.clone().into()),
@@ -141,7 +198,106 @@ impl<'data> DateTimeFormat<'data> {
             None
         };
 
-        Ok(Self::new(locale, pattern, symbols_data))
+        let day_period_rules = if datetime::requires_day_period_rules(&pattern) {
+            Some(
+                data_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::DAY_PERIOD_RULES_V1,
+                            options: ResourceOptions {
+                                variant: None,
+                                langid: Some(locale.clone().into()),
+                            },
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        let fixed_decimal_format = Self::load_fixed_decimal_format(&locale, data_provider)?;
+
+        Ok(Self::new(
+            locale,
+            pattern,
+            symbols_data,
+            week_data,
+            day_period_rules,
+            fixed_decimal_format,
+            calendar,
+        ))
+    }
+
+    /// Resolves the calendar whose data is used for formatting, which is the one selected
+    /// with the `-u-ca` keyword of the locale, or Gregorian if there is none.
+    ///
+    /// Returns [`DateTimeFormatError::UnsupportedCalendar`] if there is no data for the
+    /// selected calendar.
+    pub(super) fn resolve_calendar(
+        locale: &Locale,
+    ) -> Result<AnyCalendarKind, DateTimeFormatError> {
+        let kind = AnyCalendarKind::from_locale(locale).unwrap_or(AnyCalendarKind::Gregorian);
+        data_calendar(kind).ok_or(DateTimeFormatError::UnsupportedCalendar(kind))
+    }
+
+    /// Resolves the options used for selecting the pattern, which are the `options` with the
+    /// hour cycle selected with the `-u-hc` keyword of the locale if they do not set one.
+    pub(super) fn resolve_options<'a>(
+        locale: &Locale,
+        options: &'a DateTimeFormatOptions,
+    ) -> Cow<'a, DateTimeFormatOptions> {
+        let preferences = match options {
+            DateTimeFormatOptions::Length(bag) => &bag.preferences,
+            DateTimeFormatOptions::Components(bag) => &bag.preferences,
+        };
+        if let Some(preferences::Bag {
+            hour_cycle: Some(_),
+        }) = preferences
+        {
+            return Cow::Borrowed(options);
+        }
+        let hour_cycle = match preferences::HourCycle::from_locale(locale) {
+            Some(hour_cycle) => hour_cycle,
+            None => return Cow::Borrowed(options),
+        };
+
+        let mut options = options.clone();
+        let preferences = match &mut options {
+            DateTimeFormatOptions::Length(bag) => &mut bag.preferences,
+            DateTimeFormatOptions::Components(bag) => &mut bag.preferences,
+        };
+        *preferences = Some(preferences::Bag {
+            hour_cycle: Some(hour_cycle),
+        });
+        Cow::Owned(options)
+    }
+
+    /// The [`ResourceOptions`] for loading the symbols and patterns of `calendar`, which
+    /// is identified by its BCP-47 identifier in the variant.
+    pub(super) fn calendar_options(locale: &Locale, calendar: AnyCalendarKind) -> ResourceOptions {
+        ResourceOptions {
+            variant: calendar.as_bcp47_string().map(Cow::Borrowed),
+            langid: Some(locale.clone().into()),
+        }
+    }
+
+    /// Loads the [`FixedDecimalFormat`] used for numeric fields, which renders numbers in the
+    /// numbering system of the locale, including one selected with the `-u-nu` keyword.
+    pub(super) fn load_fixed_decimal_format<D>(
+        locale: &Locale,
+        data_provider: &D,
+    ) -> Result<FixedDecimalFormat<'data>, DateTimeFormatError>
+    where
+        D: DataProvider<'data, DecimalSymbolsV1Marker> + ?Sized,
+    {
+        let mut options = FixedDecimalFormatOptions::default();
+        options.grouping_strategy = GroupingStrategy::Never;
+        Ok(FixedDecimalFormat::try_new(
+            locale.clone(),
+            data_provider,
+            options,
+        )?)
     }
 
     /// Creates a new [`DateTimeFormat`] regardless of whether there are time-zone symbols in the pattern.
@@ -161,6 +317,10 @@ impl<'data> DateTimeFormat<'data> {
         locale: T,
         pattern: Pattern,
         symbols: Option<DataPayload<'data, DateSymbolsV1Marker>>,
+        week_data: Option<DataPayload<'data, WeekDataV1Marker>>,
+        day_period_rules: Option<DataPayload<'data, DayPeriodRulesV1Marker>>,
+        fixed_decimal_format: FixedDecimalFormat<'data>,
+        calendar: AnyCalendarKind,
     ) -> Self {
         let locale = locale.into();
 
@@ -168,6 +328,10 @@ impl<'data> DateTimeFormat<'data> {
             locale,
             pattern,
             symbols,
+            week_data,
+            day_period_rules,
+            fixed_decimal_format,
+            calendar,
         }
     }
 
@@ -199,15 +363,19 @@ impl<'data> DateTimeFormat<'data> {
     /// At the moment, there's little value in using that over one of the other `format` methods,
     /// but [`FormattedDateTime`] will grow with methods for iterating over fields, extracting information
     /// about formatted date and so on.
-    pub fn format<'l, T>(&'l self, value: &'l T) -> FormattedDateTime<'l, T>
+    pub fn format<'l, T>(&'l self, value: &'l T) -> FormattedDateTime<'l, 'data, T>
     where
         T: DateTimeInput,
     {
         FormattedDateTime {
             pattern: &self.pattern,
             symbols: self.symbols.as_ref().map(|s| s.get()),
+            week_data: self.week_data.as_ref().map(|w| w.get()),
+            day_period_rules: self.day_period_rules.as_ref().map(|d| d.get()),
+            fixed_decimal_format: &self.fixed_decimal_format,
             datetime: value,
             locale: &self.locale,
+            calendar: self.calendar,
         }
     }
 
@@ -242,11 +410,19 @@ impl<'data> DateTimeFormat<'data> {
         w: &mut impl core::fmt::Write,
         value: &impl DateTimeInput,
     ) -> core::fmt::Result {
+        let value =
+            DateTimeInputInCalendar::new(value, self.calendar).map_err(|_| core::fmt::Error)?;
+        let loc_datetime = DateTimeInputWithLocale::new(
+            &value,
+            self.week_data.as_ref().map(|w| w.get()),
+            self.day_period_rules.as_ref().map(|d| d.get()),
+            &self.locale,
+        );
         datetime::write_pattern(
             &self.pattern,
             self.symbols.as_ref().map(|s| s.get()),
-            value,
-            &self.locale,
+            &self.fixed_decimal_format,
+            &loc_datetime,
             w,
         )
         .map_err(|_| core::fmt::Error)
@@ -279,4 +455,68 @@ impl<'data> DateTimeFormat<'data> {
             .expect("Failed to write to a String.");
         s
     }
+
+    /// Parses text in the format of this [`DateTimeFormat`], such as a date typed by a user,
+    /// back into a date and time.
+    ///
+    /// The parsing is lenient: letters are compared ignoring case, any amount of whitespace
+    /// matches a space, punctuation of the pattern may be omitted, names of months, days of the
+    /// week and day periods are accepted in all widths, and numbers may be written with ASCII
+    /// digits as well as with those of the locale. The [`ParsedDateTime`] reports the span of
+    /// the input that was consumed, and any text after it is ignored.
+    ///
+    /// Two-digit years are always read in the fixed century from 1950 to 2049, so "21" is 2021
+    /// and "50" is 1950, regardless of the current date. Days of the week and quarters are
+    /// not needed to construct the date, but they must match it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::Date;
+    /// use icu::datetime::{options::length, DateTimeFormat, DateTimeParseError, DateTimePartKind};
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let locale: Locale = langid!("fr").into();
+    /// let options = length::Bag {
+    ///     date: Some(length::Date::Medium),
+    ///     time: Some(length::Time::Short),
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(locale, &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let parsed = dtf
+    ///     .parse("5 janv. 2021 14:03")
+    ///     .expect("Failed to parse the date.");
+    ///
+    /// assert_eq!(
+    ///     parsed.date,
+    ///     Some(Date::new_gregorian_date_from_integers(5, 1, 2021).unwrap())
+    /// );
+    /// let time = parsed.time.expect("The pattern has a time.");
+    /// assert_eq!((u8::from(time.hour), u8::from(time.minute)), (14, 3));
+    /// assert_eq!(parsed.span, 0..18);
+    ///
+    /// assert_eq!(
+    ///     dtf.parse("5 foo 2021 14:03"),
+    ///     Err(DateTimeParseError::Mismatch {
+    ///         expected: DateTimePartKind::Month,
+    ///         position: 2,
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// [`ParsedDateTime`]: crate::parse::ParsedDateTime
+    pub fn parse(&self, input: &str) -> Result<ParsedDateTime, DateTimeParseError> {
+        parse::parse_pattern(
+            &self.pattern,
+            self.symbols.as_ref().map(|s| s.get()),
+            self.week_data.as_ref().map(|w| w.get()),
+            &self.fixed_decimal_format,
+            self.calendar,
+            input,
+        )
+    }
 }
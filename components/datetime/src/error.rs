@@ -2,11 +2,17 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::date::DateTimeError;
 use crate::fields::FieldSymbol;
 use crate::pattern;
 use crate::skeleton::SkeletonError;
+use alloc::string::String;
 use displaydoc::Display;
+use icu_calendar::AnyCalendarKind;
+use icu_decimal::FixedDecimalFormatError;
+use icu_plurals::PluralRulesError;
 use icu_provider::prelude::DataError;
+use tinystr::TinyStr8;
 
 /// A list of possible error outcomes for the [`DateTimeFormat`](crate::DateTimeFormat) struct.
 #[derive(Display, Debug)]
@@ -30,6 +36,29 @@ pub enum DateTimeFormatError {
     /// An error originating from an unsupported field in a datetime format.
     #[displaydoc("Unsupported field: {0:?}")]
     UnsupportedField(FieldSymbol),
+    /// An error originating from an era that has no name in the symbols data.
+    #[displaydoc("Missing era symbol: {0}")]
+    MissingEraSymbol(TinyStr8),
+    /// An error originating from a month that has no name in the symbols data.
+    #[displaydoc("Missing month symbol: {0}")]
+    MissingMonthSymbol(TinyStr8),
+    /// An error originating from the plural rules used to select a pattern.
+    #[displaydoc("{0}")]
+    PluralRules(PluralRulesError),
+    /// An error originating from a time zone that is not in the time zone data.
+    #[displaydoc("Unknown time zone: {0}")]
+    UnknownTimeZone(String),
+    /// An error originating from an invalid date or time value.
+    #[displaydoc("{0}")]
+    DateTime(DateTimeError),
+    /// An error originating from a locale requesting a calendar that has no date symbols
+    /// and patterns.
+    #[displaydoc("Unsupported calendar: {0:?}")]
+    UnsupportedCalendar(AnyCalendarKind),
+    /// An error originating from formatting a date of one calendar with a formatter
+    /// created for another, when the date has no fixed day number to convert it with.
+    #[displaydoc("Mismatched calendar: expected {0:?}, found {1:?}")]
+    MismatchedCalendar(AnyCalendarKind, AnyCalendarKind),
 }
 
 #[cfg(feature = "std")]
@@ -47,6 +76,29 @@ impl From<DataError> for DateTimeFormatError {
     }
 }
 
+impl From<FixedDecimalFormatError> for DateTimeFormatError {
+    fn from(e: FixedDecimalFormatError) -> Self {
+        match e {
+            FixedDecimalFormatError::Data(e) => DateTimeFormatError::DataProvider(e),
+        }
+    }
+}
+
+impl From<PluralRulesError> for DateTimeFormatError {
+    fn from(e: PluralRulesError) -> Self {
+        match e {
+            PluralRulesError::DataProvider(e) => DateTimeFormatError::DataProvider(e),
+            e => DateTimeFormatError::PluralRules(e),
+        }
+    }
+}
+
+impl From<DateTimeError> for DateTimeFormatError {
+    fn from(e: DateTimeError) -> Self {
+        DateTimeFormatError::DateTime(e)
+    }
+}
+
 impl From<core::fmt::Error> for DateTimeFormatError {
     fn from(e: core::fmt::Error) -> Self {
         DateTimeFormatError::Format(e)
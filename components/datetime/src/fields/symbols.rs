@@ -24,6 +24,8 @@ impl std::error::Error for SymbolError {}
     feature = "provider_serde",
     derive(serde::Serialize, serde::Deserialize)
 )]
+// The variant indices are part of the binary data format of patterns and skeletons,
+// so new variants are added at the end.
 pub enum FieldSymbol {
     Year(Year),
     Month(Month),
@@ -34,6 +36,9 @@ pub enum FieldSymbol {
     Minute,
     Second(Second),
     TimeZone(TimeZone),
+    Week(Week),
+    Era,
+    Quarter(Quarter),
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -59,34 +64,40 @@ impl FieldSymbol {
     /// ordered most significant to least significant.
     fn get_canonical_order(&self) -> u8 {
         match self {
-            Self::Year(Year::Calendar) => 0,
-            Self::Year(Year::WeekOf) => 1,
-            Self::Month(Month::Format) => 2,
-            Self::Month(Month::StandAlone) => 3,
-            Self::Day(Day::DayOfMonth) => 4,
-            Self::Day(Day::DayOfYear) => 5,
-            Self::Day(Day::DayOfWeekInMonth) => 6,
-            Self::Day(Day::ModifiedJulianDay) => 7,
-            Self::Weekday(Weekday::Format) => 8,
-            Self::Weekday(Weekday::Local) => 9,
-            Self::Weekday(Weekday::StandAlone) => 10,
-            Self::DayPeriod(DayPeriod::AmPm) => 11,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 12,
-            Self::Hour(Hour::H11) => 13,
-            Self::Hour(Hour::H12) => 14,
-            Self::Hour(Hour::H23) => 15,
-            Self::Hour(Hour::H24) => 16,
-            Self::Minute => 17,
-            Self::Second(Second::Second) => 18,
-            Self::Second(Second::FractionalSecond) => 19,
-            Self::Second(Second::Millisecond) => 20,
-            Self::TimeZone(TimeZone::LowerZ) => 21,
-            Self::TimeZone(TimeZone::UpperZ) => 22,
-            Self::TimeZone(TimeZone::UpperO) => 23,
-            Self::TimeZone(TimeZone::LowerV) => 24,
-            Self::TimeZone(TimeZone::UpperV) => 25,
-            Self::TimeZone(TimeZone::LowerX) => 26,
-            Self::TimeZone(TimeZone::UpperX) => 27,
+            Self::Era => 0,
+            Self::Year(Year::Calendar) => 1,
+            Self::Year(Year::WeekOf) => 2,
+            Self::Quarter(Quarter::Format) => 3,
+            Self::Quarter(Quarter::StandAlone) => 4,
+            Self::Month(Month::Format) => 5,
+            Self::Month(Month::StandAlone) => 6,
+            Self::Week(Week::WeekOfYear) => 7,
+            Self::Week(Week::WeekOfMonth) => 8,
+            Self::Day(Day::DayOfMonth) => 9,
+            Self::Day(Day::DayOfYear) => 10,
+            Self::Day(Day::DayOfWeekInMonth) => 11,
+            Self::Day(Day::ModifiedJulianDay) => 12,
+            Self::Weekday(Weekday::Format) => 13,
+            Self::Weekday(Weekday::Local) => 14,
+            Self::Weekday(Weekday::StandAlone) => 15,
+            Self::DayPeriod(DayPeriod::AmPm) => 16,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 17,
+            Self::DayPeriod(DayPeriod::Flexible) => 18,
+            Self::Hour(Hour::H11) => 19,
+            Self::Hour(Hour::H12) => 20,
+            Self::Hour(Hour::H23) => 21,
+            Self::Hour(Hour::H24) => 22,
+            Self::Minute => 23,
+            Self::Second(Second::Second) => 24,
+            Self::Second(Second::FractionalSecond) => 25,
+            Self::Second(Second::Millisecond) => 26,
+            Self::TimeZone(TimeZone::LowerZ) => 27,
+            Self::TimeZone(TimeZone::UpperZ) => 28,
+            Self::TimeZone(TimeZone::UpperO) => 29,
+            Self::TimeZone(TimeZone::LowerV) => 30,
+            Self::TimeZone(TimeZone::UpperV) => 31,
+            Self::TimeZone(TimeZone::LowerX) => 32,
+            Self::TimeZone(TimeZone::UpperX) => 33,
         }
     }
 }
@@ -95,10 +106,13 @@ impl TryFrom<u8> for FieldSymbol {
     type Error = SymbolError;
     fn try_from(b: u8) -> Result<Self, Self::Error> {
         match b {
+            b'G' => Ok(Self::Era),
             b'm' => Ok(Self::Minute),
             _ => Year::try_from(b)
                 .map(Self::Year)
+                .or_else(|_| Quarter::try_from(b).map(Self::Quarter))
                 .or_else(|_| Month::try_from(b).map(Self::Month))
+                .or_else(|_| Week::try_from(b).map(Self::Week))
                 .or_else(|_| Day::try_from(b).map(Self::Day))
                 .or_else(|_| Weekday::try_from(b).map(Self::Weekday))
                 .or_else(|_| DayPeriod::try_from(b).map(Self::DayPeriod))
@@ -124,14 +138,23 @@ impl TryFrom<char> for FieldSymbol {
 impl From<FieldSymbol> for char {
     fn from(symbol: FieldSymbol) -> Self {
         match symbol {
+            FieldSymbol::Era => 'G',
             FieldSymbol::Year(year) => match year {
                 Year::Calendar => 'y',
                 Year::WeekOf => 'Y',
             },
+            FieldSymbol::Quarter(quarter) => match quarter {
+                Quarter::Format => 'Q',
+                Quarter::StandAlone => 'q',
+            },
             FieldSymbol::Month(month) => match month {
                 Month::Format => 'M',
                 Month::StandAlone => 'L',
             },
+            FieldSymbol::Week(week) => match week {
+                Week::WeekOfYear => 'w',
+                Week::WeekOfMonth => 'W',
+            },
             FieldSymbol::Day(day) => match day {
                 Day::DayOfMonth => 'd',
                 Day::DayOfYear => 'D',
@@ -146,6 +169,7 @@ impl From<FieldSymbol> for char {
             FieldSymbol::DayPeriod(dayperiod) => match dayperiod {
                 DayPeriod::AmPm => 'a',
                 DayPeriod::NoonMidnight => 'b',
+                DayPeriod::Flexible => 'B',
             },
             FieldSymbol::Hour(hour) => match hour {
                 Hour::H11 => 'K',
@@ -217,6 +241,42 @@ impl From<Year> for FieldSymbol {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum Quarter {
+    Format,
+    StandAlone,
+}
+
+impl LengthType for Quarter {
+    fn get_length_type(&self, length: FieldLength) -> TextOrNumeric {
+        match length {
+            FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Numeric,
+            _ => TextOrNumeric::Text,
+        }
+    }
+}
+
+impl TryFrom<u8> for Quarter {
+    type Error = SymbolError;
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'Q' => Ok(Self::Format),
+            b'q' => Ok(Self::StandAlone),
+            b => Err(SymbolError::Unknown(b)),
+        }
+    }
+}
+
+impl From<Quarter> for FieldSymbol {
+    fn from(input: Quarter) -> Self {
+        Self::Quarter(input)
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 #[cfg_attr(
     feature = "provider_serde",
@@ -257,6 +317,40 @@ impl From<Month> for FieldSymbol {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[allow(clippy::enum_variant_names)]
+pub enum Week {
+    WeekOfYear,
+    WeekOfMonth,
+}
+
+impl LengthType for Week {
+    fn get_length_type(&self, _length: FieldLength) -> TextOrNumeric {
+        TextOrNumeric::Numeric
+    }
+}
+
+impl TryFrom<u8> for Week {
+    type Error = SymbolError;
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'w' => Ok(Self::WeekOfYear),
+            b'W' => Ok(Self::WeekOfMonth),
+            b => Err(SymbolError::Unknown(b)),
+        }
+    }
+}
+
+impl From<Week> for FieldSymbol {
+    fn from(input: Week) -> Self {
+        Self::Week(input)
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 #[cfg_attr(
     feature = "provider_serde",
@@ -384,8 +478,8 @@ impl LengthType for Weekday {
         match self {
             Self::Format => TextOrNumeric::Text,
             Self::Local | Self::StandAlone => match length {
-                FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Text,
-                _ => TextOrNumeric::Numeric,
+                FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Numeric,
+                _ => TextOrNumeric::Text,
             },
         }
     }
@@ -417,6 +511,7 @@ impl From<Weekday> for FieldSymbol {
 pub enum DayPeriod {
     AmPm,
     NoonMidnight,
+    Flexible,
 }
 
 impl LengthType for DayPeriod {
@@ -431,6 +526,7 @@ impl TryFrom<u8> for DayPeriod {
         match b {
             b'a' => Ok(Self::AmPm),
             b'b' => Ok(Self::NoonMidnight),
+            b'B' => Ok(Self::Flexible),
             b => Err(SymbolError::Unknown(b)),
         }
     }
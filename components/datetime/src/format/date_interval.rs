@@ -0,0 +1,71 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::date::DateTimeInput;
+use crate::format::datetime::FormattedDateTime;
+
+use core::fmt;
+use writeable::Writeable;
+
+/// [`FormattedDateInterval`] is a intermediate structure which can be retrieved as
+/// an output from [`DateIntervalFormat`](crate::DateIntervalFormat).
+///
+/// It consists of the first date formatted with the first part of the interval pattern, and
+/// the second date formatted with the second part. When both dates are formatted the same,
+/// only the first date is formatted.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::Locale;
+/// use icu::locid::macros::langid;
+/// use icu::datetime::{DateIntervalFormat, DateTimeFormatOptions};
+/// use icu::datetime::mock::datetime::MockDateTime;
+/// use icu_provider::inv::InvariantDataProvider;
+/// let locale: Locale = langid!("en").into();
+/// # let provider = InvariantDataProvider;
+/// # let options = DateTimeFormatOptions::default();
+/// let dif = DateIntervalFormat::try_new(locale, &provider, &options)
+///     .expect("Failed to create DateIntervalFormat instance.");
+///
+/// let from = MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+///     .expect("Failed to construct DateTime.");
+/// let to = MockDateTime::try_new(2020, 9, 4, 12, 34, 28)
+///     .expect("Failed to construct DateTime.");
+///
+/// let formatted_interval = dif.format(&from, &to);
+///
+/// let _ = format!("Interval: {}", formatted_interval);
+/// ```
+pub struct FormattedDateInterval<'l, 'data, T>
+where
+    T: DateTimeInput,
+{
+    pub(crate) first: FormattedDateTime<'l, 'data, T>,
+    pub(crate) second: Option<FormattedDateTime<'l, 'data, T>>,
+}
+
+impl<'l, 'data, T> Writeable for FormattedDateInterval<'l, 'data, T>
+where
+    T: DateTimeInput,
+{
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.first.write_to(sink)?;
+        if let Some(second) = &self.second {
+            second.write_to(sink)?;
+        }
+        Ok(())
+    }
+
+    // TODO(#489): Implement write_len
+}
+
+impl<'l, 'data, T> fmt::Display for FormattedDateInterval<'l, 'data, T>
+where
+    T: DateTimeInput,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
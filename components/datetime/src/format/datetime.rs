@@ -3,17 +3,24 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::arithmetic;
-use crate::date::{DateTimeInput, DateTimeInputWithLocale, LocalizedDateTimeInput};
+use crate::date::{
+    DateInput, DateTimeError, DateTimeInput, DateTimeInputInCalendar, DateTimeInputWithLocale,
+    DayOfWeekInMonth, LocalizedDateTimeInput,
+};
 use crate::error::DateTimeFormatError as Error;
-use crate::fields::{self, Field, FieldLength, FieldSymbol};
+use crate::fields::{self, Field, FieldLength, FieldSymbol, TextOrNumeric};
 use crate::pattern::{Pattern, PatternItem};
 use crate::provider;
 use crate::provider::helpers::DateTimeSymbols;
 
-use alloc::string::ToString;
-use core::fmt;
+use super::parts::{DateTimePart, DateTimePartKind, PartsWriter};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_calendar::AnyCalendarKind;
+use icu_decimal::FixedDecimalFormat;
 use icu_locid::Locale;
-use writeable::Writeable;
+use writeable::{LengthHint, Writeable};
 
 /// [`FormattedDateTime`] is a intermediate structure which can be retrieved as
 /// an output from [`DateTimeFormat`](crate::DateTimeFormat).
@@ -43,88 +50,232 @@ use writeable::Writeable;
 ///
 /// let _ = format!("Date: {}", formatted_date);
 /// ```
-pub struct FormattedDateTime<'l, T>
+pub struct FormattedDateTime<'l, 'data, T>
 where
     T: DateTimeInput,
 {
     pub(crate) pattern: &'l Pattern,
     pub(crate) symbols: Option<&'l provider::gregory::DateSymbolsV1>,
+    pub(crate) week_data: Option<&'l provider::week_data::WeekDataV1>,
+    pub(crate) day_period_rules: Option<&'l provider::day_period_rules::DayPeriodRulesV1>,
+    pub(crate) fixed_decimal_format: &'l FixedDecimalFormat<'data>,
     pub(crate) datetime: &'l T,
     pub(crate) locale: &'l Locale,
+    pub(crate) calendar: AnyCalendarKind,
 }
 
-impl<'l, T> Writeable for FormattedDateTime<'l, T>
+impl<'l, 'data, T> FormattedDateTime<'l, 'data, T>
+where
+    T: DateTimeInput,
+{
+    /// Writes the formatted value to `sink`, and returns the [`DateTimePart`]s of the output,
+    /// which annotate the byte range of every field and literal.
+    ///
+    /// See [`DateTimePart`] for an example.
+    pub fn write_to_parts<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+    ) -> Result<Vec<DateTimePart>, Error> {
+        let datetime = DateTimeInputInCalendar::new(self.datetime, self.calendar)?;
+        let loc_datetime = DateTimeInputWithLocale::new(
+            &datetime,
+            self.week_data,
+            self.day_period_rules,
+            self.locale,
+        );
+        write_pattern_to_parts(
+            self.pattern,
+            self.symbols,
+            self.fixed_decimal_format,
+            &loc_datetime,
+            sink,
+        )
+    }
+}
+
+impl<'l, 'data, T> Writeable for FormattedDateTime<'l, 'data, T>
 where
     T: DateTimeInput,
 {
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
-        write_pattern(self.pattern, self.symbols, self.datetime, self.locale, sink)
-            .map_err(|_| core::fmt::Error)
+        let datetime = DateTimeInputInCalendar::new(self.datetime, self.calendar)
+            .map_err(|_| core::fmt::Error)?;
+        let loc_datetime = DateTimeInputWithLocale::new(
+            &datetime,
+            self.week_data,
+            self.day_period_rules,
+            self.locale,
+        );
+        write_pattern(
+            self.pattern,
+            self.symbols,
+            self.fixed_decimal_format,
+            &loc_datetime,
+            sink,
+        )
+        .map_err(|_| core::fmt::Error)
     }
 
-    // TODO(#489): Implement write_len
+    fn write_len(&self) -> LengthHint {
+        let datetime = match DateTimeInputInCalendar::new(self.datetime, self.calendar) {
+            Ok(datetime) => datetime,
+            Err(_) => return LengthHint::Undefined,
+        };
+        let loc_datetime = DateTimeInputWithLocale::new(
+            &datetime,
+            self.week_data,
+            self.day_period_rules,
+            self.locale,
+        );
+        pattern_len(
+            self.pattern,
+            self.symbols,
+            self.fixed_decimal_format,
+            &loc_datetime,
+        )
+    }
 }
 
-impl<'l, T> fmt::Display for FormattedDateTime<'l, T>
+impl<'l, 'data, T> fmt::Display for FormattedDateTime<'l, 'data, T>
 where
     T: DateTimeInput,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(self.pattern, self.symbols, self.datetime, self.locale, f)
-            .map_err(|_| core::fmt::Error)
+        let datetime = DateTimeInputInCalendar::new(self.datetime, self.calendar)
+            .map_err(|_| core::fmt::Error)?;
+        let loc_datetime = DateTimeInputWithLocale::new(
+            &datetime,
+            self.week_data,
+            self.day_period_rules,
+            self.locale,
+        );
+        write_pattern(
+            self.pattern,
+            self.symbols,
+            self.fixed_decimal_format,
+            &loc_datetime,
+            f,
+        )
+        .map_err(|_| core::fmt::Error)
     }
 }
 
-// Temporary formatting number with length.
-fn format_number<W>(result: &mut W, num: isize, length: FieldLength) -> Result<(), core::fmt::Error>
-where
-    W: fmt::Write + ?Sized,
-{
-    match length {
-        FieldLength::One => write!(result, "{}", num),
-        FieldLength::TwoDigit => {
-            if num < 100 {
-                write!(result, "{:0>width$}", num, width = 2)
-            } else {
-                let buffer = num.to_string();
-                let len = buffer.len();
-                result.write_str(&buffer[len - 2..])
+/// The text of a field, which is either a symbol from the data, or a number that is
+/// formatted with the digits of the locale.
+enum FieldValue<'l> {
+    Symbol(&'l str),
+    Number(FixedDecimal),
+}
+
+impl<'l> FieldValue<'l> {
+    /// A number padded or truncated to the field length.
+    fn number(mut num: FixedDecimal, length: FieldLength) -> Self {
+        match length {
+            FieldLength::One => {}
+            FieldLength::TwoDigit => {
+                num.truncate_left(1);
+                num.pad_left(2);
             }
+            length => num.pad_left(length as u16),
         }
-        length => write!(result, "{:0>width$}", num, width = length as usize),
+        Self::Number(num)
     }
 }
 
+/// The fixed day number of November 17, 1858, which is day 0 of the modified Julian day.
+const MJD_EPOCH_FIXED_DAY: i64 = 678_576;
+
 pub fn write_pattern<T, W>(
     pattern: &crate::pattern::Pattern,
     symbols: Option<&provider::gregory::DateSymbolsV1>,
-    datetime: &T,
-    locale: &Locale,
+    fixed_decimal_format: &FixedDecimalFormat,
+    loc_datetime: &impl LocalizedDateTimeInput<T>,
     w: &mut W,
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
     W: fmt::Write + ?Sized,
 {
-    let loc_datetime = DateTimeInputWithLocale::new(datetime, locale);
     for item in pattern.items() {
         match item {
-            PatternItem::Field(field) => write_field(pattern, field, symbols, &loc_datetime, w)?,
+            PatternItem::Field(field) => write_field(
+                pattern,
+                field,
+                symbols,
+                fixed_decimal_format,
+                loc_datetime,
+                w,
+            )?,
             PatternItem::Literal(l) => w.write_str(l)?,
         }
     }
     Ok(())
 }
 
-// This function assumes that the correct decision has been
-// made regarding availability of symbols in the caller.
-//
-// When modifying the list of fields using symbols,
-// update the matching query in `analyze_pattern` function.
+/// Writes the pattern like [`write_pattern`], and returns the [`DateTimePart`] written for each
+/// of its items.
+pub fn write_pattern_to_parts<T, W>(
+    pattern: &crate::pattern::Pattern,
+    symbols: Option<&provider::gregory::DateSymbolsV1>,
+    fixed_decimal_format: &FixedDecimalFormat,
+    loc_datetime: &impl LocalizedDateTimeInput<T>,
+    w: &mut W,
+) -> Result<Vec<DateTimePart>, Error>
+where
+    T: DateTimeInput,
+    W: fmt::Write + ?Sized,
+{
+    let mut w = PartsWriter::new(w);
+    for item in pattern.items() {
+        match item {
+            PatternItem::Field(field) => w.write_part(field.symbol.into(), |w| {
+                write_field(
+                    pattern,
+                    field,
+                    symbols,
+                    fixed_decimal_format,
+                    loc_datetime,
+                    w,
+                )
+            })?,
+            PatternItem::Literal(l) => {
+                w.write_part(DateTimePartKind::Literal, |w| w.write_str(l))?
+            }
+        }
+    }
+    Ok(w.parts)
+}
+
+/// Returns the length of the output of [`write_pattern`], computed from the literals of the
+/// pattern and the lengths of the symbols and numbers of its fields, or
+/// [`LengthHint::Undefined`] if the pattern cannot be written.
+pub fn pattern_len<T>(
+    pattern: &crate::pattern::Pattern,
+    symbols: Option<&provider::gregory::DateSymbolsV1>,
+    fixed_decimal_format: &FixedDecimalFormat,
+    loc_datetime: &impl LocalizedDateTimeInput<T>,
+) -> LengthHint
+where
+    T: DateTimeInput,
+{
+    pattern
+        .items()
+        .iter()
+        .map(|item| match item {
+            PatternItem::Field(field) => {
+                field_len(pattern, field, symbols, fixed_decimal_format, loc_datetime)
+                    .unwrap_or(LengthHint::Undefined)
+            }
+            PatternItem::Literal(l) => LengthHint::Exact(l.len()),
+        })
+        .sum()
+}
+
 pub(super) fn write_field<T, W>(
     pattern: &crate::pattern::Pattern,
     field: &fields::Field,
     symbols: Option<&crate::provider::gregory::DateSymbolsV1>,
+    fixed_decimal_format: &FixedDecimalFormat,
     datetime: &impl LocalizedDateTimeInput<T>,
     w: &mut W,
 ) -> Result<(), Error>
@@ -132,61 +283,182 @@ where
     T: DateTimeInput,
     W: fmt::Write + ?Sized,
 {
-    match field.symbol {
-        FieldSymbol::Year(..) => format_number(
-            w,
+    match field_value(pattern, field, symbols, datetime)? {
+        FieldValue::Symbol(symbol) => w.write_str(symbol)?,
+        FieldValue::Number(num) => fixed_decimal_format.format(&num).write_to(w)?,
+    }
+    Ok(())
+}
+
+/// Returns the length of the field as written by [`write_field`].
+pub(super) fn field_len<T>(
+    pattern: &crate::pattern::Pattern,
+    field: &fields::Field,
+    symbols: Option<&crate::provider::gregory::DateSymbolsV1>,
+    fixed_decimal_format: &FixedDecimalFormat,
+    datetime: &impl LocalizedDateTimeInput<T>,
+) -> Result<LengthHint, Error>
+where
+    T: DateTimeInput,
+{
+    Ok(match field_value(pattern, field, symbols, datetime)? {
+        FieldValue::Symbol(symbol) => LengthHint::Exact(symbol.len()),
+        FieldValue::Number(num) => fixed_decimal_format.format(&num).write_len(),
+    })
+}
+
+// This function assumes that the correct decision has been
+// made regarding availability of symbols, week data and day period rules in the caller.
+//
+// When modifying the list of fields using symbols, week data or day period rules,
+// update the matching query in `analyze_pattern`, `requires_week_data` or
+// `requires_day_period_rules` function.
+fn field_value<'l, T>(
+    pattern: &crate::pattern::Pattern,
+    field: &fields::Field,
+    symbols: Option<&'l crate::provider::gregory::DateSymbolsV1>,
+    datetime: &impl LocalizedDateTimeInput<T>,
+) -> Result<FieldValue<'l>, Error>
+where
+    T: DateTimeInput,
+{
+    Ok(match field.symbol {
+        FieldSymbol::Era => {
+            let era = datetime
+                .datetime()
+                .year()
+                .ok_or(Error::MissingInputField)?
+                .era;
+            let symbol = symbols
+                .expect("Expect symbols to be present")
+                .get_symbol_for_era(field.length, &era)
+                .ok_or(Error::MissingEraSymbol(era.0))?;
+            FieldValue::Symbol(symbol)
+        }
+        FieldSymbol::Year(fields::Year::WeekOf) => FieldValue::number(
+            datetime
+                .year_week()
+                .ok_or(Error::MissingInputField)?
+                .number
+                .into(),
+            field.length,
+        ),
+        FieldSymbol::Year(..) => FieldValue::number(
             datetime
                 .datetime()
                 .year()
                 .ok_or(Error::MissingInputField)?
-                .number as isize,
+                .number
+                .into(),
             field.length,
-        )?,
+        ),
         FieldSymbol::Month(month) => match field.length {
-            FieldLength::One | FieldLength::TwoDigit => format_number(
-                w,
+            FieldLength::One | FieldLength::TwoDigit => FieldValue::number(
                 datetime
                     .datetime()
                     .month()
                     .ok_or(Error::MissingInputField)?
-                    .number as isize,
+                    .number
+                    .into(),
                 field.length,
-            )?,
+            ),
             length => {
+                let code = datetime
+                    .datetime()
+                    .month()
+                    .ok_or(Error::MissingInputField)?
+                    .code;
+                // Leap years of the Hebrew calendar have thirteen months, and Adar II, the
+                // month following the leap month, has a name of its own.
+                let is_leap_year = datetime.datetime().calendar_kind()
+                    == Some(AnyCalendarKind::Hebrew)
+                    && datetime.datetime().months_in_year() == Some(13);
                 let symbol = symbols
                     .expect("Expect symbols to be present")
-                    .get_symbol_for_month(
-                        month,
-                        length,
-                        datetime
-                            .datetime()
-                            .month()
-                            .ok_or(Error::MissingInputField)?
-                            .number as usize
-                            - 1,
-                    );
-                w.write_str(symbol)?
+                    .get_symbol_for_month(month, length, &code, is_leap_year)
+                    .ok_or(Error::MissingMonthSymbol(code.0))?;
+                FieldValue::Symbol(symbol)
             }
         },
-        FieldSymbol::Weekday(weekday) => {
-            let dow = datetime
-                .datetime()
-                .iso_weekday()
-                .ok_or(Error::MissingInputField)?;
-            let symbol = symbols
-                .expect("Expect symbols to be present")
-                .get_symbol_for_weekday(weekday, field.length, dow);
-            w.write_str(symbol)?
-        }
-        FieldSymbol::Day(..) => format_number(
-            w,
-            datetime
+        FieldSymbol::Week(week) => FieldValue::number(
+            match week {
+                fields::Week::WeekOfYear => {
+                    datetime.week_of_year().ok_or(Error::MissingInputField)?.0
+                }
+                fields::Week::WeekOfMonth => {
+                    datetime.week_of_month().ok_or(Error::MissingInputField)?.0
+                }
+            }
+            .into(),
+            field.length,
+        ),
+        FieldSymbol::Quarter(quarter) => {
+            let month = datetime
                 .datetime()
-                .day_of_month()
+                .month()
                 .ok_or(Error::MissingInputField)?
-                .0 as isize,
-            field.length,
-        )?,
+                .number;
+            let num = arithmetic::quarter_of_month(month).ok_or(Error::DateTime(
+                DateTimeError::Overflow {
+                    field: "Month",
+                    max: 13,
+                },
+            ))?;
+            match field.length {
+                FieldLength::One | FieldLength::TwoDigit => {
+                    FieldValue::number((num + 1).into(), field.length)
+                }
+                length => {
+                    let symbol = symbols
+                        .expect("Expect symbols to be present")
+                        .get_symbol_for_quarter(quarter, length, num as usize);
+                    FieldValue::Symbol(symbol)
+                }
+            }
+        }
+        FieldSymbol::Weekday(weekday) => match field.get_length_type() {
+            TextOrNumeric::Numeric => FieldValue::number(
+                datetime
+                    .local_day_of_week()
+                    .ok_or(Error::MissingInputField)?
+                    .into(),
+                field.length,
+            ),
+            TextOrNumeric::Text => {
+                let dow = datetime
+                    .datetime()
+                    .iso_weekday()
+                    .ok_or(Error::MissingInputField)?;
+                let symbol = symbols
+                    .expect("Expect symbols to be present")
+                    .get_symbol_for_weekday(weekday, field.length, dow);
+                FieldValue::Symbol(symbol)
+            }
+        },
+        FieldSymbol::Day(day) => {
+            let date = datetime.datetime();
+            let value: FixedDecimal = match day {
+                fields::Day::DayOfMonth => date
+                    .day_of_month()
+                    .ok_or(Error::MissingInputField)?
+                    .0
+                    .into(),
+                fields::Day::DayOfYear => date
+                    .day_of_year_info()
+                    .ok_or(Error::MissingInputField)?
+                    .day_of_year
+                    .into(),
+                fields::Day::DayOfWeekInMonth => {
+                    DayOfWeekInMonth::from(date.day_of_month().ok_or(Error::MissingInputField)?)
+                        .0
+                        .into()
+                }
+                fields::Day::ModifiedJulianDay => {
+                    (date.fixed_day().ok_or(Error::MissingInputField)? - MJD_EPOCH_FIXED_DAY).into()
+                }
+            };
+            FieldValue::number(value, field.length)
+        }
         FieldSymbol::Hour(hour) => {
             let h =
                 usize::from(datetime.datetime().hour().ok_or(Error::MissingInputField)?) as isize;
@@ -209,28 +481,40 @@ where
                     }
                 }
             };
-            format_number(w, value, field.length)?
+            FieldValue::number(value.into(), field.length)
         }
-        FieldSymbol::Minute => format_number(
-            w,
+        FieldSymbol::Minute => FieldValue::number(
             usize::from(
                 datetime
                     .datetime()
                     .minute()
                     .ok_or(Error::MissingInputField)?,
-            ) as isize,
+            )
+            .into(),
             field.length,
-        )?,
-        FieldSymbol::Second(..) => format_number(
-            w,
+        ),
+        FieldSymbol::Second(..) => FieldValue::number(
             usize::from(
                 datetime
                     .datetime()
                     .second()
                     .ok_or(Error::MissingInputField)?,
-            ) as isize,
+            )
+            .into(),
             field.length,
-        )?,
+        ),
+        FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) => {
+            let hour = datetime.datetime().hour().ok_or(Error::MissingInputField)?;
+            let day_period = datetime.flexible_day_period(arithmetic::is_top_of_hour(
+                pattern,
+                datetime.datetime().minute().map(u8::from).unwrap_or(0),
+                datetime.datetime().second().map(u8::from).unwrap_or(0),
+            ));
+            let symbol = symbols
+                .expect("Expect symbols to be present")
+                .get_symbol_for_flexible_day_period(field.length, hour, day_period);
+            FieldValue::Symbol(symbol)
+        }
         FieldSymbol::DayPeriod(period) => {
             let symbol = symbols
                 .expect("Expect symbols to be present")
@@ -244,11 +528,10 @@ where
                         datetime.datetime().second().map(u8::from).unwrap_or(0),
                     ),
                 );
-            w.write_str(symbol)?
+            FieldValue::Symbol(symbol)
         }
         field @ FieldSymbol::TimeZone(_) => return Err(Error::UnsupportedField(field)),
-    };
-    Ok(())
+    })
 }
 
 // This function determins whether the struct will load symbols data.
@@ -264,10 +547,11 @@ pub fn analyze_pattern(pattern: &Pattern, supports_time_zones: bool) -> Result<b
     for field in fields {
         if !requires_symbols {
             requires_symbols = match field.symbol {
-                FieldSymbol::Month(_) => {
+                FieldSymbol::Month(_) | FieldSymbol::Quarter(_) => {
                     !matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
                 }
-                FieldSymbol::Weekday(_) | FieldSymbol::DayPeriod(_) => true,
+                FieldSymbol::Weekday(_) => field.get_length_type() == TextOrNumeric::Text,
+                FieldSymbol::Era | FieldSymbol::DayPeriod(_) => true,
                 _ => false,
             }
         }
@@ -288,9 +572,50 @@ pub fn analyze_pattern(pattern: &Pattern, supports_time_zones: bool) -> Result<b
     Ok(requires_symbols)
 }
 
+// This function determines whether the struct will load day period rules.
+// Keep it in sync with the `write_field` use of day period rules.
+pub fn requires_day_period_rules(pattern: &Pattern) -> bool {
+    pattern.items().iter().any(|item| match item {
+        PatternItem::Field(field) => matches!(
+            field.symbol,
+            FieldSymbol::DayPeriod(fields::DayPeriod::Flexible)
+        ),
+        _ => false,
+    })
+}
+
+// This function determines whether the struct will load week data.
+// Keep it in sync with the `write_field` use of week data.
+pub fn requires_week_data(pattern: &Pattern) -> bool {
+    pattern.items().iter().any(|item| match item {
+        PatternItem::Field(field) => match field.symbol {
+            FieldSymbol::Year(fields::Year::WeekOf) | FieldSymbol::Week(_) => true,
+            // The numeric local day of week depends on the first day of the week.
+            FieldSymbol::Weekday(fields::Weekday::Local)
+            | FieldSymbol::Weekday(fields::Weekday::StandAlone) => {
+                field.get_length_type() == TextOrNumeric::Numeric
+            }
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use icu_decimal::options::{FixedDecimalFormatOptions, GroupingStrategy};
+
+    fn fixed_decimal_format() -> FixedDecimalFormat<'static> {
+        let mut options = FixedDecimalFormatOptions::default();
+        options.grouping_strategy = GroupingStrategy::Never;
+        FixedDecimalFormat::try_new(
+            Locale::und(),
+            &icu_provider::inv::InvariantDataProvider,
+            options,
+        )
+        .unwrap()
+    }
 
     #[test]
     #[cfg(feature = "provider_serde")]
@@ -303,9 +628,9 @@ mod tests {
         let data: DataPayload<DateSymbolsV1Marker> = provider
             .load_payload(&DataRequest {
                 resource_path: ResourcePath {
-                    key: provider::key::GREGORY_DATE_SYMBOLS_V1,
+                    key: provider::key::DATE_SYMBOLS_V1,
                     options: ResourceOptions {
-                        variant: None,
+                        variant: Some("gregory".into()),
                         langid: Some("en".parse().unwrap()),
                     },
                 },
@@ -316,17 +641,144 @@ mod tests {
         let pattern = crate::pattern::Pattern::from_bytes("MMM").unwrap();
         let datetime = MockDateTime::try_new(2020, 8, 1, 12, 34, 28).unwrap();
         let mut sink = String::new();
+        let loc_datetime =
+            DateTimeInputWithLocale::new(&datetime, None, None, &"und".parse().unwrap());
         write_pattern(
             &pattern,
             Some(data.get()),
-            &datetime,
-            &"und".parse().unwrap(),
+            &fixed_decimal_format(),
+            &loc_datetime,
             &mut sink,
         )
         .unwrap();
         println!("{}", sink);
     }
 
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_native_digits() {
+        use crate::mock::datetime::MockDateTime;
+
+        let provider = icu_testdata::get_provider();
+        let pattern = crate::pattern::Pattern::from_bytes("y-MM-dd HH:mm").unwrap();
+        let datetime: MockDateTime = "2021-01-04T09:05:00".parse().unwrap();
+        let mut options = FixedDecimalFormatOptions::default();
+        options.grouping_strategy = GroupingStrategy::Never;
+        let cases = [
+            ("en", "2021-01-04 09:05"),
+            ("ar-EG", "٢٠٢١-٠١-٠٤ ٠٩:٠٥"),
+            ("ar-EG-u-nu-latn", "2021-01-04 09:05"),
+            ("bn", "২০২১-০১-০৪ ০৯:০৫"),
+            ("th", "2021-01-04 09:05"),
+            ("th-u-nu-thai", "๒๐๒๑-๐๑-๐๔ ๐๙:๐๕"),
+        ];
+        for (locale, expected) in &cases {
+            let locale: Locale = locale.parse().unwrap();
+            let fixed_decimal_format =
+                FixedDecimalFormat::try_new(locale.clone(), &provider, options.clone()).unwrap();
+            let mut sink = String::new();
+            let loc_datetime = DateTimeInputWithLocale::new(&datetime, None, None, &locale);
+            write_pattern(
+                &pattern,
+                None,
+                &fixed_decimal_format,
+                &loc_datetime,
+                &mut sink,
+            )
+            .unwrap();
+            assert_eq!(&sink, expected, "{}", locale);
+        }
+    }
+
+    #[test]
+    fn test_week_fields() {
+        use crate::mock::datetime::MockDateTime;
+        use crate::provider::week_data::WeekDataV1;
+
+        let pattern = crate::pattern::Pattern::from_bytes("Y-ww-W").unwrap();
+        let en_us = WeekDataV1 {
+            first_weekday: 7,
+            min_week_days: 1,
+        };
+        let de_de = WeekDataV1 {
+            first_weekday: 1,
+            min_week_days: 4,
+        };
+        let cases = [
+            ("2020-12-31T00:00:00", "2021-01-5", "2020-53-5"),
+            ("2021-01-03T00:00:00", "2021-02-2", "2020-53-0"),
+            ("2021-01-04T00:00:00", "2021-02-2", "2021-01-1"),
+        ];
+        let fixed_decimal_format = fixed_decimal_format();
+        for (input, expected_en_us, expected_de_de) in &cases {
+            let datetime: MockDateTime = input.parse().unwrap();
+            for (week_data, expected) in &[(en_us, expected_en_us), (de_de, expected_de_de)] {
+                let mut sink = String::new();
+                let loc_datetime = DateTimeInputWithLocale::new(
+                    &datetime,
+                    Some(week_data),
+                    None,
+                    &"und".parse().unwrap(),
+                );
+                write_pattern(
+                    &pattern,
+                    None,
+                    &fixed_decimal_format,
+                    &loc_datetime,
+                    &mut sink,
+                )
+                .unwrap();
+                assert_eq!(&sink, *expected, "{}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_numeric_date_fields() {
+        use crate::mock::datetime::MockDateTime;
+        use crate::provider::week_data::WeekDataV1;
+
+        let pattern = crate::pattern::Pattern::from_bytes("Q-D-F-g-e-c").unwrap();
+        let en_us = WeekDataV1 {
+            first_weekday: 7,
+            min_week_days: 1,
+        };
+        let de_de = WeekDataV1 {
+            first_weekday: 1,
+            min_week_days: 4,
+        };
+        let cases = [
+            ("2021-01-04T00:00:00", "1-4-1-59218-2-2", "1-4-1-59218-1-1"),
+            (
+                "2021-12-31T00:00:00",
+                "4-365-5-59579-6-6",
+                "4-365-5-59579-5-5",
+            ),
+        ];
+        let fixed_decimal_format = fixed_decimal_format();
+        for (input, expected_en_us, expected_de_de) in &cases {
+            let datetime: MockDateTime = input.parse().unwrap();
+            for (week_data, expected) in &[(en_us, expected_en_us), (de_de, expected_de_de)] {
+                let mut sink = String::new();
+                let loc_datetime = DateTimeInputWithLocale::new(
+                    &datetime,
+                    Some(week_data),
+                    None,
+                    &"und".parse().unwrap(),
+                );
+                write_pattern(
+                    &pattern,
+                    None,
+                    &fixed_decimal_format,
+                    &loc_datetime,
+                    &mut sink,
+                )
+                .unwrap();
+                assert_eq!(&sink, *expected, "{}", input);
+            }
+        }
+    }
+
     #[test]
     fn test_format_number() {
         let values = &[2, 20, 201, 2017, 20173];
@@ -339,11 +791,14 @@ mod tests {
             ),
             (FieldLength::Wide, ["0002", "0020", "0201", "2017", "20173"]),
         ];
+        let fixed_decimal_format = fixed_decimal_format();
         for (length, expected) in samples {
             for (value, expected) in values.iter().zip(expected) {
-                let mut s = String::new();
-                format_number(&mut s, *value, *length).unwrap();
-                assert_eq!(s, *expected);
+                let num = match FieldValue::number((*value).into(), *length) {
+                    FieldValue::Number(num) => num,
+                    FieldValue::Symbol(_) => unreachable!(),
+                };
+                writeable::assert_writeable_eq!(*expected, fixed_decimal_format.format(&num));
             }
         }
     }
@@ -2,6 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+pub mod date_interval;
 pub mod datetime;
+pub mod parts;
+pub mod relative_time;
 pub mod time_zone;
 pub mod zoned_datetime;
@@ -0,0 +1,203 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Annotations of the parts of formatted dates and times.
+
+use crate::fields::FieldSymbol;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+/// The kind of a [`DateTimePart`], which is the field of the pattern that produced it,
+/// or [`Literal`](DateTimePartKind::Literal) for the text between fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimePartKind {
+    /// Text that is not a field, such as separators and spaces.
+    Literal,
+    /// The era, such as "AD".
+    Era,
+    /// The year, or the week-based year.
+    Year,
+    /// The quarter, such as "Q3".
+    Quarter,
+    /// The month, such as "September" or "09".
+    Month,
+    /// The week of the year or of the month.
+    Week,
+    /// The day of the month or of the year, or the modified Julian day.
+    Day,
+    /// The day of the week, such as "Tuesday".
+    Weekday,
+    /// The day period, such as "AM" or "in the afternoon".
+    DayPeriod,
+    /// The hour.
+    Hour,
+    /// The minute.
+    Minute,
+    /// The second.
+    Second,
+    /// The time zone, such as "GMT+2".
+    TimeZone,
+}
+
+impl From<FieldSymbol> for DateTimePartKind {
+    fn from(symbol: FieldSymbol) -> Self {
+        match symbol {
+            FieldSymbol::Era => Self::Era,
+            FieldSymbol::Year(_) => Self::Year,
+            FieldSymbol::Quarter(_) => Self::Quarter,
+            FieldSymbol::Month(_) => Self::Month,
+            FieldSymbol::Week(_) => Self::Week,
+            FieldSymbol::Day(_) => Self::Day,
+            FieldSymbol::Weekday(_) => Self::Weekday,
+            FieldSymbol::DayPeriod(_) => Self::DayPeriod,
+            FieldSymbol::Hour(_) => Self::Hour,
+            FieldSymbol::Minute => Self::Minute,
+            FieldSymbol::Second(_) => Self::Second,
+            FieldSymbol::TimeZone(_) => Self::TimeZone,
+        }
+    }
+}
+
+/// A part of a formatted date or time, such as the name of the month.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::{DateTimeFormat, DateTimeFormatOptions, DateTimePart, DateTimePartKind};
+/// use icu::datetime::mock::datetime::MockDateTime;
+/// use icu::datetime::options::length;
+/// use icu::locid::Locale;
+/// use icu::locid::macros::langid;
+///
+/// let provider = icu_testdata::get_provider();
+/// let locale: Locale = langid!("en").into();
+/// let options = DateTimeFormatOptions::Length(length::Bag {
+///     date: Some(length::Date::Medium),
+///     time: None,
+///     ..Default::default()
+/// });
+/// let dtf = DateTimeFormat::try_new(locale, &provider, &options)
+///     .expect("Failed to create DateTimeFormat instance.");
+///
+/// let datetime = MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+///     .expect("Failed to construct DateTime.");
+///
+/// let mut output = String::new();
+/// let parts = dtf
+///     .format(&datetime)
+///     .write_to_parts(&mut output)
+///     .expect("Failed to format the date.");
+///
+/// assert_eq!(output, "Sep 1, 2020");
+/// assert_eq!(
+///     parts[0],
+///     DateTimePart {
+///         kind: DateTimePartKind::Month,
+///         range: 0..3,
+///     }
+/// );
+/// assert_eq!(&output[parts[0].range.clone()], "Sep");
+/// assert_eq!(parts[4].kind, DateTimePartKind::Year);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTimePart {
+    /// The kind of the part.
+    pub kind: DateTimePartKind,
+    /// The byte range of the part in the formatted output.
+    pub range: Range<usize>,
+}
+
+/// A [`fmt::Write`] which records the byte range of each part written to the sink.
+pub(crate) struct PartsWriter<'a, W: fmt::Write + ?Sized> {
+    sink: &'a mut W,
+    len: usize,
+    pub(crate) parts: Vec<DateTimePart>,
+}
+
+impl<'a, W: fmt::Write + ?Sized> PartsWriter<'a, W> {
+    pub(crate) fn new(sink: &'a mut W) -> Self {
+        Self {
+            sink,
+            len: 0,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Records everything written by `write` as a part of the given `kind`.
+    ///
+    /// Adjacent literals are merged, and empty parts are dropped.
+    pub(crate) fn write_part<E>(
+        &mut self,
+        kind: DateTimePartKind,
+        write: impl FnOnce(&mut Self) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let start = self.len;
+        write(self)?;
+        let end = self.len;
+        if start == end {
+            return Ok(());
+        }
+        match self.parts.last_mut() {
+            Some(last) if kind == DateTimePartKind::Literal && last.kind == kind => {
+                last.range.end = end;
+            }
+            _ => self.parts.push(DateTimePart {
+                kind,
+                range: start..end,
+            }),
+        }
+        Ok(())
+    }
+}
+
+impl<'a, W: fmt::Write + ?Sized> fmt::Write for PartsWriter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.sink.write_str(s)?;
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn test_merge_literals() {
+        let mut output = String::new();
+        let mut writer = PartsWriter::new(&mut output);
+        let items = [
+            (DateTimePartKind::Literal, "("),
+            (DateTimePartKind::Hour, "12"),
+            (DateTimePartKind::Literal, ":"),
+            (DateTimePartKind::Literal, ""),
+            (DateTimePartKind::Minute, ""),
+            (DateTimePartKind::Literal, ")"),
+        ];
+        for (kind, s) in items.iter() {
+            writer.write_part(*kind, |w| w.write_str(s)).unwrap();
+        }
+        let parts = writer.parts;
+        assert_eq!(output, "(12:)");
+        assert_eq!(
+            parts,
+            [
+                DateTimePart {
+                    kind: DateTimePartKind::Literal,
+                    range: 0..1,
+                },
+                DateTimePart {
+                    kind: DateTimePartKind::Hour,
+                    range: 1..3,
+                },
+                DateTimePart {
+                    kind: DateTimePartKind::Literal,
+                    range: 3..5,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::FixedDecimalFormat;
+use writeable::{LengthHint, Writeable};
+
+/// The placeholder for the number in the relative time patterns.
+const PLACEHOLDER: &str = "{0}";
+
+/// [`FormattedRelativeTime`] is a intermediate structure which can be retrieved as
+/// an output from [`RelativeTimeFormat`](crate::RelativeTimeFormat).
+///
+/// It consists of the pattern selected for the value, and the absolute value to be formatted
+/// in its placeholder. Values which have a name of their own, such as "yesterday", have no
+/// number to format.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::Locale;
+/// use icu::locid::macros::langid;
+/// use icu::datetime::RelativeTimeFormat;
+/// use icu::datetime::options::relative_time;
+/// use icu::datetime::relative_time::Unit;
+/// use fixed_decimal::FixedDecimal;
+/// let locale: Locale = langid!("en").into();
+/// # let provider = icu_testdata::get_provider();
+/// # let options = relative_time::Bag::default();
+/// let rtf = RelativeTimeFormat::try_new(locale, &provider, &options)
+///     .expect("Failed to create RelativeTimeFormat instance.");
+///
+/// let formatted_relative_time = rtf.format(FixedDecimal::from(5), Unit::Minute);
+///
+/// let _ = format!("Relative time: {}", formatted_relative_time);
+/// ```
+pub struct FormattedRelativeTime<'l, 'data> {
+    pub(crate) pattern: &'l str,
+    pub(crate) value: Option<FixedDecimal>,
+    pub(crate) fixed_decimal_format: &'l FixedDecimalFormat<'data>,
+}
+
+impl<'l, 'data> Writeable for FormattedRelativeTime<'l, 'data> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match (&self.value, self.pattern.split_once(PLACEHOLDER)) {
+            (Some(value), Some((prefix, suffix))) => {
+                sink.write_str(prefix)?;
+                self.fixed_decimal_format.format(value).write_to(sink)?;
+                sink.write_str(suffix)
+            }
+            _ => sink.write_str(self.pattern),
+        }
+    }
+
+    fn write_len(&self) -> LengthHint {
+        match (&self.value, self.pattern.split_once(PLACEHOLDER)) {
+            (Some(value), Some(_)) => {
+                LengthHint::Exact(self.pattern.len() - PLACEHOLDER.len())
+                    + self.fixed_decimal_format.format(value).write_len()
+            }
+            _ => LengthHint::Exact(self.pattern.len()),
+        }
+    }
+}
+
+impl<'l, 'data> fmt::Display for FormattedRelativeTime<'l, 'data> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
@@ -6,43 +6,68 @@ use core::fmt;
 
 use crate::error::DateTimeFormatError as Error;
 use crate::fields::{self, FieldSymbol};
-use crate::pattern::{Error as PatternError, PatternItem};
+use crate::options::time_zone::Format;
+use crate::pattern::PatternItem;
 use crate::{
     date::TimeZoneInput,
-    time_zone::{IsoFormat, IsoMinutes, IsoSeconds, TimeZoneFormat},
+    time_zone::{self, TimeZoneFormat, TimeZoneFormatKind},
 };
 use writeable::Writeable;
 
-pub struct FormattedTimeZone<'l, T>
+/// [`FormattedTimeZone`] is a intermediate structure which can be retrieved as
+/// an output from [`TimeZoneFormat`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::Locale;
+/// use icu::locid::macros::langid;
+/// use icu::datetime::TimeZoneFormat;
+/// use icu::datetime::options::time_zone;
+/// use icu::datetime::mock::time_zone::MockTimeZone;
+/// let locale: Locale = langid!("en").into();
+/// # let provider = icu_testdata::get_provider();
+/// # let options = time_zone::Bag::default();
+/// let tzf = TimeZoneFormat::try_new(locale, &provider, &options)
+///     .expect("Failed to create TimeZoneFormat");
+///
+/// let time_zone: MockTimeZone = "+01:00".parse().expect("Failed to parse time zone.");
+///
+/// let formatted_time_zone = tzf.format(&time_zone);
+///
+/// let _ = format!("Time Zone: {}", formatted_time_zone);
+/// ```
+pub struct FormattedTimeZone<'l, 'data, T>
 where
     T: TimeZoneInput,
 {
-    pub(crate) time_zone_format: &'l TimeZoneFormat<'l>,
+    pub(crate) time_zone_format: &'l TimeZoneFormat<'data>,
     pub(crate) time_zone: &'l T,
 }
 
-impl<'l, T> Writeable for FormattedTimeZone<'l, T>
+impl<'l, 'data, T> Writeable for FormattedTimeZone<'l, 'data, T>
 where
     T: TimeZoneInput,
 {
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
-        write_pattern(self.time_zone_format, self.time_zone, sink).map_err(|_| core::fmt::Error)
+        write_time_zone(self.time_zone_format, self.time_zone, sink).map_err(|_| core::fmt::Error)
     }
 
     // TODO(#489): Implement write_len
 }
 
-impl<'l, T> fmt::Display for FormattedTimeZone<'l, T>
+impl<'l, 'data, T> fmt::Display for FormattedTimeZone<'l, 'data, T>
 where
     T: TimeZoneInput,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(self.time_zone_format, self.time_zone, f).map_err(|_| core::fmt::Error)
+        write_time_zone(self.time_zone_format, self.time_zone, f).map_err(|_| core::fmt::Error)
     }
 }
 
-// TODO(#622) Make this public once TimeZoneFormat is public.
-pub(crate) fn write_pattern<T, W>(
+/// Writes the time zone with the format of the [`TimeZoneFormat`], or with the time zone
+/// fields of its pattern.
+pub(crate) fn write_time_zone<T, W>(
     time_zone_format: &TimeZoneFormat,
     time_zone: &T,
     w: &mut W,
@@ -51,14 +76,20 @@ where
     T: TimeZoneInput,
     W: fmt::Write + ?Sized,
 {
-    let pattern = &time_zone_format.pattern;
-    for item in pattern.items() {
-        match item {
-            PatternItem::Field(field) => write_field(field, time_zone_format, time_zone, w)?,
-            PatternItem::Literal(l) => w.write_str(l)?,
+    match &time_zone_format.kind {
+        TimeZoneFormatKind::Pattern(pattern) => {
+            for item in pattern.items() {
+                match item {
+                    PatternItem::Field(field) => {
+                        write_field(field, time_zone_format, time_zone, w)?
+                    }
+                    PatternItem::Literal(l) => w.write_str(l)?,
+                }
+            }
+            Ok(())
         }
+        TimeZoneFormatKind::Format(format) => write_format(*format, time_zone_format, time_zone, w),
     }
-    Ok(())
 }
 
 /// Write fields according to the UTS-35 specification.
@@ -74,165 +105,55 @@ where
     W: fmt::Write + ?Sized,
 {
     if let FieldSymbol::TimeZone(zone_symbol) = field.symbol {
-        match zone_symbol {
-            fields::TimeZone::LowerZ => match u8::from(field.length) {
-                1..=3 => time_zone_format
-                    .short_specific_non_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
-                4 => time_zone_format
-                    .long_specific_non_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
-                _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
-                }
-            },
-            fields::TimeZone::UpperZ => match u8::from(field.length) {
-                1..=3 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::Basic,
-                    IsoMinutes::Required,
-                    IsoSeconds::Optional,
-                )?,
-                4 => time_zone_format.localized_gmt_format(w, time_zone)?,
-                5 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::UtcExtended,
-                    IsoMinutes::Required,
-                    IsoSeconds::Optional,
-                )?,
-                _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
-                }
-            },
-            fields::TimeZone::UpperO => match u8::from(field.length) {
-                1..=4 => time_zone_format.localized_gmt_format(w, time_zone)?,
-                _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
-                }
-            },
-            fields::TimeZone::LowerV => match u8::from(field.length) {
-                1 => time_zone_format
-                    .short_generic_non_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.generic_location_format(w, time_zone))
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
-                4 => time_zone_format
-                    .long_generic_non_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.generic_location_format(w, time_zone))
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
-                _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
-                }
-            },
-            fields::TimeZone::UpperV => match u8::from(field.length) {
-                1 => todo!("#606 (BCP-47 identifiers)"),
-                2 => todo!("#606 (BCP-47 identifiers)"),
-                3 => time_zone_format
-                    .exemplar_city(w, time_zone)
-                    .or_else(|_| time_zone_format.unknown_city(w))?,
-                4 => time_zone_format
-                    .generic_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
-                _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
-                }
-            },
-            fields::TimeZone::LowerX => match u8::from(field.length) {
-                1 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::UtcBasic,
-                    IsoMinutes::Optional,
-                    IsoSeconds::Never,
-                )?,
-                2 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::UtcBasic,
-                    IsoMinutes::Required,
-                    IsoSeconds::Never,
-                )?,
-                3 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::UtcExtended,
-                    IsoMinutes::Required,
-                    IsoSeconds::Never,
-                )?,
-                4 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::UtcBasic,
-                    IsoMinutes::Required,
-                    IsoSeconds::Optional,
-                )?,
-                5 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::UtcExtended,
-                    IsoMinutes::Required,
-                    IsoSeconds::Optional,
-                )?,
-                _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
-                }
-            },
-            fields::TimeZone::UpperX => match u8::from(field.length) {
-                1 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::Basic,
-                    IsoMinutes::Optional,
-                    IsoSeconds::Never,
-                )?,
-                2 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::Basic,
-                    IsoMinutes::Required,
-                    IsoSeconds::Never,
-                )?,
-                3 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::Extended,
-                    IsoMinutes::Required,
-                    IsoSeconds::Never,
-                )?,
-                4 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::Basic,
-                    IsoMinutes::Required,
-                    IsoSeconds::Optional,
-                )?,
-                5 => time_zone_format.iso8601_format(
-                    w,
-                    time_zone,
-                    IsoFormat::Extended,
-                    IsoMinutes::Required,
-                    IsoSeconds::Optional,
-                )?,
-                _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
-                }
-            },
+        match time_zone::format_for_field(zone_symbol, u8::from(field.length))? {
+            Some(format) => write_format(format, time_zone_format, time_zone, w)?,
+            // The BCP-47 identifier is "unk" if the time zone is unknown.
+            None => w.write_str(
+                time_zone
+                    .time_zone_id()
+                    .map_or("unk", |time_zone_id| time_zone_id.as_str()),
+            )?,
         }
     }
     Ok(())
 }
+
+/// Writes the time zone in the given format, falling back to other formats in the order
+/// specified by UTS-35 if the time zone does not have the data required by the format.
+fn write_format<T, W>(
+    format: Format,
+    time_zone_format: &TimeZoneFormat,
+    time_zone: &T,
+    w: &mut W,
+) -> Result<(), Error>
+where
+    T: TimeZoneInput,
+    W: fmt::Write + ?Sized,
+{
+    match format {
+        Format::GenericNonLocationLong => time_zone_format
+            .long_generic_non_location_format(w, time_zone)
+            .or_else(|_| time_zone_format.generic_location_format(w, time_zone))
+            .or_else(|_| time_zone_format.fallback_format(w, time_zone)),
+        Format::GenericNonLocationShort => time_zone_format
+            .short_generic_non_location_format(w, time_zone)
+            .or_else(|_| time_zone_format.generic_location_format(w, time_zone))
+            .or_else(|_| time_zone_format.fallback_format(w, time_zone)),
+        Format::GenericLocation => time_zone_format
+            .generic_location_format(w, time_zone)
+            .or_else(|_| time_zone_format.fallback_format(w, time_zone)),
+        Format::SpecificNonLocationLong => time_zone_format
+            .long_specific_non_location_format(w, time_zone)
+            .or_else(|_| time_zone_format.fallback_format(w, time_zone)),
+        Format::SpecificNonLocationShort => time_zone_format
+            .short_specific_non_location_format(w, time_zone)
+            .or_else(|_| time_zone_format.fallback_format(w, time_zone)),
+        Format::ExemplarCity => time_zone_format
+            .exemplar_city(w, time_zone)
+            .or_else(|_| time_zone_format.unknown_city(w)),
+        Format::LocalizedGmt => time_zone_format.localized_gmt_format(w, time_zone),
+        Format::Iso8601(format, minutes, seconds) => {
+            time_zone_format.iso8601_format(w, time_zone, format, minutes, seconds)
+        }
+    }
+}
@@ -4,15 +4,17 @@
 
 //! A collection of code for formatting DateTimes with time zones.
 
-use crate::date::{LocalizedDateTimeInput, ZonedDateTimeInputWithLocale};
+use crate::date::{DateTimeInputInCalendar, LocalizedDateTimeInput, ZonedDateTimeInputWithLocale};
 use crate::error::DateTimeFormatError as Error;
 use crate::fields::{self, FieldSymbol};
 use crate::pattern::PatternItem;
 use crate::{date::ZonedDateTimeInput, zoned_datetime::ZonedDateTimeFormat};
-use core::fmt;
-use writeable::Writeable;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use writeable::{LengthHint, Writeable};
 
 use super::datetime;
+use super::parts::{DateTimePart, DateTimePartKind, PartsWriter};
 use super::time_zone;
 
 #[allow(missing_docs)] // TODO(#686) - Add missing docs.
@@ -24,6 +26,22 @@ where
     pub(crate) zoned_datetime: &'l T,
 }
 
+impl<'l, 'd, T> FormattedZonedDateTime<'l, 'd, T>
+where
+    T: ZonedDateTimeInput,
+{
+    /// Writes the formatted value to `sink`, and returns the [`DateTimePart`]s of the output,
+    /// which annotate the byte range of every field and literal.
+    ///
+    /// See [`DateTimePart`] for an example.
+    pub fn write_to_parts<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+    ) -> Result<Vec<DateTimePart>, Error> {
+        write_pattern_to_parts(self.zoned_datetime_format, self.zoned_datetime, sink)
+    }
+}
+
 impl<'l, 'd, T> Writeable for FormattedZonedDateTime<'l, 'd, T>
 where
     T: ZonedDateTimeInput,
@@ -33,7 +51,10 @@ where
             .map_err(|_| core::fmt::Error)
     }
 
-    // TODO(#489): Implement write_len
+    fn write_len(&self) -> LengthHint {
+        pattern_len(self.zoned_datetime_format, self.zoned_datetime)
+            .unwrap_or(LengthHint::Undefined)
+    }
 }
 
 impl<'l, 'd, T> fmt::Display for FormattedZonedDateTime<'l, 'd, T>
@@ -55,10 +76,12 @@ where
     T: ZonedDateTimeInput,
     W: fmt::Write + ?Sized,
 {
-    let locale = &zoned_datetime_format.datetime_format.locale;
-    let pattern = &zoned_datetime_format.datetime_format.pattern;
-    let loc_datetime = ZonedDateTimeInputWithLocale::new(zoned_datetime, locale);
-    for item in pattern.items() {
+    let zoned_datetime = DateTimeInputInCalendar::new(
+        zoned_datetime,
+        zoned_datetime_format.datetime_format.calendar,
+    )?;
+    let loc_datetime = localize(zoned_datetime_format, &zoned_datetime);
+    for item in zoned_datetime_format.datetime_format.pattern.items() {
         match item {
             PatternItem::Field(field) => {
                 write_field(field, zoned_datetime_format, &loc_datetime, w)?
@@ -69,6 +92,119 @@ where
     Ok(())
 }
 
+/// Writes the pattern like [`write_pattern`], and returns the [`DateTimePart`] written for each
+/// of its items.
+pub fn write_pattern_to_parts<T, W>(
+    zoned_datetime_format: &ZonedDateTimeFormat,
+    zoned_datetime: &T,
+    w: &mut W,
+) -> Result<Vec<DateTimePart>, Error>
+where
+    T: ZonedDateTimeInput,
+    W: fmt::Write + ?Sized,
+{
+    let zoned_datetime = DateTimeInputInCalendar::new(
+        zoned_datetime,
+        zoned_datetime_format.datetime_format.calendar,
+    )?;
+    let loc_datetime = localize(zoned_datetime_format, &zoned_datetime);
+    let mut w = PartsWriter::new(w);
+    for item in zoned_datetime_format.datetime_format.pattern.items() {
+        match item {
+            PatternItem::Field(field) => w.write_part(field.symbol.into(), |w| {
+                write_field(field, zoned_datetime_format, &loc_datetime, w)
+            })?,
+            PatternItem::Literal(l) => {
+                w.write_part(DateTimePartKind::Literal, |w| w.write_str(l))?
+            }
+        }
+    }
+    Ok(w.parts)
+}
+
+/// Returns the length of the output of [`write_pattern`].
+///
+/// The time zone formats fall back to one another depending on the data available for the
+/// time zone, so the lengths of time zone fields are counted by writing them.
+pub fn pattern_len<T>(
+    zoned_datetime_format: &ZonedDateTimeFormat,
+    zoned_datetime: &T,
+) -> Result<LengthHint, Error>
+where
+    T: ZonedDateTimeInput,
+{
+    let zoned_datetime = DateTimeInputInCalendar::new(
+        zoned_datetime,
+        zoned_datetime_format.datetime_format.calendar,
+    )?;
+    let loc_datetime = localize(zoned_datetime_format, &zoned_datetime);
+    let pattern = &zoned_datetime_format.datetime_format.pattern;
+    let symbols = zoned_datetime_format
+        .datetime_format
+        .symbols
+        .as_ref()
+        .map(|s| s.get());
+    pattern
+        .items()
+        .iter()
+        .map(|item| match item {
+            PatternItem::Field(field) => match field.symbol {
+                FieldSymbol::TimeZone(_) => {
+                    let mut counter = LengthCounter(0);
+                    time_zone::write_field(
+                        field,
+                        &zoned_datetime_format.time_zone_format,
+                        loc_datetime.datetime(),
+                        &mut counter,
+                    )?;
+                    Ok(LengthHint::Exact(counter.0))
+                }
+                _ => datetime::field_len(
+                    pattern,
+                    field,
+                    symbols,
+                    &zoned_datetime_format.datetime_format.fixed_decimal_format,
+                    &loc_datetime,
+                ),
+            },
+            PatternItem::Literal(l) => Ok(LengthHint::Exact(l.len())),
+        })
+        .sum()
+}
+
+/// A [`fmt::Write`] that only counts the bytes written to it.
+struct LengthCounter(usize);
+
+impl fmt::Write for LengthCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Combines `zoned_datetime`, converted to the calendar of the format, with the locale data
+/// needed to format it.
+fn localize<'l, T>(
+    zoned_datetime_format: &'l ZonedDateTimeFormat,
+    zoned_datetime: &'l DateTimeInputInCalendar<'l, T>,
+) -> ZonedDateTimeInputWithLocale<'l, DateTimeInputInCalendar<'l, T>>
+where
+    T: ZonedDateTimeInput,
+{
+    let locale = &zoned_datetime_format.datetime_format.locale;
+    let week_data = zoned_datetime_format
+        .datetime_format
+        .week_data
+        .as_ref()
+        .map(|w| w.get());
+    let day_period_rules = zoned_datetime_format
+        .datetime_format
+        .day_period_rules
+        .as_ref()
+        .map(|d| d.get());
+    ZonedDateTimeInputWithLocale::new(zoned_datetime, week_data, day_period_rules, locale)
+}
+
 fn write_field<T, W>(
     field: &fields::Field,
     zoned_datetime_format: &ZonedDateTimeFormat,
@@ -93,7 +229,14 @@ where
             loc_datetime.datetime(),
             w,
         )?,
-        _ => datetime::write_field(pattern, field, symbols, loc_datetime, w)?,
+        _ => datetime::write_field(
+            pattern,
+            field,
+            symbols,
+            &zoned_datetime_format.datetime_format.fixed_decimal_format,
+            loc_datetime,
+            w,
+        )?,
     }
     Ok(())
 }
@@ -78,27 +78,37 @@ extern crate alloc;
 
 mod arithmetic;
 pub mod date;
+pub mod date_interval;
 pub mod datetime;
 mod error;
 mod fields;
 mod format;
 pub mod mock;
 pub mod options;
+pub mod parse;
 #[doc(hidden)]
 pub mod pattern;
 pub mod provider;
+pub mod relative_time;
 #[doc(hidden)]
 pub mod skeleton;
-// TODO(#622) make the time_zone module public once TimeZoneFormat is public.
-#[allow(missing_docs)] // TODO(#686) - Add missing docs.
-pub(crate) mod time_zone;
+pub mod time_zone;
+pub mod time_zone_resolver;
 #[allow(missing_docs)] // TODO(#686) - Add missing docs.
 pub mod zoned_datetime;
 
+pub use date_interval::DateIntervalFormat;
 pub use datetime::DateTimeFormat;
 pub use error::DateTimeFormatError;
+pub use format::date_interval::FormattedDateInterval;
 pub use format::datetime::FormattedDateTime;
+pub use format::parts::{DateTimePart, DateTimePartKind};
+pub use format::relative_time::FormattedRelativeTime;
+pub use format::time_zone::FormattedTimeZone;
 pub use format::zoned_datetime::FormattedZonedDateTime;
 pub use options::DateTimeFormatOptions;
+pub use parse::{DateTimeParseError, ParsedDateTime};
+pub use relative_time::RelativeTimeFormat;
+pub use time_zone::TimeZoneFormat;
+pub use time_zone_resolver::TimeZoneResolver;
 pub use zoned_datetime::ZonedDateTimeFormat;
-// TODO(#622) re-export TimeZoneFormat once it is made public.
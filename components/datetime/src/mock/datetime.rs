@@ -6,7 +6,7 @@ use crate::arithmetic;
 use crate::date::*;
 use core::convert::TryInto;
 use core::str::FromStr;
-use tinystr::tinystr8;
+use icu_calendar::AnyCalendarKind;
 
 /// A temporary struct that implements [`DateTimeInput`]
 /// and is used in tests, benchmarks and examples of this component.
@@ -137,15 +137,17 @@ impl FromStr for MockDateTime {
 
 impl DateInput for MockDateTime {
     fn year(&self) -> Option<Year> {
-        Some(arithmetic::iso_year_to_gregorian(self.year))
+        Some(icu_calendar::gregorian::year_as_gregorian(self.year))
     }
 
     fn month(&self) -> Option<Month> {
-        Some(Month {
-            number: self.month + 1,
-            // TODO(#486): Implement month codes
-            code: MonthCode(tinystr8!("TODO")),
-        })
+        icu_calendar::Date::new_gregorian_date_from_integers(
+            (self.day + 1).try_into().ok()?,
+            (self.month + 1).try_into().ok()?,
+            self.year,
+        )
+        .ok()
+        .map(|date| date.month())
     }
 
     fn day_of_month(&self) -> Option<DayOfMonth> {
@@ -161,7 +163,27 @@ impl DateInput for MockDateTime {
     }
 
     fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
-        unimplemented!()
+        icu_calendar::Date::new_gregorian_date_from_integers(
+            (self.day + 1).try_into().ok()?,
+            (self.month + 1).try_into().ok()?,
+            self.year,
+        )
+        .ok()
+        .map(|date| date.day_of_year_info())
+    }
+
+    fn fixed_day(&self) -> Option<i64> {
+        icu_calendar::Date::new_iso_date_from_integers(
+            (self.day + 1).try_into().ok()?,
+            (self.month + 1).try_into().ok()?,
+            self.year,
+        )
+        .ok()
+        .map(|date| date.to_fixed())
+    }
+
+    fn calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Gregorian)
     }
 }
 
@@ -8,12 +8,11 @@ use tinystr::TinyStr8;
 use crate::date::*;
 use core::str::FromStr;
 
-// TODO(#622) link [`TimeZoneFormat`] appropriately once it is public.
 /// A temporary struct that implements [`TimeZoneInput`]
 /// and is used in tests, benchmarks and examples of this component.
 ///
 /// *Notice:* Rust at the moment does not have a canonical way to represent time zones. We are introducing
-/// [`MockTimeZone`] as an example of the data necessary for ICU `TimeZoneFormat` to work, and
+/// [`MockTimeZone`] as an example of the data necessary for ICU [`TimeZoneFormat`](crate::TimeZoneFormat) to work, and
 /// [we hope to work with the community](https://github.com/unicode-org/icu4x/blob/main/docs/research/datetime.md)
 /// to develop core date and time APIs that will work as an input for this component.
 ///
@@ -37,9 +36,8 @@ use core::str::FromStr;
 pub struct MockTimeZone {
     /// The GMT offset in seconds.
     pub gmt_offset: GmtOffset,
-    /// The IANA time-zone identifier
-    // TODO(#606) change this to BCP-47 identifier
-    pub time_zone_id: Option<String>,
+    /// The BCP-47 time-zone identifier
+    pub time_zone_id: Option<TimeZoneBcp47Id>,
     /// The CLDR metazone identifier
     // TODO(#528) change this to <TBD> identifier
     pub metazone_id: Option<String>,
@@ -53,7 +51,7 @@ impl MockTimeZone {
     /// The other arguments optionally allow access to more robust formats.
     pub const fn new(
         gmt_offset: GmtOffset,
-        time_zone_id: Option<String>,
+        time_zone_id: Option<TimeZoneBcp47Id>,
         metazone_id: Option<String>,
         time_variant: Option<TinyStr8>,
     ) -> Self {
@@ -106,8 +104,8 @@ impl TimeZoneInput for MockTimeZone {
         self.gmt_offset
     }
 
-    fn time_zone_id(&self) -> Option<&str> {
-        self.time_zone_id.as_ref().map(AsRef::as_ref)
+    fn time_zone_id(&self) -> Option<&TimeZoneBcp47Id> {
+        self.time_zone_id.as_ref()
     }
 
     fn metazone_id(&self) -> Option<&str> {
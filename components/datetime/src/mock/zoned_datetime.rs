@@ -6,6 +6,7 @@ use tinystr::TinyStr8;
 
 use crate::date::*;
 use core::str::FromStr;
+use icu_calendar::AnyCalendarKind;
 
 use super::{datetime::MockDateTime, time_zone::MockTimeZone};
 
@@ -110,6 +111,14 @@ impl DateInput for MockZonedDateTime {
     fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
         self.datetime.day_of_year_info()
     }
+
+    fn fixed_day(&self) -> Option<i64> {
+        self.datetime.fixed_day()
+    }
+
+    fn calendar_kind(&self) -> Option<AnyCalendarKind> {
+        self.datetime.calendar_kind()
+    }
 }
 
 impl IsoTimeInput for MockZonedDateTime {
@@ -135,7 +144,7 @@ impl TimeZoneInput for MockZonedDateTime {
         self.time_zone.gmt_offset()
     }
 
-    fn time_zone_id(&self) -> Option<&str> {
+    fn time_zone_id(&self) -> Option<&TimeZoneBcp47Id> {
         self.time_zone.time_zone_id()
     }
 
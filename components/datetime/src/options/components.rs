@@ -88,8 +88,12 @@ pub struct Bag {
     pub era: Option<Text>,
     /// Include the year, such as "1970" or "70".
     pub year: Option<Numeric>,
+    /// Include the quarter, such as "2nd quarter" or "Q2".
+    pub quarter: Option<Quarter>,
     /// Include the month, such as "April" or "Apr".
     pub month: Option<Month>,
+    /// Include the week number, such as "1st" or "1".
+    pub week: Option<Week>,
     /// Include the day, such as "07" or "7".
     pub day: Option<Numeric>,
     /// Include the weekday, such as "Wednesday" or "Wed".
@@ -115,8 +119,20 @@ impl Bag {
     /// the UTS 35 table - https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
     pub(crate) fn to_vec_fields(&self) -> Vec<Field> {
         let mut fields = Vec::new();
-        if let Some(_era) = self.era {
-            unimplemented!("FieldSymbol::Era is needed. See issue #486.")
+        if let Some(era) = self.era {
+            fields.push(Field {
+                symbol: FieldSymbol::Era,
+                length: match era {
+                    // Era name, format length.
+                    //
+                    // G..GGG   AD           Abbreviated
+                    // GGGG     Anno Domini  Wide
+                    // GGGGG    A            Narrow
+                    Text::Short => FieldLength::One,
+                    Text::Long => FieldLength::Wide,
+                    Text::Narrow => FieldLength::Narrow,
+                },
+            })
         }
 
         if let Some(year) = self.year {
@@ -140,9 +156,24 @@ impl Bag {
             });
         }
 
-        // TODO(#501) - Unimplemented quarter fields:
-        // Q - Quarter number/name
-        // q - Stand-alone quarter
+        if let Some(quarter) = self.quarter {
+            fields.push(Field {
+                // Always choose Quarter::Format as Quarter::StandAlone is not used in skeletons.
+                symbol: FieldSymbol::Quarter(fields::Quarter::Format),
+                length: match quarter {
+                    // Q      2          Numeric: 1 digit
+                    // QQ     02         Numeric: 2 digits + zero pad
+                    // QQQ    Q2         Abbreviated
+                    // QQQQ   2nd quarter Wide
+                    // QQQQQ  2          Narrow
+                    Quarter::Numeric => FieldLength::One,
+                    Quarter::TwoDigit => FieldLength::TwoDigit,
+                    Quarter::Short => FieldLength::Abbreviated,
+                    Quarter::Long => FieldLength::Wide,
+                    Quarter::Narrow => FieldLength::Narrow,
+                },
+            });
+        }
 
         if let Some(month) = self.month {
             fields.push(Field {
@@ -164,12 +195,24 @@ impl Bag {
             });
         }
 
-        // TODO(#502) - Unimplemented week fields:
-        // w - Week of year
-        // W - Week of month
+        if let Some(week) = self.week {
+            fields.push(Field {
+                symbol: FieldSymbol::Week(match week {
+                    Week::WeekOfMonth => fields::Week::WeekOfMonth,
+                    Week::NumericWeekOfYear | Week::TwoDigitWeekOfYear => fields::Week::WeekOfYear,
+                }),
+                length: match week {
+                    // W      1        Numeric: 1 digit
+                    // w      8, 27    Numeric: minimum digits
+                    // ww     08, 27   Numeric: 2 digits, zero pad if needed
+                    Week::WeekOfMonth | Week::NumericWeekOfYear => FieldLength::One,
+                    Week::TwoDigitWeekOfYear => FieldLength::TwoDigit,
+                },
+            });
+        }
 
         if let Some(day) = self.day {
-            // TODO(#591,#592) Unimplemented day fields:
+            // The other day fields are not included in skeletons:
             // D - Day of year
             // F - Day of week in month
             // g - Modified Julian day.
@@ -186,7 +229,7 @@ impl Bag {
         }
 
         if let Some(weekday) = self.weekday {
-            // TODO(#593) Unimplemented fields
+            // The local day of week fields are not included in skeletons:
             // e - Local day of week.
             // c - Stand-alone local day of week.
             fields.push(Field {
@@ -208,7 +251,7 @@ impl Bag {
         // The period fields are not included in skeletons:
         // a - AM, PM
         // b - am, pm, noon, midnight
-        // c - flexible day periods
+        // B - flexible day periods
 
         if let Some(hour) = self.hour {
             // fields::Hour::H11
@@ -301,7 +344,9 @@ impl Default for Bag {
         Self {
             era: None,
             year: None,
+            quarter: None,
             month: None,
+            week: None,
             day: None,
             weekday: None,
 
@@ -344,6 +389,27 @@ pub enum Text {
     Narrow,
 }
 
+/// Options for displaying a Quarter for the `components::`[`Bag`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Quarter {
+    /// The numeric value of the quarter, such as "2".
+    #[cfg_attr(feature = "serde", serde(rename = "numeric"))]
+    Numeric,
+    /// The two-digit value of the quarter, such as "02".
+    #[cfg_attr(feature = "serde", serde(rename = "two-digit"))]
+    TwoDigit,
+    /// The long value of the quarter, such as "2nd quarter".
+    #[cfg_attr(feature = "serde", serde(rename = "long"))]
+    Long,
+    /// The short value of the quarter, such as "Q2".
+    #[cfg_attr(feature = "serde", serde(rename = "short"))]
+    Short,
+    /// The narrow value of the quarter, such as "2".
+    #[cfg_attr(feature = "serde", serde(rename = "narrow"))]
+    Narrow,
+}
+
 /// Options for displaying a Month for the `components::`[`Bag`].
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -365,6 +431,23 @@ pub enum Month {
     Narrow,
 }
 
+/// Options for displaying the current week number for the `components::`[`Bag`].
+///
+/// Week numbers are relative to either a month or year, e.g. 'week 3 of January' or 'week 40 of 2000'.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Week {
+    /// The week of the month, such as the "3" in "week 3 of January".
+    #[cfg_attr(feature = "serde", serde(rename = "week-of-month"))]
+    WeekOfMonth,
+    /// The numeric value of the week of the year, such as the "8" in "week 8 of 2000".
+    #[cfg_attr(feature = "serde", serde(rename = "numeric-week-of-year"))]
+    NumericWeekOfYear,
+    /// The two-digit value of the week of the year, such as the "08" in "2000-W08".
+    #[cfg_attr(feature = "serde", serde(rename = "two-digit-week-of-year"))]
+    TwoDigitWeekOfYear,
+}
+
 // Each enum variant is documented with the UTS 35 field information from:
 // https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
 //
@@ -493,4 +576,26 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_component_bag_to_vec_field_quarter_week() {
+        let bag = Bag {
+            year: Some(Numeric::Numeric),
+            quarter: Some(Quarter::Short),
+            week: Some(Week::TwoDigitWeekOfYear),
+            ..Default::default()
+        };
+        assert_eq!(
+            bag.to_vec_fields(),
+            vec![
+                (Symbol::Year(fields::Year::Calendar), Length::One).into(),
+                (
+                    Symbol::Quarter(fields::Quarter::Format),
+                    Length::Abbreviated
+                )
+                    .into(),
+                (Symbol::Week(fields::Week::WeekOfYear), Length::TwoDigit).into(),
+            ]
+        );
+    }
 }
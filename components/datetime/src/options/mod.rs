@@ -32,6 +32,8 @@
 pub mod components;
 pub mod length;
 pub mod preferences;
+pub mod relative_time;
+pub mod time_zone;
 /// A bag of options which, together with [`Locale`](icu_locid::Locale), defines how
 /// dates will be formatted with a [`DateTimeFormat`](crate::DateTimeFormat) instance.
 ///
@@ -55,7 +57,7 @@ pub mod preferences;
 ///
 /// At the moment only the [`length::Bag`] works, and we plan to extend that to support
 /// `ECMA402` like components bag later.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DateTimeFormatOptions {
     /// Bag of lengths for date and time.
     Length(length::Bag),
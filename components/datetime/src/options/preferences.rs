@@ -24,7 +24,23 @@
 //!     hour_cycle: Some(preferences::HourCycle::H23)
 //! };
 //! ```
+//!
+//! The hour cycle is read from the `-u-hc` keyword of the locale when the bag does not set one.
+//!
+//! ```
+//! use icu::datetime::options::preferences;
+//! use icu::locid::Locale;
+//!
+//! let locale: Locale = "en-US-u-hc-h23".parse().unwrap();
+//!
+//! assert_eq!(
+//!     preferences::HourCycle::from_locale(&locale),
+//!     Some(preferences::HourCycle::H23)
+//! );
+//! ```
 use crate::fields;
+use alloc::string::ToString;
+use icu_locid::{extensions::unicode::Key, Locale};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -114,4 +130,19 @@ impl HourCycle {
             Self::H24 => fields::Hour::H24,
         }
     }
+
+    /// Reads the hour cycle selected with the `-u-hc` keyword of the locale.
+    ///
+    /// Returns `None` if there is no such keyword, or if its value is not a known hour cycle.
+    pub fn from_locale(locale: &Locale) -> Option<Self> {
+        let key = Key::from_bytes(b"hc").ok()?;
+        let value = locale.get_unicode_extension(&key)?;
+        Some(match value.to_string().as_str() {
+            "h11" => Self::H11,
+            "h12" => Self::H12,
+            "h23" => Self::H23,
+            "h24" => Self::H24,
+            _ => return None,
+        })
+    }
 }
@@ -0,0 +1,108 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for formatting a time relative to now with a [`RelativeTimeFormat`].
+//!
+//! The options model those of `Intl.RelativeTimeFormat` in `ECMA402`.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::datetime::options::relative_time;
+//!
+//! let options = relative_time::Bag {
+//!     width: relative_time::Width::Short,
+//!     numeric: relative_time::Numeric::Auto,
+//! };
+//! ```
+//!
+//! [`RelativeTimeFormat`]: crate::RelativeTimeFormat
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A bag of options which defines how a time relative to now will be formatted with a
+/// [`RelativeTimeFormat`](crate::RelativeTimeFormat) instance.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::options::relative_time;
+///
+/// let options = relative_time::Bag {
+///     numeric: relative_time::Numeric::Auto,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bag {
+    /// The width of the unit names, e.g. "in 3 hours" or "in 3 hr.".
+    pub width: Width,
+    /// Whether to use words such as "yesterday" for some of the values.
+    pub numeric: Numeric,
+}
+
+/// The width of the unit names used in the formatted output.
+///
+/// The available widths correspond to the `-short` and `-narrow` variants of the fields
+/// in CLDR dateFields, and to the `style` option of `Intl.RelativeTimeFormat`.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::options::relative_time;
+///
+/// let width = relative_time::Width::Short;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Width {
+    /// The full names of the units.
+    ///
+    /// e.g. "in 3 hours"
+    Long,
+    /// Abbreviated names of the units.
+    ///
+    /// e.g. "in 3 hr."
+    Short,
+    /// The shortest names of the units, which may be the same as [`Width::Short`].
+    ///
+    /// e.g. "in 3 hr."
+    Narrow,
+}
+
+impl Default for Width {
+    fn default() -> Self {
+        Self::Long
+    }
+}
+
+/// Whether the value is always formatted as a number.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::options::relative_time;
+///
+/// let numeric = relative_time::Numeric::Auto;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Numeric {
+    /// The value is always formatted as a number.
+    ///
+    /// e.g. "in 1 day"
+    Always,
+    /// Values which have a name of their own are formatted with that name.
+    ///
+    /// e.g. "tomorrow"
+    Auto,
+}
+
+impl Default for Numeric {
+    fn default() -> Self {
+        Self::Always
+    }
+}
@@ -0,0 +1,193 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for formatting a time zone on its own with a [`TimeZoneFormat`].
+//!
+//! The [`Format`] selects one of the time zone formats of [`UTS #35: Unicode LDML 4. Dates`],
+//! and the [`Fallback`] selects the format that is used when the time zone does not have the
+//! data required by the selected format, e.g. a name for its metazone.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::datetime::options::time_zone;
+//!
+//! let options = time_zone::Bag {
+//!     format: time_zone::Format::GenericNonLocationLong,
+//!     fallback: time_zone::Fallback::LocalizedGmt,
+//! };
+//! ```
+//!
+//! [`TimeZoneFormat`]: crate::TimeZoneFormat
+//! [`UTS #35: Unicode LDML 4. Dates`]: https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A bag of options which defines how a time zone will be formatted with a
+/// [`TimeZoneFormat`](crate::TimeZoneFormat) instance.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::options::time_zone;
+///
+/// let options = time_zone::Bag {
+///     format: time_zone::Format::SpecificNonLocationShort,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bag {
+    /// The format to try first.
+    pub format: Format,
+    /// The format to use as the last resort.
+    pub fallback: Fallback,
+}
+
+impl Default for Bag {
+    fn default() -> Self {
+        Self {
+            format: Format::GenericNonLocationLong,
+            fallback: Fallback::LocalizedGmt,
+        }
+    }
+}
+
+/// The time zone formats defined by UTS #35.
+///
+/// Each of the non-location formats and location formats falls back to other formats when
+/// the time zone does not have the data it requires, in the order defined by UTS #35:
+///
+/// - The generic non-location formats fall back to [`Format::GenericLocation`].
+/// - [`Format::GenericLocation`] and the specific non-location formats fall back to the
+///   [`Fallback`] of the [`Bag`].
+/// - [`Format::ExemplarCity`] falls back to the localized name of an unknown city.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::options::time_zone;
+///
+/// let format = time_zone::Format::GenericLocation;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Format {
+    /// The long generic non-location format, which is the same as the `vvvv` pattern.
+    ///
+    /// e.g. Pacific Time
+    GenericNonLocationLong,
+    /// The short generic non-location format, which is the same as the `v` pattern.
+    ///
+    /// e.g. PT
+    GenericNonLocationShort,
+    /// The generic location format, which is the same as the `VVVV` pattern.
+    ///
+    /// e.g. Los Angeles Time
+    GenericLocation,
+    /// The long specific non-location format, which is the same as the `zzzz` pattern.
+    ///
+    /// e.g. Pacific Daylight Time
+    SpecificNonLocationLong,
+    /// The short specific non-location format, which is the same as the `z` pattern.
+    ///
+    /// e.g. PDT
+    SpecificNonLocationShort,
+    /// The exemplar city of the time zone, which is the same as the `VVV` pattern.
+    ///
+    /// e.g. Los Angeles
+    ExemplarCity,
+    /// The localized GMT format, which is the same as the `OOOO` pattern.
+    ///
+    /// e.g. GMT-07:00
+    LocalizedGmt,
+    /// The ISO 8601 format, with the given options.
+    ///
+    /// e.g. -07:00
+    Iso8601(IsoFormat, IsoMinutes, IsoSeconds),
+}
+
+/// The format that is used when the time zone cannot be formatted with the selected
+/// [`Format`], nor with any of the formats it falls back to.
+///
+/// Both formats only require the GMT offset of the time zone, so they always succeed.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::options::time_zone;
+///
+/// let fallback = time_zone::Fallback::Iso8601(
+///     time_zone::IsoFormat::UtcExtended,
+///     time_zone::IsoMinutes::Required,
+///     time_zone::IsoSeconds::Never,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Fallback {
+    /// The localized GMT format, as specified by UTS #35.
+    ///
+    /// e.g. GMT-07:00
+    LocalizedGmt,
+    /// The ISO 8601 format, with the given options.
+    ///
+    /// e.g. -07:00
+    Iso8601(IsoFormat, IsoMinutes, IsoSeconds),
+}
+
+impl Default for Fallback {
+    fn default() -> Self {
+        Self::LocalizedGmt
+    }
+}
+
+/// Determines which ISO-8601 format should be used to format a [`GmtOffset`](crate::date::GmtOffset).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IsoFormat {
+    /// ISO-8601 Basic Format.
+    /// Formats zero-offset numerically.
+    /// e.g. +0500, +0000
+    Basic,
+
+    /// ISO-8601 Extended Format.
+    /// Formats zero-offset numerically.
+    /// e.g. +05:00, +00:00
+    Extended,
+
+    /// ISO-8601 Basic Format.
+    /// Formats zero-offset with the ISO-8601 UTC indicator: "Z"
+    /// e.g. +0500, Z
+    UtcBasic,
+
+    /// ISO-8601 Extended Format.
+    /// Formats zero-offset with the ISO-8601 UTC indicator: "Z"
+    /// e.g. +05:00, Z
+    UtcExtended,
+}
+
+/// Whether the minutes field should be optional or required in ISO-8601 format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IsoMinutes {
+    /// Minutes are always displayed.
+    Required,
+
+    /// Minutes are displayed only if they are non-zero.
+    Optional,
+}
+
+/// Whether the seconds field should be optional or excluded in ISO-8601 format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IsoSeconds {
+    /// Seconds are displayed only if they are non-zero.
+    Optional,
+
+    /// Seconds are not displayed.
+    Never,
+}
@@ -0,0 +1,632 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lenient parsing of dates and times in the format of a [`DateTimeFormat`].
+//!
+//! The input is matched against the same [`Pattern`] and symbols the [`DateTimeFormat`] uses to
+//! format, so that user-typed text in the locale of the formatter, such as "5 janv. 2021 14:03"
+//! in French, can be read back into a date and time.
+//!
+//! [`DateTimeFormat`]: crate::DateTimeFormat
+
+use crate::date::IsoWeekday;
+use crate::fields::{self, Field, FieldLength, FieldSymbol, TextOrNumeric};
+use crate::pattern::{Pattern, PatternItem};
+use crate::provider::gregory::{day_periods, DateSymbolsV1};
+use crate::provider::week_data::WeekDataV1;
+use crate::DateTimePartKind;
+use core::ops::Range;
+use displaydoc::Display;
+use fixed_decimal::FixedDecimal;
+use icu_calendar::week::WeekCalculator;
+use icu_calendar::{AnyCalendarKind, Date, Gregorian, Time};
+use icu_decimal::FixedDecimalFormat;
+use tinystr::{tinystr8, TinyStr8};
+use writeable::Writeable;
+
+/// A date and time parsed by [`DateTimeFormat::parse`](crate::DateTimeFormat::parse).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedDateTime {
+    /// The date, or `None` if the pattern has no date fields.
+    pub date: Option<Date<Gregorian>>,
+    /// The time, or `None` if the pattern has no time fields. Minutes and seconds
+    /// that are not in the pattern are zero.
+    pub time: Option<Time>,
+    /// The byte range of the input that matched the pattern, excluding leading whitespace.
+    /// Text after the end of the range was not consumed.
+    pub span: Range<usize>,
+}
+
+/// A list of possible error outcomes for [`DateTimeFormat::parse`](crate::DateTimeFormat::parse).
+#[derive(Display, Debug, Clone, PartialEq)]
+pub enum DateTimeParseError {
+    /// The input does not match the pattern at the byte `position`, where a part of the
+    /// `expected` kind should be.
+    #[displaydoc("Expected {expected:?} at byte {position}")]
+    Mismatch {
+        /// The kind of the part of the pattern that could not be matched.
+        expected: DateTimePartKind,
+        /// The byte offset in the input.
+        position: usize,
+    },
+    /// A field at the byte `position` has a value that is out of range, such as the 13th month
+    /// or the 30th of February, or that does not match the date, such as a Monday that is a
+    /// Tuesday or the second quarter in January.
+    #[displaydoc("Invalid {kind:?} at byte {position}")]
+    InvalidValue {
+        /// The kind of the field.
+        kind: DateTimePartKind,
+        /// The byte offset of the field in the input.
+        position: usize,
+    },
+    /// The pattern has some of the fields of a date or time, but is missing one that is needed
+    /// to construct it, such as the year of "MMM d".
+    #[displaydoc("Missing field: {0:?}")]
+    MissingField(DateTimePartKind),
+    /// The pattern has a field that cannot be parsed, such as the week of the year.
+    #[displaydoc("Unsupported field: {0:?}")]
+    UnsupportedField(FieldSymbol),
+    /// The formatter uses the symbols and patterns of a calendar other than the Gregorian
+    /// calendar, which cannot be parsed yet.
+    #[displaydoc("Unsupported calendar: {0:?}")]
+    UnsupportedCalendar(AnyCalendarKind),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateTimeParseError {}
+
+type Result<T> = core::result::Result<T, DateTimeParseError>;
+
+/// Whether a day period is before or after noon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Meridiem {
+    Am,
+    Pm,
+    /// The night spans midnight, so the hour decides: "10 at night" is after noon,
+    /// but "2 at night" is not.
+    Night,
+}
+
+/// The fields of the pattern, with the byte offset at which they were read.
+#[derive(Default)]
+struct ParsedFields {
+    bce: bool,
+    year: Option<(i32, usize)>,
+    month: Option<(u8, usize)>,
+    day: Option<(u8, usize)>,
+    /// The quarter, from 1 to 4, which is checked against the month.
+    quarter: Option<(u8, usize)>,
+    /// The ISO weekday, from Monday (1) to Sunday (7), which is checked against the date.
+    weekday: Option<(u8, usize)>,
+    hour: Option<(u8, fields::Hour, usize)>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    meridiem: Option<Meridiem>,
+}
+
+/// The symbols of every width of the `contexts` of a field, including the stand-alone
+/// context if `stand_alone` is set, and the narrow width, whose names such as "J" are
+/// ambiguous, only if `narrow` is set.
+macro_rules! widths {
+    ($contexts:expr, $stand_alone:expr, $narrow:expr) => {{
+        let contexts = $contexts;
+        let stand_alone = contexts.stand_alone.as_ref().filter(|_| $stand_alone);
+        let narrow = $narrow;
+        [
+            Some(&contexts.format.wide),
+            Some(&contexts.format.abbreviated),
+            contexts.format.short.as_ref(),
+            Some(&contexts.format.narrow).filter(|_| narrow),
+            stand_alone.and_then(|widths| widths.wide.as_ref()),
+            stand_alone.and_then(|widths| widths.abbreviated.as_ref()),
+            stand_alone.and_then(|widths| widths.short.as_ref()),
+            stand_alone
+                .and_then(|widths| widths.narrow.as_ref())
+                .filter(|_| narrow),
+        ]
+    }};
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    symbols: Option<&'a DateSymbolsV1>,
+    week_data: Option<&'a WeekDataV1>,
+    /// The digits of the numbering system of the locale, which are accepted as well as
+    /// ASCII digits.
+    digits: [char; 10],
+}
+
+/// Parses `input` with the items of `pattern`, see [`DateTimeFormat::parse`].
+///
+/// [`DateTimeFormat::parse`]: crate::DateTimeFormat::parse
+pub(crate) fn parse_pattern(
+    pattern: &Pattern,
+    symbols: Option<&DateSymbolsV1>,
+    week_data: Option<&WeekDataV1>,
+    fixed_decimal_format: &FixedDecimalFormat,
+    calendar: AnyCalendarKind,
+    input: &str,
+) -> Result<ParsedDateTime> {
+    // Other calendars have months and eras that don't map onto the Gregorian calendar.
+    if calendar != AnyCalendarKind::Gregorian {
+        return Err(DateTimeParseError::UnsupportedCalendar(calendar));
+    }
+    let mut parser = Parser {
+        input,
+        position: 0,
+        symbols,
+        week_data,
+        digits: locale_digits(fixed_decimal_format),
+    };
+    parser.skip_whitespace();
+    let start = parser.position;
+
+    let mut parsed = ParsedFields::default();
+    let items = pattern.items();
+    for (i, item) in items.iter().enumerate() {
+        match item {
+            PatternItem::Field(field) => {
+                // Numeric fields that abut another numeric field, as in "HHmm", can't be
+                // delimited by the end of the digits, so they are read at their width.
+                let abuts_number = match items.get(i + 1) {
+                    Some(PatternItem::Field(next)) => {
+                        next.get_length_type() == TextOrNumeric::Numeric
+                    }
+                    _ => false,
+                };
+                parser.skip_whitespace();
+                parser.parse_field(field, abuts_number, &mut parsed)?
+            }
+            PatternItem::Literal(literal) => parser.parse_literal(literal)?,
+        }
+    }
+    let span = start..parser.position;
+
+    Ok(ParsedDateTime {
+        date: parsed.date()?,
+        time: parsed.time()?,
+        span,
+    })
+}
+
+/// Formats the digits from zero to nine with the numbering system of the formatter.
+fn locale_digits(fixed_decimal_format: &FixedDecimalFormat) -> [char; 10] {
+    let mut digits = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+    for (i, digit) in digits.iter_mut().enumerate() {
+        let formatted = fixed_decimal_format
+            .format(&FixedDecimal::from(i as u8))
+            .writeable_to_string();
+        if let Some(c) = formatted.chars().next() {
+            *digit = c;
+        }
+    }
+    digits
+}
+
+/// Compares characters ignoring case, and treating all kinds of spaces as equal.
+fn chars_match(a: char, b: char) -> bool {
+    a == b || (a.is_whitespace() && b.is_whitespace()) || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Returns the byte length of the prefix of `input` that matches `symbol`, ignoring case.
+///
+/// A trailing period of an abbreviation, as in "janv.", may be omitted.
+fn match_prefix(input: &str, symbol: &str) -> Option<usize> {
+    let mut symbol_chars = symbol.char_indices();
+    let mut len = 0;
+    for c in input.chars() {
+        match symbol_chars.next() {
+            Some((_, s)) if chars_match(c, s) => len += c.len_utf8(),
+            Some((i, _)) => return symbol[i..].chars().all(|s| s == '.').then(|| len),
+            None => return Some(len),
+        }
+    }
+    symbol_chars.all(|(_, s)| s == '.').then(|| len)
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn mismatch(&self, expected: DateTimePartKind) -> DateTimeParseError {
+        DateTimeParseError::Mismatch {
+            expected,
+            position: self.position,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Matches the literal leniently: whitespace matches any amount of whitespace,
+    /// letters are compared ignoring case, and punctuation may be omitted.
+    fn parse_literal(&mut self, literal: &str) -> Result<()> {
+        for l in literal.chars() {
+            if l.is_whitespace() {
+                self.skip_whitespace();
+                continue;
+            }
+            match self.rest().chars().next() {
+                Some(c) if chars_match(c, l) => self.position += c.len_utf8(),
+                _ if !l.is_alphanumeric() => {}
+                _ => return Err(self.mismatch(DateTimePartKind::Literal)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a number of at most `max_digits` digits.
+    fn parse_number(&mut self, kind: DateTimePartKind, max_digits: usize) -> Result<u32> {
+        let mut value = 0u32;
+        let mut len = 0;
+        for c in self.rest().chars().take(max_digits) {
+            let digit = match c.to_digit(10) {
+                Some(digit) if c.is_ascii_digit() => digit,
+                _ => match self.digits.iter().position(|&d| d == c) {
+                    Some(digit) => digit as u32,
+                    None => break,
+                },
+            };
+            value = value * 10 + digit;
+            len += c.len_utf8();
+        }
+        if len == 0 {
+            return Err(self.mismatch(kind));
+        }
+        self.position += len;
+        Ok(value)
+    }
+
+    /// Reads the longest of the `symbols` that the input starts with, and returns its value.
+    fn parse_symbol<'s, T>(
+        &mut self,
+        kind: DateTimePartKind,
+        symbols: impl Iterator<Item = (T, &'s str)>,
+    ) -> Result<T> {
+        let rest = self.rest();
+        let (value, len) = symbols
+            .filter(|(_, symbol)| !symbol.is_empty())
+            .filter_map(|(value, symbol)| match_prefix(rest, symbol).map(|len| (value, len)))
+            // The first of the longest matches wins.
+            .fold(None, |best: Option<(T, usize)>, (value, len)| match best {
+                Some((_, best_len)) if best_len >= len => best,
+                _ => Some((value, len)),
+            })
+            .ok_or_else(|| self.mismatch(kind))?;
+        self.position += len;
+        Ok(value)
+    }
+
+    fn symbols(&self, field: &Field) -> Result<&'a DateSymbolsV1> {
+        self.symbols
+            .ok_or(DateTimeParseError::UnsupportedField(field.symbol))
+    }
+
+    fn week_data(&self, field: &Field) -> Result<&'a WeekDataV1> {
+        self.week_data
+            .ok_or(DateTimeParseError::UnsupportedField(field.symbol))
+    }
+
+    fn parse_field(
+        &mut self,
+        field: &Field,
+        abuts_number: bool,
+        parsed: &mut ParsedFields,
+    ) -> Result<()> {
+        let kind = DateTimePartKind::from(field.symbol);
+        let max_digits = |default: usize| {
+            if abuts_number {
+                usize::from(u8::from(field.length))
+            } else {
+                default
+            }
+        };
+        let position = self.position;
+        let in_range = |value: u32, range: Range<u32>| {
+            if range.contains(&value) {
+                Ok(value as u8)
+            } else {
+                Err(DateTimeParseError::InvalidValue { kind, position })
+            }
+        };
+        match field.symbol {
+            FieldSymbol::Era => {
+                let eras = &self.symbols(field)?.eras;
+                let era: TinyStr8 = self.parse_symbol(
+                    kind,
+                    [&eras.names, &eras.abbr, &eras.narrow]
+                        .iter()
+                        .flat_map(|symbols| symbols.0.iter())
+                        .map(|(code, symbol)| (*code, &**symbol)),
+                )?;
+                parsed.bce = era == tinystr8!("bce");
+            }
+            FieldSymbol::Year(fields::Year::Calendar) => {
+                let max_digits = max_digits(if field.length == FieldLength::TwoDigit {
+                    2
+                } else {
+                    9
+                });
+                let mut year = self.parse_number(kind, max_digits)? as i32;
+                // Two-digit years are in the century from 1950 to 2049.
+                if field.length == FieldLength::TwoDigit {
+                    year += if year < 50 { 2000 } else { 1900 };
+                }
+                parsed.year = Some((year, position));
+            }
+            FieldSymbol::Month(month) => {
+                let value = match field.get_length_type() {
+                    TextOrNumeric::Numeric => self.parse_number(kind, max_digits(2))?,
+                    TextOrNumeric::Text => {
+                        let code: TinyStr8 = self.parse_symbol(
+                            kind,
+                            widths!(
+                                &self.symbols(field)?.months,
+                                month == fields::Month::StandAlone,
+                                field.length == FieldLength::Narrow
+                            )
+                            .iter()
+                            .flatten()
+                            .flat_map(|symbols| symbols.0.iter())
+                            .map(|(code, symbol)| (*code, &**symbol)),
+                        )?;
+                        // Gregorian month codes are "M01" to "M12".
+                        code.as_str()
+                            .get(1..)
+                            .and_then(|number| number.parse().ok())
+                            .unwrap_or(0)
+                    }
+                };
+                parsed.month = Some((in_range(value, 1..13)?, position));
+            }
+            FieldSymbol::Quarter(quarter) => {
+                let value = match field.get_length_type() {
+                    TextOrNumeric::Numeric => self.parse_number(kind, max_digits(1))?,
+                    TextOrNumeric::Text => self.parse_symbol(
+                        kind,
+                        widths!(
+                            &self.symbols(field)?.quarters,
+                            quarter == fields::Quarter::StandAlone,
+                            field.length == FieldLength::Narrow
+                        )
+                        .iter()
+                        .flatten()
+                        .flat_map(|symbols| symbols.0.iter().zip(1..))
+                        .map(|(symbol, value)| (value, &**symbol)),
+                    )?,
+                };
+                parsed.quarter = Some((in_range(value, 1..5)?, position));
+            }
+            FieldSymbol::Weekday(weekday) => {
+                let iso_weekday = match field.get_length_type() {
+                    // The numeric day of the week is its position in the week of the locale.
+                    TextOrNumeric::Numeric => {
+                        let local = in_range(self.parse_number(kind, max_digits(1))?, 1..8)?;
+                        let week_calculator = WeekCalculator::from(self.week_data(field)?);
+                        (1..8)
+                            .map(IsoWeekday::from)
+                            .find(|&day| week_calculator.local_day_of_week(day) == u32::from(local))
+                            .expect("Every day is in the week")
+                    }
+                    // The names of the days of the week start with Sunday.
+                    TextOrNumeric::Text => self.parse_symbol(
+                        kind,
+                        widths!(
+                            &self.symbols(field)?.weekdays,
+                            weekday == fields::Weekday::StandAlone,
+                            field.length == FieldLength::Narrow
+                        )
+                        .iter()
+                        .flatten()
+                        .flat_map(|symbols| symbols.0.iter().enumerate())
+                        .map(|(i, symbol)| (IsoWeekday::from(i), &**symbol)),
+                    )?,
+                };
+                parsed.weekday = Some((iso_weekday as u8, position));
+            }
+            FieldSymbol::Day(fields::Day::DayOfMonth) => {
+                let day = self.parse_number(kind, max_digits(2))?;
+                parsed.day = Some((in_range(day, 1..32)?, position));
+            }
+            FieldSymbol::Hour(hour) => {
+                let value = self.parse_number(kind, max_digits(2))?;
+                let range = match hour {
+                    fields::Hour::H11 => 0..12,
+                    fields::Hour::H12 => 1..13,
+                    fields::Hour::H23 => 0..24,
+                    fields::Hour::H24 => 1..25,
+                };
+                parsed.hour = Some((in_range(value, range)?, hour, position));
+            }
+            FieldSymbol::Minute => {
+                let minute = self.parse_number(kind, max_digits(2))?;
+                parsed.minute = Some(in_range(minute, 0..60)?);
+            }
+            FieldSymbol::Second(fields::Second::Second) => {
+                let second = self.parse_number(kind, max_digits(2))?;
+                parsed.second = Some(in_range(second, 0..60)?);
+            }
+            FieldSymbol::DayPeriod(day_period) => {
+                let widths = &self.symbols(field)?.day_periods.format;
+                let meridiem = self.parse_symbol(
+                    kind,
+                    [&widths.wide, &widths.abbreviated, &widths.narrow]
+                        .iter()
+                        .flat_map(|symbols| day_period_symbols(symbols, day_period)),
+                )?;
+                parsed.meridiem = Some(meridiem);
+            }
+            symbol => return Err(DateTimeParseError::UnsupportedField(symbol)),
+        }
+        Ok(())
+    }
+}
+
+/// Returns the symbols of a day period field, with whether they are before or after noon.
+fn day_period_symbols(
+    symbols: &day_periods::SymbolsV1,
+    day_period: fields::DayPeriod,
+) -> impl Iterator<Item = (Meridiem, &str)> {
+    use Meridiem::{Am, Night, Pm};
+    let noon_midnight = day_period != fields::DayPeriod::AmPm;
+    let flexible = day_period == fields::DayPeriod::Flexible;
+    IntoIterator::into_iter([
+        (Am, Some(&symbols.am), true),
+        (Pm, Some(&symbols.pm), true),
+        (Pm, symbols.noon.as_ref(), noon_midnight),
+        (Am, symbols.midnight.as_ref(), noon_midnight),
+        (Am, symbols.morning1.as_ref(), flexible),
+        (Am, symbols.morning2.as_ref(), flexible),
+        (Pm, symbols.afternoon1.as_ref(), flexible),
+        (Pm, symbols.afternoon2.as_ref(), flexible),
+        (Pm, symbols.evening1.as_ref(), flexible),
+        (Pm, symbols.evening2.as_ref(), flexible),
+        (Night, symbols.night1.as_ref(), flexible),
+        (Night, symbols.night2.as_ref(), flexible),
+    ])
+    .filter(|(_, _, enabled)| *enabled)
+    .filter_map(|(meridiem, symbol, _)| Some((meridiem, &**symbol?)))
+}
+
+impl ParsedFields {
+    fn date(&self) -> Result<Option<Date<Gregorian>>> {
+        if self.year.is_none() && self.month.is_none() && self.day.is_none() {
+            return Ok(None);
+        }
+        let (year, _) = self
+            .year
+            .ok_or(DateTimeParseError::MissingField(DateTimePartKind::Year))?;
+        let (month, _) = self
+            .month
+            .ok_or(DateTimeParseError::MissingField(DateTimePartKind::Month))?;
+        let (day, position) = self
+            .day
+            .ok_or(DateTimeParseError::MissingField(DateTimePartKind::Day))?;
+        let year = if self.bce { 1 - year } else { year };
+        // The month and the day are in range on their own, so only the day can be past
+        // the end of the month.
+        let date = Date::new_gregorian_date_from_integers(day, month, year).map_err(|_| {
+            DateTimeParseError::InvalidValue {
+                kind: DateTimePartKind::Day,
+                position,
+            }
+        })?;
+        match self.quarter {
+            Some((quarter, position)) if quarter != (month - 1) / 3 + 1 => {
+                return Err(DateTimeParseError::InvalidValue {
+                    kind: DateTimePartKind::Quarter,
+                    position,
+                })
+            }
+            _ => {}
+        }
+        match self.weekday {
+            Some((weekday, position)) if weekday != date.day_of_week() => {
+                return Err(DateTimeParseError::InvalidValue {
+                    kind: DateTimePartKind::Weekday,
+                    position,
+                })
+            }
+            _ => {}
+        }
+        Ok(Some(date))
+    }
+
+    fn time(&self) -> Result<Option<Time>> {
+        let (hour, cycle, position) = match self.hour {
+            Some(hour) => hour,
+            None if self.minute.is_none() && self.second.is_none() => return Ok(None),
+            None => return Err(DateTimeParseError::MissingField(DateTimePartKind::Hour)),
+        };
+        let pm = match self.meridiem {
+            Some(Meridiem::Am) | None => false,
+            Some(Meridiem::Pm) => true,
+            Some(Meridiem::Night) => (6..12).contains(&(hour % 12)),
+        };
+        let hour = match cycle {
+            fields::Hour::H11 | fields::Hour::H12 => hour % 12 + if pm { 12 } else { 0 },
+            fields::Hour::H23 | fields::Hour::H24 => hour % 24,
+        };
+        Time::try_new(hour, self.minute.unwrap_or(0), self.second.unwrap_or(0), 0)
+            .map(Some)
+            .map_err(|_| DateTimeParseError::InvalidValue {
+                kind: DateTimePartKind::Hour,
+                position,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_decimal::options::{FixedDecimalFormatOptions, GroupingStrategy};
+    use icu_locid::Locale;
+
+    #[test]
+    fn test_match_prefix() {
+        assert_eq!(match_prefix("janv. 2021", "janv."), Some(5));
+        assert_eq!(match_prefix("JANV 2021", "janv."), Some(4));
+        assert_eq!(match_prefix("janvier 2021", "janv."), Some(4));
+        assert_eq!(match_prefix("janv", "janvier"), None);
+        assert_eq!(match_prefix("févr.", "Févr."), Some(6));
+        assert_eq!(match_prefix("10 a.m.", "10\u{202f}a.m."), Some(7));
+        assert_eq!(match_prefix("", "."), Some(0));
+    }
+
+    #[test]
+    fn test_parse_quarter_and_weekday() {
+        let mut options = FixedDecimalFormatOptions::default();
+        options.grouping_strategy = GroupingStrategy::Never;
+        let fixed_decimal_format = FixedDecimalFormat::try_new(
+            Locale::und(),
+            &icu_provider::inv::InvariantDataProvider,
+            options,
+        )
+        .unwrap();
+        // Weeks start on Sunday, as in the United States.
+        let week_data = WeekDataV1 {
+            first_weekday: 7,
+            min_week_days: 1,
+        };
+        let parse = |pattern: &str, input: &str| {
+            parse_pattern(
+                &Pattern::from_bytes(pattern).unwrap(),
+                None,
+                Some(&week_data),
+                &fixed_decimal_format,
+                AnyCalendarKind::Gregorian,
+                input,
+            )
+        };
+
+        // January 5, 2021 is a Tuesday in the first quarter.
+        let expected = Date::new_gregorian_date_from_integers(5, 1, 2021).unwrap();
+        assert_eq!(
+            parse("Q y-MM-dd", "1 2021-01-05").unwrap().date,
+            Some(expected)
+        );
+        assert_eq!(
+            parse("Q y-MM-dd", "2 2021-01-05"),
+            Err(DateTimeParseError::InvalidValue {
+                kind: DateTimePartKind::Quarter,
+                position: 0,
+            })
+        );
+        assert_eq!(
+            parse("y-MM-dd e", "2021-01-05 3").unwrap().date,
+            Some(Date::new_gregorian_date_from_integers(5, 1, 2021).unwrap())
+        );
+        assert_eq!(
+            parse("y-MM-dd e", "2021-01-05 2"),
+            Err(DateTimeParseError::InvalidValue {
+                kind: DateTimePartKind::Weekday,
+                position: 11,
+            })
+        );
+    }
+}
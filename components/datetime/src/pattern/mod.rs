@@ -122,6 +122,31 @@ impl Pattern {
             .map(Self::from)
     }
 
+    /// Adds a field to a pattern using a CLDR appendItems pattern such as `{0} ({2}: {1})`,
+    /// where `{0}` is the pattern, `{1}` the pattern of the field, and `{2}` its display name.
+    pub fn from_bytes_append_item(
+        input: &str,
+        pattern: Self,
+        field: Self,
+        display_name: &str,
+    ) -> Result<Self, Error> {
+        let display_name = Self::from(vec![PatternItem::from(display_name)]);
+        let items = Parser::new(input).parse_placeholders(vec![pattern, field, display_name])?;
+
+        // Merge the literals around the display name, e.g. " (", "hour" and ": ".
+        let mut merged: Vec<PatternItem> = Vec::with_capacity(items.len());
+        for item in items {
+            match item {
+                PatternItem::Literal(literal) => match merged.last_mut() {
+                    Some(PatternItem::Literal(last)) => last.push_str(&literal),
+                    _ => merged.push(PatternItem::Literal(literal)),
+                },
+                item => merged.push(item),
+            }
+        }
+        Ok(Self::from(merged))
+    }
+
     pub(super) fn most_granular_time(&self) -> Option<TimeGranularity> {
         self.time_granularity
     }
@@ -75,6 +75,7 @@ pub fn apply_coarse_hour_cycle(
     match skeleton::create_best_pattern_for_fields(
         &datetime.skeletons,
         &datetime.length_patterns,
+        &datetime.append_items,
         skeleton.as_slice(),
         &Default::default(),
         // Prefer using the matched pattern directly, rather than mutating it to match the
@@ -0,0 +1,131 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::vec::Vec;
+use icu_provider::yoke::{self, *};
+
+/// The flexible day periods of CLDR, such as "in the morning" or "at night".
+///
+/// The meaning of each period depends on the language, e.g. `morning1` is the only morning
+/// period in English, but Chinese splits the morning into `morning1` and `morning2`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[allow(missing_docs)] // The variants are named after the CLDR day period types.
+pub enum FlexibleDayPeriod {
+    Midnight,
+    Noon,
+    Morning1,
+    Morning2,
+    Afternoon1,
+    Afternoon2,
+    Evening1,
+    Evening2,
+    Night1,
+    Night2,
+}
+
+/// A single rule of CLDR dayPeriodRules. Times are given in minutes since midnight.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct DayPeriodRuleV1 {
+    /// The day period selected by this rule.
+    pub period: FlexibleDayPeriod,
+    /// The time at which the period starts.
+    pub from: u16,
+    /// The time before which the period ends, which may be earlier than `from` for periods
+    /// spanning midnight. [`None`] for periods that only apply exactly at `from`, such as noon.
+    pub before: Option<u16>,
+}
+
+/// An ICU4X mapping to the CLDR dayPeriodRules of a language.
+/// See CLDR-JSON dayPeriods.json for more context.
+///
+/// Languages without flexible day periods have no rules, and fall back to am and pm.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct DayPeriodRulesV1 {
+    /// The rules, in no particular order.
+    pub rules: Vec<DayPeriodRuleV1>,
+}
+
+impl DayPeriodRulesV1 {
+    /// Returns the flexible day period of a time of the day.
+    ///
+    /// Periods that apply at a single point in time, such as noon, are only returned if
+    /// `is_top_of_hour` is set, i.e. the displayed time is exactly at that point.
+    pub fn get_period(
+        &self,
+        hour: u8,
+        minute: u8,
+        is_top_of_hour: bool,
+    ) -> Option<FlexibleDayPeriod> {
+        let time = u16::from(hour) * 60 + u16::from(minute);
+        if is_top_of_hour {
+            let at = self
+                .rules
+                .iter()
+                .find(|rule| rule.before.is_none() && rule.from == u16::from(hour) * 60);
+            if let Some(rule) = at {
+                return Some(rule.period);
+            }
+        }
+        self.rules
+            .iter()
+            .find(|rule| match rule.before {
+                Some(before) if rule.from < before => rule.from <= time && time < before,
+                Some(before) => rule.from <= time || time < before,
+                None => false,
+            })
+            .map(|rule| rule.period)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_period() {
+        use FlexibleDayPeriod::*;
+
+        let rule = |period, from: u16, before: Option<u16>| DayPeriodRuleV1 {
+            period,
+            from: from * 60,
+            before: before.map(|before| before * 60),
+        };
+        // The rules of English.
+        let en = DayPeriodRulesV1 {
+            rules: vec![
+                rule(Midnight, 0, None),
+                rule(Noon, 12, None),
+                rule(Morning1, 6, Some(12)),
+                rule(Afternoon1, 12, Some(18)),
+                rule(Evening1, 18, Some(21)),
+                rule(Night1, 21, Some(6)),
+            ],
+        };
+
+        assert_eq!(en.get_period(0, 0, true), Some(Midnight));
+        assert_eq!(en.get_period(0, 0, false), Some(Night1));
+        assert_eq!(en.get_period(5, 59, false), Some(Night1));
+        assert_eq!(en.get_period(6, 0, true), Some(Morning1));
+        assert_eq!(en.get_period(12, 0, true), Some(Noon));
+        assert_eq!(en.get_period(12, 30, false), Some(Afternoon1));
+        assert_eq!(en.get_period(20, 59, false), Some(Evening1));
+        assert_eq!(en.get_period(23, 15, false), Some(Night1));
+
+        assert_eq!(DayPeriodRulesV1::default().get_period(9, 0, true), None);
+    }
+}
@@ -7,6 +7,8 @@
 use crate::pattern;
 use alloc::borrow::Cow;
 use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+use tinystr::TinyStr8;
 
 #[icu_provider::data_struct]
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -21,6 +23,10 @@ pub struct DateSymbolsV1 {
     pub weekdays: weekdays::ContextsV1,
 
     pub day_periods: day_periods::ContextsV1,
+
+    pub eras: eras::ErasV1,
+
+    pub quarters: quarters::ContextsV1,
 }
 
 #[icu_provider::data_struct]
@@ -48,6 +54,24 @@ pub struct DatePatternsV1 {
 
     pub datetime: patterns::DateTimeFormatsV1,
 }
+
+/// The patterns used to format an interval between two dates, such as "Jan 3 – 7, 2022".
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct DateIntervalPatternsV1 {
+    /// The pattern combining two formatted dates when there is no interval pattern for the
+    /// requested fields, e.g. `{0} – {1}`.
+    pub fallback: Cow<'static, str>,
+
+    /// The interval patterns, keyed by the skeleton of the fields they contain.
+    pub skeletons: patterns::IntervalSkeletonsV1,
+}
+
 macro_rules! symbols {
         ($name: ident, $expr: ty) => {
             pub mod $name {
@@ -134,19 +158,61 @@ macro_rules! symbols {
         };
     }
 
-symbols!(months, [Cow<'static, str>; 12]);
+// Months are keyed by month code, such as `M01` or `M05L`, since calendars differ in
+// the number of months they have and in which of them are leap months.
+symbols!(months, LiteMap<TinyStr8, Cow<'static, str>>);
+
+/// The suffix of the month code that keys the name a month has in leap years, if it differs
+/// from the name in common years. For example, `M06-leap` is Adar II, which is the month
+/// following Adar I in leap years of the Hebrew calendar, and is called Adar in common years.
+pub const LEAP_YEAR_MONTH_SUFFIX: &str = "-leap";
 
 symbols!(weekdays, [Cow<'static, str>; 7]);
 
+symbols!(quarters, [Cow<'static, str>; 4]);
+
 symbols!(
     day_periods {
         am: Cow<'static, str>,
         pm: Cow<'static, str>,
         noon: Option<Cow<'static, str>>,
         midnight: Option<Cow<'static, str>>,
+        morning1: Option<Cow<'static, str>>,
+        morning2: Option<Cow<'static, str>>,
+        afternoon1: Option<Cow<'static, str>>,
+        afternoon2: Option<Cow<'static, str>>,
+        evening1: Option<Cow<'static, str>>,
+        evening2: Option<Cow<'static, str>>,
+        night1: Option<Cow<'static, str>>,
+        night2: Option<Cow<'static, str>>,
     }
 );
 
+pub mod eras {
+    use super::*;
+
+    /// Era names, keyed by the era codes of the calendar, such as `bce` and `ce`.
+    #[derive(Debug, PartialEq, Clone, Default)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct SymbolsV1(pub LiteMap<TinyStr8, Cow<'static, str>>);
+
+    /// Unlike the other symbols, era names have no stand-alone context.
+    #[derive(Debug, PartialEq, Clone, Default, Yokeable, ZeroCopyFrom)]
+    #[yoke(cloning_zcf)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct ErasV1 {
+        pub names: SymbolsV1,
+        pub abbr: SymbolsV1,
+        pub narrow: SymbolsV1,
+    }
+}
+
 pub mod patterns {
     use super::*;
     use crate::{
@@ -154,7 +220,6 @@ pub mod patterns {
         skeleton::{Skeleton, SkeletonError},
     };
     use core::convert::TryFrom;
-    use litemap::LiteMap;
 
     #[derive(Debug, PartialEq, Clone, Default)]
     #[cfg_attr(
@@ -230,6 +295,68 @@ pub mod patterns {
     )]
     pub struct SkeletonsV1(pub LiteMap<SkeletonV1, PatternV1>);
 
+    /// A CLDR appendItems pattern, such as `{0} ({2}: {1})`, used to add a field to a pattern
+    /// that is missing it. `{0}` is the pattern the field is added to, `{1}` is the pattern for
+    /// the field, and `{2}` is the display name of the field, e.g. "hour".
+    #[derive(Debug, PartialEq, Clone, Default)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct AppendItemV1 {
+        pub pattern: Cow<'static, str>,
+        pub display_name: Option<Cow<'static, str>>,
+    }
+
+    /// The appendItems patterns for each type of field, see UTS 35 2.6.2.2
+    /// [Missing Skeleton Fields](https://unicode.org/reports/tr35/tr35-dates.html#Missing_Skeleton_Fields).
+    #[derive(Debug, PartialEq, Clone, Default)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct AppendItemsV1 {
+        pub era: AppendItemV1,
+        pub year: AppendItemV1,
+        pub quarter: AppendItemV1,
+        pub month: AppendItemV1,
+        pub week: AppendItemV1,
+        pub day: AppendItemV1,
+        pub day_of_week: AppendItemV1,
+        pub hour: AppendItemV1,
+        pub minute: AppendItemV1,
+        pub second: AppendItemV1,
+        pub time_zone: AppendItemV1,
+    }
+
+    /// The interval patterns of a skeleton, keyed by the greatest field that differs between
+    /// the two dates. Each pattern contains the fields twice, e.g. `MMM d – d, y`, where the first
+    /// part is used for the first date, starting again with the first repeated field for the
+    /// second date.
+    ///
+    /// Day periods use either `a` or `B`, and hours either `h` or `H`, depending on the skeleton.
+    #[derive(Debug, PartialEq, Clone, Default)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct IntervalPatternsV1 {
+        pub era: Option<PatternV1>,
+        pub year: Option<PatternV1>,
+        pub month: Option<PatternV1>,
+        pub day: Option<PatternV1>,
+        pub day_period: Option<PatternV1>,
+        pub hour: Option<PatternV1>,
+        pub minute: Option<PatternV1>,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Default)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct IntervalSkeletonsV1(pub LiteMap<SkeletonV1, IntervalPatternsV1>);
+
     #[derive(Debug, PartialEq, Clone, Default)]
     #[cfg_attr(
         feature = "provider_serde",
@@ -238,5 +365,6 @@ pub mod patterns {
     pub struct DateTimeFormatsV1 {
         pub length_patterns: LengthPatternsV1,
         pub skeletons: SkeletonsV1,
+        pub append_items: AppendItemsV1,
     }
 }
@@ -8,6 +8,7 @@ use crate::fields;
 use crate::options::{components, length, preferences, DateTimeFormatOptions};
 use crate::pattern::{Pattern, PatternItem};
 use crate::provider;
+use crate::provider::day_period_rules::FlexibleDayPeriod;
 use crate::skeleton;
 use alloc::borrow::Cow;
 
@@ -39,6 +40,13 @@ pub trait DateTimeSymbols {
         &self,
         month: fields::Month,
         length: fields::FieldLength,
+        code: &date::MonthCode,
+        is_leap_year: bool,
+    ) -> Option<&Cow<str>>;
+    fn get_symbol_for_quarter(
+        &self,
+        quarter: fields::Quarter,
+        length: fields::FieldLength,
         num: usize,
     ) -> &Cow<str>;
     fn get_symbol_for_weekday(
@@ -54,6 +62,14 @@ pub trait DateTimeSymbols {
         hour: date::IsoHour,
         is_top_of_hour: bool,
     ) -> &Cow<str>;
+    fn get_symbol_for_flexible_day_period(
+        &self,
+        length: fields::FieldLength,
+        hour: date::IsoHour,
+        day_period: Option<FlexibleDayPeriod>,
+    ) -> &Cow<str>;
+    fn get_symbol_for_era(&self, length: fields::FieldLength, era: &date::Era)
+        -> Option<&Cow<str>>;
 }
 
 impl DateTimePatterns for provider::gregory::DatePatternsV1 {
@@ -74,6 +90,7 @@ impl DateTimePatterns for provider::gregory::DatePatternsV1 {
             match skeleton::create_best_pattern_for_fields(
                 &self.datetime.skeletons,
                 &self.datetime.length_patterns,
+                &self.datetime.append_items,
                 &requested_fields,
                 components,
                 false, // Prefer the requested fields over the matched pattern.
@@ -188,7 +205,8 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
         day: date::IsoWeekday,
     ) -> &Cow<str> {
         let widths = match weekday {
-            fields::Weekday::Format => &self.weekdays.format,
+            // The text forms of the local day of week are those of the day of week.
+            fields::Weekday::Format | fields::Weekday::Local => &self.weekdays.format,
             fields::Weekday::StandAlone => {
                 if let Some(ref widths) = self.weekdays.stand_alone {
                     let symbols = match length {
@@ -209,7 +227,6 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
                     return self.get_symbol_for_weekday(fields::Weekday::Format, length, day);
                 }
             }
-            fields::Weekday::Local => unimplemented!(),
         };
         let symbols = match length {
             fields::FieldLength::Wide => &widths.wide,
@@ -224,14 +241,64 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
         &self,
         month: fields::Month,
         length: fields::FieldLength,
-        num: usize,
-    ) -> &Cow<str> {
-        // TODO(#493): Support symbols for non-Gregorian calendars.
-        debug_assert!(num < 12);
+        code: &date::MonthCode,
+        is_leap_year: bool,
+    ) -> Option<&Cow<str>> {
+        if is_leap_year {
+            let leap_year_code =
+                alloc::format!("{}{}", code.0, provider::gregory::LEAP_YEAR_MONTH_SUFFIX)
+                    .parse()
+                    .ok()
+                    .map(date::MonthCode);
+            if let Some(symbol) = leap_year_code
+                .and_then(|code| self.get_symbol_for_month(month, length, &code, false))
+            {
+                return Some(symbol);
+            }
+        }
         let widths = match month {
             fields::Month::Format => &self.months.format,
             fields::Month::StandAlone => {
                 if let Some(ref widths) = self.months.stand_alone {
+                    let symbols = match length {
+                        fields::FieldLength::Wide => widths.wide.as_ref(),
+                        fields::FieldLength::Narrow => widths.narrow.as_ref(),
+                        _ => widths.abbreviated.as_ref(),
+                    };
+                    if let Some(symbol) = symbols.and_then(|symbols| symbols.0.get(&code.0)) {
+                        return Some(symbol);
+                    } else {
+                        return self.get_symbol_for_month(
+                            fields::Month::Format,
+                            length,
+                            code,
+                            false,
+                        );
+                    }
+                } else {
+                    return self.get_symbol_for_month(fields::Month::Format, length, code, false);
+                }
+            }
+        };
+        let symbols = match length {
+            fields::FieldLength::Wide => &widths.wide,
+            fields::FieldLength::Narrow => &widths.narrow,
+            _ => &widths.abbreviated,
+        };
+        symbols.0.get(&code.0)
+    }
+
+    fn get_symbol_for_quarter(
+        &self,
+        quarter: fields::Quarter,
+        length: fields::FieldLength,
+        num: usize,
+    ) -> &Cow<str> {
+        debug_assert!(num < 4);
+        let widths = match quarter {
+            fields::Quarter::Format => &self.quarters.format,
+            fields::Quarter::StandAlone => {
+                if let Some(ref widths) = self.quarters.stand_alone {
                     let symbols = match length {
                         fields::FieldLength::Wide => widths.wide.as_ref(),
                         fields::FieldLength::Narrow => widths.narrow.as_ref(),
@@ -240,10 +307,10 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
                     if let Some(symbols) = symbols {
                         return &symbols.0[num];
                     } else {
-                        return self.get_symbol_for_month(fields::Month::Format, length, num);
+                        return self.get_symbol_for_quarter(fields::Quarter::Format, length, num);
                     }
                 } else {
-                    return self.get_symbol_for_month(fields::Month::Format, length, num);
+                    return self.get_symbol_for_quarter(fields::Quarter::Format, length, num);
                 }
             }
         };
@@ -276,4 +343,50 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
             _ => &symbols.pm,
         }
     }
+
+    fn get_symbol_for_flexible_day_period(
+        &self,
+        length: fields::FieldLength,
+        hour: date::IsoHour,
+        day_period: Option<FlexibleDayPeriod>,
+    ) -> &Cow<str> {
+        use fields::FieldLength;
+        let widths = &self.day_periods.format;
+        let symbols = match length {
+            FieldLength::Wide => &widths.wide,
+            FieldLength::Narrow => &widths.narrow,
+            _ => &widths.abbreviated,
+        };
+        let symbol = day_period.and_then(|day_period| match day_period {
+            FlexibleDayPeriod::Midnight => symbols.midnight.as_ref(),
+            FlexibleDayPeriod::Noon => symbols.noon.as_ref(),
+            FlexibleDayPeriod::Morning1 => symbols.morning1.as_ref(),
+            FlexibleDayPeriod::Morning2 => symbols.morning2.as_ref(),
+            FlexibleDayPeriod::Afternoon1 => symbols.afternoon1.as_ref(),
+            FlexibleDayPeriod::Afternoon2 => symbols.afternoon2.as_ref(),
+            FlexibleDayPeriod::Evening1 => symbols.evening1.as_ref(),
+            FlexibleDayPeriod::Evening2 => symbols.evening2.as_ref(),
+            FlexibleDayPeriod::Night1 => symbols.night1.as_ref(),
+            FlexibleDayPeriod::Night2 => symbols.night2.as_ref(),
+        });
+        // Languages without flexible day periods use am and pm instead.
+        match symbol {
+            Some(symbol) => symbol,
+            None if u8::from(hour) < 12 => &symbols.am,
+            None => &symbols.pm,
+        }
+    }
+
+    fn get_symbol_for_era(
+        &self,
+        length: fields::FieldLength,
+        era: &date::Era,
+    ) -> Option<&Cow<str>> {
+        let symbols = match length {
+            fields::FieldLength::Wide => &self.eras.names,
+            fields::FieldLength::Narrow => &self.eras.narrow,
+            _ => &self.eras.abbr,
+        };
+        symbols.0.get(&era.0)
+    }
 }
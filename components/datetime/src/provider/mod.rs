@@ -9,27 +9,53 @@
 #[cfg(doc)]
 use icu_provider::prelude::ResourceKey;
 
-/// Data providers for the Gregorian Calendar.
+/// Data providers for the rules of flexible day periods.
+pub mod day_period_rules;
+
+/// Data providers for date symbols and patterns.
+///
+/// Despite its name, this module is shared by all calendars: the data for a particular
+/// calendar is requested with its BCP-47 identifier, such as `buddhist`, as the variant.
 pub mod gregory;
 
 pub(crate) mod helpers;
 
+/// Data providers for formatting a time relative to now.
+pub mod relative_time;
+
 /// Data providers for time zones.
 pub mod time_zones;
 
+/// Data providers for the first day of the week and the rules for numbering weeks.
+pub mod week_data;
+
 /// A collection of [`ResourceKey`] structs for DateTime providers.
 pub mod key {
     #[cfg(doc)]
-    use crate::provider::{gregory, time_zones};
+    use crate::provider::{day_period_rules, gregory, relative_time, time_zones, week_data};
 
     use icu_provider::{resource_key, ResourceKey};
 
-    /// A [`ResourceKey`] to [`gregory::DatePatternsV1`].
-    pub const GREGORY_DATE_PATTERNS_V1: ResourceKey =
-        resource_key!(DateTime, "gregory_patterns", 1);
+    /// A [`ResourceKey`] to [`gregory::DatePatternsV1`], with the BCP-47 identifier of the
+    /// calendar, such as `gregory` or `buddhist`, as the variant.
+    pub const DATE_PATTERNS_V1: ResourceKey = resource_key!(DateTime, "patterns", 1);
+
+    /// A [`ResourceKey`] to [`gregory::DateIntervalPatternsV1`].
+    pub const GREGORY_DATE_INTERVAL_PATTERNS_V1: ResourceKey =
+        resource_key!(DateTime, "gregory_interval", 1);
+
+    /// A [`ResourceKey`] to [`gregory::DateSymbolsV1`], with the BCP-47 identifier of the
+    /// calendar, such as `gregory` or `buddhist`, as the variant.
+    pub const DATE_SYMBOLS_V1: ResourceKey = resource_key!(DateTime, "symbols", 1);
 
-    /// A [`ResourceKey`] to [`gregory::DateSymbolsV1`]
-    pub const GREGORY_DATE_SYMBOLS_V1: ResourceKey = resource_key!(DateTime, "gregory_symbols", 1);
+    /// A [`ResourceKey`] to [`week_data::WeekDataV1`].
+    pub const WEEK_DATA_V1: ResourceKey = resource_key!(DateTime, "week_data", 1);
+
+    /// A [`ResourceKey`] to [`day_period_rules::DayPeriodRulesV1`].
+    pub const DAY_PERIOD_RULES_V1: ResourceKey = resource_key!(DateTime, "day_period_rules", 1);
+
+    /// A [`ResourceKey`] to [`relative_time::RelativeTimePatternsV1`].
+    pub const RELATIVE_TIME_PATTERNS_V1: ResourceKey = resource_key!(DateTime, "relative_time", 1);
 
     /// A [`ResourceKey`] to [`time_zones::TimeZoneFormatsV1`].
     pub const TIMEZONE_FORMATS_V1: ResourceKey = resource_key!(TimeZone, "formats", 1);
@@ -53,4 +79,14 @@ pub mod key {
     /// A [`ResourceKey`] to [`time_zones::MetaZoneSpecificNamesShortV1`].
     pub const TIMEZONE_SPECIFIC_NAMES_SHORT_V1: ResourceKey =
         resource_key!(TimeZone, "specific_short", 1);
+
+    /// A [`ResourceKey`] to [`time_zones::IanaToBcp47MapV1`].
+    pub const TIMEZONE_IANA_TO_BCP47_V1: ResourceKey = resource_key!(TimeZone, "iana_to_bcp47", 1);
+
+    /// A [`ResourceKey`] to [`time_zones::MetaZonePeriodsV1`].
+    pub const TIMEZONE_METAZONE_PERIODS_V1: ResourceKey =
+        resource_key!(TimeZone, "metazone_periods", 1);
+
+    /// A [`ResourceKey`] to [`time_zones::TimeZoneTransitionsV1`].
+    pub const TIMEZONE_TRANSITIONS_V1: ResourceKey = resource_key!(TimeZone, "transitions", 1);
 }
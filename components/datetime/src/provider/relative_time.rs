@@ -0,0 +1,107 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
+use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+
+/// The patterns used to format a time relative to now, such as "in 3 days" or "yesterday",
+/// for each of the widths.
+/// See CLDR-JSON dateFields.json for more context.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct RelativeTimePatternsV1 {
+    /// The patterns of the `long` width, e.g. "in 3 days".
+    pub long: RelativeTimeUnitsV1,
+    /// The patterns of the `short` width, e.g. "in 3 days" or "in 3 hr.".
+    pub short: RelativeTimeUnitsV1,
+    /// The patterns of the `narrow` width, e.g. "in 3 days" or "in 3 hr.".
+    pub narrow: RelativeTimeUnitsV1,
+}
+
+/// The patterns of a single width, for each of the units.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct RelativeTimeUnitsV1 {
+    /// The patterns of the `year` unit.
+    pub year: RelativeTimeV1,
+    /// The patterns of the `quarter` unit.
+    pub quarter: RelativeTimeV1,
+    /// The patterns of the `month` unit.
+    pub month: RelativeTimeV1,
+    /// The patterns of the `week` unit.
+    pub week: RelativeTimeV1,
+    /// The patterns of the `day` unit.
+    pub day: RelativeTimeV1,
+    /// The patterns of the `hour` unit.
+    pub hour: RelativeTimeV1,
+    /// The patterns of the `minute` unit.
+    pub minute: RelativeTimeV1,
+    /// The patterns of the `second` unit.
+    pub second: RelativeTimeV1,
+}
+
+/// The patterns of a single unit and width.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct RelativeTimeV1 {
+    /// The names of offsets which have their own word, e.g. -1 day is "yesterday".
+    /// These are only used with [`Numeric::Auto`](crate::options::relative_time::Numeric::Auto).
+    pub relatives: LiteMap<i8, Cow<'static, str>>,
+    /// The patterns for a time in the past, e.g. "{0} days ago".
+    pub past: PluralPatternsV1,
+    /// The patterns for a time in the future, e.g. "in {0} days".
+    pub future: PluralPatternsV1,
+}
+
+/// A pattern with a `{0}` placeholder for the number, for each plural category.
+/// Only the [`other`](PluralPatternsV1::other) category is required.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PluralPatternsV1 {
+    /// The pattern for the [`zero`](PluralCategory::Zero) category.
+    pub zero: Option<Cow<'static, str>>,
+    /// The pattern for the [`one`](PluralCategory::One) category.
+    pub one: Option<Cow<'static, str>>,
+    /// The pattern for the [`two`](PluralCategory::Two) category.
+    pub two: Option<Cow<'static, str>>,
+    /// The pattern for the [`few`](PluralCategory::Few) category.
+    pub few: Option<Cow<'static, str>>,
+    /// The pattern for the [`many`](PluralCategory::Many) category.
+    pub many: Option<Cow<'static, str>>,
+    /// The pattern for the [`other`](PluralCategory::Other) category, also used for
+    /// any category which has no pattern of its own.
+    pub other: Cow<'static, str>,
+}
+
+impl PluralPatternsV1 {
+    /// Returns the pattern for the given plural category, falling back to
+    /// [`other`](PluralPatternsV1::other) if the category has no pattern of its own.
+    pub fn get(&self, category: PluralCategory) -> &str {
+        let pattern = match category {
+            PluralCategory::Zero => &self.zero,
+            PluralCategory::One => &self.one,
+            PluralCategory::Two => &self.two,
+            PluralCategory::Few => &self.few,
+            PluralCategory::Many => &self.many,
+            PluralCategory::Other => return &self.other,
+        };
+        pattern.as_deref().unwrap_or(&self.other)
+    }
+}
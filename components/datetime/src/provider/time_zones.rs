@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use icu_provider::yoke::{self, *};
 use litemap::LiteMap;
 use tinystr::TinyStr8;
@@ -40,6 +41,23 @@ macro_rules! map_access {
     };
 }
 
+/// A BCP-47 time zone identifier, such as "uslax" for America/Los_Angeles.
+/// See the `tz` key of the Unicode locale extension in UTS 35 for more context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct TimeZoneBcp47Id(pub TinyStr8);
+
+impl TimeZoneBcp47Id {
+    /// Returns the identifier as a string slice.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
 /// An ICU4X mapping to the CLDR timeZoneNames format strings.
 /// See CLDR-JSON timeZoneNames.json for more context.
 #[icu_provider::data_struct]
@@ -74,8 +92,8 @@ pub struct TimeZoneFormatsV1<'data> {
     derive(serde::Serialize, serde::Deserialize)
 )]
 #[yoke(cloning_zcf)]
-pub struct ExemplarCitiesV1<'data>(pub LiteMap<Cow<'data, str>, Cow<'data, str>>);
-map_access!(ExemplarCitiesV1<'data>[str] => Cow<'data, str>: 'data);
+pub struct ExemplarCitiesV1<'data>(pub LiteMap<Cow<'data, TimeZoneBcp47Id>, Cow<'data, str>>);
+map_access!(ExemplarCitiesV1<'data>[TimeZoneBcp47Id] => Cow<'data, str>: 'data);
 
 /// An ICU4X mapping to the long-form generic metazone names.
 /// See CLDR-JSON timeZoneNames.json for more context.
@@ -143,3 +161,143 @@ map_access!(MetaZoneSpecificNamesShortV1<'data>[str] => MetaZoneSpecificNamesV1<
 #[yoke(cloning_zcf)]
 pub struct MetaZoneSpecificNamesV1<'data>(pub LiteMap<Cow<'data, TinyStr8>, Cow<'data, str>>);
 map_access!(MetaZoneSpecificNamesV1<'data>[TinyStr8] => Cow<'data, str>: 'data);
+
+/// An ICU4X mapping from IANA time zone identifiers, including aliases, to BCP-47 time zone
+/// identifiers. See CLDR-JSON bcp47/timezone.json for more context.
+#[icu_provider::data_struct]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct IanaToBcp47MapV1<'data>(pub LiteMap<Cow<'data, str>, TimeZoneBcp47Id>);
+map_access!(IanaToBcp47MapV1<'data>[str] => TimeZoneBcp47Id: 'data);
+
+/// An ICU4X mapping from time zone identifiers to the metazones they used over time.
+/// See CLDR-JSON metaZones.json for more context.
+#[icu_provider::data_struct]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct MetaZonePeriodsV1<'data>(
+    pub LiteMap<Cow<'data, TimeZoneBcp47Id>, Vec<MetaZonePeriodV1<'data>>>,
+);
+map_access!(MetaZonePeriodsV1<'data>[TimeZoneBcp47Id] => Vec<MetaZonePeriodV1<'data>>: 'data);
+
+/// A period of time during which a time zone used a metazone.
+/// See CLDR-JSON metaZones.json for more context.
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct MetaZonePeriodV1<'data> {
+    /// The start of the period in seconds since the Unix epoch, inclusive,
+    /// or `None` if the period has no start.
+    pub from: Option<i64>,
+    /// The end of the period in seconds since the Unix epoch, exclusive,
+    /// or `None` if the period has no end.
+    pub to: Option<i64>,
+    /// The metazone identifier, e.g. "America_Pacific".
+    pub metazone_id: Cow<'data, str>,
+}
+
+/// An ICU4X mapping from time zone identifiers to their GMT offset transitions.
+/// The transitions are compiled from the TZif files of the IANA time zone database.
+#[icu_provider::data_struct]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct TimeZoneTransitionsV1<'data>(pub LiteMap<Cow<'data, TimeZoneBcp47Id>, TransitionsV1>);
+map_access!(TimeZoneTransitionsV1<'data>[TimeZoneBcp47Id] => TransitionsV1: 'data);
+
+/// The GMT offset transitions of a single time zone, in the layout of the body of a TZif file.
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct TransitionsV1 {
+    /// The instants of the transitions in seconds since the Unix epoch, in ascending order.
+    pub transition_times: Vec<i64>,
+    /// The index into `local_time_types` of the local time type which starts
+    /// at each of the `transition_times`.
+    pub transition_types: Vec<u8>,
+    /// The local time types of the time zone. The first local time type is used
+    /// before the first transition.
+    pub local_time_types: Vec<LocalTimeTypeV1>,
+    /// The rule which computes the transitions after the last of the `transition_times`,
+    /// from the footer of the TZif file.
+    pub rule: Option<TransitionRuleV1>,
+}
+
+/// A GMT offset used by a time zone, in the layout of a TZif `ttinfo` record.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct LocalTimeTypeV1 {
+    /// The GMT offset in seconds.
+    pub offset: i32,
+    /// Whether the local time type is daylight saving time.
+    pub is_dst: bool,
+}
+
+/// A rule for the GMT offset transitions of a time zone, equivalent to a POSIX TZ string
+/// such as "PST8PDT,M3.2.0,M11.1.0".
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct TransitionRuleV1 {
+    /// The GMT offset of standard time in seconds.
+    pub std_offset: i32,
+    /// The daylight saving time of the rule, or `None` if the time zone
+    /// does not observe daylight saving time.
+    pub dst: Option<DstRuleV1>,
+}
+
+/// The daylight saving time of a [`TransitionRuleV1`].
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct DstRuleV1 {
+    /// The GMT offset of daylight saving time in seconds.
+    pub offset: i32,
+    /// The transition from standard time to daylight saving time,
+    /// in the local time of standard time.
+    pub start: DateRuleV1,
+    /// The transition from daylight saving time to standard time,
+    /// in the local time of daylight saving time.
+    pub end: DateRuleV1,
+}
+
+/// A transition of a [`DstRuleV1`] in a year, equivalent to the `Mm.w.d/time`
+/// form of a POSIX TZ string.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct DateRuleV1 {
+    /// The month, from 1 to 12.
+    pub month: u8,
+    /// The week of the month, from 1 to 5, where 5 is the last week of the month.
+    pub week: u8,
+    /// The day of the week, from 0 (Sunday) to 6 (Saturday).
+    pub weekday: u8,
+    /// The local time of the transition in seconds since midnight,
+    /// which may be negative or exceed one day.
+    pub time: i32,
+}
@@ -0,0 +1,42 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::date::IsoWeekday;
+use icu_calendar::week::WeekCalculator;
+use icu_provider::yoke::{self, *};
+
+/// An ICU4X mapping to a subset of CLDR weekData.
+/// See CLDR-JSON weekData.json for more context.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct WeekDataV1 {
+    /// The first day of a week, as an ISO-8601 weekday number (Monday = 1, Sunday = 7).
+    pub first_weekday: u8,
+    /// The minimal number of days of a week that must fall in a month or year for the
+    /// week to be counted as the first week of that month or year.
+    pub min_week_days: u8,
+}
+
+/// The week data of the world region (`001`), which is also used by the root locale.
+impl Default for WeekDataV1 {
+    fn default() -> Self {
+        Self {
+            first_weekday: IsoWeekday::Monday as u8,
+            min_week_days: 1,
+        }
+    }
+}
+
+impl From<&WeekDataV1> for WeekCalculator {
+    fn from(week_data: &WeekDataV1) -> Self {
+        WeekCalculator::new(
+            IsoWeekday::from(week_data.first_weekday as usize),
+            week_data.min_week_days,
+        )
+    }
+}
@@ -0,0 +1,379 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The collection of code that is needed for formatting a time relative to now, such as
+//! "in 3 days" or "yesterday". Central to this is the [`RelativeTimeFormat`].
+
+use crate::{
+    format::relative_time::FormattedRelativeTime,
+    options::relative_time::{Bag, Numeric, Width},
+    provider::{
+        self,
+        relative_time::{RelativeTimePatternsV1Marker, RelativeTimeV1},
+    },
+    DateTimeFormatError,
+};
+use alloc::string::String;
+use fixed_decimal::{FixedDecimal, Signum};
+use icu_decimal::{
+    options::FixedDecimalFormatOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormat,
+};
+use icu_locid::Locale;
+use icu_plurals::{provider::PluralRuleStringsV1Marker, PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// The unit of a time relative to now.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::relative_time::Unit;
+///
+/// let unit = Unit::Day;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    /// Years, e.g. "in 3 years".
+    Year,
+    /// Quarters of a year, e.g. "in 3 quarters".
+    Quarter,
+    /// Months, e.g. "in 3 months".
+    Month,
+    /// Weeks, e.g. "in 3 weeks".
+    Week,
+    /// Days, e.g. "in 3 days".
+    Day,
+    /// Hours, e.g. "in 3 hours".
+    Hour,
+    /// Minutes, e.g. "in 3 minutes".
+    Minute,
+    /// Seconds, e.g. "in 3 seconds".
+    Second,
+}
+
+/// [`RelativeTimeFormat`] formats a time relative to now, such as "in 3 days" or "3 days ago",
+/// using the CLDR relative time patterns of the locale.
+///
+/// The value is a signed number of [`Unit`]s, where negative values are in the past. The number
+/// is formatted with a [`FixedDecimalFormat`], and the pattern is selected by the plural
+/// category of the number. With [`Numeric::Auto`], values which have a name of their own
+/// in the locale are formatted with that name, e.g. "yesterday" instead of "1 day ago".
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::Locale;
+/// use icu::locid::macros::langid;
+/// use icu::datetime::RelativeTimeFormat;
+/// use icu::datetime::options::relative_time;
+/// use icu::datetime::relative_time::Unit;
+/// use fixed_decimal::FixedDecimal;
+///
+/// let locale: Locale = langid!("en").into();
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let options = relative_time::Bag {
+///     numeric: relative_time::Numeric::Auto,
+///     ..Default::default()
+/// };
+/// let rtf = RelativeTimeFormat::try_new(locale, &provider, &options)
+///     .expect("Failed to create RelativeTimeFormat instance.");
+///
+/// assert_eq!(rtf.format_to_string(FixedDecimal::from(3), Unit::Day), "in 3 days");
+/// assert_eq!(rtf.format_to_string(FixedDecimal::from(-1), Unit::Day), "yesterday");
+/// ```
+pub struct RelativeTimeFormat<'data> {
+    pub(super) options: Bag,
+    pub(super) patterns: DataPayload<'data, RelativeTimePatternsV1Marker>,
+    pub(super) plural_rules: PluralRules,
+    pub(super) fixed_decimal_format: FixedDecimalFormat<'data>,
+}
+
+impl<'data> RelativeTimeFormat<'data> {
+    /// Constructor that takes a selected [`Locale`], reference to a [`DataProvider`] and
+    /// a bag of options, then collects all data necessary to format relative times into the
+    /// given locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::RelativeTimeFormat;
+    /// use icu::datetime::options::relative_time;
+    ///
+    /// let locale: Locale = langid!("en").into();
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let options = relative_time::Bag::default();
+    ///
+    /// let rtf = RelativeTimeFormat::try_new(locale, &provider, &options);
+    ///
+    /// assert_eq!(rtf.is_ok(), true);
+    /// ```
+    pub fn try_new<
+        T: Into<Locale>,
+        D: DataProvider<'data, RelativeTimePatternsV1Marker>
+            + DataProvider<'data, PluralRuleStringsV1Marker>
+            + DataProvider<'data, DecimalSymbolsV1Marker>
+            + ?Sized,
+    >(
+        locale: T,
+        data_provider: &D,
+        options: &Bag,
+    ) -> Result<Self, DateTimeFormatError> {
+        let locale = locale.into();
+
+        let patterns = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::RELATIVE_TIME_PATTERNS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.clone().into()),
+                    },
+                },
+            })?
+            .take_payload()?;
+
+        let plural_rules =
+            PluralRules::try_new(locale.id.clone(), data_provider, PluralRuleType::Cardinal)?;
+
+        let fixed_decimal_format = FixedDecimalFormat::try_new(
+            locale,
+            data_provider,
+            FixedDecimalFormatOptions::default(),
+        )?;
+
+        Ok(Self {
+            options: *options,
+            patterns,
+            plural_rules,
+            fixed_decimal_format,
+        })
+    }
+
+    /// Takes a signed number of [`Unit`]s and returns an instance of a [`FormattedRelativeTime`]
+    /// that contains all information necessary to display the formatted relative time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::RelativeTimeFormat;
+    /// use icu::datetime::options::relative_time;
+    /// use icu::datetime::relative_time::Unit;
+    /// use fixed_decimal::FixedDecimal;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = icu_testdata::get_provider();
+    /// let options = relative_time::Bag {
+    ///     width: relative_time::Width::Short,
+    ///     ..Default::default()
+    /// };
+    /// let rtf = RelativeTimeFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create RelativeTimeFormat instance.");
+    ///
+    /// let formatted_relative_time = rtf.format(FixedDecimal::from(-3), Unit::Hour);
+    ///
+    /// assert_eq!(formatted_relative_time.to_string(), "3 hr. ago");
+    /// ```
+    pub fn format<'l>(
+        &'l self,
+        value: FixedDecimal,
+        unit: Unit,
+    ) -> FormattedRelativeTime<'l, 'data> {
+        let relative_time = self.get_relative_time(unit);
+
+        if self.options.numeric == Numeric::Auto {
+            if let Some(name) = get_relative_name(relative_time, &value) {
+                return FormattedRelativeTime {
+                    pattern: name,
+                    value: None,
+                    fixed_decimal_format: &self.fixed_decimal_format,
+                };
+            }
+        }
+
+        let (patterns, value) = match value.signum() {
+            Signum::BelowZero | Signum::NegativeZero => (&relative_time.past, value.negated()),
+            Signum::PositiveZero | Signum::AboveZero => (&relative_time.future, value),
+        };
+        let category = self.plural_rules.select(&value);
+
+        FormattedRelativeTime {
+            pattern: patterns.get(category),
+            value: Some(value),
+            fixed_decimal_format: &self.fixed_decimal_format,
+        }
+    }
+
+    /// Takes a mutable reference to anything that implements [`Write`](std::fmt::Write) trait
+    /// and a signed number of [`Unit`]s, and populates the buffer with the formatted relative
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::RelativeTimeFormat;
+    /// use icu::datetime::options::relative_time;
+    /// use icu::datetime::relative_time::Unit;
+    /// use fixed_decimal::FixedDecimal;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = icu_testdata::get_provider();
+    /// # let options = relative_time::Bag::default();
+    /// let rtf = RelativeTimeFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create RelativeTimeFormat instance.");
+    ///
+    /// let mut buffer = String::new();
+    /// rtf.format_to_write(&mut buffer, FixedDecimal::from(1), Unit::Week)
+    ///     .expect("Failed to write to a buffer.");
+    ///
+    /// assert_eq!(buffer, "in 1 week");
+    /// ```
+    pub fn format_to_write(
+        &self,
+        w: &mut impl core::fmt::Write,
+        value: FixedDecimal,
+        unit: Unit,
+    ) -> core::fmt::Result {
+        self.format(value, unit).write_to(w)
+    }
+
+    /// Takes a signed number of [`Unit`]s and returns the formatted relative time as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::RelativeTimeFormat;
+    /// use icu::datetime::options::relative_time;
+    /// use icu::datetime::relative_time::Unit;
+    /// use fixed_decimal::FixedDecimal;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = icu_testdata::get_provider();
+    /// # let options = relative_time::Bag::default();
+    /// let rtf = RelativeTimeFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create RelativeTimeFormat instance.");
+    ///
+    /// assert_eq!(rtf.format_to_string(FixedDecimal::from(-2), Unit::Month), "2 months ago");
+    /// ```
+    pub fn format_to_string(&self, value: FixedDecimal, unit: Unit) -> String {
+        let mut s = String::new();
+        self.format_to_write(&mut s, value, unit)
+            .expect("Failed to write to a String.");
+        s
+    }
+
+    fn get_relative_time(&self, unit: Unit) -> &RelativeTimeV1 {
+        let patterns = self.patterns.get();
+        let units = match self.options.width {
+            Width::Long => &patterns.long,
+            Width::Short => &patterns.short,
+            Width::Narrow => &patterns.narrow,
+        };
+        match unit {
+            Unit::Year => &units.year,
+            Unit::Quarter => &units.quarter,
+            Unit::Month => &units.month,
+            Unit::Week => &units.week,
+            Unit::Day => &units.day,
+            Unit::Hour => &units.hour,
+            Unit::Minute => &units.minute,
+            Unit::Second => &units.second,
+        }
+    }
+}
+
+/// Returns the name of the value if it has one of its own, e.g. "tomorrow" for 1 day.
+///
+/// Only integer values without fraction digits have names, so "1.0 days" is never "tomorrow".
+/// Zero has a name regardless of its sign, so "-0 days" is "today" but "0.0 days" is not.
+fn get_relative_name<'l>(
+    relative_time: &'l RelativeTimeV1,
+    value: &FixedDecimal,
+) -> Option<&'l str> {
+    let zero = FixedDecimal::from(0);
+    let is_zero = *value == zero || *value == zero.negated();
+    relative_time
+        .relatives
+        .iter()
+        .find(|(offset, _)| {
+            if **offset == 0 {
+                is_zero
+            } else {
+                *value == FixedDecimal::from(**offset)
+            }
+        })
+        .map(|(_, name)| &**name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid_macros::langid;
+
+    #[test]
+    fn test_format() {
+        let provider = icu_testdata::get_provider();
+        let format = |options: &Bag, value: &str, unit: Unit| {
+            let rtf = RelativeTimeFormat::try_new(langid!("en"), &provider, options)
+                .expect("Failed to create RelativeTimeFormat");
+            let value: FixedDecimal = value.parse().expect("Failed to parse");
+            rtf.format_to_string(value, unit)
+        };
+
+        let always = Bag::default();
+        let auto = Bag {
+            numeric: Numeric::Auto,
+            ..Default::default()
+        };
+        let short = Bag {
+            width: Width::Short,
+            numeric: Numeric::Auto,
+        };
+
+        let cases = [
+            (&always, "3", Unit::Day, "in 3 days"),
+            (&always, "-3", Unit::Day, "3 days ago"),
+            (&always, "1", Unit::Day, "in 1 day"),
+            (&always, "-1", Unit::Year, "1 year ago"),
+            (&always, "0", Unit::Second, "in 0 seconds"),
+            (&always, "-0", Unit::Second, "0 seconds ago"),
+            (&always, "1.5", Unit::Hour, "in 1.5 hours"),
+            (&always, "1000", Unit::Minute, "in 1,000 minutes"),
+            (&auto, "1", Unit::Day, "tomorrow"),
+            (&auto, "-1", Unit::Day, "yesterday"),
+            (&auto, "0", Unit::Day, "today"),
+            (&auto, "-0", Unit::Day, "today"),
+            (&auto, "0.0", Unit::Day, "in 0.0 days"),
+            (&auto, "-0.0", Unit::Day, "0.0 days ago"),
+            (&auto, "0", Unit::Second, "now"),
+            (&auto, "-1", Unit::Month, "last month"),
+            // Only some of the values have names of their own.
+            (&auto, "2", Unit::Day, "in 2 days"),
+            (&auto, "1", Unit::Hour, "in 1 hour"),
+            (&auto, "1.0", Unit::Day, "in 1.0 days"),
+            (&short, "-3", Unit::Hour, "3 hr. ago"),
+            (&short, "2", Unit::Quarter, "in 2 qtrs."),
+            (&short, "1", Unit::Week, "next wk."),
+        ];
+        for (options, value, unit, expected) in cases.iter() {
+            assert_eq!(
+                format(options, value, *unit),
+                *expected,
+                "{:?} {} {:?}",
+                options,
+                value,
+                unit
+            );
+        }
+    }
+}
@@ -9,6 +9,7 @@ use alloc::format;
 #[cfg(feature = "provider_serde")]
 use alloc::string::String;
 
+use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
 use displaydoc::Display;
@@ -18,7 +19,10 @@ use crate::{
     fields::{self, Field, FieldLength, FieldSymbol},
     options::{components, length, preferences},
     pattern::{Pattern, PatternItem},
-    provider::gregory::patterns::{LengthPatternsV1, PatternV1, SkeletonV1, SkeletonsV1},
+    provider::gregory::patterns::{
+        AppendItemV1, AppendItemsV1, IntervalPatternsV1, IntervalSkeletonsV1, LengthPatternsV1,
+        PatternV1, SkeletonV1, SkeletonsV1,
+    },
 };
 
 #[cfg(feature = "provider_serde")]
@@ -211,10 +215,10 @@ impl TryFrom<&str> for Skeleton {
 /// fields into skeleton-appropriate ones. For instance, in the "ja" locale the pattern "aK:mm"
 /// gets transformed into the skeleton "hmm".
 ///
-/// At the time of this writing, it's being used for applying hour cycle preferences and should not
-/// be exposed as a public API for end users.
+/// At the time of this writing, it's being used for applying hour cycle preferences, and for
+/// matching the interval patterns of a [`DateIntervalFormat`](crate::DateIntervalFormat). It
+/// should not be exposed as a public API for end users.
 #[doc(hidden)]
-#[cfg(feature = "provider_transform_internals")]
 impl From<&Pattern> for Skeleton {
     fn from(pattern: &Pattern) -> Self {
         let mut fields: SmallVec<[fields::Field; 5]> = SmallVec::new();
@@ -228,15 +232,15 @@ impl From<&Pattern> for Skeleton {
                     // Only the format varieties are used in the skeletons, the matched patterns
                     // will be more specific.
                     FieldSymbol::Month(_) => FieldSymbol::Month(fields::Month::Format),
+                    FieldSymbol::Quarter(_) => FieldSymbol::Quarter(fields::Quarter::Format),
                     FieldSymbol::Weekday(_) => FieldSymbol::Weekday(fields::Weekday::Format),
 
                     // Only flexible day periods are used in skeletons, ignore all others.
                     FieldSymbol::DayPeriod(fields::DayPeriod::AmPm)
                     | FieldSymbol::DayPeriod(fields::DayPeriod::NoonMidnight) => continue,
-                    // TODO(#487) - Flexible day periods should be included here.
-                    // FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) => {
-                    //     FieldSymbol::DayPeriod(fields::DayPeriod::Flexible)
-                    // }
+                    FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) => {
+                        FieldSymbol::DayPeriod(fields::DayPeriod::Flexible)
+                    }
 
                     // Only the H12 and H23 symbols are used in skeletons, while the patterns may
                     // contain H11 or H23 depending on the localization.
@@ -248,10 +252,12 @@ impl From<&Pattern> for Skeleton {
                     }
 
                     // Pass through all of the following preferences unchanged.
-                    FieldSymbol::Minute
+                    FieldSymbol::Era
+                    | FieldSymbol::Minute
                     | FieldSymbol::Second(_)
                     | FieldSymbol::TimeZone(_)
                     | FieldSymbol::Year(_)
+                    | FieldSymbol::Week(_)
                     | FieldSymbol::Day(_) => field.symbol,
                 };
 
@@ -327,25 +333,7 @@ impl From<fields::SymbolError> for SkeletonError {
     fn from(symbol_error: fields::SymbolError) -> Self {
         match symbol_error {
             fields::SymbolError::Invalid(ch) => Self::SymbolInvalid(ch),
-            fields::SymbolError::Unknown(byte) => {
-                // NOTE: If you remove a symbol due to it now being supported,
-                //       make sure to regenerate the test data.
-                //       https://github.com/unicode-org/icu4x/blob/main/provider/testdata/README.md
-                match byte {
-                    // TODO(#487) - Flexible day periods
-                    b'B'
-                    // TODO(#486) - Era
-                    | b'G'
-                    // TODO(#502) - Week of month
-                    | b'W'
-                    // TODO(#501) - Quarters
-                    | b'Q'
-                    // TODO (#488) - Week of year
-                    | b'w'
-                    => Self::SymbolUnimplemented(byte.into()),
-                    _ => Self::SymbolUnknown(byte.into()),
-                }
-            }
+            fields::SymbolError::Unknown(byte) => Self::SymbolUnknown(byte.into()),
         }
     }
 }
@@ -411,8 +399,8 @@ const REQUESTED_SYMBOL_MISSING: u32 = 10000;
 
 /// According to the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons)
 /// there will be a guaranteed match for a skeleton. However, with this initial implementation,
-/// there is no attempt to add on missing fields. This enum encodes the variants for the current
-/// search for a best skeleton.
+/// the matched pattern may have extra fields, or miss fields that have no appendItems pattern.
+/// This enum encodes the variants for the current search for a best skeleton.
 #[derive(Debug, PartialEq, Clone)]
 pub enum BestSkeleton<T> {
     AllFieldsMatch(T),
@@ -472,6 +460,7 @@ fn naively_apply_time_zone_name(
 ///
 /// * `skeletons` - The skeletons that will be matched against
 /// * `length_patterns` - Contains information on how to combine date and time patterns.
+/// * `append_items` - Contains information on how to add fields missing from the matched patterns.
 /// * `fields` - The desired fields to match against.
 /// * `prefer_matched_pattern` - This algorithm does some extra steps of trying to respect
 ///         the desired fields, even if the provider data doesn't completely match. This
@@ -480,6 +469,7 @@ fn naively_apply_time_zone_name(
 pub fn create_best_pattern_for_fields<'a>(
     skeletons: &'a SkeletonsV1,
     length_patterns: &LengthPatternsV1,
+    append_items: &AppendItemsV1,
     fields: &[Field],
     components: &components::Bag,
     prefer_matched_pattern: bool,
@@ -501,12 +491,23 @@ pub fn create_best_pattern_for_fields<'a>(
             BestSkeleton::AllFieldsMatch(_) => {
                 unreachable!("Logic error in implementation. AllFieldsMatch handled above.")
             }
-            BestSkeleton::MissingOrExtraFields(mut pattern) => {
+            BestSkeleton::MissingOrExtraFields(pattern) => {
+                let mut best = if prefer_matched_pattern {
+                    BestSkeleton::MissingOrExtraFields(pattern)
+                } else {
+                    append_missing_fields(skeletons, append_items, fields, pattern)
+                };
                 if date.is_empty() {
-                    naively_apply_hour_cycle_preferences(&mut pattern, &components.preferences);
-                    naively_apply_time_zone_name(&mut pattern, &components.time_zone_name);
+                    match &mut best {
+                        BestSkeleton::AllFieldsMatch(pattern)
+                        | BestSkeleton::MissingOrExtraFields(pattern) => {
+                            naively_apply_hour_cycle_preferences(pattern, &components.preferences);
+                            naively_apply_time_zone_name(pattern, &components.time_zone_name);
+                        }
+                        BestSkeleton::NoMatch => {}
+                    }
                 }
-                BestSkeleton::MissingOrExtraFields(pattern)
+                best
             }
             BestSkeleton::NoMatch => BestSkeleton::NoMatch,
         };
@@ -514,19 +515,27 @@ pub fn create_best_pattern_for_fields<'a>(
 
     // Match the date and time, and then simplify the combinatorial logic of the results into
     // an optional values of the results, and a boolean value.
-    let (date_pattern, date_missing_or_extra) =
-        match get_best_available_format_pattern(skeletons, &date, prefer_matched_pattern) {
-            BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
-            BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
-            BestSkeleton::NoMatch => (None, true),
-        };
+    let get_best_pattern = |fields: &[Field]| {
+        let best = get_best_available_format_pattern(skeletons, fields, prefer_matched_pattern);
+        match best {
+            BestSkeleton::MissingOrExtraFields(pattern) if !prefer_matched_pattern => {
+                append_missing_fields(skeletons, append_items, fields, pattern)
+            }
+            best => best,
+        }
+    };
 
-    let (mut time_pattern, time_missing_or_extra) =
-        match get_best_available_format_pattern(skeletons, &time, prefer_matched_pattern) {
-            BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
-            BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
-            BestSkeleton::NoMatch => (None, true),
-        };
+    let (date_pattern, date_missing_or_extra) = match get_best_pattern(&date) {
+        BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
+        BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
+        BestSkeleton::NoMatch => (None, true),
+    };
+
+    let (mut time_pattern, time_missing_or_extra) = match get_best_pattern(&time) {
+        BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
+        BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
+        BestSkeleton::NoMatch => (None, true),
+    };
 
     if let Some(ref mut pattern) = time_pattern {
         naively_apply_hour_cycle_preferences(pattern, &components.preferences);
@@ -535,49 +544,14 @@ pub fn create_best_pattern_for_fields<'a>(
 
     // Determine how to combine the date and time.
     let pattern: Option<Pattern> = match (date_pattern, time_pattern) {
-        (Some(date_pattern), Some(time_pattern)) => {
-            let month_field = fields
-                .iter()
-                .find(|f| matches!(f.symbol, FieldSymbol::Month(_)));
-
-            // Per UTS-35, choose a "length" pattern for combining the date and time.
-            // https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons
-            //
-            // 1. If the requested date fields include Wide month and weekday name of any length, use length::Date::Full
-            // 2. Otherwise, if the requested date fields include wide month, use length::Date::Long
-            // 3. Otherwise, if the requested date fields include abbreviated month, use length::Date::Medium
-            // 4. Otherwise use length::Date::Short
-            let length = match month_field {
-                Some(field) => match field.length {
-                    FieldLength::Wide => {
-                        let weekday = fields
-                            .iter()
-                            .find(|f| matches!(f.symbol, FieldSymbol::Weekday(_)));
-
-                        if weekday.is_some() {
-                            length::Date::Full
-                        } else {
-                            length::Date::Long
-                        }
-                    }
-                    FieldLength::Abbreviated => length::Date::Medium,
-                    _ => length::Date::Short,
-                },
-                None => length::Date::Short,
-            };
-
-            let bytes = match length {
-                length::Date::Full => &length_patterns.full,
-                length::Date::Long => &length_patterns.long,
-                length::Date::Medium => &length_patterns.medium,
-                length::Date::Short => &length_patterns.short,
-            };
-
-            Some(
-                Pattern::from_bytes_combination(bytes, date_pattern, time_pattern)
-                    .expect("Failed to create a Pattern from bytes"),
+        (Some(date_pattern), Some(time_pattern)) => Some(
+            Pattern::from_bytes_combination(
+                get_length_pattern_for_fields(length_patterns, fields),
+                date_pattern,
+                time_pattern,
             )
-        }
+            .expect("Failed to create a Pattern from bytes"),
+        ),
         (None, Some(pattern)) => Some(pattern),
         (Some(pattern), None) => Some(pattern),
         (None, None) => None,
@@ -595,12 +569,54 @@ pub fn create_best_pattern_for_fields<'a>(
     }
 }
 
-struct FieldsByType {
+/// Per UTS-35, choose a "length" pattern for combining the date and time.
+/// <https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons>
+///
+/// 1. If the requested date fields include Wide month and weekday name of any length, use length::Date::Full
+/// 2. Otherwise, if the requested date fields include wide month, use length::Date::Long
+/// 3. Otherwise, if the requested date fields include abbreviated month, use length::Date::Medium
+/// 4. Otherwise use length::Date::Short
+pub(crate) fn get_length_pattern_for_fields<'a>(
+    length_patterns: &'a LengthPatternsV1,
+    fields: &[Field],
+) -> &'a str {
+    let month_field = fields
+        .iter()
+        .find(|f| matches!(f.symbol, FieldSymbol::Month(_)));
+
+    let length = match month_field {
+        Some(field) => match field.length {
+            FieldLength::Wide => {
+                let weekday = fields
+                    .iter()
+                    .find(|f| matches!(f.symbol, FieldSymbol::Weekday(_)));
+
+                if weekday.is_some() {
+                    length::Date::Full
+                } else {
+                    length::Date::Long
+                }
+            }
+            FieldLength::Abbreviated => length::Date::Medium,
+            _ => length::Date::Short,
+        },
+        None => length::Date::Short,
+    };
+
+    match length {
+        length::Date::Full => &length_patterns.full,
+        length::Date::Long => &length_patterns.long,
+        length::Date::Medium => &length_patterns.medium,
+        length::Date::Short => &length_patterns.short,
+    }
+}
+
+pub(crate) struct FieldsByType {
     pub date: Vec<Field>,
     pub time: Vec<Field>,
 }
 
-fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
+pub(crate) fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
     let mut date = Vec::new();
     let mut time = Vec::new();
 
@@ -611,8 +627,11 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
             //  - Time examples: "EBhm" "EBhms" "Ed" "Ehm" "EHm" "Ehms" "EHms"
             //  - Date examples: "GyMMMEd" "MEd" "MMMEd" "MMMMEd" "yMEd" "yMMMEd"
             //  - Solo example: "E"
-            FieldSymbol::Year(_)
+            FieldSymbol::Era
+            | FieldSymbol::Year(_)
+            | FieldSymbol::Quarter(_)
             | FieldSymbol::Month(_)
+            | FieldSymbol::Week(_)
             | FieldSymbol::Day(_)
             | FieldSymbol::Weekday(_) => date.push(*field),
 
@@ -622,16 +641,117 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
             | FieldSymbol::Minute
             | FieldSymbol::Second(_)
             | FieldSymbol::TimeZone(_) => time.push(*field),
-            // Other components
-            // TODO(#486)
-            // FieldSymbol::Era(_) => other.push(*field),
-            // Plus others...
         };
     }
 
     FieldsByType { date, time }
 }
 
+/// Returns the appendItems pattern that adds a field with the given symbol, if there is one.
+fn get_append_item(append_items: &AppendItemsV1, symbol: FieldSymbol) -> Option<&AppendItemV1> {
+    let append_item = match symbol {
+        FieldSymbol::Era => &append_items.era,
+        FieldSymbol::Year(_) => &append_items.year,
+        FieldSymbol::Quarter(_) => &append_items.quarter,
+        FieldSymbol::Month(_) => &append_items.month,
+        FieldSymbol::Week(_) => &append_items.week,
+        FieldSymbol::Day(_) => &append_items.day,
+        FieldSymbol::Weekday(_) => &append_items.day_of_week,
+        FieldSymbol::Hour(_) => &append_items.hour,
+        FieldSymbol::Minute => &append_items.minute,
+        FieldSymbol::Second(_) => &append_items.second,
+        FieldSymbol::TimeZone(_) => &append_items.time_zone,
+        // Day periods are displayed along with the hour, and are never appended on their own.
+        FieldSymbol::DayPeriod(_) => return None,
+    };
+    if append_item.pattern.is_empty() {
+        None
+    } else {
+        Some(append_item)
+    }
+}
+
+/// Checks if two symbols display the same component of a date or time. The matched patterns
+/// may use a different symbol than the requested one, e.g. a stand-alone month, or a specific
+/// time zone name instead of the generic one used in skeletons.
+fn is_same_component(a: FieldSymbol, b: FieldSymbol) -> bool {
+    matches!(
+        (a, b),
+        (FieldSymbol::Month(_), FieldSymbol::Month(_))
+            | (FieldSymbol::Quarter(_), FieldSymbol::Quarter(_))
+            | (FieldSymbol::Weekday(_), FieldSymbol::Weekday(_))
+            | (FieldSymbol::DayPeriod(_), FieldSymbol::DayPeriod(_))
+            | (FieldSymbol::Hour(_), FieldSymbol::Hour(_))
+            | (FieldSymbol::TimeZone(_), FieldSymbol::TimeZone(_))
+    ) || a == b
+}
+
+fn contains_component(pattern: &Pattern, symbol: FieldSymbol) -> bool {
+    pattern.items().iter().any(|item| match item {
+        PatternItem::Field(field) => is_same_component(field.symbol, symbol),
+        PatternItem::Literal(_) => false,
+    })
+}
+
+/// Implements UTS 35 2.6.2.2 [Missing Skeleton Fields](https://unicode.org/reports/tr35/tr35-dates.html#Missing_Skeleton_Fields).
+///
+/// Every requested field that is missing from the matched pattern is added to it using the
+/// CLDR appendItems pattern for its type, in the canonical order of the fields. The pattern for
+/// the field itself is the best match for the field if it matches exactly, or else the field
+/// on its own. Locales without a display name for a field use `{0} {1}` instead of appendItems
+/// patterns that include the display name.
+fn append_missing_fields(
+    skeletons: &SkeletonsV1,
+    append_items: &AppendItemsV1,
+    fields: &[Field],
+    mut pattern: Pattern,
+) -> BestSkeleton<Pattern> {
+    let mut missing_fields = false;
+    for field in fields {
+        if contains_component(&pattern, field.symbol) {
+            continue;
+        }
+        let append_item = match get_append_item(append_items, field.symbol) {
+            Some(append_item) => append_item,
+            None => {
+                missing_fields = true;
+                continue;
+            }
+        };
+
+        let field_pattern = match get_best_available_format_pattern(skeletons, &[*field], false) {
+            BestSkeleton::AllFieldsMatch(field_pattern) => field_pattern,
+            _ => Pattern::from(vec![PatternItem::Field(*field)]),
+        };
+
+        let (append_pattern, display_name) = match append_item.display_name {
+            Some(ref display_name) => (&*append_item.pattern, &**display_name),
+            None if append_item.pattern.contains("{2}") => ("{0} {1}", ""),
+            None => (&*append_item.pattern, ""),
+        };
+        pattern =
+            Pattern::from_bytes_append_item(append_pattern, pattern, field_pattern, display_name)
+                .expect("Failed to create a Pattern from an append item");
+    }
+
+    // Day periods accompany the hour, and are not requested on their own.
+    let extra_fields = pattern.items().iter().any(|item| match item {
+        PatternItem::Field(field) => {
+            !matches!(field.symbol, FieldSymbol::DayPeriod(_))
+                && !fields
+                    .iter()
+                    .any(|requested| is_same_component(field.symbol, requested.symbol))
+        }
+        PatternItem::Literal(_) => false,
+    });
+
+    if missing_fields || extra_fields {
+        BestSkeleton::MissingOrExtraFields(pattern)
+    } else {
+        BestSkeleton::AllFieldsMatch(pattern)
+    }
+}
+
 /// A partial implementation of the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons).
 ///
 /// The following is implemented:
@@ -643,11 +763,8 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
 ///      then be modified to use the requested length to produce a pattern "d MMMM y".
 ///      However, fields should not be changed from numeric to text.
 ///
-/// The following is not implemented:
-///
-///  * 2.6.2.2 Missing Skeleton Fields
-///    - TODO(#586) - Using the CLDR appendItems field. Note: There is not agreement yet on how
-///      much of this step to implement. See the issue for more information.
+/// Fields that are missing from the best match are not added here, this is done when creating
+/// the final pattern in [`create_best_pattern_for_fields`].
 pub fn get_best_available_format_pattern(
     skeletons: &SkeletonsV1,
     fields: &[Field],
@@ -658,76 +775,8 @@ pub fn get_best_available_format_pattern(
     let mut closest_missing_fields = 0;
 
     for available_format_pattern in get_available_format_patterns(skeletons) {
-        let skeleton = &available_format_pattern.skeleton;
-        debug_assert!(
-            skeleton.fields_len() <= MAX_SKELETON_FIELDS as usize,
-            "The distance mechanism assumes skeletons are less than MAX_SKELETON_FIELDS in length."
-        );
-        let mut missing_fields = 0;
-        let mut distance: u32 = 0;
-        // The distance should fit into a u32.
-
-        let mut requested_fields = fields.iter().peekable();
-        let mut skeleton_fields = skeleton.fields_iter().peekable();
-        loop {
-            let next = (requested_fields.peek(), skeleton_fields.peek());
-
-            // Try to find matching symbols.
-            match next {
-                (Some(requested_field), Some(skeleton_field)) => {
-                    debug_assert!(
-                        // As of the time of this writing, stand-alone months are not in the CLDR
-                        // skeleton data. The components::Bag could produce stand-alone month fields,
-                        // but since the CLDR does not have them, only Month::Format symbols are
-                        // used for matching.
-                        skeleton_field.symbol != FieldSymbol::Month(fields::Month::StandAlone)
-                    );
-
-                    if skeleton_field.symbol > requested_field.symbol {
-                        // Keep searching for a matching skeleton field.
-                        skeleton_fields.next();
-                        distance += SKELETON_EXTRA_SYMBOL;
-                        continue;
-                    }
-
-                    if skeleton_field.symbol < requested_field.symbol {
-                        // The requested field symbol is missing from the skeleton.
-                        distance += REQUESTED_SYMBOL_MISSING;
-                        missing_fields += 1;
-                        requested_fields.next();
-                        continue;
-                    }
-
-                    distance += if requested_field == skeleton_field {
-                        NO_DISTANCE
-                    } else if requested_field.symbol != skeleton_field.symbol {
-                        SUBSTANTIAL_DIFFERENCES_DISTANCE
-                    } else if requested_field.get_length_type() != skeleton_field.get_length_type()
-                    {
-                        TEXT_VS_NUMERIC_DISTANCE
-                    } else {
-                        WIDTH_MISMATCH_DISTANCE
-                    };
-
-                    requested_fields.next();
-                    skeleton_fields.next();
-                }
-                (None, Some(_)) => {
-                    // The skeleton has additional fields that we are not matching.
-                    distance += SKELETON_EXTRA_SYMBOL;
-                    skeleton_fields.next();
-                }
-                (Some(_), None) => {
-                    // The skeleton is missing requested fields.
-                    distance += REQUESTED_SYMBOL_MISSING;
-                    requested_fields.next();
-                    missing_fields += 1;
-                }
-                (None, None) => {
-                    break;
-                }
-            }
-        }
+        let (distance, missing_fields) =
+            get_skeleton_distance(available_format_pattern.skeleton, fields);
 
         if distance < closest_distance {
             closest_format_pattern = Some(available_format_pattern.pattern);
@@ -755,29 +804,7 @@ pub fn get_best_available_format_pattern(
         #[cfg(feature = "provider_transform_internals")]
         closest_format_pattern.clone()
     } else {
-        Pattern::from(
-            closest_format_pattern
-                .items()
-                .iter()
-                .map(|item| {
-                    if let PatternItem::Field(pattern_field) = item {
-                        if let Some(requested_field) = fields
-                            .iter()
-                            .find(|field| field.symbol == pattern_field.symbol)
-                        {
-                            if requested_field.length != pattern_field.length
-                                && requested_field.get_length_type()
-                                    == pattern_field.get_length_type()
-                            {
-                                return PatternItem::Field(*requested_field);
-                            }
-                        }
-                    }
-                    // There's no match, or this is a string literal return the original item.
-                    item.clone()
-                })
-                .collect::<Vec<PatternItem>>(),
-        )
+        adjust_field_lengths(closest_format_pattern, fields)
     };
 
     if closest_distance >= SKELETON_EXTRA_SYMBOL {
@@ -787,6 +814,123 @@ pub fn get_best_available_format_pattern(
     BestSkeleton::AllFieldsMatch(expanded_pattern)
 }
 
+/// Computes the distance between a skeleton and the requested fields, as described in
+/// [`get_best_available_format_pattern`], along with the number of requested fields missing
+/// from the skeleton.
+fn get_skeleton_distance(skeleton: &Skeleton, fields: &[Field]) -> (u32, usize) {
+    debug_assert!(
+        skeleton.fields_len() <= MAX_SKELETON_FIELDS as usize,
+        "The distance mechanism assumes skeletons are less than MAX_SKELETON_FIELDS in length."
+    );
+    let mut missing_fields = 0;
+    let mut distance: u32 = 0;
+    // The distance should fit into a u32.
+
+    let mut requested_fields = fields.iter().peekable();
+    let mut skeleton_fields = skeleton.fields_iter().peekable();
+    loop {
+        let next = (requested_fields.peek(), skeleton_fields.peek());
+
+        // Try to find matching symbols.
+        match next {
+            (Some(requested_field), Some(skeleton_field)) => {
+                debug_assert!(
+                    // As of the time of this writing, stand-alone months are not in the CLDR
+                    // skeleton data. The components::Bag could produce stand-alone month fields,
+                    // but since the CLDR does not have them, only Month::Format symbols are
+                    // used for matching.
+                    skeleton_field.symbol != FieldSymbol::Month(fields::Month::StandAlone)
+                );
+
+                if skeleton_field.symbol > requested_field.symbol {
+                    // Keep searching for a matching skeleton field.
+                    skeleton_fields.next();
+                    distance += SKELETON_EXTRA_SYMBOL;
+                    continue;
+                }
+
+                if skeleton_field.symbol < requested_field.symbol {
+                    // The requested field symbol is missing from the skeleton.
+                    distance += REQUESTED_SYMBOL_MISSING;
+                    missing_fields += 1;
+                    requested_fields.next();
+                    continue;
+                }
+
+                distance += if requested_field == skeleton_field {
+                    NO_DISTANCE
+                } else if requested_field.symbol != skeleton_field.symbol {
+                    SUBSTANTIAL_DIFFERENCES_DISTANCE
+                } else if requested_field.get_length_type() != skeleton_field.get_length_type() {
+                    TEXT_VS_NUMERIC_DISTANCE
+                } else {
+                    WIDTH_MISMATCH_DISTANCE
+                };
+
+                requested_fields.next();
+                skeleton_fields.next();
+            }
+            (None, Some(_)) => {
+                // The skeleton has additional fields that we are not matching.
+                distance += SKELETON_EXTRA_SYMBOL;
+                skeleton_fields.next();
+            }
+            (Some(_), None) => {
+                // The skeleton is missing requested fields.
+                distance += REQUESTED_SYMBOL_MISSING;
+                requested_fields.next();
+                missing_fields += 1;
+            }
+            (None, None) => {
+                break;
+            }
+        }
+    }
+    (distance, missing_fields)
+}
+
+/// Changes the lengths of the fields of a matched pattern to the requested lengths. Fields are
+/// not changed from numeric to text, or from text to numeric.
+pub(crate) fn adjust_field_lengths(pattern: &Pattern, fields: &[Field]) -> Pattern {
+    pattern
+        .items()
+        .iter()
+        .map(|item| {
+            if let PatternItem::Field(pattern_field) = item {
+                if let Some(requested_field) = fields
+                    .iter()
+                    .find(|field| field.symbol == pattern_field.symbol)
+                {
+                    if requested_field.length != pattern_field.length
+                        && requested_field.get_length_type() == pattern_field.get_length_type()
+                    {
+                        return PatternItem::Field(*requested_field);
+                    }
+                }
+            }
+            // There's no match, or this is a string literal return the original item.
+            item.clone()
+        })
+        .collect()
+}
+
+/// Finds the interval patterns of the skeleton that contains exactly the requested fields, up to
+/// differences in the lengths of the fields. Unlike [`get_best_available_format_pattern`], no
+/// missing or extra fields are allowed, as the interval patterns cannot be combined with the
+/// appendItems patterns.
+pub(crate) fn get_best_interval_patterns<'a>(
+    skeletons: &'a IntervalSkeletonsV1,
+    fields: &[Field],
+) -> Option<&'a IntervalPatternsV1> {
+    skeletons
+        .0
+        .iter()
+        .map(|(skeleton, patterns)| (get_skeleton_distance(&skeleton.0, fields).0, patterns))
+        .filter(|(distance, _)| *distance < SKELETON_EXTRA_SYMBOL)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, patterns)| patterns)
+}
+
 pub fn get_available_format_patterns<'a>(
     skeletons: &'a SkeletonsV1,
 ) -> impl Iterator<Item = AvailableFormatPattern> + 'a {
@@ -797,24 +941,30 @@ pub fn get_available_format_patterns<'a>(
 mod test {
     use super::*;
 
+    use icu_locid::LanguageIdentifier;
     use icu_locid_macros::langid;
     use icu_provider::prelude::*;
 
     use crate::{
         fields::{Day, Field, FieldLength, Month, Weekday},
         options::components,
-        provider::{gregory::DatePatternsV1Marker, key::GREGORY_DATE_PATTERNS_V1},
+        provider::{gregory::DatePatternsV1Marker, key::DATE_PATTERNS_V1},
     };
 
     fn get_data_payload() -> DataPayload<'static, DatePatternsV1Marker> {
+        get_locale_data_payload(langid!("en"))
+    }
+
+    fn get_locale_data_payload(
+        langid: LanguageIdentifier,
+    ) -> DataPayload<'static, DatePatternsV1Marker> {
         let provider = icu_testdata::get_provider();
-        let langid = langid!("en");
         provider
             .load_payload(&DataRequest {
                 resource_path: ResourcePath {
-                    key: GREGORY_DATE_PATTERNS_V1,
+                    key: DATE_PATTERNS_V1,
                     options: ResourceOptions {
-                        variant: None,
+                        variant: Some("gregory".into()),
                         langid: Some(langid),
                     },
                 },
@@ -882,37 +1032,111 @@ mod test {
         };
     }
 
-    // TODO(#586) - Append items support needs to be added.
     #[test]
-    #[should_panic]
-    fn test_missing_append_items_support() {
+    fn test_append_items() {
+        let data_provider = get_data_payload();
+        let skeletons = &data_provider.get().datetime.skeletons;
+        let append_items = &data_provider.get().datetime.append_items;
+
+        let append = |append_items: &AppendItemsV1, skeleton: &str, pattern: &str| {
+            let skeleton = Skeleton::try_from(skeleton).expect("Failed to parse a skeleton");
+            append_missing_fields(
+                skeletons,
+                append_items,
+                skeleton.as_slice(),
+                Pattern::from_bytes(pattern).expect("Failed to parse a pattern"),
+            )
+        };
+
+        // Every type of field can be appended. The pattern for the field is the matched pattern
+        // if it matches exactly, e.g. "LLL" for "MMM", or else the field on its own.
+        let cases = [
+            ("Gy", "y", "y G"),
+            ("yMMM", "MMM", "MMM y"),
+            ("yQQQ", "y", "y '(quarter: 'QQQ')'"),
+            ("yMMM", "y", "y '(month: 'LLL')'"),
+            ("yw", "y", "y '(week: 'w')'"),
+            ("MMMd", "MMM", "MMM '(day: 'd')'"),
+            ("dE", "d", "d ccc"),
+            ("hm", "mm", "mm '(hour: 'h a')'"),
+            ("hm", "h a", "h a '(minute: 'm')'"),
+            ("hms", "h:mm a", "h:mm a '(second: 's')'"),
+            ("hmv", "h:mm a", "h:mm a v"),
+            // Multiple fields are appended in the canonical order.
+            ("yMMMd", "d", "d y '(month: 'LLL')'"),
+        ];
+        for &(skeleton, pattern, expected) in cases.iter() {
+            assert_eq!(
+                append(append_items, skeleton, pattern),
+                BestSkeleton::AllFieldsMatch(Pattern::from_bytes(expected).unwrap()),
+                "{} {}",
+                skeleton,
+                pattern
+            );
+        }
+
+        // Extra fields are not removed.
+        assert_eq!(
+            append(append_items, "yQQQ", "y G"),
+            BestSkeleton::MissingOrExtraFields(
+                Pattern::from_bytes("y G '(quarter: 'QQQ')'").unwrap()
+            ),
+        );
+
+        // Without the display name of a field, it is appended with a space instead.
+        let mut without_display_names = append_items.clone();
+        without_display_names.quarter.display_name = None;
+        assert_eq!(
+            append(&without_display_names, "yQQQ", "y"),
+            BestSkeleton::AllFieldsMatch(Pattern::from_bytes("y QQQ").unwrap()),
+        );
+
+        // There is no skeleton with a week, so it is appended to the year.
         let components = components::Bag {
             year: Some(components::Numeric::Numeric),
-            month: Some(components::Month::Long),
-            day: Some(components::Numeric::Numeric),
-            // This will be appended.
-            time_zone_name: Some(components::TimeZoneName::LongSpecific),
+            week: Some(components::Week::NumericWeekOfYear),
             ..Default::default()
         };
         let requested_fields = components.to_vec_fields();
-        let data_provider = get_data_payload();
+        assert_eq!(
+            create_best_pattern_for_fields(
+                skeletons,
+                &data_provider.get().datetime.length_patterns,
+                append_items,
+                &requested_fields,
+                &components,
+                false,
+            ),
+            BestSkeleton::AllFieldsMatch(Pattern::from_bytes("y '(week: 'w')'").unwrap()),
+        );
+    }
 
-        match create_best_pattern_for_fields(
-            &data_provider.get().datetime.skeletons,
-            &data_provider.get().datetime.length_patterns,
-            &requested_fields,
-            &Default::default(),
-            false,
-        ) {
-            BestSkeleton::AllFieldsMatch(available_format_pattern) => {
-                // TODO - Append items are needed here.
-                assert_eq!(
-                    available_format_pattern.to_string(),
-                    String::from("MMMM d, y vvvv")
-                )
-            }
-            best => panic!("Unexpected {:?}", best),
-        };
+    #[test]
+    fn test_append_items_localized() {
+        let data_provider = get_locale_data_payload(langid!("fr"));
+        let skeletons = &data_provider.get().datetime.skeletons;
+        let append_items = &data_provider.get().datetime.append_items;
+
+        // The display names of the fields come from the locale's dateFields.
+        let cases = [
+            ("yQQQ", "y", "y '(trimestre: 'QQQ')'"),
+            ("yw", "y", "y '(semaine: 'w')'"),
+            ("MMMd", "MMM", "MMM '(jour: 'd')'"),
+        ];
+        for &(skeleton, pattern, expected) in cases.iter() {
+            assert_eq!(
+                append_missing_fields(
+                    skeletons,
+                    append_items,
+                    Skeleton::try_from(skeleton).unwrap().as_slice(),
+                    Pattern::from_bytes(pattern).unwrap(),
+                ),
+                BestSkeleton::AllFieldsMatch(Pattern::from_bytes(expected).unwrap()),
+                "{} {}",
+                skeleton,
+                pattern
+            );
+        }
     }
 
     #[test]
@@ -959,32 +1183,22 @@ mod test {
     // https://gist.github.com/gregtatum/1d76bbdb87132f71a969a10f0c1d2d9c
 
     #[rustfmt::skip]
-    const SUPPORTED_STRING_SKELETONS: [&str; 60] = [
+    const SUPPORTED_STRING_SKELETONS: [&str; 79] = [
         "E", "dEEEE", "EHm", "EHms", "dE", "Ehm", "Ehms", "H", "HHmm", "HHmmss", "Hm", "Hms", "M",
         "MdEEEE", "MdE", "MMM", "MMMdEEEE", "MMMdE", "MMMM", "MMMMdEEEE", "MMMMdE", "MMMMd",
         "MMMMdd", "MMMd", "MMMdd", "MMd", "MMdd", "Md", "Mdd", "d", "h", "hm", "hms", "mmss", "ms",
         "y", "yM", "yMdEEEE", "yMdE", "yMM", "yMMM", "yMMMdEEEE", "yMMMdE", "yMMMM", "yMMMMdEEEE",
         "yMMMMdE", "yMMMMdcccc", "yMMMMd", "yMMMd", "yMMdd", "yMd",
-        // Timezones
-        "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
-    ];
-
-    // NOTE: If you are moving this to the SUPPORTED section, make sure to remove the match
-    //       on your symbol from impl From<fields::SymbolError> for SkeletonError
-    //       and then regenerate the test data.
-    //       https://github.com/unicode-org/icu4x/blob/main/provider/testdata/README.md
-    #[rustfmt::skip]
-    const UNSUPPORTED_STRING_SKELETONS: [&str; 19] = [
-        // TODO(#487) - Flexible day periods
-        "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
-        // TODO(#486) - Era
+        // Eras
         "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
-        // TODO(#502) - Week of month
-        "MMMMW",
-        // TODO(#501) - Quarters
+        // Quarters
         "yQ", "yQQQ", "yQQQQ",
-        // TODO (#488) - Week of year
-        "yw"
+        // Weeks
+        "MMMMW", "yw",
+        // Flexible day periods
+        "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
+        // Timezones
+        "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
     ];
 
     #[test]
@@ -1002,27 +1216,6 @@ mod test {
         }
     }
 
-    #[test]
-    fn test_unsupported_skeletons_skeletons_err() {
-        for string_skeleton in &UNSUPPORTED_STRING_SKELETONS {
-            match Skeleton::try_from(*string_skeleton) {
-                Ok(_) => {
-                    panic!(
-                        "An unsupported field is now supported, consider moving {:?} to the \
-                         supported skeletons, and ensure the skeleton is properly implemented.",
-                        string_skeleton
-                    )
-                }
-                Err(err) => match err {
-                    SkeletonError::SymbolUnimplemented(_) => {
-                        // Every skeleton should return this error.
-                    }
-                    _ => panic!("{}", err),
-                },
-            }
-        }
-    }
-
     #[test]
     fn test_skeleton_deserialization() {
         assert_eq!(
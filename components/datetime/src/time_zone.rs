@@ -2,18 +2,27 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+//! The collection of code that is needed for formatting time zones on their own.
+//! Central to this is the [`TimeZoneFormat`].
+
 use alloc::borrow::Cow;
 use alloc::format;
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt;
 
 use crate::{
-    date::TimeZoneInput, format::time_zone::FormattedTimeZone, pattern::Error as PatternError,
-    provider, DateTimeFormatError,
+    date::{TimeZoneBcp47Id, TimeZoneInput},
+    format::time_zone::FormattedTimeZone,
+    options::time_zone::{Bag, Fallback, Format, IsoFormat, IsoMinutes, IsoSeconds},
+    pattern::Error as PatternError,
+    provider::{self, time_zones::TimeZoneFormatsV1Marker},
+    DateTimeFormatError,
 };
-use crate::{format::time_zone, provider::time_zones::TimeZoneFormatsV1Marker};
 use icu_locid::{LanguageIdentifier, Locale};
 use icu_provider::prelude::*;
+use tinystr::tinystr8;
+use writeable::Writeable;
 
 use crate::fields::{FieldSymbol, TimeZone};
 use crate::pattern::{Pattern, PatternItem};
@@ -49,11 +58,11 @@ where
 }
 
 /// [`TimeZoneFormat`] uses data from the [`DataProvider`], the selected [`Locale`], and the provided
-/// pattern to collect all data necessary to format time zones into that locale.
+/// options to collect all data necessary to format time zones into that locale.
 ///
-/// The various time-zone pattern symbols specified in UTS-35 require different sets of data for
-/// formatting. As such,[`TimeZoneFormat`] will pull in only the resources needed to format the
-/// pattern that it is given upon construction.
+/// The various time zone formats specified in UTS-35 require different sets of data for
+/// formatting. As such, [`TimeZoneFormat`] will pull in only the resources needed to format the
+/// time zone with the options that it is given upon construction.
 ///
 /// For that reason, one should think of the process of formatting a time zone in two steps:
 /// first, a computationally heavy construction of [`TimeZoneFormat`], and then fast formatting
@@ -61,35 +70,35 @@ where
 ///
 /// # Examples
 ///
-/// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-/// // ```
-/// // use icu_locid::Locale;
-/// // use icu_locid::macros::langid;
-/// // use icu_datetime::TimeZoneFormat;
-/// // use icu_datetime::date::GmtOffset;
-/// // use icu_datetime::mock::time_zone::MockTimeZone;
-/// // use icu_provider::inv::InvariantDataProvider;
+/// ```
+/// use icu::locid::Locale;
+/// use icu::locid::macros::langid;
+/// use icu::datetime::TimeZoneFormat;
+/// use icu::datetime::options::time_zone;
+/// use icu::datetime::mock::time_zone::MockTimeZone;
+///
+/// let locale: Locale = langid!("en").into();
 ///
-/// // let locale: Locale = langid!("en").into();
-/// // let pattern = std::iter::empty().collect();
-/// // let provider = InvariantDataProvider;
+/// let provider = icu_testdata::get_provider();
 ///
-/// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
-/// //     .expect("Failed to create TimeZoneFormat");
+/// let options = time_zone::Bag {
+///     format: time_zone::Format::GenericNonLocationLong,
+///     ..Default::default()
+/// };
+/// let tzf = TimeZoneFormat::try_new(locale, &provider, &options)
+///     .expect("Failed to create TimeZoneFormat");
 ///
-/// // let time_zone = MockTimeZone::new(
-/// //        GmtOffset::default(),
-/// //        None,
-/// //        None,
-/// //        None,
-/// // );
+/// let mut time_zone: MockTimeZone = "-07:00".parse().expect("Failed to parse time zone.");
+/// assert_eq!(tzf.format_to_string(&time_zone), "GMT-07:00");
 ///
-/// // let value = tzf.format_to_string(&time_zone);
-/// // ```
-// TODO(#622) Make TimeZoneFormat public once we have a clean way to provide it options.
-pub(super) struct TimeZoneFormat<'data> {
-    /// The pattern to format.
-    pub(super) pattern: Pattern,
+/// time_zone.metazone_id = Some("America_Pacific".into());
+/// assert_eq!(tzf.format_to_string(&time_zone), "Pacific Time");
+/// ```
+pub struct TimeZoneFormat<'data> {
+    /// What to format when the time zone is formatted on its own.
+    pub(super) kind: TimeZoneFormatKind,
+    /// The format to use as the last resort.
+    pub(super) fallback: Fallback,
     /// The data that contains meta information about how to display content.
     pub(super) zone_formats: DataPayload<'data, provider::time_zones::TimeZoneFormatsV1Marker>,
     /// The exemplar cities for time zones.
@@ -109,30 +118,71 @@ pub(super) struct TimeZoneFormat<'data> {
         Option<DataPayload<'data, provider::time_zones::MetaZoneSpecificNamesShortV1Marker>>,
 }
 
+/// What a [`TimeZoneFormat`] formats when the time zone is formatted on its own.
+pub(super) enum TimeZoneFormatKind {
+    /// The time zone fields of a pattern, used by [`ZonedDateTimeFormat`](crate::ZonedDateTimeFormat).
+    Pattern(Pattern),
+    /// A single format selected by the options.
+    Format(Format),
+}
+
 impl<'data> TimeZoneFormat<'data> {
-    /// Constructor that selectively loads data based on what is required to
-    /// format the given pattern into the given locale.
+    /// Constructor that takes a selected [`Locale`], reference to a [`DataProvider`] and
+    /// a bag of options, then selectively loads the data required to format time zones
+    /// with the given options into the given locale.
     ///
     /// # Examples
     ///
-    /// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-    /// // ```
-    /// // use icu_locid::Locale;
-    /// // use icu_locid::macros::langid;
-    /// // use icu_datetime::TimeZoneFormat;
-    /// // use icu_datetime::mock::time_zone::MockTimeZone;
-    /// // use icu_provider::inv::InvariantDataProvider;
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::TimeZoneFormat;
+    /// use icu::datetime::options::time_zone;
+    ///
+    /// let locale: Locale = langid!("en").into();
     ///
-    /// // let locale: Locale = langid!("en").into();
-    /// // let pattern = std::iter::empty().collect();
-    /// // let provider = InvariantDataProvider;
+    /// let provider = icu_testdata::get_provider();
     ///
-    /// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider);
+    /// let options = time_zone::Bag::default();
     ///
-    /// // assert!(tzf.is_ok());
-    /// // ```
-    // TODO(#622) Make this public once TimeZoneFormat is public.
-    pub(super) fn try_new<L, ZP>(
+    /// let tzf = TimeZoneFormat::try_new(locale, &provider, &options);
+    ///
+    /// assert!(tzf.is_ok());
+    /// ```
+    pub fn try_new<L, ZP>(
+        locale: L,
+        zone_provider: &ZP,
+        options: &Bag,
+    ) -> Result<Self, DateTimeFormatError>
+    where
+        L: Into<Locale>,
+        ZP: DataProvider<'data, provider::time_zones::TimeZoneFormatsV1Marker>
+            + DataProvider<'data, provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<'data, provider::time_zones::MetaZoneGenericNamesLongV1Marker>
+            + DataProvider<'data, provider::time_zones::MetaZoneGenericNamesShortV1Marker>
+            + DataProvider<'data, provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
+            + DataProvider<'data, provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+
+        let mut time_zone_format = Self::new(
+            &locale,
+            TimeZoneFormatKind::Format(options.format),
+            options.fallback,
+            zone_provider,
+        )?;
+        time_zone_format.load_data_for_format(&locale, options.format, zone_provider)?;
+
+        Ok(time_zone_format)
+    }
+
+    /// Constructor that selectively loads data based on what is required to
+    /// format the time zone fields of the given pattern into the given locale.
+    ///
+    /// This is used by [`ZonedDateTimeFormat`](crate::ZonedDateTimeFormat), which formats the
+    /// time zone fields of its pattern with this [`TimeZoneFormat`].
+    pub(super) fn try_from_pattern<L, ZP>(
         locale: L,
         pattern: Pattern,
         zone_provider: &ZP,
@@ -149,6 +199,46 @@ impl<'data> TimeZoneFormat<'data> {
     {
         let locale = locale.into();
 
+        let zone_symbols: Vec<(TimeZone, u8)> = pattern
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                PatternItem::Field(field) => Some(field),
+                _ => None,
+            })
+            .filter_map(|field| match field.symbol {
+                FieldSymbol::TimeZone(zone) => Some((zone, u8::from(field.length))),
+                _ => None,
+            })
+            .collect();
+
+        let mut time_zone_format = Self::new(
+            &locale,
+            TimeZoneFormatKind::Pattern(pattern),
+            Fallback::LocalizedGmt,
+            zone_provider,
+        )?;
+
+        for (symbol, length) in zone_symbols {
+            // The BCP-47 time zone identifier requires no CLDR data.
+            if let Some(format) = format_for_field(symbol, length)? {
+                time_zone_format.load_data_for_format(&locale, format, zone_provider)?;
+            }
+        }
+
+        Ok(time_zone_format)
+    }
+
+    /// Creates a [`TimeZoneFormat`] with only the data that is required by all formats.
+    fn new<ZP>(
+        locale: &Locale,
+        kind: TimeZoneFormatKind,
+        fallback: Fallback,
+        zone_provider: &ZP,
+    ) -> Result<Self, DateTimeFormatError>
+    where
+        ZP: DataProvider<'data, provider::time_zones::TimeZoneFormatsV1Marker> + ?Sized,
+    {
         let zone_formats: DataPayload<TimeZoneFormatsV1Marker> = zone_provider
             .load_payload(&DataRequest {
                 resource_path: ResourcePath {
@@ -161,106 +251,86 @@ impl<'data> TimeZoneFormat<'data> {
             })?
             .take_payload()?;
 
-        let mut time_zone_format = Self {
-            pattern,
+        Ok(Self {
+            kind,
+            fallback,
             zone_formats,
             exemplar_cities: None,
             mz_generic_long: None,
             mz_generic_short: None,
             mz_specific_long: None,
             mz_specific_short: None,
-        };
+        })
+    }
 
-        let zone_symbols = time_zone_format
-            .pattern
-            .items()
-            .iter()
-            .filter_map(|item| match item {
-                PatternItem::Field(field) => Some(field),
-                _ => None,
-            })
-            .filter_map(|field| match field.symbol {
-                FieldSymbol::TimeZone(zone) => Some((u8::from(field.length), zone)),
-                _ => None,
-            });
-
-        for (length, symbol) in zone_symbols {
-            match symbol {
-                TimeZone::LowerZ => match length {
-                    1..=3 => load_resource(
-                        &locale,
-                        provider::key::TIMEZONE_SPECIFIC_NAMES_SHORT_V1,
-                        &mut time_zone_format.mz_specific_short,
-                        zone_provider,
-                    )?,
-                    4 => load_resource(
-                        &locale,
-                        provider::key::TIMEZONE_SPECIFIC_NAMES_LONG_V1,
-                        &mut time_zone_format.mz_specific_long,
-                        zone_provider,
-                    )?,
-                    _ => {
-                        return Err(DateTimeFormatError::Pattern(
-                            PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(symbol)),
-                        ))
-                    }
-                },
-                TimeZone::LowerV => match length {
-                    1 => {
-                        load_resource(
-                            &locale,
-                            provider::key::TIMEZONE_GENERIC_NAMES_SHORT_V1,
-                            &mut time_zone_format.mz_generic_short,
-                            zone_provider,
-                        )?;
-                        load_resource(
-                            &locale,
-                            provider::key::TIMEZONE_EXEMPLAR_CITIES_V1,
-                            &mut time_zone_format.exemplar_cities,
-                            zone_provider,
-                        )?;
-                    }
-                    4 => {
-                        load_resource(
-                            &locale,
-                            provider::key::TIMEZONE_GENERIC_NAMES_LONG_V1,
-                            &mut time_zone_format.mz_generic_long,
-                            zone_provider,
-                        )?;
-                        load_resource(
-                            &locale,
-                            provider::key::TIMEZONE_EXEMPLAR_CITIES_V1,
-                            &mut time_zone_format.exemplar_cities,
-                            zone_provider,
-                        )?;
-                    }
-                    _ => {
-                        return Err(DateTimeFormatError::Pattern(
-                            PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(symbol)),
-                        ))
-                    }
-                },
-                TimeZone::UpperV => match length {
-                    1 => (), // BCP-47 identifier, no CLDR-data necessary.
-                    2 => (), // IANA time-zone ID, no CLDR data necessary.
-                    3 | 4 => load_resource(
-                        &locale,
-                        provider::key::TIMEZONE_EXEMPLAR_CITIES_V1,
-                        &mut time_zone_format.exemplar_cities,
-                        zone_provider,
-                    )?,
-                    _ => {
-                        return Err(DateTimeFormatError::Pattern(
-                            PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(symbol)),
-                        ))
-                    }
-                },
-                // ISO-8601 or localized GMT formats. CLDR data is either unneeded or required by default.
-                TimeZone::LowerX | TimeZone::UpperX | TimeZone::UpperZ | TimeZone::UpperO => (),
+    /// Loads the data required by the given format, and by the formats it falls back to.
+    fn load_data_for_format<ZP>(
+        &mut self,
+        locale: &Locale,
+        format: Format,
+        zone_provider: &ZP,
+    ) -> Result<(), DateTimeFormatError>
+    where
+        ZP: DataProvider<'data, provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<'data, provider::time_zones::MetaZoneGenericNamesLongV1Marker>
+            + DataProvider<'data, provider::time_zones::MetaZoneGenericNamesShortV1Marker>
+            + DataProvider<'data, provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
+            + DataProvider<'data, provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
+            + ?Sized,
+    {
+        match format {
+            Format::GenericNonLocationLong => {
+                load_resource(
+                    locale,
+                    provider::key::TIMEZONE_GENERIC_NAMES_LONG_V1,
+                    &mut self.mz_generic_long,
+                    zone_provider,
+                )?;
+                // The generic location format is the fallback.
+                load_resource(
+                    locale,
+                    provider::key::TIMEZONE_EXEMPLAR_CITIES_V1,
+                    &mut self.exemplar_cities,
+                    zone_provider,
+                )?;
             }
+            Format::GenericNonLocationShort => {
+                load_resource(
+                    locale,
+                    provider::key::TIMEZONE_GENERIC_NAMES_SHORT_V1,
+                    &mut self.mz_generic_short,
+                    zone_provider,
+                )?;
+                // The generic location format is the fallback.
+                load_resource(
+                    locale,
+                    provider::key::TIMEZONE_EXEMPLAR_CITIES_V1,
+                    &mut self.exemplar_cities,
+                    zone_provider,
+                )?;
+            }
+            Format::GenericLocation | Format::ExemplarCity => load_resource(
+                locale,
+                provider::key::TIMEZONE_EXEMPLAR_CITIES_V1,
+                &mut self.exemplar_cities,
+                zone_provider,
+            )?,
+            Format::SpecificNonLocationLong => load_resource(
+                locale,
+                provider::key::TIMEZONE_SPECIFIC_NAMES_LONG_V1,
+                &mut self.mz_specific_long,
+                zone_provider,
+            )?,
+            Format::SpecificNonLocationShort => load_resource(
+                locale,
+                provider::key::TIMEZONE_SPECIFIC_NAMES_SHORT_V1,
+                &mut self.mz_specific_short,
+                zone_provider,
+            )?,
+            // ISO-8601 or localized GMT formats. CLDR data is either unneeded or required by default.
+            Format::LocalizedGmt | Format::Iso8601(..) => (),
         }
-
-        Ok(time_zone_format)
+        Ok(())
     }
 
     /// Takes a [`TimeZoneInput`] implementer and returns an instance of a [`FormattedTimeZone`]
@@ -268,35 +338,28 @@ impl<'data> TimeZoneFormat<'data> {
     ///
     /// # Examples
     ///
-    /// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-    /// // ```
-    /// // use icu_locid::Locale;
-    /// // use icu_locid::macros::langid;
-    /// // use icu_datetime::TimeZoneFormat;
-    /// // use icu_datetime::date::GmtOffset;
-    /// // use icu_datetime::mock::time_zone::MockTimeZone;
-    /// // use icu_provider::inv::InvariantDataProvider;
-    ///
-    /// // # let locale: Locale = langid!("en").into();
-    /// // # let pattern = std::iter::empty().collect();
-    /// // # let provider = InvariantDataProvider;
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::TimeZoneFormat;
+    /// use icu::datetime::options::time_zone;
+    /// use icu::datetime::mock::time_zone::MockTimeZone;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = icu_testdata::get_provider();
+    /// let options = time_zone::Bag {
+    ///     format: time_zone::Format::LocalizedGmt,
+    ///     ..Default::default()
+    /// };
+    /// let tzf = TimeZoneFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create TimeZoneFormat");
     ///
-    /// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
-    /// //     .expect("Failed to create TimeZoneFormat");
+    /// let time_zone: MockTimeZone = "+05:30".parse().expect("Failed to parse time zone.");
     ///
-    /// // let time_zone = MockTimeZone::new(
-    /// //        GmtOffset::default(),
-    /// //        None,
-    /// //        None,
-    /// //        None,
-    /// // );
+    /// let formatted_time_zone = tzf.format(&time_zone);
     ///
-    /// // let _ = tzf.format(&time_zone);
-    /// // ```
-    // TODO(#622) Make this public once TimeZoneFormat is public.
-    //           And remove #[allow(unused)]
-    #[allow(unused)]
-    pub(super) fn format<'l: 'data, T>(&'l self, value: &'l T) -> FormattedTimeZone<'l, T>
+    /// assert_eq!(formatted_time_zone.to_string(), "GMT+05:30");
+    /// ```
+    pub fn format<'l, T>(&'l self, value: &'l T) -> FormattedTimeZone<'l, 'data, T>
     where
         T: TimeZoneInput,
     {
@@ -311,85 +374,83 @@ impl<'data> TimeZoneFormat<'data> {
     ///
     /// # Examples
     ///
-    /// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-    /// // ```
-    /// // use icu_locid::Locale;
-    /// // use icu_locid::macros::langid;
-    /// // use icu_datetime::TimeZoneFormat;
-    /// // use icu_datetime::date::GmtOffset;
-    /// // use icu_datetime::mock::time_zone::MockTimeZone;
-    /// // use icu_provider::inv::InvariantDataProvider;
-    ///
-    /// // # let locale: Locale = langid!("en").into();
-    /// // # let pattern = std::iter::empty().collect();
-    /// // # let provider = InvariantDataProvider;
-    ///
-    /// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
-    /// //     .expect("Failed to create TimeZoneFormat");
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::TimeZoneFormat;
+    /// use icu::datetime::options::time_zone;
+    /// use icu::datetime::mock::time_zone::MockTimeZone;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = icu_testdata::get_provider();
+    /// # let options = time_zone::Bag::default();
+    /// let tzf = TimeZoneFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create TimeZoneFormat");
     ///
-    /// // let time_zone = MockTimeZone::new(
-    /// //        GmtOffset::default(),
-    /// //        None,
-    /// //        None,
-    /// //        None,
-    /// // );
+    /// let time_zone: MockTimeZone = "Z".parse().expect("Failed to parse time zone.");
     ///
-    /// // let mut buffer = String::new();
-    /// // tzf.format_to_write(&mut buffer, &time_zone)
-    /// //     .expect("Failed to write to a buffer.");
+    /// let mut buffer = String::new();
+    /// tzf.format_to_write(&mut buffer, &time_zone)
+    ///     .expect("Failed to write to a buffer.");
     ///
-    /// // let _ = format!("Time Zone: {}", buffer);
-    /// // ```
-    // TODO(#622) Make this public once TimeZoneFormat is public.
-    //            And remove #[allow(unused)]
-    #[allow(unused)]
-    pub(super) fn format_to_write(
+    /// assert_eq!(buffer, "GMT");
+    /// ```
+    pub fn format_to_write(
         &self,
         w: &mut impl core::fmt::Write,
         value: &impl TimeZoneInput,
     ) -> fmt::Result {
-        time_zone::write_pattern(self, value, w).map_err(|_| core::fmt::Error)
+        self.format(value).write_to(w)
     }
 
     /// Takes a [`TimeZoneInput`] implementer and returns a string with the formatted value.
     ///
     /// # Examples
     ///
-    /// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-    /// // ```
-    /// // use icu_locid::Locale;
-    /// // use icu_locid::macros::langid;
-    /// // use icu_datetime::TimeZoneFormat;
-    /// // use icu_datetime::date::GmtOffset;
-    /// // use icu_datetime::mock::time_zone::MockTimeZone;
-    /// // use icu_provider::inv::InvariantDataProvider;
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::TimeZoneFormat;
+    /// use icu::datetime::options::time_zone;
+    /// use icu::datetime::mock::time_zone::MockTimeZone;
+    /// # let locale: Locale = langid!("en").into();
+    /// # let provider = icu_testdata::get_provider();
+    /// let options = time_zone::Bag {
+    ///     format: time_zone::Format::Iso8601(
+    ///         time_zone::IsoFormat::UtcExtended,
+    ///         time_zone::IsoMinutes::Required,
+    ///         time_zone::IsoSeconds::Never,
+    ///     ),
+    ///     ..Default::default()
+    /// };
+    /// let tzf = TimeZoneFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create TimeZoneFormat");
     ///
-    /// // # let locale: Locale = langid!("en").into();
-    /// // # let pattern = std::iter::empty().collect();
-    /// // # let provider = InvariantDataProvider;
+    /// let time_zone: MockTimeZone = "-0800".parse().expect("Failed to parse time zone.");
     ///
-    /// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
-    /// //     .expect("Failed to create TimeZoneFormat");
-    ///
-    /// // let time_zone = MockTimeZone::new(
-    /// //        GmtOffset::default(),
-    /// //        None,
-    /// //        None,
-    /// //        None,
-    /// // );
-    ///
-    /// // let _ = tzf.format_to_string(&time_zone);
-    /// // ```
-    // TODO(#622) Make this public once TimeZoneFormat is public.
-    //           And remove #[allow(unused)]
-    #[allow(unused)]
-    pub(super) fn format_to_string(&self, value: &impl TimeZoneInput) -> String {
+    /// assert_eq!(tzf.format_to_string(&time_zone), "-08:00");
+    /// ```
+    pub fn format_to_string(&self, value: &impl TimeZoneInput) -> String {
         let mut s = String::new();
         self.format_to_write(&mut s, value)
             .expect("Failed to write to a String.");
         s
     }
 
+    /// Writes the time zone in the format of the [`Fallback`] option, which is the last
+    /// resort of all formats that require more than the GMT offset.
+    pub(super) fn fallback_format<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        time_zone: &impl TimeZoneInput,
+    ) -> Result<(), DateTimeFormatError> {
+        match self.fallback {
+            Fallback::LocalizedGmt => self.localized_gmt_format(sink, time_zone),
+            Fallback::Iso8601(format, minutes, seconds) => {
+                self.iso8601_format(sink, time_zone, format, minutes, seconds)
+            }
+        }
+    }
+
     /// Writes the time zone in generic location format as defined by the UTS-35 spec.
     /// e.g. France Time
     /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
@@ -553,7 +614,7 @@ impl<'data> TimeZoneFormat<'data> {
         .map_err(DateTimeFormatError::from)
     }
 
-    /// Writes the unknown city "Etc/Unknown" for the current locale.
+    /// Writes the unknown city "Etc/Unknown", or "unk" in BCP-47, for the current locale.
     ///
     /// If there is no localized form of "Etc/Unknown" for the current locale,
     /// returns the "Etc/Uknown" value of the `und` locale as a hard-coded string.
@@ -568,7 +629,7 @@ impl<'data> TimeZoneFormat<'data> {
             self.exemplar_cities
                 .as_ref()
                 .map(|p| p.get())
-                .and_then(|cities| cities.get("Etc/Unknown"))
+                .and_then(|cities| cities.get(&TimeZoneBcp47Id(tinystr8!("unk"))))
                 .unwrap_or(&Cow::Borrowed("Unknown")),
         )
         .map_err(DateTimeFormatError::from)
@@ -669,47 +730,6 @@ impl<'data> TimeZoneFormat<'data> {
     }
 }
 
-/// Determines which ISO-8601 format should be used to format a [`GmtOffset`](crate::date::GmtOffset).
-pub(super) enum IsoFormat {
-    /// ISO-8601 Basic Format.
-    /// Formats zero-offset numerically.
-    /// e.g. +0500, +0000
-    Basic,
-
-    /// ISO-8601 Extended Format.
-    /// Formats zero-offset numerically.
-    /// e.g. +05:00, +00:00
-    Extended,
-
-    /// ISO-8601 Basic Format.
-    /// Formats zero-offset with the ISO-8601 UTC indicator: "Z"
-    /// e.g. +0500, Z
-    UtcBasic,
-
-    /// ISO-8601 Extended Format.
-    /// Formats zero-offset with the ISO-8601 UTC indicator: "Z"
-    /// e.g. +05:00, Z
-    UtcExtended,
-}
-
-/// Whether the minutes field should be optional or required in ISO-8601 format.
-pub(super) enum IsoMinutes {
-    /// Minutes are always displayed.
-    Required,
-
-    /// Minutes are displayed only if they are non-zero.
-    Optional,
-}
-
-/// Whether the seconds field should be optional or excluded in ISO-8601 format.
-pub(super) enum IsoSeconds {
-    /// Seconds are displayed only if they are non-zero.
-    Optional,
-
-    /// Seconds are not displayed.
-    Never,
-}
-
 /// Whether a field should be zero-padded in ISO-8601 format.
 pub(super) enum ZeroPadding {
     /// Add zero-padding.
@@ -718,3 +738,198 @@ pub(super) enum ZeroPadding {
     /// Do not add zero-padding.
     Off,
 }
+
+/// Returns the time zone format of a time zone field of a pattern, according to the
+/// UTS-35 specification.
+/// https://unicode.org/reports/tr35/tr35-dates.html#dfst-zone
+///
+/// Returns `None` for the BCP-47 time zone identifier, `V`, which is not a format of
+/// [`Format`], and an error for the IANA time zone identifier, `VV`, which is not
+/// supported yet.
+pub(super) fn format_for_field(
+    symbol: TimeZone,
+    length: u8,
+) -> Result<Option<Format>, DateTimeFormatError> {
+    let format = match (symbol, length) {
+        (TimeZone::LowerZ, 1..=3) => Format::SpecificNonLocationShort,
+        (TimeZone::LowerZ, 4) => Format::SpecificNonLocationLong,
+        (TimeZone::UpperZ, 1..=3) => {
+            Format::Iso8601(IsoFormat::Basic, IsoMinutes::Required, IsoSeconds::Optional)
+        }
+        (TimeZone::UpperZ, 4) => Format::LocalizedGmt,
+        (TimeZone::UpperZ, 5) => Format::Iso8601(
+            IsoFormat::UtcExtended,
+            IsoMinutes::Required,
+            IsoSeconds::Optional,
+        ),
+        (TimeZone::UpperO, 1..=4) => Format::LocalizedGmt,
+        (TimeZone::LowerV, 1) => Format::GenericNonLocationShort,
+        (TimeZone::LowerV, 4) => Format::GenericNonLocationLong,
+        (TimeZone::UpperV, 1) => return Ok(None), // BCP-47 identifier
+        // TODO(#606): The IANA time zone identifier (`VV`) requires the canonical IANA
+        // identifier of each BCP-47 identifier, which is not in the data yet.
+        (TimeZone::UpperV, 3) => Format::ExemplarCity,
+        (TimeZone::UpperV, 4) => Format::GenericLocation,
+        (TimeZone::LowerX, 1) => {
+            Format::Iso8601(IsoFormat::UtcBasic, IsoMinutes::Optional, IsoSeconds::Never)
+        }
+        (TimeZone::LowerX, 2) => {
+            Format::Iso8601(IsoFormat::UtcBasic, IsoMinutes::Required, IsoSeconds::Never)
+        }
+        (TimeZone::LowerX, 3) => Format::Iso8601(
+            IsoFormat::UtcExtended,
+            IsoMinutes::Required,
+            IsoSeconds::Never,
+        ),
+        (TimeZone::LowerX, 4) => Format::Iso8601(
+            IsoFormat::UtcBasic,
+            IsoMinutes::Required,
+            IsoSeconds::Optional,
+        ),
+        (TimeZone::LowerX, 5) => Format::Iso8601(
+            IsoFormat::UtcExtended,
+            IsoMinutes::Required,
+            IsoSeconds::Optional,
+        ),
+        (TimeZone::UpperX, 1) => {
+            Format::Iso8601(IsoFormat::Basic, IsoMinutes::Optional, IsoSeconds::Never)
+        }
+        (TimeZone::UpperX, 2) => {
+            Format::Iso8601(IsoFormat::Basic, IsoMinutes::Required, IsoSeconds::Never)
+        }
+        (TimeZone::UpperX, 3) => {
+            Format::Iso8601(IsoFormat::Extended, IsoMinutes::Required, IsoSeconds::Never)
+        }
+        (TimeZone::UpperX, 4) => {
+            Format::Iso8601(IsoFormat::Basic, IsoMinutes::Required, IsoSeconds::Optional)
+        }
+        (TimeZone::UpperX, 5) => Format::Iso8601(
+            IsoFormat::Extended,
+            IsoMinutes::Required,
+            IsoSeconds::Optional,
+        ),
+        _ => {
+            return Err(DateTimeFormatError::Pattern(
+                PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(symbol)),
+            ))
+        }
+    };
+    Ok(Some(format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::time_zone::MockTimeZone;
+    use icu_locid_macros::langid;
+
+    #[test]
+    fn test_format() {
+        let provider = icu_testdata::get_provider();
+        let format = |options: &Bag, time_zone: &MockTimeZone| {
+            let tzf = TimeZoneFormat::try_new(langid!("en"), &provider, options)
+                .expect("Failed to create TimeZoneFormat");
+            tzf.format_to_string(time_zone)
+        };
+
+        let options = |format: Format| Bag {
+            format,
+            ..Default::default()
+        };
+        let iso_fallback = |format: Format| Bag {
+            format,
+            fallback: Fallback::Iso8601(
+                IsoFormat::UtcExtended,
+                IsoMinutes::Required,
+                IsoSeconds::Never,
+            ),
+        };
+
+        let los_angeles = MockTimeZone::new(
+            "-07:00".parse().expect("Failed to parse offset"),
+            Some(TimeZoneBcp47Id(tinystr8!("uslax"))),
+            Some("America_Pacific".into()),
+            Some(tinystr8!("daylight")),
+        );
+        let offset_only: MockTimeZone = "-07:00".parse().expect("Failed to parse time zone");
+        let utc: MockTimeZone = "Z".parse().expect("Failed to parse time zone");
+
+        let cases = [
+            (
+                options(Format::GenericNonLocationLong),
+                &los_angeles,
+                "Pacific Time",
+            ),
+            (options(Format::GenericNonLocationShort), &los_angeles, "PT"),
+            (
+                options(Format::GenericLocation),
+                &los_angeles,
+                "Los Angeles Time",
+            ),
+            (
+                options(Format::SpecificNonLocationLong),
+                &los_angeles,
+                "Pacific Daylight Time",
+            ),
+            (
+                options(Format::SpecificNonLocationShort),
+                &los_angeles,
+                "PDT",
+            ),
+            (options(Format::ExemplarCity), &los_angeles, "Los Angeles"),
+            (options(Format::LocalizedGmt), &los_angeles, "GMT-07:00"),
+            (
+                options(Format::Iso8601(
+                    IsoFormat::Basic,
+                    IsoMinutes::Required,
+                    IsoSeconds::Never,
+                )),
+                &los_angeles,
+                "-0700",
+            ),
+            // The formats fall back to the fallback format without the data they require.
+            (
+                options(Format::GenericNonLocationLong),
+                &offset_only,
+                "GMT-07:00",
+            ),
+            (
+                options(Format::SpecificNonLocationShort),
+                &offset_only,
+                "GMT-07:00",
+            ),
+            (options(Format::ExemplarCity), &offset_only, "Unknown City"),
+            (options(Format::GenericLocation), &utc, "GMT"),
+            (
+                iso_fallback(Format::GenericNonLocationLong),
+                &offset_only,
+                "-07:00",
+            ),
+            (iso_fallback(Format::GenericLocation), &utc, "Z"),
+            // The fallback format is not used when the selected format succeeds.
+            (
+                iso_fallback(Format::GenericNonLocationLong),
+                &los_angeles,
+                "Pacific Time",
+            ),
+        ];
+        for (options, time_zone, expected) in cases.iter() {
+            assert_eq!(format(options, time_zone), *expected, "{:?}", options);
+        }
+    }
+
+    #[test]
+    fn test_format_for_field() {
+        assert!(matches!(format_for_field(TimeZone::UpperV, 1), Ok(None)));
+        assert!(matches!(
+            format_for_field(TimeZone::UpperV, 2),
+            Err(DateTimeFormatError::Pattern(
+                PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(TimeZone::UpperV))
+            ))
+        ));
+        assert!(matches!(
+            format_for_field(TimeZone::UpperV, 3),
+            Ok(Some(Format::ExemplarCity))
+        ));
+    }
+}
@@ -0,0 +1,466 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Resolving a time zone identifier and an instant to the data needed to format the time zone.
+//!
+//! [`TimeZoneResolver`] computes the [`GmtOffset`] and time variant of a time zone at a given
+//! instant from the transitions of the IANA time zone database, and its metazone from the
+//! CLDR metazone data. The result is a [`ResolvedTimeZone`], which implements [`TimeZoneInput`].
+//!
+//! Time zones are identified by their BCP-47 identifier, such as "uslax". [`IanaToBcp47Mapper`]
+//! finds the BCP-47 identifier of an IANA time zone identifier, such as "America/Los_Angeles".
+
+use crate::arithmetic;
+use crate::date::{GmtOffset, TimeZoneBcp47Id, TimeZoneInput};
+use crate::error::DateTimeFormatError;
+use crate::provider::{
+    self,
+    time_zones::{
+        DateRuleV1, IanaToBcp47MapV1Marker, LocalTimeTypeV1, MetaZonePeriodsV1Marker,
+        TimeZoneTransitionsV1Marker, TransitionRuleV1, TransitionsV1,
+    },
+};
+use alloc::string::{String, ToString};
+use icu_provider::prelude::*;
+use tinystr::{tinystr8, TinyStr8};
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// [`IanaToBcp47Mapper`] finds the BCP-47 identifier of an IANA time zone identifier,
+/// including the aliases of the IANA time zone database.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::time_zone_resolver::IanaToBcp47Mapper;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let mapper = IanaToBcp47Mapper::try_new(&provider)
+///     .expect("Failed to create IanaToBcp47Mapper instance.");
+///
+/// assert_eq!(
+///     mapper.get("America/Los_Angeles").map(|id| id.as_str()),
+///     Some("uslax")
+/// );
+/// assert_eq!(mapper.get("US/Pacific").map(|id| id.as_str()), Some("uslax"));
+/// assert_eq!(mapper.get("Mars/Olympus_Mons"), None);
+/// ```
+pub struct IanaToBcp47Mapper<'data> {
+    data: DataPayload<'data, IanaToBcp47MapV1Marker>,
+}
+
+impl<'data> IanaToBcp47Mapper<'data> {
+    /// Constructor that loads the mapping from the data provider.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, DateTimeFormatError>
+    where
+        D: DataProvider<'data, IanaToBcp47MapV1Marker> + ?Sized,
+    {
+        let data = data_provider
+            .load_payload(&DataRequest::from(provider::key::TIMEZONE_IANA_TO_BCP47_V1))?
+            .take_payload()?;
+        Ok(Self { data })
+    }
+
+    /// Returns the BCP-47 identifier of the given IANA time zone identifier,
+    /// or `None` if the identifier is unknown.
+    pub fn get(&self, iana_id: &str) -> Option<TimeZoneBcp47Id> {
+        self.data.get().get(iana_id).copied()
+    }
+}
+
+/// [`TimeZoneResolver`] is the main structure of the [`icu_datetime`] component
+/// for finding the GMT offset, time variant and metazone of a time zone at an instant.
+///
+/// It loads the offset transitions of the time zones and the metazone periods
+/// from the data provider once, and can resolve any number of time zones and instants.
+///
+/// [`icu_datetime`]: crate
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::date::{TimeZoneBcp47Id, TimeZoneInput};
+/// use icu::datetime::TimeZoneResolver;
+/// use tinystr::tinystr8;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let resolver = TimeZoneResolver::try_new(&provider)
+///     .expect("Failed to create TimeZoneResolver instance.");
+///
+/// // America/Los_Angeles at 2021-07-01T12:00:00Z
+/// let time_zone = resolver
+///     .resolve(&TimeZoneBcp47Id(tinystr8!("uslax")), 1_625_140_800)
+///     .expect("Failed to resolve the time zone.");
+///
+/// assert_eq!(time_zone.gmt_offset().raw_offset_seconds(), -7 * 60 * 60);
+/// assert_eq!(time_zone.metazone_id(), Some("America_Pacific"));
+/// assert_eq!(time_zone.time_variant().map(|v| v.as_str()), Some("daylight"));
+/// ```
+pub struct TimeZoneResolver<'data> {
+    transitions: DataPayload<'data, TimeZoneTransitionsV1Marker>,
+    metazone_periods: DataPayload<'data, MetaZonePeriodsV1Marker>,
+}
+
+impl<'data> TimeZoneResolver<'data> {
+    /// Constructor that loads the time zone transitions and metazone periods from the
+    /// data provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::TimeZoneResolver;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let resolver = TimeZoneResolver::try_new(&provider);
+    ///
+    /// assert!(resolver.is_ok());
+    /// ```
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, DateTimeFormatError>
+    where
+        D: DataProvider<'data, TimeZoneTransitionsV1Marker>
+            + DataProvider<'data, MetaZonePeriodsV1Marker>
+            + ?Sized,
+    {
+        let transitions = data_provider
+            .load_payload(&DataRequest::from(provider::key::TIMEZONE_TRANSITIONS_V1))?
+            .take_payload()?;
+        let metazone_periods = data_provider
+            .load_payload(&DataRequest::from(
+                provider::key::TIMEZONE_METAZONE_PERIODS_V1,
+            ))?
+            .take_payload()?;
+        Ok(Self {
+            transitions,
+            metazone_periods,
+        })
+    }
+
+    /// Resolves the time zone with the given BCP-47 identifier at the given instant,
+    /// in seconds since the Unix epoch.
+    ///
+    /// The metazone of the result is `None` if the time zone did not use a metazone at the
+    /// instant. Returns an error if there is no transition data for the time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::date::{TimeZoneBcp47Id, TimeZoneInput};
+    /// use icu::datetime::TimeZoneResolver;
+    /// use tinystr::tinystr8;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let resolver = TimeZoneResolver::try_new(&provider)
+    ///     .expect("Failed to create TimeZoneResolver instance.");
+    ///
+    /// // Europe/London
+    /// let london = TimeZoneBcp47Id(tinystr8!("gblon"));
+    ///
+    /// // 1970-07-01T12:00:00Z, during the British Standard Time experiment.
+    /// let time_zone = resolver
+    ///     .resolve(&london, 15_681_600)
+    ///     .expect("Failed to resolve the time zone.");
+    /// assert_eq!(time_zone.gmt_offset().raw_offset_seconds(), 60 * 60);
+    /// assert_eq!(time_zone.metazone_id(), Some("Europe_Central"));
+    ///
+    /// // 2021-01-01T12:00:00Z
+    /// let time_zone = resolver
+    ///     .resolve(&london, 1_609_502_400)
+    ///     .expect("Failed to resolve the time zone.");
+    /// assert_eq!(time_zone.gmt_offset().raw_offset_seconds(), 0);
+    /// assert_eq!(time_zone.metazone_id(), Some("GMT"));
+    ///
+    /// assert!(resolver
+    ///     .resolve(&TimeZoneBcp47Id(tinystr8!("mrolm")), 1_609_502_400)
+    ///     .is_err());
+    /// ```
+    pub fn resolve(
+        &self,
+        time_zone_id: &TimeZoneBcp47Id,
+        seconds_since_epoch: i64,
+    ) -> Result<ResolvedTimeZone, DateTimeFormatError> {
+        let transitions = self.transitions.get().get(time_zone_id).ok_or_else(|| {
+            DateTimeFormatError::UnknownTimeZone(time_zone_id.as_str().to_string())
+        })?;
+        let local_time_type =
+            local_time_type_at(transitions, seconds_since_epoch).ok_or_else(|| {
+                DateTimeFormatError::UnknownTimeZone(time_zone_id.as_str().to_string())
+            })?;
+
+        let metazone_id = self
+            .metazone_periods
+            .get()
+            .get(time_zone_id)
+            .and_then(|periods| {
+                periods.iter().find(|period| {
+                    period.from.map_or(true, |from| from <= seconds_since_epoch)
+                        && period.to.map_or(true, |to| seconds_since_epoch < to)
+                })
+            })
+            .map(|period| period.metazone_id.to_string());
+
+        Ok(ResolvedTimeZone {
+            gmt_offset: GmtOffset::try_new(local_time_type.offset)?,
+            time_zone_id: *time_zone_id,
+            metazone_id,
+            time_variant: if local_time_type.is_dst {
+                tinystr8!("daylight")
+            } else {
+                tinystr8!("standard")
+            },
+        })
+    }
+}
+
+/// A time zone at an instant, as resolved by a [`TimeZoneResolver`].
+///
+/// It implements [`TimeZoneInput`], so it can be formatted with a
+/// [`TimeZoneFormat`](crate::TimeZoneFormat).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedTimeZone {
+    gmt_offset: GmtOffset,
+    time_zone_id: TimeZoneBcp47Id,
+    metazone_id: Option<String>,
+    time_variant: TinyStr8,
+}
+
+impl TimeZoneInput for ResolvedTimeZone {
+    fn gmt_offset(&self) -> GmtOffset {
+        self.gmt_offset
+    }
+
+    fn time_zone_id(&self) -> Option<&TimeZoneBcp47Id> {
+        Some(&self.time_zone_id)
+    }
+
+    fn metazone_id(&self) -> Option<&str> {
+        self.metazone_id.as_deref()
+    }
+
+    fn time_variant(&self) -> Option<&TinyStr8> {
+        Some(&self.time_variant)
+    }
+}
+
+/// Returns the local time type of the time zone at the given instant.
+///
+/// Instants before the first transition use the first local time type, and instants after
+/// the last transition use the rule of the time zone if it has one, as in a TZif file.
+fn local_time_type_at(transitions: &TransitionsV1, seconds: i64) -> Option<LocalTimeTypeV1> {
+    let index = match transitions.transition_times.binary_search(&seconds) {
+        Ok(index) => index + 1,
+        Err(index) => index,
+    };
+    if index == transitions.transition_times.len() {
+        if let Some(rule) = transitions.rule {
+            return Some(local_time_type_from_rule(&rule, seconds));
+        }
+    }
+    let local_time_type_index = match index.checked_sub(1) {
+        Some(index) => *transitions.transition_types.get(index)? as usize,
+        None => 0,
+    };
+    transitions
+        .local_time_types
+        .get(local_time_type_index)
+        .copied()
+}
+
+/// Returns the local time type of the time zone rule at the given instant.
+fn local_time_type_from_rule(rule: &TransitionRuleV1, seconds: i64) -> LocalTimeTypeV1 {
+    let standard = LocalTimeTypeV1 {
+        offset: rule.std_offset,
+        is_dst: false,
+    };
+    let dst = match rule.dst {
+        Some(dst) => dst,
+        None => return standard,
+    };
+    let year = arithmetic::epoch_days_to_iso_year(
+        (seconds + rule.std_offset as i64).div_euclid(SECONDS_PER_DAY),
+    );
+    let start = transition_time(year, &dst.start, rule.std_offset);
+    let end = transition_time(year, &dst.end, dst.offset);
+    let is_dst = if start < end {
+        // Northern hemisphere: daylight saving time is in the middle of the year.
+        start <= seconds && seconds < end
+    } else {
+        // Southern hemisphere: daylight saving time spans the start of the year.
+        !(end <= seconds && seconds < start)
+    };
+    if is_dst {
+        LocalTimeTypeV1 {
+            offset: dst.offset,
+            is_dst: true,
+        }
+    } else {
+        standard
+    }
+}
+
+/// Returns the instant of the transition of a date rule in the given year, in seconds since
+/// the Unix epoch, where `offset` is the GMT offset of the local time of the rule.
+fn transition_time(year: i64, rule: &DateRuleV1, offset: i32) -> i64 {
+    let first_day = arithmetic::iso_date_to_epoch_days(year, rule.month, 1);
+    let next_month_first_day = if rule.month == 12 {
+        arithmetic::iso_date_to_epoch_days(year + 1, 1, 1)
+    } else {
+        arithmetic::iso_date_to_epoch_days(year, rule.month + 1, 1)
+    };
+    // The Unix epoch was a Thursday, and weekdays in the rule are counted from Sunday.
+    let first_weekday = (first_day + 4).rem_euclid(7);
+    let mut day = first_day
+        + (rule.weekday as i64 - first_weekday).rem_euclid(7)
+        + (rule.week as i64 - 1) * 7;
+    // Week 5 is the last week of the month, which may be the fourth.
+    while day >= next_month_first_day {
+        day -= 7;
+    }
+    day * SECONDS_PER_DAY + rule.time as i64 - offset as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::time_zones::DstRuleV1;
+
+    /// The rule of America/Los_Angeles: PST8PDT,M3.2.0,M11.1.0
+    const PACIFIC: TransitionRuleV1 = TransitionRuleV1 {
+        std_offset: -8 * 60 * 60,
+        dst: Some(DstRuleV1 {
+            offset: -7 * 60 * 60,
+            start: DateRuleV1 {
+                month: 3,
+                week: 2,
+                weekday: 0,
+                time: 2 * 60 * 60,
+            },
+            end: DateRuleV1 {
+                month: 11,
+                week: 1,
+                weekday: 0,
+                time: 2 * 60 * 60,
+            },
+        }),
+    };
+
+    /// The rule of Australia/Sydney: AEST-10AEDT,M10.1.0,M4.1.0/3
+    const SYDNEY: TransitionRuleV1 = TransitionRuleV1 {
+        std_offset: 10 * 60 * 60,
+        dst: Some(DstRuleV1 {
+            offset: 11 * 60 * 60,
+            start: DateRuleV1 {
+                month: 10,
+                week: 1,
+                weekday: 0,
+                time: 2 * 60 * 60,
+            },
+            end: DateRuleV1 {
+                month: 4,
+                week: 1,
+                weekday: 0,
+                time: 3 * 60 * 60,
+            },
+        }),
+    };
+
+    #[test]
+    fn test_rules() {
+        let cases = [
+            // 2050-03-13T09:59:59Z, 2050-03-13T10:00:00Z: start of daylight saving time.
+            (&PACIFIC, 2_530_778_399, -8, false),
+            (&PACIFIC, 2_530_778_400, -7, true),
+            // 2050-11-06T08:59:59Z, 2050-11-06T09:00:00Z: end of daylight saving time.
+            (&PACIFIC, 2_551_337_999, -7, true),
+            (&PACIFIC, 2_551_338_000, -8, false),
+            // 2050-01-01T00:00:00Z: daylight saving time at the start of the year.
+            (&SYDNEY, 2_524_608_000, 11, true),
+            // 2050-04-02T15:59:59Z, 2050-04-02T16:00:00Z: end of daylight saving time.
+            (&SYDNEY, 2_532_527_999, 11, true),
+            (&SYDNEY, 2_532_528_000, 10, false),
+            // 2050-10-01T15:59:59Z, 2050-10-01T16:00:00Z: start of daylight saving time.
+            (&SYDNEY, 2_548_252_799, 10, false),
+            (&SYDNEY, 2_548_252_800, 11, true),
+        ];
+        for (rule, seconds, hours, is_dst) in cases.iter() {
+            let local_time_type = local_time_type_from_rule(rule, *seconds);
+            assert_eq!(local_time_type.offset, hours * 60 * 60, "{}", seconds);
+            assert_eq!(local_time_type.is_dst, *is_dst, "{}", seconds);
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let provider = icu_testdata::get_provider();
+        let resolver = TimeZoneResolver::try_new(&provider).expect("Failed to load data");
+
+        let cases = [
+            // 2021-01-01T12:00:00Z
+            (
+                tinystr8!("usnyc"),
+                1_609_502_400,
+                -5,
+                "America_Eastern",
+                "standard",
+            ),
+            (tinystr8!("jptyo"), 1_609_502_400, 9, "Japan", "standard"),
+            (
+                tinystr8!("ausyd"),
+                1_609_502_400,
+                11,
+                "Australia_Eastern",
+                "daylight",
+            ),
+            // 2021-07-01T12:00:00Z
+            (
+                tinystr8!("usnyc"),
+                1_625_140_800,
+                -4,
+                "America_Eastern",
+                "daylight",
+            ),
+            (tinystr8!("gblon"), 1_625_140_800, 1, "GMT", "daylight"),
+            (
+                tinystr8!("ausyd"),
+                1_625_140_800,
+                10,
+                "Australia_Eastern",
+                "standard",
+            ),
+            // 2050-07-01T12:00:00Z, after the last transition in the TZif files.
+            (
+                tinystr8!("uslax"),
+                2_540_289_600,
+                -7,
+                "America_Pacific",
+                "daylight",
+            ),
+            (tinystr8!("gblon"), 2_540_289_600, 1, "GMT", "daylight"),
+        ];
+        for (time_zone_id, seconds, hours, metazone_id, time_variant) in cases.iter() {
+            let time_zone = resolver
+                .resolve(&TimeZoneBcp47Id(*time_zone_id), *seconds)
+                .expect("Failed to resolve the time zone");
+            assert_eq!(
+                time_zone.gmt_offset().raw_offset_seconds(),
+                hours * 60 * 60,
+                "{} {}",
+                time_zone_id,
+                seconds
+            );
+            assert_eq!(
+                time_zone.time_zone_id(),
+                Some(&TimeZoneBcp47Id(*time_zone_id))
+            );
+            assert_eq!(time_zone.metazone_id(), Some(*metazone_id));
+            assert_eq!(
+                time_zone.time_variant().map(|v| v.as_str()),
+                Some(*time_variant)
+            );
+        }
+    }
+}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::string::String;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_locid::Locale;
 use icu_provider::{DataProvider, DataRequest, ResourceOptions, ResourcePath};
 
@@ -19,14 +20,13 @@ use crate::{
     DateTimeFormatError,
 };
 
-// TODO(#622) link [`TimeZoneFormat`] once it is public.
-/// The composition of [`DateTimeFormat`] and `TimeZoneFormat`.
+/// The composition of [`DateTimeFormat`] and [`TimeZoneFormat`].
 ///
 /// [`ZonedDateTimeFormat`] uses data from the [`DataProvider`]s, the selected [`Locale`], and the
 /// provided pattern to collect all data necessary to format a datetime with time zones into that locale.
 ///
 /// The various pattern symbols specified in UTS-35 require different sets of data for formatting.
-/// As such, `TimeZoneFormat` will pull in only the resources it needs to format that pattern
+/// As such, [`TimeZoneFormat`] will pull in only the resources it needs to format that pattern
 /// that is derived from the provided [`DateTimeFormatOptions`].
 ///
 /// For that reason, one should think of the process of formatting a zoned datetime in two steps:
@@ -102,6 +102,9 @@ impl<'data> ZonedDateTimeFormat<'data> {
         L: Into<Locale>,
         DP: DataProvider<'data, provider::gregory::DatePatternsV1Marker>
             + DataProvider<'data, provider::gregory::DateSymbolsV1Marker>
+            + DataProvider<'data, provider::week_data::WeekDataV1Marker>
+            + DataProvider<'data, provider::day_period_rules::DayPeriodRulesV1Marker>
+            + DataProvider<'data, DecimalSymbolsV1Marker>
             + ?Sized,
         ZP: DataProvider<'data, provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<'data, provider::time_zones::ExemplarCitiesV1Marker>
@@ -112,22 +115,21 @@ impl<'data> ZonedDateTimeFormat<'data> {
             + ?Sized,
     {
         let locale = locale.into();
+        let calendar = DateTimeFormat::resolve_calendar(&locale)?;
+        let options = DateTimeFormat::resolve_options(&locale, options);
         let pattern_data: icu_provider::DataPayload<'_, provider::gregory::DatePatternsV1Marker> =
             date_provider
                 .load_payload(&DataRequest {
                     resource_path: ResourcePath {
-                        key: provider::key::GREGORY_DATE_PATTERNS_V1,
-                        options: ResourceOptions {
-                            variant: None,
-                            langid: Some(locale.clone().into()),
-                        },
+                        key: provider::key::DATE_PATTERNS_V1,
+                        options: DateTimeFormat::calendar_options(&locale, calendar),
                     },
                 })?
                 .take_payload()?;
 
         let pattern = pattern_data
             .get()
-            .get_pattern_for_options(options)?
+            .get_pattern_for_options(&options)?
             .unwrap_or_default();
 
         let requires_data = datetime::analyze_pattern(&pattern, true)
@@ -138,7 +140,40 @@ impl<'data> ZonedDateTimeFormat<'data> {
                 date_provider
                     .load_payload(&DataRequest {
                         resource_path: ResourcePath {
-                            key: provider::key::GREGORY_DATE_SYMBOLS_V1,
+                            key: provider::key::DATE_SYMBOLS_V1,
+                            options: DateTimeFormat::calendar_options(&locale, calendar),
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        let week_data = if datetime::requires_week_data(&pattern) {
+            Some(
+                date_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::WEEK_DATA_V1,
+                            options: ResourceOptions {
+                                variant: None,
+                                langid: Some(locale.clone().into()),
+                            },
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        let day_period_rules = if datetime::requires_day_period_rules(&pattern) {
+            Some(
+                date_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::DAY_PERIOD_RULES_V1,
                             options: ResourceOptions {
                                 variant: None,
                                 langid: Some(locale.clone().into()),
@@ -151,8 +186,19 @@ impl<'data> ZonedDateTimeFormat<'data> {
             None
         };
 
-        let datetime_format = DateTimeFormat::new(locale, pattern, symbols_data);
-        let time_zone_format = TimeZoneFormat::try_new(
+        let fixed_decimal_format =
+            DateTimeFormat::load_fixed_decimal_format(&locale, date_provider)?;
+
+        let datetime_format = DateTimeFormat::new(
+            locale,
+            pattern,
+            symbols_data,
+            week_data,
+            day_period_rules,
+            fixed_decimal_format,
+            calendar,
+        );
+        let time_zone_format = TimeZoneFormat::try_from_pattern(
             datetime_format.locale.clone(),
             datetime_format.pattern.clone(),
             zone_provider,
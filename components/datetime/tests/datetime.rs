@@ -13,11 +13,14 @@ use icu_datetime::{
 };
 use icu_datetime::{
     provider::{
+        day_period_rules::DayPeriodRulesV1Marker,
         gregory::{DatePatternsV1Marker, DateSymbolsV1Marker},
-        key::{GREGORY_DATE_PATTERNS_V1, GREGORY_DATE_SYMBOLS_V1},
+        key::{DATE_PATTERNS_V1, DATE_SYMBOLS_V1},
+        week_data::WeekDataV1Marker,
     },
     DateTimeFormat,
 };
+use icu_decimal::provider::{DecimalSymbolsV1, DecimalSymbolsV1Marker};
 use icu_locid::{LanguageIdentifier, Locale};
 use icu_provider::prelude::*;
 use icu_provider::struct_provider::StructProvider;
@@ -55,6 +58,41 @@ impl<'data> DataProvider<'data, DatePatternsV1Marker> for MultiKeyStructProvider
     }
 }
 
+impl<'data> DataProvider<'data, WeekDataV1Marker> for MultiKeyStructProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, WeekDataV1Marker>, icu_provider::DataError> {
+        Err(icu_provider::DataError::MissingResourceKey(
+            req.resource_path.key,
+        ))
+    }
+}
+
+impl<'data> DataProvider<'data, DayPeriodRulesV1Marker> for MultiKeyStructProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DayPeriodRulesV1Marker>, icu_provider::DataError> {
+        // The rules are not overridden by any test, so load them from the test data.
+        icu_testdata::get_provider().load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, DecimalSymbolsV1Marker> for MultiKeyStructProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DecimalSymbolsV1Marker>, icu_provider::DataError> {
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(DecimalSymbolsV1::default())),
+        })
+    }
+}
+
 fn test_fixture(fixture_name: &str) {
     let provider = icu_testdata::get_provider();
 
@@ -148,9 +186,9 @@ fn test_dayperiod_patterns() {
         let mut patterns_data: DataPayload<DatePatternsV1Marker> = provider
             .load_payload(&DataRequest {
                 resource_path: ResourcePath {
-                    key: GREGORY_DATE_PATTERNS_V1,
+                    key: DATE_PATTERNS_V1,
                     options: ResourceOptions {
-                        variant: None,
+                        variant: Some("gregory".into()),
                         langid: Some(langid.clone()),
                     },
                 },
@@ -164,9 +202,9 @@ fn test_dayperiod_patterns() {
         let symbols_data: DataPayload<DateSymbolsV1Marker> = provider
             .load_payload(&DataRequest {
                 resource_path: ResourcePath {
-                    key: GREGORY_DATE_SYMBOLS_V1,
+                    key: DATE_SYMBOLS_V1,
                     options: ResourceOptions {
-                        variant: None,
+                        variant: Some("gregory".into()),
                         langid: Some(langid.clone()),
                     },
                 },
@@ -187,11 +225,11 @@ fn test_dayperiod_patterns() {
                         });
                         let local_provider = MultiKeyStructProvider {
                             symbols: StructProvider {
-                                key: GREGORY_DATE_SYMBOLS_V1,
+                                key: DATE_SYMBOLS_V1,
                                 data: symbols_data.clone(),
                             },
                             patterns: StructProvider {
-                                key: GREGORY_DATE_PATTERNS_V1,
+                                key: DATE_PATTERNS_V1,
                                 data: patterns_data.clone(),
                             },
                         };
@@ -236,9 +274,9 @@ fn test_time_zone_patterns() {
         let mut patterns_data: DataPayload<DatePatternsV1Marker> = date_provider
             .load_payload(&DataRequest {
                 resource_path: ResourcePath {
-                    key: GREGORY_DATE_PATTERNS_V1,
+                    key: DATE_PATTERNS_V1,
                     options: ResourceOptions {
-                        variant: None,
+                        variant: Some("gregory".into()),
                         langid: Some(langid.clone()),
                     },
                 },
@@ -249,9 +287,9 @@ fn test_time_zone_patterns() {
         let symbols_data: DataPayload<DateSymbolsV1Marker> = date_provider
             .load_payload(&DataRequest {
                 resource_path: ResourcePath {
-                    key: GREGORY_DATE_SYMBOLS_V1,
+                    key: DATE_SYMBOLS_V1,
                     options: ResourceOptions {
-                        variant: None,
+                        variant: Some("gregory".into()),
                         langid: Some(langid.clone()),
                     },
                 },
@@ -274,11 +312,11 @@ fn test_time_zone_patterns() {
                 });
                 let local_provider = MultiKeyStructProvider {
                     symbols: StructProvider {
-                        key: GREGORY_DATE_SYMBOLS_V1,
+                        key: DATE_SYMBOLS_V1,
                         data: symbols_data.clone(),
                     },
                     patterns: StructProvider {
-                        key: GREGORY_DATE_PATTERNS_V1,
+                        key: DATE_PATTERNS_V1,
                         data: patterns_data.clone(),
                     },
                 };
@@ -383,3 +421,455 @@ fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_format_gregorian_date() {
+    use icu_calendar::Date;
+    use icu_datetime::options::length;
+    use icu_locid_macros::langid;
+
+    let options = DateTimeFormatOptions::Length(length::Bag {
+        date: Some(length::Date::Medium),
+        time: None,
+        ..Default::default()
+    });
+
+    let locale: Locale = langid!("en").into();
+    let provider = icu_testdata::get_provider();
+    let dtf = DateTimeFormat::try_new(locale, &provider, &options).unwrap();
+
+    let date = Date::new_gregorian_date_from_integers(5, 1, 2021).unwrap();
+    assert_eq!(dtf.format_to_string(&date), "Jan 5, 2021");
+
+    let date = Date::new_iso_date_from_integers(5, 1, 2021)
+        .unwrap()
+        .to_calendar(icu_calendar::Gregorian);
+    assert_eq!(dtf.format(&date).to_string(), "Jan 5, 2021");
+}
+
+#[test]
+fn test_format_gregorian_datetime() {
+    use icu_calendar::DateTime;
+    use icu_datetime::options::length;
+    use icu_locid_macros::langid;
+
+    let options = DateTimeFormatOptions::Length(length::Bag {
+        date: Some(length::Date::Medium),
+        time: Some(length::Time::Short),
+        ..Default::default()
+    });
+
+    let locale: Locale = langid!("en").into();
+    let provider = icu_testdata::get_provider();
+    let dtf = DateTimeFormat::try_new(locale, &provider, &options).unwrap();
+
+    let mut datetime =
+        DateTime::new_gregorian_datetime_from_integers(2020, 9, 12, 12, 35, 0).unwrap();
+    assert_eq!(dtf.format_to_string(&datetime), "Sep 12, 2020, 12:35 PM");
+
+    datetime.add_hours(12);
+    assert_eq!(dtf.format_to_string(&datetime), "Sep 13, 2020, 12:35 AM");
+}
+
+#[test]
+fn test_format_era() {
+    use icu_calendar::Date;
+    use icu_datetime::options::components;
+    use icu_locid_macros::langid;
+
+    let format = |era| {
+        let options = DateTimeFormatOptions::Components(components::Bag {
+            era: Some(era),
+            year: Some(components::Numeric::Numeric),
+            month: Some(components::Month::Short),
+            day: Some(components::Numeric::Numeric),
+            ..Default::default()
+        });
+        let locale: Locale = langid!("en").into();
+        let provider = icu_testdata::get_provider();
+        DateTimeFormat::try_new(locale, &provider, &options).unwrap()
+    };
+
+    let bce = Date::new_gregorian_date_from_integers(5, 1, -43).unwrap();
+    let ce = Date::new_gregorian_date_from_integers(5, 1, 2021).unwrap();
+
+    let dtf = format(components::Text::Short);
+    assert_eq!(dtf.format_to_string(&bce), "Jan 5, 44 BC");
+    assert_eq!(dtf.format_to_string(&ce), "Jan 5, 2021 AD");
+
+    let dtf = format(components::Text::Long);
+    assert_eq!(dtf.format_to_string(&bce), "Jan 5, 44 Before Christ");
+
+    let dtf = format(components::Text::Narrow);
+    assert_eq!(dtf.format_to_string(&ce), "Jan 5, 2021 A");
+}
+
+#[test]
+fn test_format_quarter() {
+    use icu_calendar::Date;
+    use icu_datetime::options::components;
+
+    let format = |locale: &str, quarter| {
+        let options = DateTimeFormatOptions::Components(components::Bag {
+            year: Some(components::Numeric::Numeric),
+            quarter: Some(quarter),
+            ..Default::default()
+        });
+        let locale: Locale = locale.parse().unwrap();
+        let provider = icu_testdata::get_provider();
+        DateTimeFormat::try_new(locale, &provider, &options).unwrap()
+    };
+
+    let date = Date::new_gregorian_date_from_integers(31, 12, 2021).unwrap();
+
+    let dtf = format("en", components::Quarter::Short);
+    assert_eq!(dtf.format_to_string(&date), "Q4 2021");
+
+    let dtf = format("en", components::Quarter::Long);
+    assert_eq!(dtf.format_to_string(&date), "4th quarter 2021");
+
+    // The short thirteenth month of the Coptic calendar is in the fourth quarter.
+    let date = Date::new_coptic_date_from_integers(5, 13, 1737).unwrap();
+
+    let dtf = format("en-u-ca-coptic", components::Quarter::Short);
+    assert_eq!(dtf.format_to_string(&date), "Q4 1737 ERA1");
+
+    let dtf = format("en-u-ca-coptic", components::Quarter::Long);
+    assert_eq!(dtf.format_to_string(&date), "4th quarter 1737 ERA1");
+}
+
+#[test]
+fn test_format_calendars() {
+    use icu_calendar::{types::MonthCode, Buddhist, Date};
+    use icu_datetime::options::length;
+
+    let format = |locale: &str| {
+        let options = DateTimeFormatOptions::Length(length::Bag {
+            date: Some(length::Date::Medium),
+            time: None,
+            ..Default::default()
+        });
+        let locale: Locale = locale.parse().unwrap();
+        let provider = icu_testdata::get_provider();
+        DateTimeFormat::try_new(locale, &provider, &options).unwrap()
+    };
+
+    let date = Date::new_gregorian_date_from_integers(5, 1, 2021)
+        .unwrap()
+        .to_calendar(Buddhist);
+    let dtf = format("en-u-ca-buddhist");
+    assert_eq!(dtf.format_to_string(&date), "Jan 5, 2564 BE");
+
+    let dtf = format("en-u-ca-hebrew");
+    let date = Date::new_hebrew_date(1, MonthCode(tinystr8!("M05L")), 5782).unwrap();
+    assert_eq!(dtf.format_to_string(&date), "1 Adar I 5782");
+
+    // Adar is called Adar II in leap years, following Adar I.
+    let date = Date::new_hebrew_date(1, MonthCode(tinystr8!("M06")), 5782).unwrap();
+    assert_eq!(dtf.format_to_string(&date), "1 Adar II 5782");
+    let date = Date::new_hebrew_date(1, MonthCode(tinystr8!("M06")), 5783).unwrap();
+    assert_eq!(dtf.format_to_string(&date), "1 Adar 5783");
+    let date = Date::new_hebrew_date(1, MonthCode(tinystr8!("M07")), 5782).unwrap();
+    assert_eq!(dtf.format_to_string(&date), "1 Nisan 5782");
+
+    // ISO dates use the symbols and patterns of the Gregorian calendar.
+    let date = Date::new_iso_date_from_integers(5, 1, 2021).unwrap();
+    let dtf = format("en-u-ca-iso8601");
+    assert_eq!(dtf.format_to_string(&date), "Jan 5, 2021");
+}
+
+#[test]
+fn test_format_mismatched_calendar() {
+    use icu_calendar::{Date, DateTime};
+    use icu_datetime::mock::{datetime::MockDateTime, zoned_datetime::MockZonedDateTime};
+    use icu_datetime::options::length;
+    use icu_datetime::{DateTimeFormatError, ZonedDateTimeFormat};
+
+    let options = DateTimeFormatOptions::Length(length::Bag {
+        date: Some(length::Date::Medium),
+        time: None,
+        ..Default::default()
+    });
+    let provider = icu_testdata::get_provider();
+    let format = |locale: &str| {
+        let locale: Locale = locale.parse().unwrap();
+        DateTimeFormat::try_new(locale, &provider, &options).unwrap()
+    };
+
+    // Dates of other calendars are converted to the calendar of the format.
+    let date = Date::new_gregorian_date_from_integers(5, 1, 2021).unwrap();
+    let datetime = DateTime::new_gregorian_datetime_from_integers(2021, 1, 5, 12, 0, 0).unwrap();
+    let mock = MockDateTime::try_new(2021, 0, 4, 12, 0, 0).unwrap();
+    let cases = [
+        ("th-u-ca-buddhist", "5 ม.ค. 2564"),
+        ("ja-u-ca-japanese", "令和3年1月5日"),
+    ];
+    for &(locale, expected) in cases.iter() {
+        let dtf = format(locale);
+        assert_eq!(dtf.format_to_string(&date), expected, "{}", locale);
+        assert_eq!(dtf.format(&date).to_string(), expected, "{}", locale);
+        assert_eq!(dtf.format_to_string(&datetime), expected, "{}", locale);
+        assert_eq!(dtf.format_to_string(&mock), expected, "{}", locale);
+        assert_eq!(dtf.format(&mock).to_string(), expected, "{}", locale);
+    }
+
+    let locale: Locale = "ja-u-ca-japanese".parse().unwrap();
+    let zdtf = ZonedDateTimeFormat::try_new(locale, &provider, &provider, &options).unwrap();
+    let zoned_datetime: MockZonedDateTime = "2021-01-05T12:00:00.000+09:00".parse().unwrap();
+    assert_eq!(zdtf.format_to_string(&zoned_datetime), "令和3年1月5日");
+    assert_eq!(zdtf.format(&zoned_datetime).to_string(), "令和3年1月5日");
+
+    let locale: Locale = "en-u-ca-chinese".parse().unwrap();
+    assert!(matches!(
+        DateTimeFormat::try_new(locale, &provider, &options),
+        Err(DateTimeFormatError::UnsupportedCalendar(_))
+    ));
+}
+
+#[test]
+fn test_format_unicode_extensions() {
+    use icu_calendar::{Buddhist, DateTime};
+    use icu_datetime::options::{length, preferences};
+
+    let format = |locale: &str, hour_cycle: Option<preferences::HourCycle>| {
+        let options = DateTimeFormatOptions::Length(length::Bag {
+            date: None,
+            time: Some(length::Time::Short),
+            preferences: hour_cycle.map(|hour_cycle| preferences::Bag {
+                hour_cycle: Some(hour_cycle),
+            }),
+        });
+        let locale: Locale = locale.parse().unwrap();
+        let provider = icu_testdata::get_provider();
+        DateTimeFormat::try_new(locale, &provider, &options).unwrap()
+    };
+
+    let datetime = DateTime::new_gregorian_datetime_from_integers(2020, 9, 12, 0, 35, 0).unwrap();
+    let cases = [
+        ("en", None, "12:35 AM"),
+        ("en-u-hc-h11", None, "0:35 AM"),
+        ("en-u-hc-h23", None, "00:35"),
+        ("en-u-hc-h24", None, "24:35"),
+        // An hour cycle set in the preferences takes precedence over the locale.
+        ("en-u-hc-h23", Some(preferences::HourCycle::H12), "12:35 AM"),
+        ("th-u-nu-thai", None, "๐๐:๓๕"),
+        ("th-u-hc-h12-nu-thai", None, "๑๒:๓๕ ก่อนเที่ยง"),
+    ];
+    for (locale, hour_cycle, expected) in IntoIterator::into_iter(cases) {
+        let dtf = format(locale, hour_cycle);
+        assert_eq!(dtf.format_to_string(&datetime), expected, "{}", locale);
+    }
+
+    let options = DateTimeFormatOptions::Length(length::Bag {
+        date: Some(length::Date::Medium),
+        time: Some(length::Time::Short),
+        ..Default::default()
+    });
+    let locale: Locale = "en-u-ca-buddhist-hc-h23".parse().unwrap();
+    let provider = icu_testdata::get_provider();
+    let dtf = DateTimeFormat::try_new(locale, &provider, &options).unwrap();
+    assert_eq!(
+        dtf.format_to_string(&datetime.to_calendar(Buddhist)),
+        "Sep 12, 2563 BE, 00:35"
+    );
+}
+
+#[test]
+fn test_format_to_parts() {
+    use icu_calendar::DateTime;
+    use icu_datetime::options::length;
+    use icu_datetime::DateTimePartKind;
+    use icu_locid_macros::langid;
+
+    let options = DateTimeFormatOptions::Length(length::Bag {
+        date: Some(length::Date::Medium),
+        time: Some(length::Time::Short),
+        ..Default::default()
+    });
+
+    let locale: Locale = langid!("en").into();
+    let provider = icu_testdata::get_provider();
+    let dtf = DateTimeFormat::try_new(locale, &provider, &options).unwrap();
+
+    let datetime = DateTime::new_gregorian_datetime_from_integers(2020, 9, 12, 12, 35, 0).unwrap();
+    let mut output = String::new();
+    let parts = dtf.format(&datetime).write_to_parts(&mut output).unwrap();
+    assert_eq!(output, "Sep 12, 2020, 12:35 PM");
+    let parts: Vec<_> = parts
+        .into_iter()
+        .map(|part| (part.kind, &output[part.range]))
+        .collect();
+    assert_eq!(
+        parts,
+        [
+            (DateTimePartKind::Month, "Sep"),
+            (DateTimePartKind::Literal, " "),
+            (DateTimePartKind::Day, "12"),
+            (DateTimePartKind::Literal, ", "),
+            (DateTimePartKind::Year, "2020"),
+            (DateTimePartKind::Literal, ", "),
+            (DateTimePartKind::Hour, "12"),
+            (DateTimePartKind::Literal, ":"),
+            (DateTimePartKind::Minute, "35"),
+            (DateTimePartKind::Literal, " "),
+            (DateTimePartKind::DayPeriod, "PM"),
+        ]
+    );
+}
+
+#[test]
+fn test_format_zoned_to_parts() {
+    use icu_datetime::options::length;
+    use icu_datetime::DateTimePartKind;
+    use icu_locid_macros::langid;
+
+    let options = DateTimeFormatOptions::Length(length::Bag {
+        date: None,
+        time: Some(length::Time::Long),
+        ..Default::default()
+    });
+
+    let locale: Locale = langid!("en").into();
+    let provider = icu_testdata::get_provider();
+    let zdtf = ZonedDateTimeFormat::try_new(locale, &provider, &provider, &options).unwrap();
+
+    let datetime: MockZonedDateTime = "2020-10-14T13:21:00+05:00".parse().unwrap();
+    let mut output = String::new();
+    let parts = zdtf.format(&datetime).write_to_parts(&mut output).unwrap();
+    assert_eq!(output, zdtf.format_to_string(&datetime));
+
+    // The parts cover the whole output.
+    assert_eq!(parts.first().unwrap().range.start, 0);
+    assert_eq!(parts.last().unwrap().range.end, output.len());
+    for pair in parts.windows(2) {
+        assert_eq!(pair[0].range.end, pair[1].range.start);
+    }
+
+    let time_zone = parts.last().unwrap();
+    assert_eq!(time_zone.kind, DateTimePartKind::TimeZone);
+    assert!(output[time_zone.range.clone()].starts_with("GMT"));
+}
+
+#[test]
+fn test_parse() {
+    use icu_calendar::{Date, DateTime};
+    use icu_datetime::options::length;
+    use icu_datetime::{DateTimeParseError, DateTimePartKind};
+
+    let format = |locale: &str, date, time| {
+        let options = DateTimeFormatOptions::Length(length::Bag {
+            date,
+            time,
+            ..Default::default()
+        });
+        let locale: Locale = locale.parse().unwrap();
+        let provider = icu_testdata::get_provider();
+        DateTimeFormat::try_new(locale, &provider, &options).unwrap()
+    };
+
+    let dtf = format("fr", Some(length::Date::Medium), Some(length::Time::Short));
+    let parsed = dtf.parse("  5 janv. 2021 14:03, etc.").unwrap();
+    let expected = DateTime::new_gregorian_datetime_from_integers(2021, 1, 5, 14, 3, 0).unwrap();
+    assert_eq!(parsed.date, Some(expected.date));
+    assert_eq!(parsed.time, Some(expected.time));
+    assert_eq!(parsed.span, 2..20);
+
+    assert_eq!(
+        dtf.parse("30 févr. 2021 14:03"),
+        Err(DateTimeParseError::InvalidValue {
+            kind: DateTimePartKind::Day,
+            position: 0,
+        })
+    );
+    assert_eq!(
+        dtf.parse("5 janv. 2021 25:03"),
+        Err(DateTimeParseError::InvalidValue {
+            kind: DateTimePartKind::Hour,
+            position: 13,
+        })
+    );
+    assert_eq!(
+        dtf.parse("5 janv. 2021"),
+        Err(DateTimeParseError::Mismatch {
+            expected: DateTimePartKind::Hour,
+            position: 12,
+        })
+    );
+
+    // Case, spacing and punctuation are lenient.
+    let dtf = format("en", Some(length::Date::Medium), Some(length::Time::Short));
+    let parsed = dtf.parse("sep 12 2020 12:35pm").unwrap();
+    let expected = DateTime::new_gregorian_datetime_from_integers(2020, 9, 12, 12, 35, 0).unwrap();
+    assert_eq!(parsed.date, Some(expected.date));
+    assert_eq!(parsed.time, Some(expected.time));
+
+    // Two-digit years are in the century from 1950 to 2049.
+    let dtf = format("en", Some(length::Date::Short), None);
+    let parsed = dtf.parse("1/5/21").unwrap();
+    assert_eq!(
+        parsed.date,
+        Some(Date::new_gregorian_date_from_integers(5, 1, 2021).unwrap())
+    );
+    assert_eq!(parsed.time, None);
+
+    // The day of the week must match the date.
+    let dtf = format("en", Some(length::Date::Full), None);
+    let parsed = dtf.parse("Tuesday, January 5, 2021").unwrap();
+    assert_eq!(
+        parsed.date,
+        Some(Date::new_gregorian_date_from_integers(5, 1, 2021).unwrap())
+    );
+    assert_eq!(
+        dtf.parse("Monday, January 5, 2021"),
+        Err(DateTimeParseError::InvalidValue {
+            kind: DateTimePartKind::Weekday,
+            position: 0,
+        })
+    );
+
+    let dtf = format("en-u-ca-buddhist", Some(length::Date::Medium), None);
+    assert!(matches!(
+        dtf.parse("Jan 5, 2564 BE"),
+        Err(DateTimeParseError::UnsupportedCalendar(_))
+    ));
+}
+
+#[test]
+fn test_parse_round_trip() {
+    use icu_calendar::DateTime;
+    use icu_datetime::options::length;
+
+    let datetimes = [
+        DateTime::new_gregorian_datetime_from_integers(2021, 1, 5, 14, 3, 9).unwrap(),
+        DateTime::new_gregorian_datetime_from_integers(1999, 12, 31, 0, 0, 0).unwrap(),
+        DateTime::new_gregorian_datetime_from_integers(2020, 2, 29, 12, 30, 59).unwrap(),
+    ];
+    let date_lengths = [
+        length::Date::Full,
+        length::Date::Long,
+        length::Date::Medium,
+        length::Date::Short,
+    ];
+    let provider = icu_testdata::get_provider();
+
+    for locale in ["ar", "bn", "en", "es", "fr", "ja", "ru", "sr", "th", "tr"].iter() {
+        for date_length in date_lengths.iter() {
+            let options = DateTimeFormatOptions::Length(length::Bag {
+                date: Some(*date_length),
+                time: Some(length::Time::Medium),
+                ..Default::default()
+            });
+            let locale: Locale = locale.parse().unwrap();
+            let dtf = DateTimeFormat::try_new(locale.clone(), &provider, &options).unwrap();
+            for datetime in datetimes.iter() {
+                let formatted = dtf.format_to_string(datetime);
+                let parsed = dtf
+                    .parse(&formatted)
+                    .unwrap_or_else(|e| panic!("{}: {:?}: {}", locale, formatted, e));
+                assert_eq!(parsed.date.as_ref(), Some(&datetime.date), "{}", formatted);
+                assert_eq!(parsed.time, Some(datetime.time), "{}", formatted);
+                assert_eq!(parsed.span, 0..formatted.len(), "{}", formatted);
+            }
+        }
+    }
+}
@@ -2,11 +2,12 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use icu_datetime::date::TimeZoneBcp47Id;
 use serde::{Deserialize, Serialize};
 use tinystr::TinyStr8;
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimeZoneConfig {
-    pub time_zone_id: Option<String>,
+    pub time_zone_id: Option<TimeZoneBcp47Id>,
     pub metazone_id: Option<String>,
     pub time_variant: Option<TinyStr8>,
 }
@@ -0,0 +1,203 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Checks that the `write_len` of formatted dates, times and relative times matches the length
+//! of their output, for all of the test locales.
+
+use fixed_decimal::FixedDecimal;
+use icu_calendar::{DateTime, Gregorian};
+use icu_datetime::mock::zoned_datetime::MockZonedDateTime;
+use icu_datetime::options::{length, relative_time};
+use icu_datetime::relative_time::Unit;
+use icu_datetime::{
+    DateTimeFormat, DateTimeFormatOptions, RelativeTimeFormat, ZonedDateTimeFormat,
+};
+use icu_locid::Locale;
+use writeable::assert_writeable_len_exact;
+
+const DATE_LENGTHS: &[Option<length::Date>] = &[
+    Some(length::Date::Full),
+    Some(length::Date::Long),
+    Some(length::Date::Medium),
+    Some(length::Date::Short),
+    None,
+];
+
+/// Datetimes in every month, before and after noon, in both eras and with years of
+/// different numbers of digits.
+fn sample_datetimes() -> Vec<DateTime<Gregorian>> {
+    let years = [-43, 7, 1999, 2021, 12345];
+    let mut datetimes = vec![];
+    for month in 1..=12 {
+        for (i, hour) in [0, 9, 12, 23].iter().enumerate() {
+            let year = years[(month as usize + i) % years.len()];
+            let day = 1 + (month * 7 + i as u8) % 28;
+            datetimes.push(
+                DateTime::new_gregorian_datetime_from_integers(year, month, day, *hour, 5, 59)
+                    .unwrap(),
+            );
+        }
+    }
+    datetimes
+}
+
+fn sample_zoned_datetimes() -> Vec<MockZonedDateTime> {
+    let offsets = ["Z", "+05:00", "-07:00", "+05:45", "-03:30"];
+    sample_datetimes()
+        .iter()
+        // The mock parser reads four-digit years of the common era.
+        .filter(|datetime| {
+            datetime.date.year().era.0.as_str() == "ce" && datetime.date.year().number < 10000
+        })
+        .zip(offsets.iter().cycle())
+        .map(|(datetime, offset)| {
+            format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}",
+                datetime.date.year().number,
+                datetime.date.month().number,
+                datetime.date.day_of_month().0,
+                u8::from(datetime.time.hour),
+                u8::from(datetime.time.minute),
+                u8::from(datetime.time.second),
+                offset
+            )
+            .parse()
+            .unwrap()
+        })
+        .collect()
+}
+
+fn test_locales() -> Vec<Locale> {
+    icu_testdata::metadata::load()
+        .unwrap()
+        .package_metadata
+        .locales
+        .into_iter()
+        .map(Locale::from)
+        .collect()
+}
+
+#[test]
+fn test_datetime_write_len() {
+    let provider = icu_testdata::get_provider();
+    let datetimes = sample_datetimes();
+    // Longer time lengths have a time zone, which DateTimeFormat does not support.
+    let time_lengths = [Some(length::Time::Medium), Some(length::Time::Short), None];
+
+    for locale in test_locales() {
+        for date in DATE_LENGTHS.iter() {
+            for time in time_lengths.iter() {
+                if date.is_none() && time.is_none() {
+                    continue;
+                }
+                let options = DateTimeFormatOptions::Length(length::Bag {
+                    date: *date,
+                    time: *time,
+                    ..Default::default()
+                });
+                let dtf = DateTimeFormat::try_new(locale.clone(), &provider, &options).unwrap();
+                for datetime in datetimes.iter() {
+                    assert_writeable_len_exact!(
+                        &dtf.format(datetime),
+                        "{} {:?} {:?} {:?}",
+                        locale,
+                        date,
+                        time,
+                        datetime
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_zoned_datetime_write_len() {
+    let provider = icu_testdata::get_provider();
+    let datetimes = sample_zoned_datetimes();
+    let time_lengths = [Some(length::Time::Full), Some(length::Time::Long)];
+
+    for locale in test_locales() {
+        for date in DATE_LENGTHS.iter() {
+            for time in time_lengths.iter() {
+                let options = DateTimeFormatOptions::Length(length::Bag {
+                    date: *date,
+                    time: *time,
+                    ..Default::default()
+                });
+                let zdtf =
+                    ZonedDateTimeFormat::try_new(locale.clone(), &provider, &provider, &options)
+                        .unwrap();
+                for datetime in datetimes.iter() {
+                    assert_writeable_len_exact!(
+                        &zdtf.format(datetime),
+                        "{} {:?} {:?} {:?}",
+                        locale,
+                        date,
+                        time,
+                        datetime
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_relative_time_write_len() {
+    let provider = icu_testdata::get_provider();
+    let units = [
+        Unit::Year,
+        Unit::Quarter,
+        Unit::Month,
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+    ];
+    // Zero and one have names of their own in many locales, unlike their fractional forms.
+    let values = [
+        "0", "-0", "0.0", "1", "-1", "2", "-2", "1.5", "-3", "1000", "-123456",
+    ];
+    let mut tested_formats = 0;
+
+    for locale in test_locales() {
+        for width in [
+            relative_time::Width::Long,
+            relative_time::Width::Short,
+            relative_time::Width::Narrow,
+        ]
+        .iter()
+        {
+            for numeric in [relative_time::Numeric::Always, relative_time::Numeric::Auto].iter() {
+                let options = relative_time::Bag {
+                    width: *width,
+                    numeric: *numeric,
+                };
+                // The test data does not have relative times for every test locale.
+                let rtf = match RelativeTimeFormat::try_new(locale.clone(), &provider, &options) {
+                    Ok(rtf) => rtf,
+                    Err(_) => continue,
+                };
+                tested_formats += 1;
+                for unit in units.iter() {
+                    for value in values.iter() {
+                        let decimal: FixedDecimal = value.parse().unwrap();
+                        assert_writeable_len_exact!(
+                            &rtf.format(decimal, *unit),
+                            "{} {:?} {:?} {:?} {}",
+                            locale,
+                            width,
+                            numeric,
+                            unit,
+                            value
+                        );
+                    }
+                }
+            }
+        }
+    }
+    assert_ne!(tested_formats, 0, "no relative time data");
+}
@@ -62,4 +62,29 @@ impl<'l> Writeable for FormattedFixedDecimal<'l> {
         }
         Ok(())
     }
+
+    fn write_len(&self) -> writeable::LengthHint {
+        let mut len = 0;
+        if let Some(affixes) = self.get_affixes() {
+            len += affixes.prefix.len() + affixes.suffix.len();
+        }
+        let range = self.value.magnitude_range();
+        let upper_magnitude = *range.end();
+        for m in range.rev() {
+            if m == -1 {
+                len += self.symbols.decimal_separator.len();
+            }
+            // The digits of some numbering systems take more than one byte in UTF-8.
+            len += self.symbols.digits[self.value.digit_at(m) as usize].len_utf8();
+            if grouper::check(
+                upper_magnitude,
+                m,
+                self.options.grouping_strategy,
+                &self.symbols.grouping_sizes,
+            ) {
+                len += self.symbols.grouping_separator.len();
+            }
+        }
+        writeable::LengthHint::Exact(len)
+    }
 }
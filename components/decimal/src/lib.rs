@@ -72,8 +72,11 @@ mod sign_selector;
 pub use error::Error as FixedDecimalFormatError;
 pub use format::FormattedFixedDecimal;
 
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 use fixed_decimal::FixedDecimal;
-use icu_locid::Locale;
+use icu_locid::extensions::unicode::Key;
+use icu_locid::{LanguageIdentifier, Locale};
 use icu_provider::prelude::*;
 
 /// A formatter for [`FixedDecimal`], rendering decimal digits in an i18n-friendly way.
@@ -94,6 +97,32 @@ pub struct FixedDecimalFormat<'data> {
 
 impl<'data> FixedDecimalFormat<'data> {
     /// Creates a new [`FixedDecimalFormat`] from locale data and an options bag.
+    ///
+    /// The digits are those of the numbering system given by the `-u-nu` keyword of the locale,
+    /// if the data provider supports it, and of the default numbering system of the locale
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::FixedDecimalFormat;
+    /// use icu::locid::Locale;
+    /// use writeable::Writeable;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let fixed_decimal = 1000007.into();
+    ///
+    /// let locale: Locale = "bn-u-nu-latn".parse().expect("Failed to parse locale");
+    /// let fdf = FixedDecimalFormat::try_new(locale, &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    /// assert_eq!("10,00,007", fdf.format(&fixed_decimal).writeable_to_string());
+    ///
+    /// // Numbering systems that are not supported are ignored
+    /// let locale: Locale = "bn-u-nu-roman".parse().expect("Failed to parse locale");
+    /// let fdf = FixedDecimalFormat::try_new(locale, &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    /// assert_eq!("১০,০০,০০৭", fdf.format(&fixed_decimal).writeable_to_string());
+    /// ```
     pub fn try_new<
         T: Into<Locale>,
         D: DataProvider<'data, provider::DecimalSymbolsV1Marker> + ?Sized,
@@ -102,17 +131,32 @@ impl<'data> FixedDecimalFormat<'data> {
         data_provider: &D,
         options: options::FixedDecimalFormatOptions,
     ) -> Result<Self, FixedDecimalFormatError> {
-        let symbols = data_provider
-            .load_payload(&DataRequest {
+        let locale = locale.into();
+        let numbering_system = Key::from_bytes(b"nu")
+            .ok()
+            .and_then(|key| locale.get_unicode_extension(&key))
+            .map(|value| Cow::Owned(value.to_string()));
+        let langid: LanguageIdentifier = locale.into();
+        let load = |variant| {
+            data_provider.load_payload(&DataRequest {
                 resource_path: ResourcePath {
                     key: provider::key::SYMBOLS_V1,
                     options: ResourceOptions {
-                        variant: None,
-                        langid: Some(locale.into().into()),
+                        variant,
+                        langid: Some(langid.clone()),
                     },
                 },
-            })?
-            .take_payload()?;
+            })
+        };
+        let response = match numbering_system {
+            Some(variant) => match load(Some(variant)) {
+                // Fall back to the default numbering system
+                Err(DataError::MissingResourceOptions(_)) => load(None),
+                response => response,
+            },
+            None => load(None),
+        };
+        let symbols = response?.take_payload()?;
         Ok(Self { options, symbols })
     }
 
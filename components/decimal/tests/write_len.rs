@@ -0,0 +1,71 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Checks that the `write_len` of formatted decimals matches the length of their output, for
+//! all of the test locales.
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::options::{FixedDecimalFormatOptions, GroupingStrategy, SignDisplay};
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use rand::SeedableRng;
+use rand_distr::{Distribution, Triangular};
+use rand_pcg::Lcg64Xsh32;
+use writeable::assert_writeable_len_exact;
+
+/// Random numbers between -1e9 and 1e9, weighted around 0, with up to three fraction digits.
+fn sample_decimals() -> Vec<FixedDecimal> {
+    let rng = Lcg64Xsh32::seed_from_u64(2020);
+    let dist = Triangular::new(-1e9, 1e9, 0.0).unwrap();
+    dist.sample_iter(rng)
+        .take(200)
+        .enumerate()
+        .map(|(i, v)| {
+            FixedDecimal::from(v as isize)
+                .multiplied_pow10(-((i % 4) as i16))
+                .unwrap()
+        })
+        .chain(vec![FixedDecimal::from(0), FixedDecimal::from(-1)])
+        .collect()
+}
+
+#[test]
+fn test_write_len() {
+    let provider = icu_testdata::get_provider();
+    let locales = icu_testdata::metadata::load()
+        .unwrap()
+        .package_metadata
+        .locales;
+    let decimals = sample_decimals();
+
+    for langid in locales {
+        let locale: Locale = langid.into();
+        for grouping_strategy in [
+            GroupingStrategy::Auto,
+            GroupingStrategy::Never,
+            GroupingStrategy::Min2,
+        ]
+        .iter()
+        {
+            for sign_display in [SignDisplay::Auto, SignDisplay::Always, SignDisplay::Never].iter()
+            {
+                let options = FixedDecimalFormatOptions {
+                    grouping_strategy: *grouping_strategy,
+                    sign_display: *sign_display,
+                };
+                let fdf = FixedDecimalFormat::try_new(locale.clone(), &provider, options).unwrap();
+                for decimal in decimals.iter() {
+                    assert_writeable_len_exact!(
+                        &fdf.format(decimal),
+                        "{} {:?} {:?} {}",
+                        locale,
+                        grouping_strategy,
+                        sign_display,
+                        decimal
+                    );
+                }
+            }
+        }
+    }
+}
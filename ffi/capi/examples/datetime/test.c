@@ -0,0 +1,88 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#include "../../include/ICU4XDateTimeFormat.h"
+#include "../../include/ICU4XDateTimeParts.h"
+#include <string.h>
+#include <stdio.h>
+
+int main() {
+    ICU4XLocale* locale = ICU4XLocale_create("en", 2);
+    const char* path = "../../../../provider/testdata/data/json/";
+    ICU4XCreateDataProviderResult result = ICU4XDataProvider_create_fs(path, strlen(path));
+    if (!result.success) {
+        printf("Failed to create FsDataProvider\n");
+        return 1;
+    }
+    ICU4XDataProvider* provider = result.provider;
+
+    ICU4XCreateGregorianDateTimeResult datetime_result = ICU4XGregorianDateTime_create(2020, 9, 12, 12, 35, 0);
+    if (!datetime_result.success) {
+        printf("Failed to create GregorianDateTime\n");
+        return 1;
+    }
+    ICU4XGregorianDateTime* datetime = datetime_result.datetime;
+
+    ICU4XDateTimeFormatResult dtf_result = ICU4XDateTimeFormat_try_new(locale, provider, ICU4XDateLength_Medium, ICU4XTimeLength_Short);
+    if (!dtf_result.success) {
+        printf("Failed to create DateTimeFormat\n");
+        return 1;
+    }
+    ICU4XDateTimeFormat* dtf = dtf_result.dtf;
+    char output[40];
+
+    DiplomatWriteable write = diplomat_simple_writeable(output, 40);
+
+    bool success = ICU4XDateTimeFormat_format(dtf, datetime, &write);
+    if (!success) {
+        printf("Failed to write result of DateTimeFormat::format to string.\n");
+        return 1;
+    }
+    printf("Output is %s\n", output);
+
+    const char* expected = u8"Sep 12, 2020, 12:35 PM";
+    if (strcmp(output, expected) != 0) {
+        printf("Output does not match expected output!\n");
+        return 1;
+    }
+
+    write = diplomat_simple_writeable(output, 40);
+
+    ICU4XFormatDateTimePartsResult parts_result = ICU4XDateTimeFormat_format_to_parts(dtf, datetime, &write);
+    if (!parts_result.success) {
+        printf("Failed to write result of DateTimeFormat::format_to_parts to string.\n");
+        return 1;
+    }
+    ICU4XDateTimeParts* parts = parts_result.parts;
+
+    if (strcmp(output, expected) != 0) {
+        printf("Output of format_to_parts does not match expected output!\n");
+        return 1;
+    }
+
+    if (ICU4XDateTimeParts_len(parts) != 11) {
+        printf("Expected 11 parts, found %zu\n", ICU4XDateTimeParts_len(parts));
+        return 1;
+    }
+
+    ICU4XDateTimePart month = ICU4XDateTimeParts_get(parts, 0);
+    if (month.kind != ICU4XDateTimePartKind_Month || month.start != 0 || month.end != 3) {
+        printf("The first part is not the month \"Sep\"\n");
+        return 1;
+    }
+
+    ICU4XDateTimePart day_period = ICU4XDateTimeParts_get(parts, 10);
+    if (day_period.kind != ICU4XDateTimePartKind_DayPeriod || day_period.start != 20 || day_period.end != 22) {
+        printf("The last part is not the day period \"PM\"\n");
+        return 1;
+    }
+
+    ICU4XDateTimeParts_destroy(parts);
+    ICU4XDateTimeFormat_destroy(dtf);
+    ICU4XGregorianDateTime_destroy(datetime);
+    ICU4XLocale_destroy(locale);
+    ICU4XDataProvider_destroy(provider);
+
+    return 0;
+}
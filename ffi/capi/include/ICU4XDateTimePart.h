@@ -0,0 +1,25 @@
+#ifndef ICU4XDateTimePart_H
+#define ICU4XDateTimePart_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#include "ICU4XDateTimePartKind.h"
+
+typedef struct ICU4XDateTimePart {
+    ICU4XDateTimePartKind kind;
+    size_t start;
+    size_t end;
+} ICU4XDateTimePart;
+
+void ICU4XDateTimePart_destroy(ICU4XDateTimePart* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
@@ -0,0 +1,34 @@
+#ifndef ICU4XDateTimePartKind_H
+#define ICU4XDateTimePartKind_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum ICU4XDateTimePartKind {
+  ICU4XDateTimePartKind_Literal = 0,
+  ICU4XDateTimePartKind_Era = 1,
+  ICU4XDateTimePartKind_Year = 2,
+  ICU4XDateTimePartKind_Quarter = 3,
+  ICU4XDateTimePartKind_Month = 4,
+  ICU4XDateTimePartKind_Week = 5,
+  ICU4XDateTimePartKind_Day = 6,
+  ICU4XDateTimePartKind_Weekday = 7,
+  ICU4XDateTimePartKind_DayPeriod = 8,
+  ICU4XDateTimePartKind_Hour = 9,
+  ICU4XDateTimePartKind_Minute = 10,
+  ICU4XDateTimePartKind_Second = 11,
+  ICU4XDateTimePartKind_TimeZone = 12,
+} ICU4XDateTimePartKind;
+
+void ICU4XDateTimePartKind_destroy(ICU4XDateTimePartKind* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
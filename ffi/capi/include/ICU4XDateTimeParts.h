@@ -0,0 +1,26 @@
+#ifndef ICU4XDateTimeParts_H
+#define ICU4XDateTimeParts_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct ICU4XDateTimeParts ICU4XDateTimeParts;
+#include "ICU4XDateTimePart.h"
+
+size_t ICU4XDateTimeParts_len(const ICU4XDateTimeParts* self);
+
+bool ICU4XDateTimeParts_is_empty(const ICU4XDateTimeParts* self);
+
+ICU4XDateTimePart ICU4XDateTimeParts_get(const ICU4XDateTimeParts* self, size_t index);
+void ICU4XDateTimeParts_destroy(ICU4XDateTimeParts* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
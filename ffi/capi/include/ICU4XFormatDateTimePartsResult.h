@@ -0,0 +1,24 @@
+#ifndef ICU4XFormatDateTimePartsResult_H
+#define ICU4XFormatDateTimePartsResult_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+typedef struct ICU4XDateTimeParts ICU4XDateTimeParts;
+
+typedef struct ICU4XFormatDateTimePartsResult {
+    ICU4XDateTimeParts* parts;
+    bool success;
+} ICU4XFormatDateTimePartsResult;
+
+void ICU4XFormatDateTimePartsResult_destroy(ICU4XFormatDateTimePartsResult* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
@@ -0,0 +1,240 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+pub mod ffi {
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+    use icu_calendar::{DateTime, Gregorian};
+    use icu_datetime::{
+        options::length, DateTimeFormat, DateTimeFormatOptions, DateTimePart, DateTimePartKind,
+    };
+    use writeable::Writeable;
+
+    use crate::{locale::ffi::ICU4XLocale, provider::ffi::ICU4XDataProvider};
+
+    #[diplomat::opaque]
+    /// A date and time in the Gregorian calendar.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_calendar/struct.DateTime.html) for more information.
+    pub struct ICU4XGregorianDateTime(pub DateTime<Gregorian>);
+
+    pub struct ICU4XCreateGregorianDateTimeResult {
+        /// Will be `None` if `success` is `false`, do not use in that case.
+        pub datetime: Option<Box<ICU4XGregorianDateTime>>,
+        /// Whether the date and time were valid.
+        pub success: bool,
+    }
+
+    impl ICU4XGregorianDateTime {
+        /// Creates a new [`ICU4XGregorianDateTime`] from the year, month (1-based), day (1-based),
+        /// hour, minute and second.
+        /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_calendar/struct.DateTime.html#method.new_gregorian_datetime_from_integers) for more information.
+        pub fn create(
+            year: i32,
+            month: u8,
+            day: u8,
+            hour: u8,
+            minute: u8,
+            second: u8,
+        ) -> ICU4XCreateGregorianDateTimeResult {
+            DateTime::new_gregorian_datetime_from_integers(year, month, day, hour, minute, second)
+                .map(|datetime| ICU4XCreateGregorianDateTimeResult {
+                    datetime: Some(Box::new(ICU4XGregorianDateTime(datetime))),
+                    success: true,
+                })
+                .unwrap_or(ICU4XCreateGregorianDateTimeResult {
+                    datetime: None,
+                    success: false,
+                })
+        }
+    }
+
+    /// FFI version of `length::Date`, with `None` for omitting the date.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_datetime/options/length/enum.Date.html) for more details.
+    pub enum ICU4XDateLength {
+        Full,
+        Long,
+        Medium,
+        Short,
+        None,
+    }
+
+    /// FFI version of `length::Time`, with `None` for omitting the time.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_datetime/options/length/enum.Time.html) for more details.
+    pub enum ICU4XTimeLength {
+        Full,
+        Long,
+        Medium,
+        Short,
+        None,
+    }
+
+    #[diplomat::opaque]
+    /// An ICU4X DateTimeFormat object, capable of formatting a [`ICU4XGregorianDateTime`] as a string.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_datetime/struct.DateTimeFormat.html) for more information.
+    pub struct ICU4XDateTimeFormat(pub DateTimeFormat<'static>);
+
+    pub struct ICU4XDateTimeFormatResult {
+        /// The [`ICU4XDateTimeFormat`], exists if creation was successful.
+        pub dtf: Option<Box<ICU4XDateTimeFormat>>,
+        /// Whether creating the [`ICU4XDateTimeFormat`] was successful.
+        pub success: bool,
+    }
+
+    /// FFI version of `DateTimePartKind`.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_datetime/enum.DateTimePartKind.html) for more details.
+    pub enum ICU4XDateTimePartKind {
+        Literal,
+        Era,
+        Year,
+        Quarter,
+        Month,
+        Week,
+        Day,
+        Weekday,
+        DayPeriod,
+        Hour,
+        Minute,
+        Second,
+        TimeZone,
+    }
+
+    /// FFI version of `DateTimePart`, the byte range `start..end` of a part of the output.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_datetime/struct.DateTimePart.html) for more details.
+    pub struct ICU4XDateTimePart {
+        pub kind: ICU4XDateTimePartKind,
+        pub start: usize,
+        pub end: usize,
+    }
+
+    #[diplomat::opaque]
+    /// The parts of a formatted date and time, in the order of the output.
+    pub struct ICU4XDateTimeParts(pub Vec<DateTimePart>);
+
+    pub struct ICU4XFormatDateTimePartsResult {
+        /// Will be `None` if `success` is `false`, do not use in that case.
+        pub parts: Option<Box<ICU4XDateTimeParts>>,
+        /// Whether formatting was successful.
+        pub success: bool,
+    }
+
+    impl ICU4XDateTimeParts {
+        /// The number of parts.
+        pub fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        /// Whether there are no parts, which is the case for an empty output.
+        pub fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        /// The part at `index`, or an empty literal if `index` is out of bounds.
+        pub fn get(&self, index: usize) -> ICU4XDateTimePart {
+            self.0
+                .get(index)
+                .map(|part| ICU4XDateTimePart {
+                    kind: match part.kind {
+                        DateTimePartKind::Literal => ICU4XDateTimePartKind::Literal,
+                        DateTimePartKind::Era => ICU4XDateTimePartKind::Era,
+                        DateTimePartKind::Year => ICU4XDateTimePartKind::Year,
+                        DateTimePartKind::Quarter => ICU4XDateTimePartKind::Quarter,
+                        DateTimePartKind::Month => ICU4XDateTimePartKind::Month,
+                        DateTimePartKind::Week => ICU4XDateTimePartKind::Week,
+                        DateTimePartKind::Day => ICU4XDateTimePartKind::Day,
+                        DateTimePartKind::Weekday => ICU4XDateTimePartKind::Weekday,
+                        DateTimePartKind::DayPeriod => ICU4XDateTimePartKind::DayPeriod,
+                        DateTimePartKind::Hour => ICU4XDateTimePartKind::Hour,
+                        DateTimePartKind::Minute => ICU4XDateTimePartKind::Minute,
+                        DateTimePartKind::Second => ICU4XDateTimePartKind::Second,
+                        DateTimePartKind::TimeZone => ICU4XDateTimePartKind::TimeZone,
+                    },
+                    start: part.range.start,
+                    end: part.range.end,
+                })
+                .unwrap_or(ICU4XDateTimePart {
+                    kind: ICU4XDateTimePartKind::Literal,
+                    start: 0,
+                    end: 0,
+                })
+        }
+    }
+
+    impl ICU4XDateTimeFormat {
+        /// Creates a new [`ICU4XDateTimeFormat`] from locale data.
+        /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_datetime/struct.DateTimeFormat.html#method.try_new) for more information.
+        pub fn try_new(
+            locale: &ICU4XLocale,
+            provider: &ICU4XDataProvider,
+            date_length: ICU4XDateLength,
+            time_length: ICU4XTimeLength,
+        ) -> ICU4XDateTimeFormatResult {
+            let locale = locale.0.clone();
+            let provider = provider.0.as_ref();
+            let options = DateTimeFormatOptions::Length(length::Bag {
+                date: match date_length {
+                    ICU4XDateLength::Full => Some(length::Date::Full),
+                    ICU4XDateLength::Long => Some(length::Date::Long),
+                    ICU4XDateLength::Medium => Some(length::Date::Medium),
+                    ICU4XDateLength::Short => Some(length::Date::Short),
+                    ICU4XDateLength::None => None,
+                },
+                time: match time_length {
+                    ICU4XTimeLength::Full => Some(length::Time::Full),
+                    ICU4XTimeLength::Long => Some(length::Time::Long),
+                    ICU4XTimeLength::Medium => Some(length::Time::Medium),
+                    ICU4XTimeLength::Short => Some(length::Time::Short),
+                    ICU4XTimeLength::None => None,
+                },
+                preferences: None,
+            });
+
+            if let Result::Ok(dtf) = DateTimeFormat::try_new(locale, provider, &options) {
+                ICU4XDateTimeFormatResult {
+                    dtf: Some(Box::new(ICU4XDateTimeFormat(dtf))),
+                    success: true,
+                }
+            } else {
+                ICU4XDateTimeFormatResult {
+                    dtf: None,
+                    success: false,
+                }
+            }
+        }
+
+        /// Formats a [`ICU4XGregorianDateTime`] to a string. Returns whether formatting was successful.
+        /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_datetime/struct.DateTimeFormat.html#method.format) for more information.
+        pub fn format(
+            &self,
+            value: &ICU4XGregorianDateTime,
+            write: &mut diplomat_runtime::DiplomatWriteable,
+        ) -> bool {
+            let success = self.0.format(&value.0).write_to(write).is_ok();
+            write.flush();
+            success
+        }
+
+        /// Formats a [`ICU4XGregorianDateTime`] to a string, and returns the byte range and kind
+        /// of each part of the output.
+        /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_datetime/struct.FormattedDateTime.html#method.write_to_parts) for more information.
+        pub fn format_to_parts(
+            &self,
+            value: &ICU4XGregorianDateTime,
+            write: &mut diplomat_runtime::DiplomatWriteable,
+        ) -> ICU4XFormatDateTimePartsResult {
+            let result = self.0.format(&value.0).write_to_parts(write);
+            write.flush();
+            match result {
+                Ok(parts) => ICU4XFormatDateTimePartsResult {
+                    parts: Some(Box::new(ICU4XDateTimeParts(parts))),
+                    success: true,
+                },
+                Err(_) => ICU4XFormatDateTimePartsResult {
+                    parts: None,
+                    success: false,
+                },
+            }
+        }
+    }
+}
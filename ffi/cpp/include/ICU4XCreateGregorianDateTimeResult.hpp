@@ -0,0 +1,30 @@
+#ifndef ICU4XCreateGregorianDateTimeResult_HPP
+#define ICU4XCreateGregorianDateTimeResult_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XCreateGregorianDateTimeResult.h"
+}
+
+class ICU4XGregorianDateTime;
+
+struct ICU4XCreateGregorianDateTimeResultDeleter {
+  void operator()(capi::ICU4XCreateGregorianDateTimeResult* l) const noexcept {
+    capi::ICU4XCreateGregorianDateTimeResult_destroy(l);
+  }
+};
+struct ICU4XCreateGregorianDateTimeResult {
+ public:
+  std::optional<ICU4XGregorianDateTime> datetime;
+  bool success;
+};
+
+
+#endif
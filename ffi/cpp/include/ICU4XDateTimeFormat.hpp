@@ -0,0 +1,75 @@
+#ifndef ICU4XDateTimeFormat_HPP
+#define ICU4XDateTimeFormat_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XDateTimeFormat.h"
+}
+
+class ICU4XLocale;
+class ICU4XDataProvider;
+#include "ICU4XDateLength.hpp"
+#include "ICU4XTimeLength.hpp"
+struct ICU4XDateTimeFormatResult;
+class ICU4XGregorianDateTime;
+struct ICU4XFormatDateTimePartsResult;
+
+struct ICU4XDateTimeFormatDeleter {
+  void operator()(capi::ICU4XDateTimeFormat* l) const noexcept {
+    capi::ICU4XDateTimeFormat_destroy(l);
+  }
+};
+class ICU4XDateTimeFormat {
+ public:
+  static ICU4XDateTimeFormatResult try_new(const ICU4XLocale& locale, const ICU4XDataProvider& provider, ICU4XDateLength date_length, ICU4XTimeLength time_length);
+  template<typename W> bool format(const ICU4XGregorianDateTime& value, W& write) const;
+  template<typename W> ICU4XFormatDateTimePartsResult format_to_parts(const ICU4XGregorianDateTime& value, W& write) const;
+  inline const capi::ICU4XDateTimeFormat* AsFFI() const { return this->inner.get(); }
+  inline capi::ICU4XDateTimeFormat* AsFFIMut() { return this->inner.get(); }
+  inline ICU4XDateTimeFormat(capi::ICU4XDateTimeFormat* i) : inner(i) {}
+ private:
+  std::unique_ptr<capi::ICU4XDateTimeFormat, ICU4XDateTimeFormatDeleter> inner;
+};
+
+#include "ICU4XLocale.hpp"
+#include "ICU4XDataProvider.hpp"
+#include "ICU4XDateTimeFormatResult.hpp"
+#include "ICU4XGregorianDateTime.hpp"
+#include "ICU4XDateTimeParts.hpp"
+#include "ICU4XFormatDateTimePartsResult.hpp"
+
+inline ICU4XDateTimeFormatResult ICU4XDateTimeFormat::try_new(const ICU4XLocale& locale, const ICU4XDataProvider& provider, ICU4XDateLength date_length, ICU4XTimeLength time_length) {
+  capi::ICU4XDateTimeFormatResult diplomat_raw_struct_out_value = capi::ICU4XDateTimeFormat_try_new(locale.AsFFI(), provider.AsFFI(), static_cast<capi::ICU4XDateLength>(date_length), static_cast<capi::ICU4XTimeLength>(time_length));
+  auto diplomat_optional_raw_out_value_dtf = diplomat_raw_struct_out_value.dtf;
+  std::optional<ICU4XDateTimeFormat> diplomat_optional_out_value_dtf;
+  if (diplomat_optional_raw_out_value_dtf != nullptr) {
+    diplomat_optional_out_value_dtf = ICU4XDateTimeFormat(diplomat_optional_raw_out_value_dtf);
+  } else {
+    diplomat_optional_out_value_dtf = std::nullopt;
+  }
+  return ICU4XDateTimeFormatResult{ .dtf = std::move(diplomat_optional_out_value_dtf), .success = std::move(diplomat_raw_struct_out_value.success) };
+}
+template<typename W> inline bool ICU4XDateTimeFormat::format(const ICU4XGregorianDateTime& value, W& write) const {
+  capi::DiplomatWriteable write_writer = diplomat::WriteableTrait<W>::Construct(write);
+  return capi::ICU4XDateTimeFormat_format(this->inner.get(), value.AsFFI(), &write_writer);
+}
+template<typename W> inline ICU4XFormatDateTimePartsResult ICU4XDateTimeFormat::format_to_parts(const ICU4XGregorianDateTime& value, W& write) const {
+  capi::DiplomatWriteable write_writer = diplomat::WriteableTrait<W>::Construct(write);
+  capi::ICU4XFormatDateTimePartsResult diplomat_raw_struct_out_value = capi::ICU4XDateTimeFormat_format_to_parts(this->inner.get(), value.AsFFI(), &write_writer);
+  auto diplomat_optional_raw_out_value_parts = diplomat_raw_struct_out_value.parts;
+  std::optional<ICU4XDateTimeParts> diplomat_optional_out_value_parts;
+  if (diplomat_optional_raw_out_value_parts != nullptr) {
+    diplomat_optional_out_value_parts = ICU4XDateTimeParts(diplomat_optional_raw_out_value_parts);
+  } else {
+    diplomat_optional_out_value_parts = std::nullopt;
+  }
+  return ICU4XFormatDateTimePartsResult{ .parts = std::move(diplomat_optional_out_value_parts), .success = std::move(diplomat_raw_struct_out_value.success) };
+}
+#endif
@@ -0,0 +1,30 @@
+#ifndef ICU4XDateTimeFormatResult_HPP
+#define ICU4XDateTimeFormatResult_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XDateTimeFormatResult.h"
+}
+
+class ICU4XDateTimeFormat;
+
+struct ICU4XDateTimeFormatResultDeleter {
+  void operator()(capi::ICU4XDateTimeFormatResult* l) const noexcept {
+    capi::ICU4XDateTimeFormatResult_destroy(l);
+  }
+};
+struct ICU4XDateTimeFormatResult {
+ public:
+  std::optional<ICU4XDateTimeFormat> dtf;
+  bool success;
+};
+
+
+#endif
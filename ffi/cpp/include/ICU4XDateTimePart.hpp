@@ -0,0 +1,31 @@
+#ifndef ICU4XDateTimePart_HPP
+#define ICU4XDateTimePart_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XDateTimePart.h"
+}
+
+#include "ICU4XDateTimePartKind.hpp"
+
+struct ICU4XDateTimePartDeleter {
+  void operator()(capi::ICU4XDateTimePart* l) const noexcept {
+    capi::ICU4XDateTimePart_destroy(l);
+  }
+};
+struct ICU4XDateTimePart {
+ public:
+  ICU4XDateTimePartKind kind;
+  size_t start;
+  size_t end;
+};
+
+
+#endif
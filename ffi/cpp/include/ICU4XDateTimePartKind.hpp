@@ -0,0 +1,33 @@
+#ifndef ICU4XDateTimePartKind_HPP
+#define ICU4XDateTimePartKind_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XDateTimePartKind.h"
+}
+
+
+enum struct ICU4XDateTimePartKind {
+  Literal = 0,
+  Era = 1,
+  Year = 2,
+  Quarter = 3,
+  Month = 4,
+  Week = 5,
+  Day = 6,
+  Weekday = 7,
+  DayPeriod = 8,
+  Hour = 9,
+  Minute = 10,
+  Second = 11,
+  TimeZone = 12,
+};
+
+#endif
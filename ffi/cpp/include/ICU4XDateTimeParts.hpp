@@ -0,0 +1,47 @@
+#ifndef ICU4XDateTimeParts_HPP
+#define ICU4XDateTimeParts_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XDateTimeParts.h"
+}
+
+struct ICU4XDateTimePart;
+
+struct ICU4XDateTimePartsDeleter {
+  void operator()(capi::ICU4XDateTimeParts* l) const noexcept {
+    capi::ICU4XDateTimeParts_destroy(l);
+  }
+};
+class ICU4XDateTimeParts {
+ public:
+  size_t len() const;
+  bool is_empty() const;
+  ICU4XDateTimePart get(size_t index) const;
+  inline const capi::ICU4XDateTimeParts* AsFFI() const { return this->inner.get(); }
+  inline capi::ICU4XDateTimeParts* AsFFIMut() { return this->inner.get(); }
+  inline ICU4XDateTimeParts(capi::ICU4XDateTimeParts* i) : inner(i) {}
+ private:
+  std::unique_ptr<capi::ICU4XDateTimeParts, ICU4XDateTimePartsDeleter> inner;
+};
+
+#include "ICU4XDateTimePart.hpp"
+
+inline size_t ICU4XDateTimeParts::len() const {
+  return capi::ICU4XDateTimeParts_len(this->inner.get());
+}
+inline bool ICU4XDateTimeParts::is_empty() const {
+  return capi::ICU4XDateTimeParts_is_empty(this->inner.get());
+}
+inline ICU4XDateTimePart ICU4XDateTimeParts::get(size_t index) const {
+  capi::ICU4XDateTimePart diplomat_raw_struct_out_value = capi::ICU4XDateTimeParts_get(this->inner.get(), index);
+  return ICU4XDateTimePart{ .kind = std::move(static_cast<ICU4XDateTimePartKind>(diplomat_raw_struct_out_value.kind)), .start = std::move(diplomat_raw_struct_out_value.start), .end = std::move(diplomat_raw_struct_out_value.end) };
+}
+#endif
@@ -0,0 +1,30 @@
+#ifndef ICU4XFormatDateTimePartsResult_HPP
+#define ICU4XFormatDateTimePartsResult_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XFormatDateTimePartsResult.h"
+}
+
+class ICU4XDateTimeParts;
+
+struct ICU4XFormatDateTimePartsResultDeleter {
+  void operator()(capi::ICU4XFormatDateTimePartsResult* l) const noexcept {
+    capi::ICU4XFormatDateTimePartsResult_destroy(l);
+  }
+};
+struct ICU4XFormatDateTimePartsResult {
+ public:
+  std::optional<ICU4XDateTimeParts> parts;
+  bool success;
+};
+
+
+#endif
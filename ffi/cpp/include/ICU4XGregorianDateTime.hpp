@@ -0,0 +1,46 @@
+#ifndef ICU4XGregorianDateTime_HPP
+#define ICU4XGregorianDateTime_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XGregorianDateTime.h"
+}
+
+struct ICU4XCreateGregorianDateTimeResult;
+
+struct ICU4XGregorianDateTimeDeleter {
+  void operator()(capi::ICU4XGregorianDateTime* l) const noexcept {
+    capi::ICU4XGregorianDateTime_destroy(l);
+  }
+};
+class ICU4XGregorianDateTime {
+ public:
+  static ICU4XCreateGregorianDateTimeResult create(int32_t year, uint8_t month, uint8_t day, uint8_t hour, uint8_t minute, uint8_t second);
+  inline const capi::ICU4XGregorianDateTime* AsFFI() const { return this->inner.get(); }
+  inline capi::ICU4XGregorianDateTime* AsFFIMut() { return this->inner.get(); }
+  inline ICU4XGregorianDateTime(capi::ICU4XGregorianDateTime* i) : inner(i) {}
+ private:
+  std::unique_ptr<capi::ICU4XGregorianDateTime, ICU4XGregorianDateTimeDeleter> inner;
+};
+
+#include "ICU4XCreateGregorianDateTimeResult.hpp"
+
+inline ICU4XCreateGregorianDateTimeResult ICU4XGregorianDateTime::create(int32_t year, uint8_t month, uint8_t day, uint8_t hour, uint8_t minute, uint8_t second) {
+  capi::ICU4XCreateGregorianDateTimeResult diplomat_raw_struct_out_value = capi::ICU4XGregorianDateTime_create(year, month, day, hour, minute, second);
+  auto diplomat_optional_raw_out_value_datetime = diplomat_raw_struct_out_value.datetime;
+  std::optional<ICU4XGregorianDateTime> diplomat_optional_out_value_datetime;
+  if (diplomat_optional_raw_out_value_datetime != nullptr) {
+    diplomat_optional_out_value_datetime = ICU4XGregorianDateTime(diplomat_optional_raw_out_value_datetime);
+  } else {
+    diplomat_optional_out_value_datetime = std::nullopt;
+  }
+  return ICU4XCreateGregorianDateTimeResult{ .datetime = std::move(diplomat_optional_out_value_datetime), .success = std::move(diplomat_raw_struct_out_value.success) };
+}
+#endif
@@ -57,6 +57,28 @@ export class ICU4XCreateFixedDecimalResult {
   }
 }
 
+const ICU4XCreateGregorianDateTimeResult_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XCreateGregorianDateTimeResult_destroy(underlying);
+});
+
+export class ICU4XCreateGregorianDateTimeResult {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  get datetime() {
+    return (() => {
+      const out = new ICU4XGregorianDateTime((new Uint32Array(wasm.memory.buffer, this.underlying + 0, 1))[0]);
+      out.owner = null;
+      return out;
+    })();
+  }
+
+  get success() {
+    return (new Uint8Array(wasm.memory.buffer, this.underlying + 4, 1))[0] == 1;
+  }
+}
+
 const ICU4XCreatePluralOperandsResult_box_destroy_registry = new FinalizationRegistry(underlying => {
   wasm.ICU4XCreatePluralOperandsResult_destroy(underlying);
 });
@@ -172,6 +194,179 @@ export class ICU4XDataProvider {
   }
 }
 
+const ICU4XDateLength_js_to_rust = {
+  "Full": 0,
+  "Long": 1,
+  "Medium": 2,
+  "Short": 3,
+  "None": 4,
+};
+const ICU4XDateLength_rust_to_js = {
+  0: "Full",
+  1: "Long",
+  2: "Medium",
+  3: "Short",
+  4: "None",
+};
+
+const ICU4XDateTimeFormat_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XDateTimeFormat_destroy(underlying);
+});
+
+export class ICU4XDateTimeFormat {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  static try_new(locale, provider, date_length, time_length) {
+    const diplomat_out = (() => {
+      const diplomat_receive_buffer = wasm.diplomat_alloc(5);
+      wasm.ICU4XDateTimeFormat_try_new(diplomat_receive_buffer, locale.underlying, provider.underlying, ICU4XDateLength_js_to_rust[date_length], ICU4XTimeLength_js_to_rust[time_length]);
+      const out = new ICU4XDateTimeFormatResult(diplomat_receive_buffer);
+      const out_dtf_value = out.dtf;
+      ICU4XDateTimeFormat_box_destroy_registry.register(out_dtf_value, out_dtf_value.underlying);
+      Object.defineProperty(out, "dtf", { value: out_dtf_value });
+      diplomat_alloc_destroy_registry.register(out, {
+        ptr: out.underlying,
+        size: 5
+      });
+      return out;
+    })();
+    return diplomat_out;
+  }
+
+  format(value, write) {
+    const diplomat_out = wasm.ICU4XDateTimeFormat_format(this.underlying, value.underlying, write);
+    return diplomat_out;
+  }
+
+  format_to_parts(value, write) {
+    const diplomat_out = (() => {
+      const diplomat_receive_buffer = wasm.diplomat_alloc(5);
+      wasm.ICU4XDateTimeFormat_format_to_parts(diplomat_receive_buffer, this.underlying, value.underlying, write);
+      const out = new ICU4XFormatDateTimePartsResult(diplomat_receive_buffer);
+      const out_parts_value = out.parts;
+      ICU4XDateTimeParts_box_destroy_registry.register(out_parts_value, out_parts_value.underlying);
+      Object.defineProperty(out, "parts", { value: out_parts_value });
+      diplomat_alloc_destroy_registry.register(out, {
+        ptr: out.underlying,
+        size: 5
+      });
+      return out;
+    })();
+    return diplomat_out;
+  }
+}
+
+const ICU4XDateTimeFormatResult_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XDateTimeFormatResult_destroy(underlying);
+});
+
+export class ICU4XDateTimeFormatResult {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  get dtf() {
+    return (() => {
+      const out = new ICU4XDateTimeFormat((new Uint32Array(wasm.memory.buffer, this.underlying + 0, 1))[0]);
+      out.owner = null;
+      return out;
+    })();
+  }
+
+  get success() {
+    return (new Uint8Array(wasm.memory.buffer, this.underlying + 4, 1))[0] == 1;
+  }
+}
+
+const ICU4XDateTimePart_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XDateTimePart_destroy(underlying);
+});
+
+export class ICU4XDateTimePart {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  get kind() {
+    return ICU4XDateTimePartKind_rust_to_js[(new Int32Array(wasm.memory.buffer, this.underlying + 0, 1))[0]];
+  }
+
+  get start() {
+    return (new Uint32Array(wasm.memory.buffer, this.underlying + 4, 1))[0];
+  }
+
+  get end() {
+    return (new Uint32Array(wasm.memory.buffer, this.underlying + 8, 1))[0];
+  }
+}
+
+const ICU4XDateTimePartKind_js_to_rust = {
+  "Literal": 0,
+  "Era": 1,
+  "Year": 2,
+  "Quarter": 3,
+  "Month": 4,
+  "Week": 5,
+  "Day": 6,
+  "Weekday": 7,
+  "DayPeriod": 8,
+  "Hour": 9,
+  "Minute": 10,
+  "Second": 11,
+  "TimeZone": 12,
+};
+const ICU4XDateTimePartKind_rust_to_js = {
+  0: "Literal",
+  1: "Era",
+  2: "Year",
+  3: "Quarter",
+  4: "Month",
+  5: "Week",
+  6: "Day",
+  7: "Weekday",
+  8: "DayPeriod",
+  9: "Hour",
+  10: "Minute",
+  11: "Second",
+  12: "TimeZone",
+};
+
+const ICU4XDateTimeParts_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XDateTimeParts_destroy(underlying);
+});
+
+export class ICU4XDateTimeParts {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  len() {
+    const diplomat_out = wasm.ICU4XDateTimeParts_len(this.underlying);
+    return diplomat_out;
+  }
+
+  is_empty() {
+    const diplomat_out = wasm.ICU4XDateTimeParts_is_empty(this.underlying);
+    return diplomat_out;
+  }
+
+  get(index) {
+    const diplomat_out = (() => {
+      const diplomat_receive_buffer = wasm.diplomat_alloc(12);
+      wasm.ICU4XDateTimeParts_get(diplomat_receive_buffer, this.underlying, index);
+      const out = new ICU4XDateTimePart(diplomat_receive_buffer);
+      diplomat_alloc_destroy_registry.register(out, {
+        ptr: out.underlying,
+        size: 12
+      });
+      return out;
+    })();
+    return diplomat_out;
+  }
+}
+
 const ICU4XFixedDecimal_box_destroy_registry = new FinalizationRegistry(underlying => {
   wasm.ICU4XFixedDecimal_destroy(underlying);
 });
@@ -375,6 +570,55 @@ const ICU4XFixedDecimalSignDisplay_rust_to_js = {
   4: "Negative",
 };
 
+const ICU4XFormatDateTimePartsResult_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XFormatDateTimePartsResult_destroy(underlying);
+});
+
+export class ICU4XFormatDateTimePartsResult {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  get parts() {
+    return (() => {
+      const out = new ICU4XDateTimeParts((new Uint32Array(wasm.memory.buffer, this.underlying + 0, 1))[0]);
+      out.owner = null;
+      return out;
+    })();
+  }
+
+  get success() {
+    return (new Uint8Array(wasm.memory.buffer, this.underlying + 4, 1))[0] == 1;
+  }
+}
+
+const ICU4XGregorianDateTime_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XGregorianDateTime_destroy(underlying);
+});
+
+export class ICU4XGregorianDateTime {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  static create(year, month, day, hour, minute, second) {
+    const diplomat_out = (() => {
+      const diplomat_receive_buffer = wasm.diplomat_alloc(5);
+      wasm.ICU4XGregorianDateTime_create(diplomat_receive_buffer, year, month, day, hour, minute, second);
+      const out = new ICU4XCreateGregorianDateTimeResult(diplomat_receive_buffer);
+      const out_datetime_value = out.datetime;
+      ICU4XGregorianDateTime_box_destroy_registry.register(out_datetime_value, out_datetime_value.underlying);
+      Object.defineProperty(out, "datetime", { value: out_datetime_value });
+      diplomat_alloc_destroy_registry.register(out, {
+        ptr: out.underlying,
+        size: 5
+      });
+      return out;
+    })();
+    return diplomat_out;
+  }
+}
+
 const ICU4XLocale_box_destroy_registry = new FinalizationRegistry(underlying => {
   wasm.ICU4XLocale_destroy(underlying);
 });
@@ -908,3 +1152,18 @@ export class ICU4XStaticDataProvider {
     return diplomat_out;
   }
 }
+
+const ICU4XTimeLength_js_to_rust = {
+  "Full": 0,
+  "Long": 1,
+  "Medium": 2,
+  "Short": 3,
+  "None": 4,
+};
+const ICU4XTimeLength_rust_to_js = {
+  0: "Full",
+  1: "Long",
+  2: "Medium",
+  3: "Short",
+  4: "None",
+};
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::error::{Error, MissingSourceError};
+use std::collections::BTreeMap;
 use std::default::Default;
 use std::path::PathBuf;
 
@@ -20,6 +21,15 @@ pub trait CldrPaths: std::fmt::Debug {
     /// Path to checkout of cldr-numbers:
     /// <https://github.com/unicode-cldr/cldr-numbers-full>
     fn cldr_numbers(&self) -> Result<PathBuf, Error>;
+
+    /// Path to checkout of cldr-bcp47:
+    /// <https://github.com/unicode-cldr/cldr-bcp47>
+    fn cldr_bcp47(&self) -> Result<PathBuf, Error>;
+
+    /// Path to checkout of the cldr-cal package of a non-Gregorian calendar, such as
+    /// `buddhist` for cldr-cal-buddhist:
+    /// <https://github.com/unicode-cldr/cldr-cal-buddhist-full>
+    fn cldr_calendar(&self, package: &str) -> Result<PathBuf, Error>;
 }
 
 /// An implementation of [`CldrPaths`] for multiple separate local CLDR JSON directories per
@@ -44,6 +54,9 @@ pub struct CldrPathsLocal {
     pub cldr_core: Result<PathBuf, MissingSourceError>,
     pub cldr_dates: Result<PathBuf, MissingSourceError>,
     pub cldr_numbers: Result<PathBuf, MissingSourceError>,
+    pub cldr_bcp47: Result<PathBuf, MissingSourceError>,
+    /// Paths to the cldr-cal packages, keyed by package name, such as `buddhist`.
+    pub cldr_calendars: BTreeMap<String, PathBuf>,
 }
 
 impl CldrPaths for CldrPathsLocal {
@@ -56,6 +69,15 @@ impl CldrPaths for CldrPathsLocal {
     fn cldr_numbers(&self) -> Result<PathBuf, Error> {
         self.cldr_numbers.clone().map_err(|e| e.into())
     }
+    fn cldr_bcp47(&self) -> Result<PathBuf, Error> {
+        self.cldr_bcp47.clone().map_err(|e| e.into())
+    }
+    fn cldr_calendar(&self, package: &str) -> Result<PathBuf, Error> {
+        self.cldr_calendars
+            .get(package)
+            .cloned()
+            .ok_or_else(|| MissingSourceError { src: "cldr-cal" }.into())
+    }
 }
 
 impl Default for CldrPathsLocal {
@@ -66,6 +88,8 @@ impl Default for CldrPathsLocal {
             cldr_numbers: Err(MissingSourceError {
                 src: "cldr-numbers",
             }),
+            cldr_bcp47: Err(MissingSourceError { src: "cldr-bcp47" }),
+            cldr_calendars: BTreeMap::new(),
         }
     }
 }
@@ -110,6 +134,15 @@ impl CldrPaths for CldrPathsAllInOne {
             .clone()
             .join(format!("cldr-numbers-{}", self.locale_subset)))
     }
+    fn cldr_bcp47(&self) -> Result<PathBuf, Error> {
+        Ok(self.cldr_json_root.clone().join("cldr-bcp47"))
+    }
+    fn cldr_calendar(&self, package: &str) -> Result<PathBuf, Error> {
+        Ok(self
+            .cldr_json_root
+            .clone()
+            .join(format!("cldr-cal-{}-{}", package, self.locale_subset)))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,215 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::reader::open_reader;
+use crate::CldrPaths;
+use icu_datetime::provider::time_zones::{
+    IanaToBcp47MapV1, IanaToBcp47MapV1Marker, TimeZoneBcp47Id,
+};
+use icu_datetime::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use tinystr::TinyStr8;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [key::TIMEZONE_IANA_TO_BCP47_V1];
+
+/// A data provider reading from the CLDR JSON BCP-47 time zone identifiers file.
+#[derive(PartialEq, Debug)]
+pub struct Bcp47TimeZonesProvider<'data> {
+    data: IanaToBcp47MapV1<'static>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for Bcp47TimeZonesProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let map = load_iana_to_bcp47(cldr_paths)?
+            .into_tuple_vec()
+            .into_iter()
+            .map(|(iana_id, bcp47_id)| (Cow::Owned(iana_id), bcp47_id))
+            .collect();
+        Ok(Self {
+            data: IanaToBcp47MapV1(map),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for Bcp47TimeZonesProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::TIMEZONE_IANA_TO_BCP47_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, IanaToBcp47MapV1Marker> for Bcp47TimeZonesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, IanaToBcp47MapV1Marker>, DataError> {
+        Bcp47TimeZonesProvider::supports_key(&req.resource_path.key)?;
+        let langid = &req.resource_path.options.langid;
+
+        // The time zone identifiers are not locale-specific, so only the root request is supported.
+        if langid.is_none() {
+            Ok(DataResponse {
+                metadata: DataResponseMetadata {
+                    data_langid: langid.clone(),
+                },
+                payload: Some(DataPayload::from_owned(self.data.clone())),
+            })
+        } else {
+            Err(DataError::MissingResourceOptions(req.clone()))
+        }
+    }
+}
+
+icu_provider::impl_dyn_provider!(Bcp47TimeZonesProvider<'data>, {
+    _ => IanaToBcp47MapV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for Bcp47TimeZonesProvider<'data> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Reads the BCP-47 time zone identifiers together with their IANA identifiers, the CLDR
+/// canonical IANA identifier first.
+///
+/// The IANA identifiers of deprecated BCP-47 identifiers are added to the preferred identifier.
+pub(crate) fn load_bcp47_ids(
+    cldr_paths: &dyn CldrPaths,
+) -> Result<LiteMap<TimeZoneBcp47Id, Vec<String>>, Error> {
+    let resource: cldr_json::Resource = {
+        let path = cldr_paths.cldr_bcp47()?.join("bcp47").join("timezone.json");
+        serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?
+    };
+    let parse = |id: &str| -> Result<TimeZoneBcp47Id, Error> {
+        id.parse::<TinyStr8>()
+            .map(TimeZoneBcp47Id)
+            .map_err(|_| Error::Custom(format!("Invalid BCP-47 time zone: {}", id), None))
+    };
+
+    let mut ids: LiteMap<TimeZoneBcp47Id, Vec<String>> = LiteMap::new();
+    let mut deprecated = vec![];
+    for (id, entry) in resource.keyword.u.time_zones {
+        let entry = match entry {
+            cldr_json::TimeZoneEntry::TimeZone(entry) => entry,
+            cldr_json::TimeZoneEntry::Other(_) => continue,
+        };
+        let aliases: Vec<String> = entry
+            .alias
+            .as_deref()
+            .unwrap_or_default()
+            .split(' ')
+            .filter(|alias| !alias.is_empty())
+            .map(String::from)
+            .collect();
+        match entry.preferred {
+            Some(preferred) => deprecated.push((parse(&preferred)?, aliases)),
+            None => {
+                ids.insert(parse(&id)?, aliases);
+            }
+        }
+    }
+    for (preferred, mut aliases) in deprecated {
+        match ids.get_mut(&preferred) {
+            Some(preferred_aliases) => preferred_aliases.append(&mut aliases),
+            None => {
+                return Err(Error::Custom(
+                    format!("Unknown preferred time zone: {}", preferred.as_str()),
+                    None,
+                ))
+            }
+        }
+    }
+    Ok(ids)
+}
+
+/// Reads the mapping from IANA time zone identifiers, including aliases, to BCP-47 time zone
+/// identifiers.
+pub(crate) fn load_iana_to_bcp47(
+    cldr_paths: &dyn CldrPaths,
+) -> Result<LiteMap<String, TimeZoneBcp47Id>, Error> {
+    Ok(load_bcp47_ids(cldr_paths)?
+        .into_tuple_vec()
+        .into_iter()
+        .flat_map(|(bcp47_id, aliases)| aliases.into_iter().map(move |iana_id| (iana_id, bcp47_id)))
+        .collect())
+}
+
+/// Serde structs for the CLDR JSON BCP-47 time zone identifiers file.
+pub(self) mod cldr_json {
+    use serde::de::IgnoredAny;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct TimeZone {
+        #[serde(rename = "_alias")]
+        pub alias: Option<String>,
+        #[serde(rename = "_preferred")]
+        pub preferred: Option<String>,
+    }
+
+    /// An entry of the `tz` key, which is either a time zone or an attribute of the key
+    /// itself, such as "_description".
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    pub enum TimeZoneEntry {
+        TimeZone(TimeZone),
+        Other(IgnoredAny),
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct UnicodeKeys {
+        #[serde(rename = "tz")]
+        pub time_zones: BTreeMap<String, TimeZoneEntry>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Keyword {
+        pub u: UnicodeKeys,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Resource {
+        pub keyword: Keyword,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use tinystr::tinystr8;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = Bcp47TimeZonesProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let map: DataPayload<IanaToBcp47MapV1Marker> = provider
+        .load_payload(&DataRequest::from(key::TIMEZONE_IANA_TO_BCP47_V1))
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let uslax = TimeZoneBcp47Id(tinystr8!("uslax"));
+    assert_eq!(map.get()["America/Los_Angeles"], uslax);
+    assert_eq!(map.get()["US/Pacific"], uslax);
+    assert_eq!(map.get()["Etc/Unknown"], TimeZoneBcp47Id(tinystr8!("unk")));
+    // Asia/Chongqing belongs to the deprecated "cnckg", which prefers "cnsha".
+    assert_eq!(
+        map.get()["Asia/Chongqing"],
+        TimeZoneBcp47Id(tinystr8!("cnsha"))
+    );
+    assert_eq!(map.get().get("_description"), None);
+}
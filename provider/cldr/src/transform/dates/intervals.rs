@@ -0,0 +1,201 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::cldr_json;
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_datetime::provider::gregory::patterns::{
+    IntervalPatternsV1, IntervalSkeletonsV1, PatternV1, SkeletonV1,
+};
+use icu_datetime::{provider::*, skeleton::SkeletonError};
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::GREGORY_DATE_INTERVAL_PATTERNS_V1, //
+];
+
+/// A data provider reading the interval formats from CLDR JSON dates files.
+#[derive(PartialEq, Debug)]
+pub struct DateIntervalPatternsProvider<'data> {
+    data: Vec<(CldrLangID, cldr_json::LangDates)>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for DateIntervalPatternsProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_dates()?.join("main");
+
+        let locale_dirs = get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            let path = dir.join("ca-gregorian.json");
+
+            let mut resource: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.append(&mut resource.main.0);
+        }
+
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for DateIntervalPatternsProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::GREGORY_DATE_INTERVAL_PATTERNS_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, gregory::DateIntervalPatternsV1Marker>
+    for DateIntervalPatternsProvider<'data>
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, gregory::DateIntervalPatternsV1Marker>, DataError> {
+        DateIntervalPatternsProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        let gregorian = match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => self.data[idx].1.dates.calendars.get("gregorian"),
+            Err(_) => None,
+        }
+        .ok_or_else(|| DataError::MissingResourceOptions(req.clone()))?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(
+                gregory::DateIntervalPatternsV1::from(&gregorian.datetime_formats.interval_formats),
+            )),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(DateIntervalPatternsProvider<'data>, {
+    _ => gregory::DateIntervalPatternsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for DateIntervalPatternsProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                // TODO: Avoid the clone
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+impl From<&cldr_json::IntervalFormats> for gregory::DateIntervalPatternsV1 {
+    fn from(other: &cldr_json::IntervalFormats) -> Self {
+        let mut fallback = None;
+        let mut skeletons = IntervalSkeletonsV1(LiteMap::new());
+
+        for (key, interval_format) in other.0.iter() {
+            let patterns = match interval_format {
+                cldr_json::IntervalFormat::Fallback(pattern) => {
+                    fallback = Some(Cow::Owned(pattern.clone()));
+                    continue;
+                }
+                cldr_json::IntervalFormat::Patterns(patterns) => patterns,
+            };
+
+            let skeleton = match SkeletonV1::try_from(key as &str) {
+                Ok(s) => s,
+                Err(err) => match err {
+                    // Ignore unimplemented fields for now.
+                    SkeletonError::SymbolUnimplemented(_) => continue,
+                    _ => panic!("{:?} {}", key, err),
+                },
+            };
+
+            let mut interval_patterns = IntervalPatternsV1::default();
+            for (greatest_difference, pattern) in patterns.iter() {
+                let slot = match greatest_difference.as_str() {
+                    "G" => &mut interval_patterns.era,
+                    "y" => &mut interval_patterns.year,
+                    "M" => &mut interval_patterns.month,
+                    "d" => &mut interval_patterns.day,
+                    "a" | "B" => &mut interval_patterns.day_period,
+                    "h" | "H" => &mut interval_patterns.hour,
+                    "m" => &mut interval_patterns.minute,
+                    // TODO: Support the alternate variants, such as "M-alt-variant".
+                    _ => continue,
+                };
+                *slot =
+                    Some(PatternV1::try_from(pattern as &str).expect("Unable to parse a pattern"));
+            }
+
+            skeletons.0.insert(skeleton, interval_patterns);
+        }
+
+        Self {
+            fallback: fallback.expect("Expected to find an interval format fallback."),
+            skeletons,
+        }
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DateIntervalPatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let en_intervals: DataPayload<gregory::DateIntervalPatternsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::GREGORY_DATE_INTERVAL_PATTERNS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!("{0} – {1}", en_intervals.get().fallback);
+
+    let skeleton = SkeletonV1::try_from("yMMMd").unwrap();
+    let patterns = en_intervals.get().skeletons.0.get(&skeleton).unwrap();
+    assert_eq!(
+        patterns.day,
+        Some(PatternV1::try_from("MMM d – d, y").unwrap())
+    );
+    assert_eq!(patterns.hour, None);
+
+    let skeleton = SkeletonV1::try_from("hm").unwrap();
+    let patterns = en_intervals.get().skeletons.0.get(&skeleton).unwrap();
+    assert_eq!(
+        patterns.day_period,
+        Some(PatternV1::try_from("h:mm a – h:mm a").unwrap())
+    );
+}
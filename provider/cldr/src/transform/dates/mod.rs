@@ -2,9 +2,119 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+pub mod intervals;
 pub mod patterns;
+pub mod relative_time;
 pub mod symbols;
 
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_provider::prelude::*;
+
+/// The calendars with date symbols and patterns: the BCP-47 identifier of the calendar, which
+/// is the variant of the data, its name in CLDR, and the cldr-cal package with its data, if it
+/// is not in cldr-dates.
+pub(self) const CALENDARS: [(&str, &str, Option<&str>); 10] = [
+    ("gregory", "gregorian", None),
+    ("buddhist", "buddhist", Some("buddhist")),
+    ("japanese", "japanese", Some("japanese")),
+    ("roc", "roc", Some("roc")),
+    ("hebrew", "hebrew", Some("hebrew")),
+    ("coptic", "coptic", Some("coptic")),
+    ("ethiopic", "ethiopic", Some("ethiopic")),
+    ("ethioaa", "ethiopic-amete-alem", Some("ethiopic")),
+    ("islamic-civil", "islamic-civil", Some("islamic")),
+    ("islamic-tbla", "islamic-tbla", Some("islamic")),
+];
+
+/// Reads the ca-*.json files of all [`CALENDARS`], merging the calendars of each locale.
+///
+/// The Gregorian calendar is required, but the other calendars are skipped if their cldr-cal
+/// package is not available.
+pub(self) fn load_calendars(
+    cldr_paths: &dyn CldrPaths,
+) -> Result<Vec<(CldrLangID, cldr_json::LangDates)>, Error> {
+    let mut data: Vec<(CldrLangID, cldr_json::LangDates)> = vec![];
+
+    for (_, name, package) in CALENDARS.iter() {
+        let path = match package {
+            None => cldr_paths.cldr_dates()?.join("main"),
+            Some(package) => match cldr_paths.cldr_calendar(package) {
+                Ok(path) if path.exists() => path.join("main"),
+                Ok(_) | Err(Error::MissingSource(_)) => continue,
+                Err(e) => return Err(e),
+            },
+        };
+
+        for dir in get_subdirectories(&path)? {
+            let path = dir.join(format!("ca-{}.json", name));
+            if package.is_some() && !path.exists() {
+                continue;
+            }
+
+            let resource: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            for (langid, mut lang_dates) in resource.main.0 {
+                match data.iter_mut().find(|(l, _)| *l == langid) {
+                    Some((_, existing)) => existing
+                        .dates
+                        .calendars
+                        .0
+                        .append(&mut lang_dates.dates.calendars.0),
+                    None => data.push((langid, lang_dates)),
+                }
+            }
+        }
+    }
+
+    // The data is searched by locale.
+    data.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(data)
+}
+
+/// Finds the dates of the locale of the request, along with the data of the calendar whose
+/// BCP-47 identifier is the variant of the request.
+pub(self) fn get_calendar_dates<'a>(
+    data: &'a [(CldrLangID, cldr_json::LangDates)],
+    req: &DataRequest,
+) -> Result<(&'a cldr_json::Dates, &'a cldr_json::CalendarDates), DataError> {
+    let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+    let dates = match data.binary_search_by_key(&&cldr_langid, |(lid, _)| lid) {
+        Ok(idx) => &data[idx].1.dates,
+        Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
+    };
+    let calendar = req
+        .resource_path
+        .options
+        .variant
+        .as_ref()
+        .and_then(|variant| CALENDARS.iter().find(|(bcp47, ..)| bcp47 == variant))
+        .and_then(|(_, name, _)| dates.calendars.get(name))
+        .ok_or_else(|| DataError::MissingResourceOptions(req.clone()))?;
+    Ok((dates, calendar))
+}
+
+/// The options of all locales and calendars in the data, with the BCP-47 identifier of the
+/// calendar as the variant.
+pub(self) fn get_calendar_options(
+    data: &[(CldrLangID, cldr_json::LangDates)],
+) -> Vec<ResourceOptions> {
+    data.iter()
+        .flat_map(|(l, lang_dates)| {
+            CALENDARS
+                .iter()
+                .filter(move |(_, name, _)| lang_dates.dates.calendars.get(name).is_some())
+                .map(move |(bcp47, ..)| ResourceOptions {
+                    variant: Some((*bcp47).into()),
+                    // TODO: Avoid the clone
+                    langid: Some(l.langid.clone()),
+                })
+        })
+        .collect()
+}
+
 /// Serde structs for the CLDR JSON dates files.
 pub(self) mod cldr_json {
     use crate::cldr_langid::CldrLangID;
@@ -39,6 +149,16 @@ pub(self) mod cldr_json {
                 symbols!();
             }
         };
+        ($name: ident, map) => {
+            pub mod $name {
+                use super::*;
+
+                #[derive(Debug, PartialEq, Clone, Deserialize)]
+                pub struct Symbols(#[serde(with = "tuple_vec_map")] pub Vec<(String, String)>);
+
+                symbols!();
+            }
+        };
         () => {
             #[derive(Debug, PartialEq, Clone, Deserialize)]
             pub struct FormatWidths {
@@ -65,20 +185,16 @@ pub(self) mod cldr_json {
         }
     }
 
+    // The months are keyed by the month number, which runs up to 13 in some calendars, with
+    // alternate names for leap years under keys such as "7-yeartype-leap".
+    symbols!(months, map);
+
     symbols!(
-        months,
-        ["1", m1, String],
-        ["2", m2, String],
-        ["3", m3, String],
-        ["4", m4, String],
-        ["5", m5, String],
-        ["6", m6, String],
-        ["7", m7, String],
-        ["8", m8, String],
-        ["9", m9, String],
-        ["10", m10, String],
-        ["11", m11, String],
-        ["12", m12, String],
+        quarters,
+        ["1", q1, String],
+        ["2", q2, String],
+        ["3", q3, String],
+        ["4", q4, String],
     );
 
     symbols!(
@@ -100,8 +216,28 @@ pub(self) mod cldr_json {
         ["pm", pm, Cow<'static, str>],
         ["noon", noon, Option<Cow<'static, str>>],
         ["midnight", midnight, Option<Cow<'static, str>>],
+        ["morning1", morning1, Option<Cow<'static, str>>],
+        ["morning2", morning2, Option<Cow<'static, str>>],
+        ["afternoon1", afternoon1, Option<Cow<'static, str>>],
+        ["afternoon2", afternoon2, Option<Cow<'static, str>>],
+        ["evening1", evening1, Option<Cow<'static, str>>],
+        ["evening2", evening2, Option<Cow<'static, str>>],
+        ["night1", night1, Option<Cow<'static, str>>],
+        ["night2", night2, Option<Cow<'static, str>>],
     );
 
+    /// The era names are keyed by the CLDR era number, e.g. "0" for BC in the Gregorian
+    /// calendar, with alternate names under keys such as "0-alt-variant".
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Eras {
+        #[serde(with = "tuple_vec_map", rename = "eraNames")]
+        pub names: Vec<(String, String)>,
+        #[serde(with = "tuple_vec_map", rename = "eraAbbr")]
+        pub abbr: Vec<(String, String)>,
+        #[serde(with = "tuple_vec_map", rename = "eraNarrow")]
+        pub narrow: Vec<(String, String)>,
+    }
+
     #[derive(PartialEq, Debug, Deserialize)]
     #[serde(untagged)]
     pub enum LengthPattern {
@@ -140,6 +276,47 @@ pub(self) mod cldr_json {
         pub short: LengthPattern,
         #[serde(rename = "availableFormats")]
         pub available_formats: AvailableFormats,
+        #[serde(rename = "appendItems")]
+        pub append_items: AppendItems,
+        #[serde(rename = "intervalFormats")]
+        pub interval_formats: IntervalFormats,
+    }
+
+    /// The interval formats are keyed by skeleton, except for the fallback pattern.
+    #[derive(PartialEq, Debug, Deserialize)]
+    #[serde(untagged)]
+    pub enum IntervalFormat {
+        Fallback(String),
+        Patterns(#[serde(with = "tuple_vec_map")] Vec<(String, String)>),
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct IntervalFormats(#[serde(with = "tuple_vec_map")] pub Vec<(String, IntervalFormat)>);
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct AppendItems {
+        #[serde(rename = "Era")]
+        pub era: String,
+        #[serde(rename = "Year")]
+        pub year: String,
+        #[serde(rename = "Quarter")]
+        pub quarter: String,
+        #[serde(rename = "Month")]
+        pub month: String,
+        #[serde(rename = "Week")]
+        pub week: String,
+        #[serde(rename = "Day")]
+        pub day: String,
+        #[serde(rename = "Day-Of-Week")]
+        pub day_of_week: String,
+        #[serde(rename = "Hour")]
+        pub hour: String,
+        #[serde(rename = "Minute")]
+        pub minute: String,
+        #[serde(rename = "Second")]
+        pub second: String,
+        #[serde(rename = "Timezone")]
+        pub time_zone: String,
     }
 
     #[derive(PartialEq, Clone, Debug, Deserialize)]
@@ -147,17 +324,20 @@ pub(self) mod cldr_json {
         #[serde(with = "tuple_vec_map")] pub(crate) Vec<(Cow<'static, str>, Cow<'static, str>)>,
     );
 
-    /// This struct represents a 1:1 mapping of the CLDR ca-gregorian.json data at the key
-    /// "main.LANGID.dates.calendars.gregorian" where "LANGID" is the identifier.
+    /// This struct represents a 1:1 mapping of the CLDR ca-*.json data at the key
+    /// "main.LANGID.dates.calendars.CALENDAR" where "LANGID" is the identifier and
+    /// "CALENDAR" is the CLDR name of the calendar, such as "gregorian".
     ///
     /// e.g.
     /// https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-dates-full/main/en/ca-gregorian.json
     #[derive(PartialEq, Debug, Deserialize)]
-    pub struct GregoryDates {
+    pub struct CalendarDates {
         pub months: months::Contexts,
         pub days: days::Contexts,
         #[serde(rename = "dayPeriods")]
         pub day_periods: day_periods::Contexts,
+        pub eras: Eras,
+        pub quarters: quarters::Contexts,
         #[serde(rename = "dateFormats")]
         pub date_formats: LengthPatterns,
         #[serde(rename = "timeFormats")]
@@ -166,14 +346,26 @@ pub(self) mod cldr_json {
         pub datetime_formats: DateTimeFormats,
     }
 
+    /// The calendars of a locale, keyed by their CLDR name. The data of each calendar is read
+    /// from its own file.
     #[derive(PartialEq, Debug, Deserialize)]
-    pub struct Calendars {
-        pub gregorian: GregoryDates,
+    pub struct Calendars(#[serde(with = "tuple_vec_map")] pub Vec<(String, CalendarDates)>);
+
+    impl Calendars {
+        pub fn get(&self, name: &str) -> Option<&CalendarDates> {
+            self.0
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, dates)| dates)
+        }
     }
 
     #[derive(PartialEq, Debug, Deserialize)]
     pub struct Dates {
         pub calendars: Calendars,
+        /// The fields are read from dateFields.json, if it is present.
+        #[serde(skip)]
+        pub fields: Option<Fields>,
     }
 
     #[derive(PartialEq, Debug, Deserialize)]
@@ -188,4 +380,132 @@ pub(self) mod cldr_json {
     pub struct Resource {
         pub main: LangData,
     }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Field {
+        #[serde(rename = "displayName")]
+        pub display_name: String,
+    }
+
+    /// This struct represents the CLDR dateFields.json data at the key
+    /// "main.LANGID.dates.fields" where "LANGID" is the identifier.
+    ///
+    /// e.g.
+    /// https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-dates-full/main/en/dateFields.json
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Fields {
+        pub era: Field,
+        pub year: Field,
+        pub quarter: Field,
+        pub month: Field,
+        pub week: Field,
+        pub day: Field,
+        pub weekday: Field,
+        pub hour: Field,
+        pub minute: Field,
+        pub second: Field,
+        pub zone: Field,
+    }
+
+    /// The patterns of a time in the past or future in dateFields.json, e.g.
+    /// "relativeTime-type-future".
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct RelativeTimePatterns {
+        #[serde(rename = "relativeTimePattern-count-zero")]
+        pub zero: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-one")]
+        pub one: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-two")]
+        pub two: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-few")]
+        pub few: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-many")]
+        pub many: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-other")]
+        pub other: String,
+    }
+
+    /// A field with the relative time data in dateFields.json, e.g. "day" or "day-short".
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct RelativeField {
+        #[serde(rename = "relative-type--2")]
+        pub minus_two: Option<String>,
+        #[serde(rename = "relative-type--1")]
+        pub minus_one: Option<String>,
+        #[serde(rename = "relative-type-0")]
+        pub zero: Option<String>,
+        #[serde(rename = "relative-type-1")]
+        pub one: Option<String>,
+        #[serde(rename = "relative-type-2")]
+        pub two: Option<String>,
+        #[serde(rename = "relativeTime-type-future")]
+        pub future: RelativeTimePatterns,
+        #[serde(rename = "relativeTime-type-past")]
+        pub past: RelativeTimePatterns,
+    }
+
+    /// The fields of dateFields.json which have relative time data, in each of the widths.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct RelativeFields {
+        pub year: RelativeField,
+        #[serde(rename = "year-short")]
+        pub year_short: RelativeField,
+        #[serde(rename = "year-narrow")]
+        pub year_narrow: RelativeField,
+        pub quarter: RelativeField,
+        #[serde(rename = "quarter-short")]
+        pub quarter_short: RelativeField,
+        #[serde(rename = "quarter-narrow")]
+        pub quarter_narrow: RelativeField,
+        pub month: RelativeField,
+        #[serde(rename = "month-short")]
+        pub month_short: RelativeField,
+        #[serde(rename = "month-narrow")]
+        pub month_narrow: RelativeField,
+        pub week: RelativeField,
+        #[serde(rename = "week-short")]
+        pub week_short: RelativeField,
+        #[serde(rename = "week-narrow")]
+        pub week_narrow: RelativeField,
+        pub day: RelativeField,
+        #[serde(rename = "day-short")]
+        pub day_short: RelativeField,
+        #[serde(rename = "day-narrow")]
+        pub day_narrow: RelativeField,
+        pub hour: RelativeField,
+        #[serde(rename = "hour-short")]
+        pub hour_short: RelativeField,
+        #[serde(rename = "hour-narrow")]
+        pub hour_narrow: RelativeField,
+        pub minute: RelativeField,
+        #[serde(rename = "minute-short")]
+        pub minute_short: RelativeField,
+        #[serde(rename = "minute-narrow")]
+        pub minute_narrow: RelativeField,
+        pub second: RelativeField,
+        #[serde(rename = "second-short")]
+        pub second_short: RelativeField,
+        #[serde(rename = "second-narrow")]
+        pub second_narrow: RelativeField,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct DateFields<F = Fields> {
+        pub fields: F,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangDateFields<F = Fields> {
+        pub dates: DateFields<F>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct FieldsLangData<F = Fields>(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangDateFields<F>)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct FieldsResource<F = Fields> {
+        pub main: FieldsLangData<F>,
+    }
 }
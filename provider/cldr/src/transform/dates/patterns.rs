@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::cldr_json;
+use super::{cldr_json, get_calendar_dates, get_calendar_options, load_calendars};
 use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
 use crate::reader::{get_subdirectories, open_reader};
@@ -17,7 +17,7 @@ use std::marker::PhantomData;
 
 /// All keys that this module is able to produce.
 pub const ALL_KEYS: [ResourceKey; 1] = [
-    key::GREGORY_DATE_PATTERNS_V1, //
+    key::DATE_PATTERNS_V1, //
 ];
 
 /// A data provider reading from CLDR JSON dates files.
@@ -30,18 +30,21 @@ pub struct DatePatternsProvider<'data> {
 impl TryFrom<&dyn CldrPaths> for DatePatternsProvider<'_> {
     type Error = Error;
     fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
-        let mut data = vec![];
+        let mut data = load_calendars(cldr_paths)?;
 
+        // The display names of the fields are used by the appendItems patterns.
         let path = cldr_paths.cldr_dates()?.join("main");
-
-        let locale_dirs = get_subdirectories(&path)?;
-
-        for dir in locale_dirs {
-            let path = dir.join("ca-gregorian.json");
-
-            let mut resource: cldr_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
-            data.append(&mut resource.main.0);
+        for dir in get_subdirectories(&path)? {
+            let path = dir.join("dateFields.json");
+            if path.exists() {
+                let fields: cldr_json::FieldsResource =
+                    serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                for (langid, lang_fields) in fields.main.0 {
+                    if let Some((_, lang_dates)) = data.iter_mut().find(|(l, _)| *l == langid) {
+                        lang_dates.dates.fields = Some(lang_fields.dates.fields);
+                    }
+                }
+            }
         }
 
         Ok(Self {
@@ -53,7 +56,7 @@ impl TryFrom<&dyn CldrPaths> for DatePatternsProvider<'_> {
 
 impl<'data> KeyedDataProvider for DatePatternsProvider<'data> {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
-        key::GREGORY_DATE_PATTERNS_V1.match_key(*resc_key)
+        key::DATE_PATTERNS_V1.match_key(*resc_key)
     }
 }
 
@@ -63,21 +66,15 @@ impl<'data> DataProvider<'data, gregory::DatePatternsV1Marker> for DatePatternsP
         req: &DataRequest,
     ) -> Result<DataResponse<'data, gregory::DatePatternsV1Marker>, DataError> {
         DatePatternsProvider::supports_key(&req.resource_path.key)?;
-        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
-        let dates = match self
-            .data
-            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
-        {
-            Ok(idx) => &self.data[idx].1.dates,
-            Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
-        };
+        let (dates, calendar_dates) = get_calendar_dates(&self.data, req)?;
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
             },
-            payload: Some(DataPayload::from_owned(gregory::DatePatternsV1::from(
-                dates,
-            ))),
+            payload: Some(DataPayload::from_owned(gregory::DatePatternsV1::from((
+                calendar_dates,
+                dates.fields.as_ref(),
+            )))),
         })
     }
 }
@@ -87,21 +84,11 @@ icu_provider::impl_dyn_provider!(DatePatternsProvider<'data>, {
 }, SERDE_SE, 'data);
 
 impl<'data> IterableDataProviderCore for DatePatternsProvider<'data> {
-    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
     fn supported_options_for_key(
         &self,
         _resc_key: &ResourceKey,
     ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
-        let list: Vec<ResourceOptions> = self
-            .data
-            .iter()
-            .map(|(l, _)| ResourceOptions {
-                variant: None,
-                // TODO: Avoid the clone
-                langid: Some(l.langid.clone()),
-            })
-            .collect();
-        Ok(Box::new(list.into_iter()))
+        Ok(Box::new(get_calendar_options(&self.data).into_iter()))
     }
 }
 
@@ -117,9 +104,38 @@ impl From<&cldr_json::LengthPatterns> for gregory::patterns::LengthPatternsV1 {
     }
 }
 
-impl From<&cldr_json::DateTimeFormats> for gregory::patterns::DateTimeFormatsV1 {
-    fn from(other: &cldr_json::DateTimeFormats) -> Self {
-        use gregory::patterns::{PatternV1, SkeletonV1, SkeletonsV1};
+impl From<(&cldr_json::AppendItems, Option<&cldr_json::Fields>)>
+    for gregory::patterns::AppendItemsV1
+{
+    fn from(other: (&cldr_json::AppendItems, Option<&cldr_json::Fields>)) -> Self {
+        use gregory::patterns::AppendItemV1;
+        let (append_items, fields) = other;
+        let append_item = |pattern: &String, field: Option<&cldr_json::Field>| AppendItemV1 {
+            pattern: Cow::Owned(pattern.clone()),
+            display_name: field.map(|field| Cow::Owned(field.display_name.clone())),
+        };
+        Self {
+            era: append_item(&append_items.era, fields.map(|f| &f.era)),
+            year: append_item(&append_items.year, fields.map(|f| &f.year)),
+            quarter: append_item(&append_items.quarter, fields.map(|f| &f.quarter)),
+            month: append_item(&append_items.month, fields.map(|f| &f.month)),
+            week: append_item(&append_items.week, fields.map(|f| &f.week)),
+            day: append_item(&append_items.day, fields.map(|f| &f.day)),
+            day_of_week: append_item(&append_items.day_of_week, fields.map(|f| &f.weekday)),
+            hour: append_item(&append_items.hour, fields.map(|f| &f.hour)),
+            minute: append_item(&append_items.minute, fields.map(|f| &f.minute)),
+            second: append_item(&append_items.second, fields.map(|f| &f.second)),
+            time_zone: append_item(&append_items.time_zone, fields.map(|f| &f.zone)),
+        }
+    }
+}
+
+impl From<(&cldr_json::DateTimeFormats, Option<&cldr_json::Fields>)>
+    for gregory::patterns::DateTimeFormatsV1
+{
+    fn from(other: (&cldr_json::DateTimeFormats, Option<&cldr_json::Fields>)) -> Self {
+        use gregory::patterns::{AppendItemsV1, PatternV1, SkeletonV1, SkeletonsV1};
+        let (other, fields) = other;
         use litemap::LiteMap;
 
         // TODO(#308): Support numbering system variations. We currently throw them away.
@@ -175,19 +191,21 @@ impl From<&cldr_json::DateTimeFormats> for gregory::patterns::DateTimeFormatsV1
 
                 skeletons
             },
+            append_items: AppendItemsV1::from((&other.append_items, fields)),
         }
     }
 }
 
-impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
-    fn from(other: &cldr_json::Dates) -> Self {
+impl From<(&cldr_json::CalendarDates, Option<&cldr_json::Fields>)> for gregory::DatePatternsV1 {
+    fn from(other: (&cldr_json::CalendarDates, Option<&cldr_json::Fields>)) -> Self {
+        let (other, fields) = other;
         let date_time_formats_v1 =
-            gregory::patterns::DateTimeFormatsV1::from(&other.calendars.gregorian.datetime_formats);
+            gregory::patterns::DateTimeFormatsV1::from((&other.datetime_formats, fields));
 
-        let pattern_str_full = other.calendars.gregorian.time_formats.full.get_pattern();
-        let pattern_str_long = other.calendars.gregorian.time_formats.long.get_pattern();
-        let pattern_str_medium = other.calendars.gregorian.time_formats.medium.get_pattern();
-        let pattern_str_short = other.calendars.gregorian.time_formats.short.get_pattern();
+        let pattern_str_full = other.time_formats.full.get_pattern();
+        let pattern_str_long = other.time_formats.long.get_pattern();
+        let pattern_str_medium = other.time_formats.medium.get_pattern();
+        let pattern_str_short = other.time_formats.short.get_pattern();
 
         let pattern_full = pattern::Pattern::from_bytes(pattern_str_full)
             .expect("Failed to create a full Pattern from bytes.");
@@ -226,7 +244,7 @@ impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
         };
 
         let (time_h11_h12, time_h23_h24) = {
-            let time = (&other.calendars.gregorian.time_formats).into();
+            let time = (&other.time_formats).into();
             let alt_time = gregory::patterns::LengthPatternsV1 {
                 full: pattern::transform_hour_cycle::apply_coarse_hour_cycle(
                     &date_time_formats_v1,
@@ -269,11 +287,11 @@ impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
         };
 
         Self {
-            date: (&other.calendars.gregorian.date_formats).into(),
+            date: (&other.date_formats).into(),
             time_h11_h12,
             time_h23_h24,
             preferred_hour_cycle,
-            datetime: (&other.calendars.gregorian.datetime_formats).into(),
+            datetime: date_time_formats_v1,
         }
     }
 }
@@ -288,9 +306,9 @@ fn test_basic() {
     let cs_dates: DataPayload<gregory::DatePatternsV1Marker> = provider
         .load_payload(&DataRequest {
             resource_path: ResourcePath {
-                key: key::GREGORY_DATE_PATTERNS_V1,
+                key: key::DATE_PATTERNS_V1,
                 options: ResourceOptions {
-                    variant: None,
+                    variant: Some("gregory".into()),
                     langid: Some(langid!("cs")),
                 },
             },
@@ -302,6 +320,44 @@ fn test_basic() {
     assert_eq!("d. M. y", cs_dates.get().date.medium);
 }
 
+#[test]
+fn test_append_items() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DatePatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |langid| -> DataPayload<gregory::DatePatternsV1Marker> {
+        provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::DATE_PATTERNS_V1,
+                    options: ResourceOptions {
+                        variant: Some("gregory".into()),
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let en_dates = load(langid!("en"));
+    let append_items = &en_dates.get().datetime.append_items;
+    assert_eq!("{0} ({2}: {1})", append_items.hour.pattern);
+    assert_eq!(Some("hour"), append_items.hour.display_name.as_deref());
+    assert_eq!("{0} {1}", append_items.time_zone.pattern);
+    assert_eq!(
+        Some("time zone"),
+        append_items.time_zone.display_name.as_deref()
+    );
+
+    // Locales without dateFields.json have no display names.
+    let cs_dates = load(langid!("cs"));
+    assert_eq!(None, cs_dates.get().datetime.append_items.hour.display_name);
+}
+
 #[test]
 fn test_with_numbering_system() {
     use icu_locid_macros::langid;
@@ -312,9 +368,9 @@ fn test_with_numbering_system() {
     let cs_dates: DataPayload<gregory::DatePatternsV1Marker> = provider
         .load_payload(&DataRequest {
             resource_path: ResourcePath {
-                key: key::GREGORY_DATE_PATTERNS_V1,
+                key: key::DATE_PATTERNS_V1,
                 options: ResourceOptions {
-                    variant: None,
+                    variant: Some("gregory".into()),
                     langid: Some(langid!("haw")),
                 },
             },
@@ -327,3 +383,30 @@ fn test_with_numbering_system() {
     // TODO(#308): Support numbering system variations. We currently throw them away.
     assert_eq!("d/M/yy", cs_dates.get().date.short);
 }
+
+#[test]
+fn test_calendars() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DatePatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let ja_dates: DataPayload<gregory::DatePatternsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::DATE_PATTERNS_V1,
+                options: ResourceOptions {
+                    variant: Some("japanese".into()),
+                    langid: Some(langid!("ja")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    // The Japanese calendar always displays the era.
+    assert_eq!("Gy年M月d日", ja_dates.get().date.long);
+    // TODO(#308): Support numbering system variations. We currently throw them away.
+    assert_eq!("Gy年M月d日EEEE", ja_dates.get().date.full);
+}
@@ -0,0 +1,235 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::cldr_json;
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_datetime::provider::relative_time::{
+    PluralPatternsV1, RelativeTimePatternsV1, RelativeTimePatternsV1Marker, RelativeTimeUnitsV1,
+    RelativeTimeV1,
+};
+use icu_datetime::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::RELATIVE_TIME_PATTERNS_V1, //
+];
+
+/// A data provider reading the relative time patterns from CLDR JSON dateFields files.
+#[derive(PartialEq, Debug)]
+pub struct RelativeTimeProvider<'data> {
+    data: Vec<(
+        CldrLangID,
+        cldr_json::LangDateFields<cldr_json::RelativeFields>,
+    )>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for RelativeTimeProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_dates()?.join("main");
+
+        let locale_dirs = get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            // Not all locales of the CLDR dates files have the dateFields.json file.
+            let path = dir.join("dateFields.json");
+            if !path.exists() {
+                continue;
+            }
+
+            let mut resource: cldr_json::FieldsResource<cldr_json::RelativeFields> =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.append(&mut resource.main.0);
+        }
+
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for RelativeTimeProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::RELATIVE_TIME_PATTERNS_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, RelativeTimePatternsV1Marker> for RelativeTimeProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, RelativeTimePatternsV1Marker>, DataError> {
+        RelativeTimeProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        let fields = match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => &self.data[idx].1.dates.fields,
+            Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(RelativeTimePatternsV1::from(
+                fields,
+            ))),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(RelativeTimeProvider<'data>, {
+    _ => RelativeTimePatternsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for RelativeTimeProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                // TODO: Avoid the clone
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+impl From<&cldr_json::RelativeFields> for RelativeTimePatternsV1 {
+    fn from(other: &cldr_json::RelativeFields) -> Self {
+        Self {
+            long: RelativeTimeUnitsV1 {
+                year: (&other.year).into(),
+                quarter: (&other.quarter).into(),
+                month: (&other.month).into(),
+                week: (&other.week).into(),
+                day: (&other.day).into(),
+                hour: (&other.hour).into(),
+                minute: (&other.minute).into(),
+                second: (&other.second).into(),
+            },
+            short: RelativeTimeUnitsV1 {
+                year: (&other.year_short).into(),
+                quarter: (&other.quarter_short).into(),
+                month: (&other.month_short).into(),
+                week: (&other.week_short).into(),
+                day: (&other.day_short).into(),
+                hour: (&other.hour_short).into(),
+                minute: (&other.minute_short).into(),
+                second: (&other.second_short).into(),
+            },
+            narrow: RelativeTimeUnitsV1 {
+                year: (&other.year_narrow).into(),
+                quarter: (&other.quarter_narrow).into(),
+                month: (&other.month_narrow).into(),
+                week: (&other.week_narrow).into(),
+                day: (&other.day_narrow).into(),
+                hour: (&other.hour_narrow).into(),
+                minute: (&other.minute_narrow).into(),
+                second: (&other.second_narrow).into(),
+            },
+        }
+    }
+}
+
+impl From<&cldr_json::RelativeField> for RelativeTimeV1 {
+    fn from(other: &cldr_json::RelativeField) -> Self {
+        let mut relatives = LiteMap::new();
+        let offsets = [
+            (-2, &other.minus_two),
+            (-1, &other.minus_one),
+            (0, &other.zero),
+            (1, &other.one),
+            (2, &other.two),
+        ];
+        for (offset, name) in offsets.iter() {
+            if let Some(name) = name {
+                relatives.insert(*offset, Cow::Owned(name.clone()));
+            }
+        }
+        Self {
+            relatives,
+            past: (&other.past).into(),
+            future: (&other.future).into(),
+        }
+    }
+}
+
+impl From<&cldr_json::RelativeTimePatterns> for PluralPatternsV1 {
+    fn from(other: &cldr_json::RelativeTimePatterns) -> Self {
+        Self {
+            zero: other.zero.clone().map(Cow::Owned),
+            one: other.one.clone().map(Cow::Owned),
+            two: other.two.clone().map(Cow::Owned),
+            few: other.few.clone().map(Cow::Owned),
+            many: other.many.clone().map(Cow::Owned),
+            other: Cow::Owned(other.other.clone()),
+        }
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = RelativeTimeProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let patterns: DataPayload<RelativeTimePatternsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::RELATIVE_TIME_PATTERNS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let day = &patterns.get().long.day;
+    assert_eq!(day.relatives.get(&-1).map(|s| &**s), Some("yesterday"));
+    assert_eq!(day.relatives.get(&2), None);
+    assert_eq!(
+        day.future.get(icu_plurals::PluralCategory::One),
+        "in {0} day"
+    );
+    assert_eq!(
+        day.past.get(icu_plurals::PluralCategory::Other),
+        "{0} days ago"
+    );
+    // Categories without a pattern of their own fall back to "other".
+    assert_eq!(
+        day.past.get(icu_plurals::PluralCategory::Few),
+        "{0} days ago"
+    );
+
+    let hour = &patterns.get().short.hour;
+    assert_eq!(
+        hour.future.get(icu_plurals::PluralCategory::Other),
+        "in {0} hr."
+    );
+}
@@ -2,10 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::cldr_json;
+use super::{cldr_json, get_calendar_dates, get_calendar_options, load_calendars};
 use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
-use crate::reader::{get_subdirectories, open_reader};
 use crate::CldrPaths;
 use icu_datetime::provider::*;
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
@@ -13,10 +12,11 @@ use icu_provider::prelude::*;
 use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::marker::PhantomData;
+use tinystr::{tinystr8, TinyStr8};
 
 /// All keys that this module is able to produce.
 pub const ALL_KEYS: [ResourceKey; 1] = [
-    key::GREGORY_DATE_SYMBOLS_V1, //
+    key::DATE_SYMBOLS_V1, //
 ];
 
 /// A data provider reading from CLDR JSON dates files.
@@ -29,22 +29,8 @@ pub struct DateSymbolsProvider<'data> {
 impl TryFrom<&dyn CldrPaths> for DateSymbolsProvider<'_> {
     type Error = Error;
     fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
-        let mut data = vec![];
-
-        let path = cldr_paths.cldr_dates()?.join("main");
-
-        let locale_dirs = get_subdirectories(&path)?;
-
-        for dir in locale_dirs {
-            let path = dir.join("ca-gregorian.json");
-
-            let mut resource: cldr_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
-            data.append(&mut resource.main.0);
-        }
-
         Ok(Self {
-            data,
+            data: load_calendars(cldr_paths)?,
             _phantom: PhantomData,
         })
     }
@@ -52,7 +38,7 @@ impl TryFrom<&dyn CldrPaths> for DateSymbolsProvider<'_> {
 
 impl<'data> KeyedDataProvider for DateSymbolsProvider<'data> {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
-        key::GREGORY_DATE_SYMBOLS_V1.match_key(*resc_key)
+        key::DATE_SYMBOLS_V1.match_key(*resc_key)
     }
 }
 
@@ -62,19 +48,22 @@ impl<'data> DataProvider<'data, gregory::DateSymbolsV1Marker> for DateSymbolsPro
         req: &DataRequest,
     ) -> Result<DataResponse<'data, gregory::DateSymbolsV1Marker>, DataError> {
         DateSymbolsProvider::supports_key(&req.resource_path.key)?;
-        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
-        let dates = match self
-            .data
-            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
-        {
-            Ok(idx) => &self.data[idx].1.dates,
-            Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
-        };
+        let (dates, calendar_dates) = get_calendar_dates(&self.data, req)?;
+        let calendar = req
+            .resource_path
+            .options
+            .variant
+            .as_deref()
+            .unwrap_or_default();
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
             },
-            payload: Some(DataPayload::from_owned(gregory::DateSymbolsV1::from(dates))),
+            payload: Some(DataPayload::from_owned(convert_dates(
+                calendar,
+                dates,
+                calendar_dates,
+            ))),
         })
     }
 }
@@ -84,32 +73,168 @@ icu_provider::impl_dyn_provider!(DateSymbolsProvider<'data>, {
 }, SERDE_SE, 'data);
 
 impl<'data> IterableDataProviderCore for DateSymbolsProvider<'data> {
-    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
     fn supported_options_for_key(
         &self,
         _resc_key: &ResourceKey,
     ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
-        let list: Vec<ResourceOptions> = self
-            .data
-            .iter()
-            .map(|(l, _)| ResourceOptions {
-                variant: None,
-                // TODO: Avoid the clone
-                langid: Some(l.langid.clone()),
-            })
-            .collect();
-        Ok(Box::new(list.into_iter()))
+        Ok(Box::new(get_calendar_options(&self.data).into_iter()))
     }
 }
 
-impl From<&cldr_json::Dates> for gregory::DateSymbolsV1 {
-    fn from(other: &cldr_json::Dates) -> Self {
-        Self {
-            months: (&other.calendars.gregorian.months).into(),
-            weekdays: (&other.calendars.gregorian.days).into(),
-            day_periods: (&other.calendars.gregorian.day_periods).into(),
+/// Converts the symbols of `calendar`, which is identified by its BCP-47 identifier.
+fn convert_dates(
+    calendar: &str,
+    dates: &cldr_json::Dates,
+    other: &cldr_json::CalendarDates,
+) -> gregory::DateSymbolsV1 {
+    let mut eras = convert_eras(calendar, &other.eras);
+    if calendar == "japanese" {
+        // Dates before the Meiji era use the Gregorian eras.
+        if let Some(gregorian) = dates.calendars.get("gregorian") {
+            let gregorian_eras = convert_eras("gregory", &gregorian.eras);
+            for (symbols, gregorian_symbols) in [
+                (&mut eras.names, gregorian_eras.names),
+                (&mut eras.abbr, gregorian_eras.abbr),
+                (&mut eras.narrow, gregorian_eras.narrow),
+            ] {
+                for (code, name) in gregorian_symbols.0.into_tuple_vec() {
+                    symbols.0.insert(code, name);
+                }
+            }
         }
     }
+
+    gregory::DateSymbolsV1 {
+        months: convert_months(calendar, &other.months),
+        weekdays: (&other.days).into(),
+        day_periods: (&other.day_periods).into(),
+        eras,
+        quarters: (&other.quarters).into(),
+    }
+}
+
+/// The era code used by `icu_calendar` for the CLDR era number `index` of `calendar`, which
+/// is identified by its BCP-47 identifier.
+fn era_code(calendar: &str, index: usize) -> Option<TinyStr8> {
+    Some(match (calendar, index) {
+        ("gregory", 0) => tinystr8!("bce"),
+        ("gregory", 1) => tinystr8!("ce"),
+        ("buddhist", 0) => tinystr8!("be"),
+        // CLDR has the names of all Japanese eras, but `icu_calendar` only supports the modern
+        // ones, starting with Meiji.
+        ("japanese", 232) => tinystr8!("meiji"),
+        ("japanese", 233) => tinystr8!("taisho"),
+        ("japanese", 234) => tinystr8!("showa"),
+        ("japanese", 235) => tinystr8!("heisei"),
+        ("japanese", 236) => tinystr8!("reiwa"),
+        ("roc", 0) => tinystr8!("broc"),
+        ("roc", 1) => tinystr8!("roc"),
+        ("hebrew", 0) => tinystr8!("am"),
+        ("coptic", 0) => tinystr8!("bd"),
+        ("coptic", 1) => tinystr8!("ad"),
+        ("ethiopic", 0) => tinystr8!("mundi"),
+        ("ethiopic", 1) => tinystr8!("incar"),
+        ("ethioaa", 0) => tinystr8!("mundi"),
+        ("islamic-civil", 0) | ("islamic-tbla", 0) => tinystr8!("ah"),
+        _ => return None,
+    })
+}
+
+fn convert_eras(calendar: &str, other: &cldr_json::Eras) -> gregory::eras::ErasV1 {
+    let convert = |symbols: &[(String, String)]| {
+        gregory::eras::SymbolsV1(
+            symbols
+                .iter()
+                // Alternate names, such as "BCE", have keys like "0-alt-variant".
+                .filter_map(|(index, name)| {
+                    let code = era_code(calendar, index.parse().ok()?)?;
+                    Some((code, Cow::Owned(name.clone())))
+                })
+                .collect(),
+        )
+    };
+    gregory::eras::ErasV1 {
+        names: convert(&other.names),
+        abbr: convert(&other.abbr),
+        narrow: convert(&other.narrow),
+    }
+}
+
+/// The month code used by `icu_calendar`, e.g. "M05" for the fifth month, or "M05L" for the
+/// leap month following it.
+fn month_code(number: u8, is_leap: bool) -> TinyStr8 {
+    format!("M{:02}{}", number, if is_leap { "L" } else { "" })
+        .parse()
+        .expect("Month codes are ASCII")
+}
+
+fn with_suffix(code: TinyStr8, suffix: &str) -> TinyStr8 {
+    format!("{}{}", code, suffix)
+        .parse()
+        .expect("Month codes with a suffix fit in a TinyStr8")
+}
+
+fn convert_months(
+    calendar: &str,
+    other: &cldr_json::months::Contexts,
+) -> gregory::months::ContextsV1 {
+    let mut months: gregory::months::ContextsV1 = other.into();
+    if calendar == "hebrew" {
+        // CLDR numbers the months of the Hebrew calendar as if every year were a leap year,
+        // with Adar I as the sixth month, whereas `icu_calendar` considers Adar I to be the
+        // leap month following Shevat, the fifth month.
+        let hebrew_month_code = |code: &TinyStr8| match code[1..3].parse::<u8>() {
+            Ok(number) if number < 6 => *code,
+            Ok(6) => month_code(5, true),
+            // Keep the suffix of names that are only used in leap years.
+            Ok(number) => with_suffix(month_code(number - 1, false), &code[3..]),
+            Err(_) => *code,
+        };
+        let format = &mut months.format;
+        let mut widths = vec![
+            &mut format.abbreviated,
+            &mut format.narrow,
+            &mut format.wide,
+        ];
+        widths.extend(format.short.as_mut());
+        if let Some(stand_alone) = months.stand_alone.as_mut() {
+            widths.extend(stand_alone.abbreviated.as_mut());
+            widths.extend(stand_alone.narrow.as_mut());
+            widths.extend(stand_alone.short.as_mut());
+            widths.extend(stand_alone.wide.as_mut());
+        }
+        for symbols in widths {
+            symbols.0 = symbols
+                .0
+                .iter()
+                .map(|(code, name)| (hebrew_month_code(code), name.clone()))
+                .collect();
+        }
+    }
+    months
+}
+
+impl From<&cldr_json::months::Symbols> for gregory::months::SymbolsV1 {
+    fn from(other: &cldr_json::months::Symbols) -> Self {
+        Self(
+            other
+                .0
+                .iter()
+                .filter_map(|(key, name)| {
+                    // Names that a month only has in leap years, such as "7-yeartype-leap" for
+                    // Adar II in the Hebrew calendar, are keyed by the month code with a suffix.
+                    let code = match key.strip_suffix("-yeartype-leap") {
+                        Some(number) => with_suffix(
+                            month_code(number.parse().ok()?, false),
+                            gregory::LEAP_YEAR_MONTH_SUFFIX,
+                        ),
+                        None => month_code(key.parse().ok()?, false),
+                    };
+                    Some((code, Cow::Owned(name.clone())))
+                })
+                .collect(),
+        )
+    }
 }
 
 macro_rules! symbols_from {
@@ -209,10 +334,9 @@ macro_rules! symbols_from {
     };
 }
 
-symbols_from!(
-    [months, months],
-    [m1, m2, m3, m4, m5, m6, m7, m8, m9, m10, m11, m12],
-);
+symbols_from!([months, months]);
+
+symbols_from!([quarters, quarters], [q1, q2, q3, q4]);
 
 symbols_from!([days, weekdays], [sun, mon, tue, wed, thu, fri, sat]);
 
@@ -226,6 +350,14 @@ symbols_from!(
         pm,
         noon,
         midnight,
+        morning1,
+        morning2,
+        afternoon1,
+        afternoon2,
+        evening1,
+        evening2,
+        night1,
+        night2,
     },
 );
 
@@ -239,9 +371,9 @@ fn test_basic() {
     let cs_dates: DataPayload<gregory::DateSymbolsV1Marker> = provider
         .load_payload(&DataRequest {
             resource_path: ResourcePath {
-                key: key::GREGORY_DATE_SYMBOLS_V1,
+                key: key::DATE_SYMBOLS_V1,
                 options: ResourceOptions {
-                    variant: None,
+                    variant: Some("gregory".into()),
                     langid: Some(langid!("cs")),
                 },
             },
@@ -250,12 +382,27 @@ fn test_basic() {
         .take_payload()
         .unwrap();
 
-    assert_eq!("srpna", cs_dates.get().months.format.wide.0[7]);
+    assert_eq!(
+        "srpna",
+        *cs_dates
+            .get()
+            .months
+            .format
+            .wide
+            .0
+            .get(&tinystr8!("M08"))
+            .unwrap()
+    );
 
     assert_eq!(
         "po",
         cs_dates.get().weekdays.format.short.as_ref().unwrap().0[1]
     );
+
+    assert_eq!(
+        "n. l.",
+        *cs_dates.get().eras.abbr.0.get(&tinystr8!("ce")).unwrap()
+    );
 }
 
 #[test]
@@ -268,9 +415,9 @@ fn unalias_contexts() {
     let cs_dates: DataPayload<gregory::DateSymbolsV1Marker> = provider
         .load_payload(&DataRequest {
             resource_path: ResourcePath {
-                key: key::GREGORY_DATE_SYMBOLS_V1,
+                key: key::DATE_SYMBOLS_V1,
                 options: ResourceOptions {
-                    variant: None,
+                    variant: Some("gregory".into()),
                     langid: Some(langid!("cs")),
                 },
             },
@@ -319,3 +466,62 @@ fn unalias_contexts() {
     // Czech weekdays are unaliased because they completely overlap.
     assert!(cs_dates.get().weekdays.stand_alone.is_none());
 }
+
+#[test]
+fn test_calendars() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DateSymbolsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |calendar: &'static str, langid| -> DataPayload<gregory::DateSymbolsV1Marker> {
+        provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::DATE_SYMBOLS_V1,
+                    options: ResourceOptions {
+                        variant: Some(calendar.into()),
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let th_buddhist = load("buddhist", langid!("th"));
+    assert_eq!(
+        "พ.ศ.",
+        *th_buddhist.get().eras.abbr.0.get(&tinystr8!("be")).unwrap()
+    );
+
+    // Adar I is the leap month following Shevat.
+    let en_hebrew = load("hebrew", langid!("en"));
+    let wide = &en_hebrew.get().months.format.wide.0;
+    assert_eq!("Shevat", *wide.get(&tinystr8!("M05")).unwrap());
+    assert_eq!("Adar I", *wide.get(&tinystr8!("M05L")).unwrap());
+    assert_eq!("Adar", *wide.get(&tinystr8!("M06")).unwrap());
+    assert_eq!("Adar II", *wide.get(&tinystr8!("M06-leap")).unwrap());
+    assert_eq!("Nisan", *wide.get(&tinystr8!("M07")).unwrap());
+    assert_eq!("Elul", *wide.get(&tinystr8!("M12")).unwrap());
+
+    // The Japanese calendar uses the Gregorian eras before Meiji.
+    let ja_japanese = load("japanese", langid!("ja"));
+    let names = &ja_japanese.get().eras.names.0;
+    assert_eq!("令和", *names.get(&tinystr8!("reiwa")).unwrap());
+    assert_eq!("西暦", *names.get(&tinystr8!("ce")).unwrap());
+
+    // Calendars without data for a locale are not supported.
+    assert!(provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::DATE_SYMBOLS_V1,
+                options: ResourceOptions {
+                    variant: Some("buddhist".into()),
+                    langid: Some(langid!("cs")),
+                },
+            },
+        })
+        .is_err());
+}
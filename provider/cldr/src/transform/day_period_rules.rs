@@ -0,0 +1,247 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_datetime::provider::{day_period_rules::*, key};
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::DAY_PERIOD_RULES_V1, //
+];
+
+/// A data provider reading from the CLDR JSON day periods file.
+///
+/// Day period rules are given per language. Languages that are not listed in CLDR, as well
+/// as the root locale, get no rules.
+#[derive(PartialEq, Debug)]
+pub struct DayPeriodRulesProvider<'data> {
+    rule_sets: Vec<(String, cldr_json::RuleSet)>,
+    langids: Vec<CldrLangID>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for DayPeriodRulesProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let rule_sets = {
+            let path = cldr_paths
+                .cldr_core()?
+                .join("supplemental")
+                .join("dayPeriods.json");
+            let data: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.supplemental.rule_sets
+        };
+        // Day period rules are available for the same locales as the other datetime data.
+        let mut langids = vec![];
+        for dir in get_subdirectories(&cldr_paths.cldr_dates()?.join("main"))? {
+            if let Some(name) = dir.file_name().and_then(|name| name.to_str()) {
+                let langid = name
+                    .parse::<CldrLangID>()
+                    .map_err(|e| Error::Custom(format!("{}: {}", e, name), None))?;
+                langids.push(langid);
+            }
+        }
+        Ok(Self {
+            rule_sets,
+            langids,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for DayPeriodRulesProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::DAY_PERIOD_RULES_V1.match_key(*resc_key)
+    }
+}
+
+/// Parses a CLDR time of the form `HH:MM` into minutes since midnight.
+fn parse_time(time: &str) -> Option<u16> {
+    let (hour, minute) = time.split_once(':')?;
+    let hour: u16 = hour.parse().ok()?;
+    let minute: u16 = minute.parse().ok()?;
+    // "24:00" is used as the end of periods that last until midnight.
+    if hour > 24 || minute >= 60 || (hour == 24 && minute != 0) {
+        return None;
+    }
+    Some(hour * 60 + minute)
+}
+
+fn parse_period(period: &str) -> Option<FlexibleDayPeriod> {
+    Some(match period {
+        "midnight" => FlexibleDayPeriod::Midnight,
+        "noon" => FlexibleDayPeriod::Noon,
+        "morning1" => FlexibleDayPeriod::Morning1,
+        "morning2" => FlexibleDayPeriod::Morning2,
+        "afternoon1" => FlexibleDayPeriod::Afternoon1,
+        "afternoon2" => FlexibleDayPeriod::Afternoon2,
+        "evening1" => FlexibleDayPeriod::Evening1,
+        "evening2" => FlexibleDayPeriod::Evening2,
+        "night1" => FlexibleDayPeriod::Night1,
+        "night2" => FlexibleDayPeriod::Night2,
+        _ => return None,
+    })
+}
+
+impl<'data> DataProvider<'data, DayPeriodRulesV1Marker> for DayPeriodRulesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DayPeriodRulesV1Marker>, DataError> {
+        DayPeriodRulesProvider::supports_key(&req.resource_path.key)?;
+        let langid = req.try_langid()?;
+        let mut rules = vec![];
+        if let Some((_, rule_set)) = self
+            .rule_sets
+            .iter()
+            .find(|(language, _)| language == langid.language.as_str())
+        {
+            for (period, rule) in rule_set.0.iter() {
+                let invalid = || {
+                    DataError::new_resc_error(Error::Custom(
+                        format!("Invalid day period rule: {} {:?}", period, rule),
+                        None,
+                    ))
+                };
+                let period = parse_period(period).ok_or_else(invalid)?;
+                let (from, before) = match (&rule.at, &rule.from, &rule.before) {
+                    (Some(at), None, None) => (parse_time(at), None),
+                    (None, Some(from), Some(before)) => (
+                        parse_time(from),
+                        Some(parse_time(before).ok_or_else(invalid)?),
+                    ),
+                    _ => return Err(invalid()),
+                };
+                rules.push(DayPeriodRuleV1 {
+                    period,
+                    from: from.ok_or_else(invalid)?,
+                    before,
+                });
+            }
+        }
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(DayPeriodRulesV1 { rules })),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(DayPeriodRulesProvider<'data>, {
+    _ => DayPeriodRulesV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for DayPeriodRulesProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .langids
+            .iter()
+            .map(|l| ResourceOptions {
+                variant: None,
+                // TODO: Avoid the clone
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON day periods file.
+pub(self) mod cldr_json {
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Rule {
+        #[serde(rename = "_at")]
+        pub at: Option<String>,
+        #[serde(rename = "_from")]
+        pub from: Option<String>,
+        #[serde(rename = "_before")]
+        pub before: Option<String>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        #[serde(with = "tuple_vec_map", rename = "dayPeriodRuleSet")]
+        pub rule_sets: Vec<(String, RuleSet)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    #[serde(transparent)]
+    pub struct RuleSet(#[serde(with = "tuple_vec_map")] pub Vec<(String, Rule)>);
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::LanguageIdentifier;
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DayPeriodRulesProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |langid: LanguageIdentifier| -> DayPeriodRulesV1 {
+        let data: DataPayload<DayPeriodRulesV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::DAY_PERIOD_RULES_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        data.get().clone()
+    };
+
+    let en = load(langid!("en"));
+    assert_eq!(
+        en.rules
+            .iter()
+            .find(|r| r.period == FlexibleDayPeriod::Noon),
+        Some(&DayPeriodRuleV1 {
+            period: FlexibleDayPeriod::Noon,
+            from: 12 * 60,
+            before: None,
+        })
+    );
+    assert_eq!(
+        en.get_period(9, 30, false),
+        Some(FlexibleDayPeriod::Morning1)
+    );
+    assert_eq!(en.get_period(23, 0, true), Some(FlexibleDayPeriod::Night1));
+
+    // Regional variants use the rules of their language
+    assert_eq!(load(langid!("en-ZA")), en);
+
+    // Periods that last until midnight
+    let fr = load(langid!("fr"));
+    assert_eq!(
+        fr.get_period(23, 59, false),
+        Some(FlexibleDayPeriod::Evening1)
+    );
+
+    // The root locale has no rules
+    assert_eq!(load(LanguageIdentifier::und()), DayPeriodRulesV1::default());
+}
@@ -0,0 +1,256 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::bcp47_time_zones;
+use crate::error::Error;
+use crate::reader::open_reader;
+use crate::CldrPaths;
+use icu_datetime::provider::time_zones::{
+    MetaZonePeriodV1, MetaZonePeriodsV1, MetaZonePeriodsV1Marker, TimeZoneBcp47Id,
+};
+use icu_datetime::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [key::TIMEZONE_METAZONE_PERIODS_V1];
+
+/// A data provider reading from the CLDR JSON metazones file.
+#[derive(PartialEq, Debug)]
+pub struct MetaZonesProvider<'data> {
+    data: MetaZonePeriodsV1<'static>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for MetaZonesProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let resource: cldr_json::Resource = {
+            let path = cldr_paths
+                .cldr_core()?
+                .join("supplemental")
+                .join("metaZones.json");
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?
+        };
+        let bcp47_ids = bcp47_time_zones::load_iana_to_bcp47(cldr_paths)?;
+        let mut periods = LiteMap::new();
+        for (name, zones) in resource.supplemental.meta_zones.metazone_info.timezone {
+            zones.flatten_into(name, &bcp47_ids, &mut periods)?;
+        }
+        Ok(Self {
+            data: MetaZonePeriodsV1(periods),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for MetaZonesProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::TIMEZONE_METAZONE_PERIODS_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, MetaZonePeriodsV1Marker> for MetaZonesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, MetaZonePeriodsV1Marker>, DataError> {
+        MetaZonesProvider::supports_key(&req.resource_path.key)?;
+        let langid = &req.resource_path.options.langid;
+
+        // The metazones are not locale-specific, so only the root request is supported.
+        if langid.is_none() {
+            Ok(DataResponse {
+                metadata: DataResponseMetadata {
+                    data_langid: langid.clone(),
+                },
+                payload: Some(DataPayload::from_owned(self.data.clone())),
+            })
+        } else {
+            Err(DataError::MissingResourceOptions(req.clone()))
+        }
+    }
+}
+
+icu_provider::impl_dyn_provider!(MetaZonesProvider<'data>, {
+    _ => MetaZonePeriodsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for MetaZonesProvider<'data> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Parses a date and time of the form "1971-10-31 02:00" in UTC as seconds since the Unix epoch.
+fn parse_utc_datetime(input: &str) -> Option<i64> {
+    let (date, time) = input.split_once(' ')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let (hour, minute) = time.split_once(':')?;
+    let (hour, minute) = (hour.parse::<i64>().ok()?, minute.parse::<i64>().ok()?);
+
+    // Days from the Unix epoch, counting the years from March so that the leap day is the
+    // last day of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    Some(days * 86400 + hour * 3600 + minute * 60)
+}
+
+impl cldr_json::ZonePeriods {
+    /// Adds the periods of all time zones in `self` to `periods`, keyed by the BCP-47
+    /// identifier of the path from the root of the metazone info.
+    fn flatten_into(
+        self,
+        name: String,
+        bcp47_ids: &LiteMap<String, TimeZoneBcp47Id>,
+        periods: &mut LiteMap<Cow<'static, TimeZoneBcp47Id>, Vec<MetaZonePeriodV1<'static>>>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Periods(list) => {
+                let parse = |value: Option<String>| -> Result<Option<i64>, Error> {
+                    value
+                        .map(|value| {
+                            parse_utc_datetime(&value).ok_or_else(|| {
+                                Error::Custom(format!("Invalid metazone period: {}", value), None)
+                            })
+                        })
+                        .transpose()
+                };
+                let list = list
+                    .into_iter()
+                    .map(|period| -> Result<_, Error> {
+                        let period = period.uses_metazone;
+                        Ok(MetaZonePeriodV1 {
+                            from: parse(period.from)?,
+                            to: parse(period.to)?,
+                            metazone_id: Cow::Owned(period.mzone),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                let bcp47_id = bcp47_ids.get(&name).ok_or_else(|| {
+                    Error::Custom(format!("Unknown BCP-47 time zone: {}", name), None)
+                })?;
+                periods.insert(Cow::Owned(*bcp47_id), list);
+            }
+            Self::Zones(zones) => {
+                for (child, zones) in zones {
+                    zones.flatten_into(format!("{}/{}", name, child), bcp47_ids, periods)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serde structs for the CLDR JSON metazones file.
+pub(self) mod cldr_json {
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct UsesMetazone {
+        #[serde(rename = "_mzone")]
+        pub mzone: String,
+        #[serde(rename = "_from")]
+        pub from: Option<String>,
+        #[serde(rename = "_to")]
+        pub to: Option<String>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct MetazoneForPeriod {
+        #[serde(rename = "usesMetazone")]
+        pub uses_metazone: UsesMetazone,
+    }
+
+    /// The metazone periods of a time zone, or the time zones under a path such as "America".
+    #[derive(PartialEq, Debug, Deserialize)]
+    #[serde(untagged)]
+    pub enum ZonePeriods {
+        Periods(Vec<MetazoneForPeriod>),
+        Zones(BTreeMap<String, ZonePeriods>),
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct MetazoneInfo {
+        pub timezone: BTreeMap<String, ZonePeriods>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct MetaZones {
+        #[serde(rename = "metazoneInfo")]
+        pub metazone_info: MetazoneInfo,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        #[serde(rename = "metaZones")]
+        pub meta_zones: MetaZones,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+}
+
+#[test]
+fn test_parse_utc_datetime() {
+    assert_eq!(parse_utc_datetime("1970-01-01 00:00"), Some(0));
+    assert_eq!(parse_utc_datetime("1971-10-31 02:00"), Some(57_722_400));
+    assert_eq!(parse_utc_datetime("2000-02-29 12:30"), Some(951_827_400));
+    assert_eq!(parse_utc_datetime("1971-10-31"), None);
+}
+
+#[test]
+fn test_basic() {
+    use tinystr::tinystr8;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = MetaZonesProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let periods: DataPayload<MetaZonePeriodsV1Marker> = provider
+        .load_payload(&DataRequest::from(key::TIMEZONE_METAZONE_PERIODS_V1))
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        periods.get()[&TimeZoneBcp47Id(tinystr8!("uslax"))],
+        vec![MetaZonePeriodV1 {
+            from: None,
+            to: None,
+            metazone_id: Cow::Borrowed("America_Pacific"),
+        }]
+    );
+    assert_eq!(
+        periods.get()[&TimeZoneBcp47Id(tinystr8!("gblon"))],
+        vec![
+            MetaZonePeriodV1 {
+                from: None,
+                to: Some(57_722_400),
+                metazone_id: Cow::Borrowed("Europe_Central"),
+            },
+            MetaZonePeriodV1 {
+                from: Some(57_722_400),
+                to: None,
+                metazone_id: Cow::Borrowed("GMT"),
+            },
+        ]
+    );
+}
@@ -3,17 +3,30 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 mod aliases;
+mod bcp47_time_zones;
 mod dates;
+mod day_period_rules;
 mod likelysubtags;
+mod metazones;
 mod numbers;
 mod plurals;
 mod time_zones;
+mod tzif;
+mod week_data;
 
 pub use aliases::AliasesProvider;
-pub use dates::{patterns::DatePatternsProvider, symbols::DateSymbolsProvider};
+pub use bcp47_time_zones::Bcp47TimeZonesProvider;
+pub use dates::{
+    intervals::DateIntervalPatternsProvider, patterns::DatePatternsProvider,
+    relative_time::RelativeTimeProvider, symbols::DateSymbolsProvider,
+};
+pub use day_period_rules::DayPeriodRulesProvider;
 pub use likelysubtags::LikelySubtagsProvider;
+pub use metazones::MetaZonesProvider;
 pub use numbers::NumbersProvider;
 pub use plurals::PluralsProvider;
+pub use tzif::TzifProvider;
+pub use week_data::WeekDataProvider;
 
 use crate::support::LazyCldrProvider;
 use crate::CldrPaths;
@@ -27,12 +40,18 @@ use self::time_zones::TimeZonesProvider;
 pub fn get_all_cldr_keys() -> Vec<ResourceKey> {
     let mut result: Vec<ResourceKey> = vec![];
     result.extend(&aliases::ALL_KEYS);
+    result.extend(&bcp47_time_zones::ALL_KEYS);
     result.extend(&dates::symbols::ALL_KEYS);
     result.extend(&dates::patterns::ALL_KEYS);
+    result.extend(&dates::intervals::ALL_KEYS);
+    result.extend(&dates::relative_time::ALL_KEYS);
+    result.extend(&day_period_rules::ALL_KEYS);
     result.extend(&likelysubtags::ALL_KEYS);
+    result.extend(&metazones::ALL_KEYS);
     result.extend(&numbers::ALL_KEYS);
     result.extend(&plurals::ALL_KEYS);
     result.extend(&time_zones::ALL_KEYS);
+    result.extend(&week_data::ALL_KEYS);
     result
 }
 
@@ -40,12 +59,18 @@ pub fn get_all_cldr_keys() -> Vec<ResourceKey> {
 pub struct CldrJsonDataProvider<'a, 'data> {
     pub cldr_paths: &'a dyn CldrPaths,
     aliases: LazyCldrProvider<AliasesProvider<'data>>,
+    bcp47_time_zones: LazyCldrProvider<Bcp47TimeZonesProvider<'data>>,
     date_symbols: LazyCldrProvider<DateSymbolsProvider<'data>>,
     date_patterns: LazyCldrProvider<DatePatternsProvider<'data>>,
+    date_interval_patterns: LazyCldrProvider<DateIntervalPatternsProvider<'data>>,
+    relative_time: LazyCldrProvider<RelativeTimeProvider<'data>>,
+    day_period_rules: LazyCldrProvider<DayPeriodRulesProvider<'data>>,
     likelysubtags: LazyCldrProvider<LikelySubtagsProvider<'data>>,
+    metazones: LazyCldrProvider<MetaZonesProvider<'data>>,
     numbers: LazyCldrProvider<NumbersProvider>,
     plurals: LazyCldrProvider<PluralsProvider<'data>>,
     time_zones: LazyCldrProvider<TimeZonesProvider<'data>>,
+    week_data: LazyCldrProvider<WeekDataProvider<'data>>,
 }
 
 impl<'a> CldrJsonDataProvider<'a, '_> {
@@ -53,12 +78,18 @@ impl<'a> CldrJsonDataProvider<'a, '_> {
         CldrJsonDataProvider {
             cldr_paths,
             aliases: Default::default(),
+            bcp47_time_zones: Default::default(),
             date_symbols: Default::default(),
             date_patterns: Default::default(),
+            date_interval_patterns: Default::default(),
+            relative_time: Default::default(),
+            day_period_rules: Default::default(),
             likelysubtags: Default::default(),
+            metazones: Default::default(),
             numbers: Default::default(),
             plurals: Default::default(),
             time_zones: Default::default(),
+            week_data: Default::default(),
         }
     }
 }
@@ -71,15 +102,33 @@ impl<'a, 'data> DataProvider<'data, SerdeSeDataStructMarker> for CldrJsonDataPro
         if let Some(result) = self.aliases.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.bcp47_time_zones.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.date_symbols.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
         if let Some(result) = self.date_patterns.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self
+            .date_interval_patterns
+            .try_load_serde(req, self.cldr_paths)?
+        {
+            return Ok(result);
+        }
+        if let Some(result) = self.relative_time.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
+        if let Some(result) = self.day_period_rules.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.likelysubtags.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.metazones.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.numbers.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
@@ -89,6 +138,9 @@ impl<'a, 'data> DataProvider<'data, SerdeSeDataStructMarker> for CldrJsonDataPro
         if let Some(result) = self.time_zones.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.week_data.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         Err(DataError::MissingResourceKey(req.resource_path.key))
     }
 }
@@ -104,6 +156,12 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .bcp47_time_zones
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .date_symbols
             .try_supported_options(resc_key, self.cldr_paths)?
@@ -116,12 +174,36 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .date_interval_patterns
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
+        if let Some(resp) = self
+            .relative_time
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
+        if let Some(resp) = self
+            .day_period_rules
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .likelysubtags
             .try_supported_options(resc_key, self.cldr_paths)?
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .metazones
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .numbers
             .try_supported_options(resc_key, self.cldr_paths)?
@@ -140,6 +222,12 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .week_data
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         Err(DataError::MissingResourceKey(*resc_key))
     }
 }
@@ -149,5 +237,11 @@ impl<'a, 'd> KeyedDataProvider for CldrJsonDataProvider<'a, 'd> {
         PluralsProvider::supports_key(resc_key)
             .or_else(|err| DateSymbolsProvider::or_else_supports_key(err, resc_key))
             .or_else(|err| DatePatternsProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| DateIntervalPatternsProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| RelativeTimeProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| DayPeriodRulesProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| Bcp47TimeZonesProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| MetaZonesProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| WeekDataProvider::or_else_supports_key(err, resc_key))
     }
 }
@@ -114,20 +114,30 @@ impl<'data> DataProvider<'data, DecimalSymbolsV1Marker> for NumbersProvider {
             Ok(idx) => &self.cldr_numbers_data[idx].1.numbers,
             Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
         };
-        let nsname = numbers.default_numbering_system;
+        // The variant selects a numbering system, e.g. from the `-u-nu` keyword of a locale.
+        let nsname = match req.resource_path.options.variant {
+            Some(ref variant) => variant
+                .parse()
+                .map_err(|_| DataError::MissingResourceOptions(req.clone()))?,
+            None => numbers.default_numbering_system,
+        };
 
-        let mut result = DecimalSymbolsV1::try_from(numbers)
+        let mut result = symbols_for_numbering_system(numbers, nsname)
             .map_err(|s| Error::Custom(s.to_string(), Some(langid.clone())))
             .map_err(DataError::new_resc_error)?;
-        result.digits = self
-            .get_digits_for_numbering_system(nsname)
-            .ok_or_else(|| {
-                Error::Custom(
+        result.digits = match self.get_digits_for_numbering_system(nsname) {
+            Some(digits) => digits,
+            // Numbering systems without digits, such as algorithmic ones, are not supported
+            None if req.resource_path.options.variant.is_some() => {
+                return Err(DataError::MissingResourceOptions(req.clone()))
+            }
+            None => {
+                return Err(DataError::new_resc_error(Error::Custom(
                     format!("Could not process numbering system: {:?}", nsname),
                     Some(langid.clone()),
-                )
-            })
-            .map_err(DataError::new_resc_error)?;
+                )))
+            }
+        };
 
         Ok(DataResponse {
             metadata: DataResponseMetadata {
@@ -151,49 +161,78 @@ impl<'data> IterableDataProviderCore for NumbersProvider {
         let list: Vec<ResourceOptions> = self
             .cldr_numbers_data
             .iter()
-            .map(|(l, _)| ResourceOptions {
-                variant: None,
-                // TODO(#568): Avoid the clone
-                langid: Some(l.langid.clone()),
+            .flat_map(|(l, data)| {
+                // The default numbering system, followed by the other numbering systems that
+                // the locale has symbols for
+                let default_nsname = data.numbers.default_numbering_system;
+                let mut nsnames: Vec<TinyStr8> = data
+                    .numbers
+                    .numsys_data
+                    .symbols
+                    .keys()
+                    .copied()
+                    .filter(|nsname| *nsname != default_nsname)
+                    .collect();
+                nsnames.sort();
+                std::iter::once(None)
+                    .chain(
+                        nsnames
+                            .into_iter()
+                            .map(|nsname| Some(nsname.to_string().into())),
+                    )
+                    .map(move |variant| ResourceOptions {
+                        variant,
+                        // TODO(#568): Avoid the clone
+                        langid: Some(l.langid.clone()),
+                    })
             })
             .collect();
         Ok(Box::new(list.into_iter()))
     }
 }
 
-impl TryFrom<&cldr_serde::numbers_json::Numbers> for DecimalSymbolsV1<'static> {
-    type Error = Cow<'static, str>;
-
-    fn try_from(other: &cldr_serde::numbers_json::Numbers) -> Result<Self, Self::Error> {
-        // TODO(#510): Select from non-default numbering systems
-        let symbols = other
-            .numsys_data
-            .symbols
-            .get(&other.default_numbering_system)
-            .ok_or("Could not find symbols for default numbering system")?;
-        let formats = other
-            .numsys_data
-            .formats
-            .get(&other.default_numbering_system)
-            .ok_or("Could not find formats for default numbering system")?;
-        let parsed_pattern: decimal_pattern::DecimalPattern = formats
-            .standard
-            .parse()
-            .map_err(|s: decimal_pattern::Error| s.to_string())?;
+/// Returns the symbols of a locale for the numbering system `nsname`. Locales without symbols for
+/// it use the symbols of their default numbering system.
+///
+/// The digits are left to be filled in from the numbering system data.
+fn symbols_for_numbering_system(
+    numbers: &cldr_serde::numbers_json::Numbers,
+    nsname: TinyStr8,
+) -> Result<DecimalSymbolsV1<'static>, Cow<'static, str>> {
+    let numsys_data = &numbers.numsys_data;
+    let (symbols, formats) = match (
+        numsys_data.symbols.get(&nsname),
+        numsys_data.formats.get(&nsname),
+    ) {
+        (Some(symbols), Some(formats)) => (symbols, formats),
+        _ => (
+            numsys_data
+                .symbols
+                .get(&numbers.default_numbering_system)
+                .ok_or("Could not find symbols for default numbering system")?,
+            numsys_data
+                .formats
+                .get(&numbers.default_numbering_system)
+                .ok_or("Could not find formats for default numbering system")?,
+        ),
+    };
+    let parsed_pattern: decimal_pattern::DecimalPattern = formats
+        .standard
+        .parse()
+        .map_err(|s: decimal_pattern::Error| s.to_string())?;
 
-        Ok(Self {
-            minus_sign_affixes: parsed_pattern.localize_sign(&symbols.minus_sign),
-            plus_sign_affixes: parsed_pattern.localize_sign(&symbols.plus_sign),
-            decimal_separator: Cow::Owned(symbols.decimal.clone()),
-            grouping_separator: Cow::Owned(symbols.group.clone()),
-            grouping_sizes: GroupingSizesV1 {
-                primary: parsed_pattern.positive.primary_grouping,
-                secondary: parsed_pattern.positive.secondary_grouping,
-                min_grouping: other.minimum_grouping_digits,
-            },
-            digits: Default::default(), // to be filled in
-        })
-    }
+    Ok(DecimalSymbolsV1 {
+        minus_sign_affixes: parsed_pattern.localize_sign(&symbols.minus_sign),
+        plus_sign_affixes: parsed_pattern.localize_sign(&symbols.plus_sign),
+        decimal_separator: Cow::Owned(symbols.decimal.clone()),
+        grouping_separator: Cow::Owned(symbols.group.clone()),
+        grouping_sizes: GroupingSizesV1 {
+            primary: parsed_pattern.positive.primary_grouping,
+            secondary: parsed_pattern.positive.secondary_grouping,
+            min_grouping: numbers.minimum_grouping_digits,
+        },
+        digits: Default::default(), // to be filled in
+    })
 }
 
 #[test]
@@ -219,4 +258,50 @@ fn test_basic() {
 
     assert_eq!(ar_decimal.get().decimal_separator, "٫");
     assert_eq!(ar_decimal.get().digits[0], '٠');
+
+    let ar_latn_decimal: DataPayload<DecimalSymbolsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::SYMBOLS_V1,
+                options: ResourceOptions {
+                    variant: Some("latn".into()),
+                    langid: Some(langid!("ar-EG")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(ar_latn_decimal.get().decimal_separator, ".");
+    assert_eq!(ar_latn_decimal.get().digits[0], '0');
+
+    // Numbering systems that the locale has no symbols for use the default symbols
+    let en_thai_decimal: DataPayload<DecimalSymbolsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::SYMBOLS_V1,
+                options: ResourceOptions {
+                    variant: Some("thai".into()),
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(en_thai_decimal.get().decimal_separator, ".");
+    assert_eq!(en_thai_decimal.get().digits[0], '๐');
+
+    let unknown = provider.load_payload(&DataRequest {
+        resource_path: ResourcePath {
+            key: key::SYMBOLS_V1,
+            options: ResourceOptions {
+                variant: Some("nonexistent".into()),
+                langid: Some(langid!("en")),
+            },
+        },
+    });
+    assert!(matches!(unknown, Err(DataError::MissingResourceOptions(_))));
 }
@@ -6,12 +6,19 @@ use super::{Location, TimeZoneNames, ZoneFormat};
 use icu_datetime::provider::time_zones::{
     ExemplarCitiesV1, MetaZoneGenericNamesLongV1, MetaZoneGenericNamesShortV1,
     MetaZoneSpecificNamesLongV1, MetaZoneSpecificNamesShortV1, MetaZoneSpecificNamesV1,
-    TimeZoneFormatsV1,
+    TimeZoneBcp47Id, TimeZoneFormatsV1,
 };
 use litemap::LiteMap;
 use std::borrow::Cow;
 use tinystr::TinyStr8;
 
+/// The time zone names of a locale, together with the mapping from the IANA time zone
+/// identifiers in the names to BCP-47 time zone identifiers.
+pub struct CldrTimeZonesData<'a> {
+    pub time_zone_names: TimeZoneNames,
+    pub bcp47_ids: &'a LiteMap<String, TimeZoneBcp47Id>,
+}
+
 fn parse_hour_format<'data>(hour_format: &str) -> (Cow<'data, str>, Cow<'data, str>) {
     // e.g. "+HH:mm;-HH:mm" -> ("+HH:mm", "-HH:mm")
     let index = hour_format.rfind(';').unwrap();
@@ -20,14 +27,15 @@ fn parse_hour_format<'data>(hour_format: &str) -> (Cow<'data, str>, Cow<'data, s
     (Cow::Owned(positive), Cow::Owned(negative))
 }
 
-impl<'data> From<TimeZoneNames> for TimeZoneFormatsV1<'data> {
-    fn from(other: TimeZoneNames) -> Self {
+impl<'data> From<CldrTimeZonesData<'_>> for TimeZoneFormatsV1<'data> {
+    fn from(other: CldrTimeZonesData<'_>) -> Self {
         Self {
-            hour_format: parse_hour_format(&other.hour_format),
-            gmt_format: other.gmt_format.into(),
-            gmt_zero_format: other.gmt_zero_format.into(),
-            region_format: other.region_format.into(),
+            hour_format: parse_hour_format(&other.time_zone_names.hour_format),
+            gmt_format: other.time_zone_names.gmt_format.into(),
+            gmt_zero_format: other.time_zone_names.gmt_zero_format.into(),
+            region_format: other.time_zone_names.region_format.into(),
             region_format_variants: other
+                .time_zone_names
                 .region_format_variants
                 .into_iter()
                 .map(|(key, value)| {
@@ -40,7 +48,7 @@ impl<'data> From<TimeZoneNames> for TimeZoneFormatsV1<'data> {
                     )
                 })
                 .collect(),
-            fallback_format: other.fallback_format.into(),
+            fallback_format: other.time_zone_names.fallback_format.into(),
         }
     }
 }
@@ -55,10 +63,12 @@ impl Location {
     }
 }
 
-impl<'data> From<TimeZoneNames> for ExemplarCitiesV1<'data> {
-    fn from(other: TimeZoneNames) -> Self {
+impl<'data> From<CldrTimeZonesData<'_>> for ExemplarCitiesV1<'data> {
+    fn from(other: CldrTimeZonesData<'_>) -> Self {
+        let bcp47_ids = other.bcp47_ids;
         Self(
             other
+                .time_zone_names
                 .zone
                 .0
                 .into_iter()
@@ -73,7 +83,7 @@ impl<'data> From<TimeZoneNames> for ExemplarCitiesV1<'data> {
                             match place_or_region {
                                 super::LocationOrSubRegion::Location(place) => place
                                     .exemplar_city()
-                                    .map(|city| vec![(key.into(), city.into())])
+                                    .map(|city| vec![(key, city)])
                                     .unwrap_or_default(),
                                 super::LocationOrSubRegion::SubRegion(region) => region
                                     .into_iter()
@@ -81,20 +91,26 @@ impl<'data> From<TimeZoneNames> for ExemplarCitiesV1<'data> {
                                         let mut key = key.clone();
                                         key.push('/');
                                         key.push_str(&inner_key);
-                                        place.exemplar_city().map(|city| (key.into(), city.into()))
+                                        place.exemplar_city().map(|city| (key, city))
                                     })
                                     .collect::<Vec<_>>(),
                             }
                         })
                 })
+                // Time zones without a BCP-47 identifier cannot be formatted, so they are skipped.
+                .filter_map(|(key, city)| {
+                    bcp47_ids
+                        .get(&key)
+                        .map(|bcp47_id| (Cow::Owned(*bcp47_id), city.into()))
+                })
                 .collect(),
         )
     }
 }
 
-impl<'data> From<TimeZoneNames> for MetaZoneGenericNamesLongV1<'data> {
-    fn from(other: TimeZoneNames) -> Self {
-        match other.metazone {
+impl<'data> From<CldrTimeZonesData<'_>> for MetaZoneGenericNamesLongV1<'data> {
+    fn from(other: CldrTimeZonesData<'_>) -> Self {
+        match other.time_zone_names.metazone {
             None => Self(LiteMap::new()),
             Some(metazones) => Self(
                 metazones
@@ -119,9 +135,9 @@ impl<'data> From<TimeZoneNames> for MetaZoneGenericNamesLongV1<'data> {
     }
 }
 
-impl<'data> From<TimeZoneNames> for MetaZoneGenericNamesShortV1<'data> {
-    fn from(other: TimeZoneNames) -> Self {
-        match other.metazone {
+impl<'data> From<CldrTimeZonesData<'_>> for MetaZoneGenericNamesShortV1<'data> {
+    fn from(other: CldrTimeZonesData<'_>) -> Self {
+        match other.time_zone_names.metazone {
             None => Self(LiteMap::new()),
             Some(metazones) => Self(
                 metazones
@@ -146,9 +162,9 @@ impl<'data> From<TimeZoneNames> for MetaZoneGenericNamesShortV1<'data> {
     }
 }
 
-impl<'data> From<TimeZoneNames> for MetaZoneSpecificNamesLongV1<'data> {
-    fn from(other: TimeZoneNames) -> Self {
-        match other.metazone {
+impl<'data> From<CldrTimeZonesData<'_>> for MetaZoneSpecificNamesLongV1<'data> {
+    fn from(other: CldrTimeZonesData<'_>) -> Self {
+        match other.time_zone_names.metazone {
             None => Self(LiteMap::new()),
             Some(metazones) => Self(
                 metazones
@@ -167,9 +183,9 @@ impl<'data> From<TimeZoneNames> for MetaZoneSpecificNamesLongV1<'data> {
     }
 }
 
-impl<'data> From<TimeZoneNames> for MetaZoneSpecificNamesShortV1<'data> {
-    fn from(other: TimeZoneNames) -> Self {
-        match other.metazone {
+impl<'data> From<CldrTimeZonesData<'_>> for MetaZoneSpecificNamesShortV1<'data> {
+    fn from(other: CldrTimeZonesData<'_>) -> Self {
+        match other.time_zone_names.metazone {
             None => Self(LiteMap::new()),
             Some(metazones) => Self(
                 metazones
@@ -6,7 +6,7 @@
 //! These provide in-memory stuctures into which `timeZoneNames.json` will be serialized.
 //! Here is the `en` [timeZoneNames.json](https://raw.githubusercontent.com/unicode-org/cldr-json/master/cldr-json/cldr-dates-full/main/en/timeZoneNames.json) for context.
 
-mod convert;
+pub(super) mod convert;
 
 use crate::cldr_langid::CldrLangID;
 use serde::{
@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use super::bcp47_time_zones;
 use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
 use crate::reader::{get_subdirectories, open_reader};
@@ -11,12 +12,15 @@ use icu_provider::{
     iter::{IterableDataProviderCore, KeyedDataProvider},
     prelude::*,
 };
+use litemap::LiteMap;
 
 use std::convert::TryFrom;
 use std::marker::PhantomData;
 
 mod cldr_json;
 
+use cldr_json::convert::CldrTimeZonesData;
+
 /// All keys that this module is able to produce.
 pub const ALL_KEYS: [ResourceKey; 6] = [
     key::TIMEZONE_FORMATS_V1,
@@ -31,6 +35,7 @@ pub const ALL_KEYS: [ResourceKey; 6] = [
 #[derive(PartialEq, Debug)]
 pub struct TimeZonesProvider<'data> {
     data: Vec<(CldrLangID, cldr_json::LangTimeZones)>,
+    bcp47_ids: LiteMap<String, TimeZoneBcp47Id>,
     phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
 }
 
@@ -53,11 +58,15 @@ impl TryFrom<&dyn CldrPaths> for TimeZonesProvider<'_> {
 
         Ok(Self {
             data,
+            bcp47_ids: bcp47_time_zones::load_iana_to_bcp47(cldr_paths)?,
             phantom: PhantomData,
         })
     }
 }
 
+/// Creates a [`TimeZonesProvider`] from the contents of a CLDR JSON timeZoneNames file.
+///
+/// There are no BCP-47 time zone identifiers in the file, so the exemplar cities are empty.
 impl TryFrom<&str> for TimeZonesProvider<'_> {
     type Error = Error;
     fn try_from(input: &str) -> Result<Self, Self::Error> {
@@ -65,6 +74,7 @@ impl TryFrom<&str> for TimeZonesProvider<'_> {
             serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
         Ok(Self {
             data: resource.main.0,
+            bcp47_ids: LiteMap::new(),
             phantom: PhantomData,
         })
     }
@@ -72,7 +82,9 @@ impl TryFrom<&str> for TimeZonesProvider<'_> {
 
 impl<'data> KeyedDataProvider for TimeZonesProvider<'data> {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
-        if resc_key.category != ResourceCategory::TimeZone || resc_key.version != 1 {
+        // The metazone periods are also in the time zone category, but are provided by
+        // the MetaZonesProvider.
+        if !ALL_KEYS.contains(resc_key) {
             return Err(resc_key.into());
         }
         Ok(())
@@ -117,7 +129,10 @@ macro_rules! impl_data_provider {
                     metadata: DataResponseMetadata {
                         data_langid: req.resource_path.options.langid.clone(),
                     },
-                    payload: Some(DataPayload::from_owned($id::from(time_zones.clone()))),
+                    payload: Some(DataPayload::from_owned($id::from(CldrTimeZonesData {
+                        time_zone_names: time_zones.clone(),
+                        bcp47_ids: &self.bcp47_ids,
+                    }))),
                 })
             }
         }
@@ -190,7 +205,10 @@ mod tests {
             .unwrap()
             .take_payload()
             .unwrap();
-        assert_eq!("Pohnpei", exemplar_cities.get()["Pacific/Ponape"]);
+        assert_eq!(
+            "Pohnpei",
+            exemplar_cities.get()[&TimeZoneBcp47Id(tinystr8!("fmpni"))]
+        );
 
         let generic_names_long: DataPayload<MetaZoneGenericNamesLongV1Marker> = provider
             .load_payload(&DataRequest {
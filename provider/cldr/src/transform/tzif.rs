@@ -0,0 +1,464 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::bcp47_time_zones;
+use crate::error::Error;
+use crate::CldrPaths;
+use icu_datetime::provider::time_zones::{
+    DateRuleV1, DstRuleV1, LocalTimeTypeV1, TimeZoneBcp47Id, TimeZoneTransitionsV1,
+    TimeZoneTransitionsV1Marker, TransitionRuleV1, TransitionsV1,
+};
+use icu_datetime::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use std::borrow::Cow;
+use std::fs;
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [key::TIMEZONE_TRANSITIONS_V1];
+
+/// A data provider reading the GMT offset transitions of time zones from a directory of
+/// compiled TZif files, such as `/usr/share/zoneinfo`.
+///
+/// TZif files are part of the IANA time zone database rather than CLDR, so this provider is
+/// constructed from the path of the directory, and is not part of
+/// [`CldrJsonDataProvider`](super::CldrJsonDataProvider). The time zones are keyed by their
+/// BCP-47 identifier from CLDR, so time zones without one are skipped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use icu_provider_cldr::transform::TzifProvider;
+/// use icu_provider_cldr::CldrPathsAllInOne;
+/// use std::path::{Path, PathBuf};
+///
+/// let cldr_paths = CldrPathsAllInOne {
+///     cldr_json_root: PathBuf::from("/path/to/cldr-json"),
+///     locale_subset: "full".to_string(),
+/// };
+///
+/// let provider = TzifProvider::try_new(Path::new("/usr/share/zoneinfo"), &cldr_paths)
+///     .expect("Failed to read the TZif files");
+/// ```
+#[derive(PartialEq, Debug)]
+pub struct TzifProvider<'data> {
+    data: TimeZoneTransitionsV1<'static>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TzifProvider<'_> {
+    /// Reads the TZif files under `root` of all time zones with a BCP-47 identifier in
+    /// `cldr_paths`, using the first of the IANA identifiers of each time zone that has a file.
+    pub fn try_new(root: &Path, cldr_paths: &dyn CldrPaths) -> Result<Self, Error> {
+        let mut transitions = LiteMap::new();
+        for (bcp47_id, iana_ids) in bcp47_time_zones::load_bcp47_ids(cldr_paths)?.into_tuple_vec() {
+            let path = match iana_ids
+                .iter()
+                .map(|iana_id| root.join(iana_id))
+                .find(|path| path.is_file())
+            {
+                Some(path) => path,
+                None => continue,
+            };
+            let bytes = fs::read(&path).map_err(|e| (e, &path))?;
+            let zone = parse_tzif(&bytes).ok_or_else(|| {
+                Error::Custom(format!("Invalid TZif file: {}", path.display()), None)
+            })?;
+            transitions.insert(Cow::Owned(bcp47_id), zone);
+        }
+        Ok(Self {
+            data: TimeZoneTransitionsV1(transitions),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for TzifProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::TIMEZONE_TRANSITIONS_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, TimeZoneTransitionsV1Marker> for TzifProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, TimeZoneTransitionsV1Marker>, DataError> {
+        TzifProvider::supports_key(&req.resource_path.key)?;
+        let langid = &req.resource_path.options.langid;
+
+        // The transitions are not locale-specific, so only the root request is supported.
+        if langid.is_none() {
+            Ok(DataResponse {
+                metadata: DataResponseMetadata {
+                    data_langid: langid.clone(),
+                },
+                payload: Some(DataPayload::from_owned(self.data.clone())),
+            })
+        } else {
+            Err(DataError::MissingResourceOptions(req.clone()))
+        }
+    }
+}
+
+icu_provider::impl_dyn_provider!(TzifProvider<'data>, {
+    _ => TimeZoneTransitionsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for TzifProvider<'data> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// A cursor over the big-endian fields of a TZif file.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.0.len() < len {
+            return None;
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.take(4)?);
+        Some(u32::from_be_bytes(bytes))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        self.u32().map(|value| value as i32)
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        Some(i64::from_be_bytes(bytes))
+    }
+}
+
+/// The counts of the records in the body of a TZif file, from its header.
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn read(reader: &mut Reader) -> Option<Self> {
+        if reader.take(4)? != b"TZif" {
+            return None;
+        }
+        let version = reader.u8()?;
+        reader.take(15)?;
+        Some(Self {
+            version,
+            isutcnt: reader.u32()? as usize,
+            isstdcnt: reader.u32()? as usize,
+            leapcnt: reader.u32()? as usize,
+            timecnt: reader.u32()? as usize,
+            typecnt: reader.u32()? as usize,
+            charcnt: reader.u32()? as usize,
+        })
+    }
+
+    /// The length of the body of the version 1 data block, which uses 32-bit times.
+    fn v1_body_len(&self) -> usize {
+        self.timecnt * 5
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * 8
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// Parses the transitions of a TZif file, as specified by RFC 8536.
+///
+/// For version 2 and later files, the 64-bit data block and the footer are used.
+fn parse_tzif(bytes: &[u8]) -> Option<TransitionsV1> {
+    let mut reader = Reader(bytes);
+    let mut header = Header::read(&mut reader)?;
+    if header.version != 0 {
+        reader.take(header.v1_body_len())?;
+        header = Header::read(&mut reader)?;
+    }
+    let is_v1 = header.version == 0;
+
+    let transition_times = (0..header.timecnt)
+        .map(|_| {
+            if is_v1 {
+                reader.i32().map(i64::from)
+            } else {
+                reader.i64()
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let transition_types = reader.take(header.timecnt)?.to_vec();
+    let local_time_types = (0..header.typecnt)
+        .map(|_| {
+            let offset = reader.i32()?;
+            let is_dst = reader.u8()? != 0;
+            // The index of the abbreviation, which is not used.
+            reader.u8()?;
+            Some(LocalTimeTypeV1 { offset, is_dst })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if transition_types
+        .iter()
+        .any(|&index| index as usize >= local_time_types.len())
+    {
+        return None;
+    }
+
+    let rule = if is_v1 {
+        None
+    } else {
+        // The abbreviations and the 64-bit leap second records, which are not used.
+        reader.take(header.charcnt + header.leapcnt * 12)?;
+        reader.take(header.isstdcnt + header.isutcnt)?;
+        let footer = core::str::from_utf8(reader.0).ok()?;
+        let footer = footer.strip_prefix('\n')?;
+        let footer = &footer[..footer.find('\n')?];
+        if footer.is_empty() {
+            None
+        } else {
+            Some(parse_posix_tz(footer)?)
+        }
+    };
+
+    Some(TransitionsV1 {
+        transition_times,
+        transition_types,
+        local_time_types,
+        rule,
+    })
+}
+
+/// Parses a POSIX TZ string of a TZif footer, e.g. "PST8PDT,M3.2.0,M11.1.0".
+///
+/// Only the `Mm.w.d` form of the transition dates is supported, since it is the only form used
+/// by the IANA time zone database.
+fn parse_posix_tz(input: &str) -> Option<TransitionRuleV1> {
+    let mut input = input;
+    skip_name(&mut input)?;
+    // POSIX offsets are positive west of Greenwich.
+    let std_offset = -parse_time(&mut input)?;
+    if input.is_empty() {
+        return Some(TransitionRuleV1 {
+            std_offset,
+            dst: None,
+        });
+    }
+    skip_name(&mut input)?;
+    let dst_offset = if input.starts_with(',') {
+        std_offset + 3600
+    } else {
+        -parse_time(&mut input)?
+    };
+    input = input.strip_prefix(',')?;
+    let start = parse_date_rule(&mut input)?;
+    input = input.strip_prefix(',')?;
+    let end = parse_date_rule(&mut input)?;
+    if !input.is_empty() {
+        return None;
+    }
+    Some(TransitionRuleV1 {
+        std_offset,
+        dst: Some(DstRuleV1 {
+            offset: dst_offset,
+            start,
+            end,
+        }),
+    })
+}
+
+/// Skips a time zone abbreviation, e.g. "PST" or "<-03>".
+fn skip_name(input: &mut &str) -> Option<()> {
+    let len = if input.starts_with('<') {
+        input.find('>')? + 1
+    } else {
+        input
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(input.len())
+    };
+    if len < 3 {
+        return None;
+    }
+    *input = &input[len..];
+    Some(())
+}
+
+/// Parses a signed time of the form `[+|-]hh[:mm[:ss]]` as seconds.
+fn parse_time(input: &mut &str) -> Option<i32> {
+    let sign = if let Some(rest) = input.strip_prefix('-') {
+        *input = rest;
+        -1
+    } else {
+        if let Some(rest) = input.strip_prefix('+') {
+            *input = rest;
+        }
+        1
+    };
+    let mut seconds = 0;
+    for (i, unit) in [3600, 60, 1].iter().enumerate() {
+        if i > 0 {
+            match input.strip_prefix(':') {
+                Some(rest) => *input = rest,
+                None => break,
+            }
+        }
+        let len = input
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len());
+        seconds += input[..len].parse::<i32>().ok()? * unit;
+        *input = &input[len..];
+    }
+    Some(sign * seconds)
+}
+
+/// Parses a transition date of the form `Mm.w.d[/time]`.
+fn parse_date_rule(input: &mut &str) -> Option<DateRuleV1> {
+    *input = input.strip_prefix('M')?;
+    let mut number = || -> Option<u8> {
+        let len = input
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len());
+        let value = input[..len].parse().ok()?;
+        *input = &input[len..];
+        if let Some(rest) = input.strip_prefix('.') {
+            *input = rest;
+        }
+        Some(value)
+    };
+    let (month, week, weekday) = (number()?, number()?, number()?);
+    if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+        return None;
+    }
+    let time = match input.strip_prefix('/') {
+        Some(rest) => {
+            *input = rest;
+            parse_time(input)?
+        }
+        None => 2 * 3600,
+    };
+    Some(DateRuleV1 {
+        month,
+        week,
+        weekday,
+        time,
+    })
+}
+
+#[test]
+fn test_parse_posix_tz() {
+    let rule = |month, week, weekday, time| DateRuleV1 {
+        month,
+        week,
+        weekday,
+        time,
+    };
+    assert_eq!(
+        parse_posix_tz("JST-9"),
+        Some(TransitionRuleV1 {
+            std_offset: 9 * 3600,
+            dst: None,
+        })
+    );
+    assert_eq!(
+        parse_posix_tz("PST8PDT,M3.2.0,M11.1.0"),
+        Some(TransitionRuleV1 {
+            std_offset: -8 * 3600,
+            dst: Some(DstRuleV1 {
+                offset: -7 * 3600,
+                start: rule(3, 2, 0, 2 * 3600),
+                end: rule(11, 1, 0, 2 * 3600),
+            }),
+        })
+    );
+    assert_eq!(
+        parse_posix_tz("<+1030>-10:30<+11>-11,M10.1.0,M4.1.0"),
+        Some(TransitionRuleV1 {
+            std_offset: 10 * 3600 + 30 * 60,
+            dst: Some(DstRuleV1 {
+                offset: 11 * 3600,
+                start: rule(10, 1, 0, 2 * 3600),
+                end: rule(4, 1, 0, 2 * 3600),
+            }),
+        })
+    );
+    assert_eq!(
+        parse_posix_tz("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1"),
+        Some(TransitionRuleV1 {
+            std_offset: -3 * 3600,
+            dst: Some(DstRuleV1 {
+                offset: -2 * 3600,
+                start: rule(3, 5, 0, -2 * 3600),
+                end: rule(10, 5, 0, -3600),
+            }),
+        })
+    );
+    assert_eq!(parse_posix_tz("EST5EDT,J60,J300"), None);
+}
+
+#[test]
+fn test_basic() {
+    use tinystr::tinystr8;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider =
+        TzifProvider::try_new(icu_testdata::paths::tzif_root().as_path(), &cldr_paths).unwrap();
+
+    let transitions: DataPayload<TimeZoneTransitionsV1Marker> = provider
+        .load_payload(&DataRequest::from(key::TIMEZONE_TRANSITIONS_V1))
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let los_angeles = &transitions.get()[&TimeZoneBcp47Id(tinystr8!("uslax"))];
+    // The first transition is from local mean time to standard time, on 1883-11-18 at noon.
+    assert_eq!(los_angeles.transition_times[0], -2_717_640_000);
+    assert_eq!(
+        los_angeles.local_time_types[los_angeles.transition_types[0] as usize],
+        LocalTimeTypeV1 {
+            offset: -8 * 3600,
+            is_dst: false,
+        }
+    );
+    assert_eq!(
+        los_angeles
+            .rule
+            .and_then(|rule| rule.dst)
+            .map(|dst| dst.offset),
+        Some(-7 * 3600)
+    );
+
+    let tokyo = &transitions.get()[&TimeZoneBcp47Id(tinystr8!("jptyo"))];
+    assert_eq!(
+        tokyo.rule,
+        Some(TransitionRuleV1 {
+            std_offset: 9 * 3600,
+            dst: None,
+        })
+    );
+}
@@ -0,0 +1,267 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_datetime::date::IsoWeekday;
+use icu_datetime::provider::{key, week_data::*};
+use icu_locid::{subtags, LanguageIdentifier};
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::WEEK_DATA_V1, //
+];
+
+/// The region whose week data applies to regions that are not listed in CLDR.
+const DEFAULT_REGION: &str = "001";
+
+/// A data provider reading from the CLDR JSON week data file.
+///
+/// Week data is given per region. The region of a locale without one is taken from its
+/// likely subtags, e.g. `en` uses the week data of `US`.
+#[derive(PartialEq, Debug)]
+pub struct WeekDataProvider<'data> {
+    week_data: cldr_json::WeekData,
+    likely_subtags: Vec<(LanguageIdentifier, LanguageIdentifier)>,
+    langids: Vec<CldrLangID>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for WeekDataProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let week_data = {
+            let path = cldr_paths
+                .cldr_core()?
+                .join("supplemental")
+                .join("weekData.json");
+            let data: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.supplemental.week_data
+        };
+        let likely_subtags = {
+            let path = cldr_paths
+                .cldr_core()?
+                .join("supplemental")
+                .join("likelySubtags.json");
+            let data: cldr_json::LikelySubtagsResource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.supplemental.likely_subtags
+        };
+        // Week data is available for the same locales as the other datetime data.
+        let mut langids = vec![];
+        for dir in get_subdirectories(&cldr_paths.cldr_dates()?.join("main"))? {
+            if let Some(name) = dir.file_name().and_then(|name| name.to_str()) {
+                let langid = name
+                    .parse::<CldrLangID>()
+                    .map_err(|e| Error::Custom(format!("{}: {}", e, name), None))?;
+                langids.push(langid);
+            }
+        }
+        Ok(Self {
+            week_data,
+            likely_subtags,
+            langids,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for WeekDataProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::WEEK_DATA_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> WeekDataProvider<'data> {
+    /// Returns the region whose week data applies to `langid`, if any.
+    fn region_for(&self, langid: &LanguageIdentifier) -> Option<subtags::Region> {
+        if langid.region.is_some() {
+            return langid.region;
+        }
+        if langid.language.is_empty() {
+            // The root locale uses the week data of the world.
+            return None;
+        }
+        let language_script = LanguageIdentifier {
+            language: langid.language,
+            script: langid.script,
+            region: None,
+            variants: subtags::Variants::new(),
+        };
+        let language = LanguageIdentifier {
+            language: langid.language,
+            script: None,
+            region: None,
+            variants: subtags::Variants::new(),
+        };
+        [language_script, language].iter().find_map(|search| {
+            self.likely_subtags
+                .iter()
+                .find(|(from, _)| from == search)
+                .and_then(|(_, to)| to.region)
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, WeekDataV1Marker> for WeekDataProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, WeekDataV1Marker>, DataError> {
+        WeekDataProvider::supports_key(&req.resource_path.key)?;
+        let langid = req.try_langid()?;
+        let region = self.region_for(langid);
+        let region = region.as_ref().map_or(DEFAULT_REGION, |r| r.as_str());
+        let lookup = |map: &[(String, String)]| -> Option<String> {
+            [region, DEFAULT_REGION].iter().find_map(|region| {
+                map.iter()
+                    .find(|(r, _)| r == *region)
+                    .map(|(_, value)| value.clone())
+            })
+        };
+        let first_weekday = match lookup(&self.week_data.first_day).as_deref() {
+            Some("mon") => IsoWeekday::Monday,
+            Some("tue") => IsoWeekday::Tuesday,
+            Some("wed") => IsoWeekday::Wednesday,
+            Some("thu") => IsoWeekday::Thursday,
+            Some("fri") => IsoWeekday::Friday,
+            Some("sat") => IsoWeekday::Saturday,
+            Some("sun") => IsoWeekday::Sunday,
+            _ => return Err(DataError::MissingResourceOptions(req.clone())),
+        };
+        let min_week_days = lookup(&self.week_data.min_days)
+            .and_then(|days| days.parse().ok())
+            .ok_or_else(|| DataError::MissingResourceOptions(req.clone()))?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(WeekDataV1 {
+                first_weekday: first_weekday as u8,
+                min_week_days,
+            })),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(WeekDataProvider<'data>, {
+    _ => WeekDataV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for WeekDataProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .langids
+            .iter()
+            .map(|l| ResourceOptions {
+                variant: None,
+                // TODO: Avoid the clone
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON week data and likely subtags files.
+pub(self) mod cldr_json {
+    use icu_locid::LanguageIdentifier;
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct WeekData {
+        #[serde(with = "tuple_vec_map", rename = "minDays")]
+        pub min_days: Vec<(String, String)>,
+        #[serde(with = "tuple_vec_map", rename = "firstDay")]
+        pub first_day: Vec<(String, String)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        #[serde(rename = "weekData")]
+        pub week_data: WeekData,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LikelySubtagsSupplemental {
+        #[serde(with = "tuple_vec_map", rename = "likelySubtags")]
+        pub likely_subtags: Vec<(LanguageIdentifier, LanguageIdentifier)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LikelySubtagsResource {
+        pub supplemental: LikelySubtagsSupplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = WeekDataProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |langid: LanguageIdentifier| -> WeekDataV1 {
+        let data: DataPayload<WeekDataV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::WEEK_DATA_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        *data.get()
+    };
+
+    let en_us = WeekDataV1 {
+        first_weekday: IsoWeekday::Sunday as u8,
+        min_week_days: 1,
+    };
+    let de_de = WeekDataV1 {
+        first_weekday: IsoWeekday::Monday as u8,
+        min_week_days: 4,
+    };
+    assert_eq!(load(langid!("en-US")), en_us);
+    assert_eq!(load(langid!("de-DE")), de_de);
+
+    // Locales without a region use the region of their likely subtags
+    assert_eq!(load(langid!("en")), en_us);
+    assert_eq!(load(langid!("fr")), de_de);
+
+    // Regions without week data, and the root locale, use the data of the world
+    let world = WeekDataV1 {
+        first_weekday: IsoWeekday::Monday as u8,
+        min_week_days: 1,
+    };
+    assert_eq!(load(langid!("en-001")), world);
+    assert_eq!(load(langid!("en-AQ")), world);
+    assert_eq!(load(LanguageIdentifier::und()), world);
+
+    // Regions in which weeks start on another day
+    assert_eq!(
+        load(langid!("ar-EG")).first_weekday,
+        IsoWeekday::Saturday as u8
+    );
+}
@@ -7,12 +7,14 @@
 //! The package exposes a `DataProvider` with stable data useful for unit testing. The data is
 //! based on a CLDR tag and a short list of locales that, together, cover a range of scenarios.
 //!
-//! The list of locales and the current CLDR tag can be found in [Cargo.toml](./Cargo.toml).
+//! The list of locales, the current CLDR tag and the release of the IANA time zone database can
+//! be found in [Cargo.toml](./Cargo.toml).
 //!
 //! The output data can be found in the [data](./data/) subdirectory. There, you will find:
 //!
 //! - `json` for the ICU4X JSON test data
 //! - `cldr` for the source CLDR JSON
+//! - `tzif` for the source TZif time zone files, compiled from the IANA time zone database
 //!
 //! ## Pointing to custom test data
 //!
@@ -33,6 +35,7 @@
 //! The following commands are also available:
 //!
 //! - `cargo make testdata-download` downloads fresh CLDR JSON
+//! - `cargo make testdata-download-tzif` downloads the IANA time zone database and compiles fresh TZif files
 //! - `cargo make testdata-build-json` re-generates the ICU4X JSON
 //! - `cargo make testdata-build-blob` re-generates the ICU4X blob file
 //! - `cargo make bincode-gen-testdata` generates Bincode filesystem testdata
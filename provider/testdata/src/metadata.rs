@@ -38,6 +38,8 @@ pub struct PackageMetadata {
     pub locales: Vec<LanguageIdentifier>,
     pub cldr_json_glob: Vec<String>,
     pub gitref: String,
+    pub tzif_zones: Vec<String>,
+    pub tzdata_version: String,
 }
 
 impl PackageMetadata {
@@ -18,3 +18,8 @@ pub fn cldr_json_root() -> PathBuf {
 pub fn icu4x_json_root() -> PathBuf {
     data_root().join("json")
 }
+
+/// Returns the absolute path to the directory of compiled TZif time zone files.
+pub fn tzif_root() -> PathBuf {
+    data_root().join("tzif")
+}
@@ -0,0 +1,169 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use anyhow::Context;
+use clap::{App, Arg, ArgMatches};
+use icu_testdata::metadata::{self, PackageInfo};
+use simple_logger::SimpleLogger;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tokio::fs;
+
+/// The files of the IANA time zone database with the rules and zones to compile.
+const TZDATA_SOURCES: [&str; 9] = [
+    "africa",
+    "antarctica",
+    "asia",
+    "australasia",
+    "europe",
+    "northamerica",
+    "southamerica",
+    "etcetera",
+    "backward",
+];
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let tzif_root = icu_testdata::paths::tzif_root();
+
+    let args = App::new("ICU4X TZif Test Data Downloader")
+        .version("0.0.1")
+        .author("The ICU4X Project Developers")
+        .about("Download the IANA time zone database and compile TZif files for ICU4X testing")
+        .arg(
+            Arg::with_name("VERBOSE")
+                .short("v")
+                .long("verbose")
+                .multiple(true)
+                .help("Sets the level of verbosity (-v, -vv, or -vvv)"),
+        )
+        .arg(
+            Arg::with_name("OUTPUT")
+                .short("o")
+                .long("out")
+                .help(
+                    "Path to output data directory. The directory will be overwritten. Omit this option to write data into the package tree.",
+                )
+                .takes_value(true)
+                .default_value_os(tzif_root.as_os_str()),
+        )
+        .get_matches();
+
+    match args.occurrences_of("VERBOSE") {
+        0 => SimpleLogger::from_env().init().unwrap(),
+        1 => SimpleLogger::new()
+            .with_level(log::LevelFilter::Info)
+            .init()
+            .unwrap(),
+        2 => SimpleLogger::new()
+            .with_level(log::LevelFilter::Debug)
+            .init()
+            .unwrap(),
+        3 => SimpleLogger::new()
+            .with_level(log::LevelFilter::Trace)
+            .init()
+            .unwrap(),
+        _ => anyhow::bail!("Only -v, -vv, and -vvv are supported"),
+    }
+
+    let metadata = metadata::load()?;
+    log::debug!("Package metadata: {:?}", metadata);
+
+    download_tzif(&args, &metadata).await?;
+
+    Ok(())
+}
+
+async fn download_tzif(args: &ArgMatches<'_>, metadata: &PackageInfo) -> anyhow::Result<()> {
+    let output_path = PathBuf::from(
+        args.value_of_os("OUTPUT")
+            .expect("Option has a default value"),
+    );
+    let version = &metadata.package_metadata.tzdata_version;
+
+    // The sources are downloaded and compiled in the target directory.
+    let work_dir = metadata
+        .target_directory
+        .as_std_path()
+        .join(format!("tzdata{}", version));
+    if work_dir.exists() {
+        fs::remove_dir_all(&work_dir)
+            .await
+            .with_context(|| format!("Failed to delete directory: {:?}", &work_dir))?;
+    }
+    fs::create_dir_all(&work_dir)
+        .await
+        .with_context(|| format!("Failed to create dir: {:?}", &work_dir))?;
+
+    let url = format!(
+        "https://data.iana.org/time-zones/releases/tzdata{}.tar.gz",
+        version
+    );
+    log::info!("Downloading: {}", url);
+    let client = reqwest::ClientBuilder::new()
+        .user_agent(concat!(
+            env!("CARGO_PKG_NAME"),
+            "/",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()?;
+    let bytes = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    let archive = work_dir.join("tzdata.tar.gz");
+    fs::write(&archive, &bytes)
+        .await
+        .with_context(|| format!("Failed to write to file: {:?}", &archive))?;
+
+    run(Command::new("tar")
+        .arg("-xzf")
+        .arg(&archive)
+        .arg("-C")
+        .arg(&work_dir))?;
+
+    // The testdata uses the "fat" TZif files, which contain the transitions of every year
+    // up to 2037 in addition to the POSIX TZ string of the footer.
+    let zoneinfo = work_dir.join("zoneinfo");
+    log::info!("Compiling: {:?}", zoneinfo);
+    run(Command::new("zic")
+        .args(&["-b", "fat", "-d"])
+        .arg(&zoneinfo)
+        .args(TZDATA_SOURCES.iter().map(|source| work_dir.join(source))))?;
+
+    if output_path.exists() {
+        fs::remove_dir_all(&output_path)
+            .await
+            .with_context(|| format!("Failed to delete directory: {:?}", &output_path))?;
+    }
+    for zone in metadata.package_metadata.tzif_zones.iter() {
+        copy_zone(&zoneinfo, &output_path, zone).await?;
+    }
+    Ok(())
+}
+
+async fn copy_zone(zoneinfo: &Path, output_path: &Path, zone: &str) -> anyhow::Result<()> {
+    log::info!("Copying: {}", zone);
+    let local_path = output_path.join(zone);
+    fs::create_dir_all(local_path.parent().unwrap())
+        .await
+        .with_context(|| format!("Failed to create dir: {:?}", &local_path))?;
+    fs::copy(zoneinfo.join(zone), &local_path)
+        .await
+        .with_context(|| format!("Failed to create file: {:?}", &local_path))?;
+    Ok(())
+}
+
+fn run(command: &mut Command) -> anyhow::Result<()> {
+    let status = command
+        .status()
+        .with_context(|| format!("Failed to run: {:?}", command))?;
+    if !status.success() {
+        anyhow::bail!("{:?} failed: {}", command, status);
+    }
+    Ok(())
+}
@@ -305,6 +305,101 @@ impl FixedDecimal {
         }
     }
 
+    /// Zero-pad the number on the left to a particular number of integer digits, modifying self.
+    ///
+    /// Leading zeros that were added by a previous call are removed if `digits` is smaller than
+    /// the current number of integer digits; significant digits are never removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(42);
+    /// assert_eq!("42", dec.to_string());
+    ///
+    /// dec.pad_left(4);
+    /// assert_eq!("0042", dec.to_string());
+    ///
+    /// dec.pad_left(1);
+    /// assert_eq!("42", dec.to_string());
+    /// ```
+    pub fn pad_left(&mut self, digits: u16) {
+        let magnitude = cmp::min(digits, i16::MAX as u16) as i16 - 1;
+        self.upper_magnitude = cmp::max(cmp::max(magnitude, self.magnitude), 0);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Zero-pad the number on the left to a particular number of integer digits, consuming self
+    /// and returning a new object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec = FixedDecimal::from(7).padded_left(2);
+    /// assert_eq!("07", dec.to_string());
+    /// ```
+    pub fn padded_left(mut self, digits: u16) -> Self {
+        self.pad_left(digits);
+        self
+    }
+
+    /// Remove the digits above a particular magnitude, modifying self.
+    ///
+    /// Digits that are left over keep their magnitude, so zeros may remain on the left; call
+    /// [`FixedDecimal::pad_left`] to change the number of integer digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(2021);
+    /// dec.truncate_left(1);
+    /// assert_eq!("21", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(2007);
+    /// dec.truncate_left(1);
+    /// assert_eq!("07", dec.to_string());
+    /// ```
+    pub fn truncate_left(&mut self, magnitude: i16) {
+        if magnitude < self.magnitude {
+            // The following cast can't fail: magnitude < self.magnitude, and u16::MAX ==
+            // i16::MAX - i16::MIN.
+            let cut = (self.magnitude as i32 - magnitude as i32) as usize;
+            if cut >= self.digits.len() {
+                self.digits.clear();
+                self.magnitude = 0;
+            } else {
+                let leading_zeros = self.digits[cut..].iter().take_while(|d| **d == 0).count();
+                self.digits.drain(..cut + leading_zeros);
+                self.magnitude = magnitude - leading_zeros as i16;
+            }
+        }
+        self.upper_magnitude = cmp::max(cmp::min(self.upper_magnitude, magnitude), 0);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Remove the digits above a particular magnitude, consuming self and returning a new
+    /// object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec = FixedDecimal::from(1999).truncated_left(1).padded_left(2);
+    /// assert_eq!("99", dec.to_string());
+    /// ```
+    pub fn truncated_left(mut self, magnitude: i16) -> Self {
+        self.truncate_left(magnitude);
+        self
+    }
+
     /// Change the value from negative to positive or from positive to negative, modifying self.
     ///
     /// # Examples
@@ -1006,3 +1101,34 @@ fn test_signum_zero() {
         assert_eq!(cas.expected_signum, signum, "{:?}", cas);
     }
 }
+
+#[test]
+fn test_pad_truncate_left() {
+    let cases = [
+        ("42", 1, 4, "0042"),
+        ("42", 3, 1, "42"),
+        ("2021", 1, 2, "21"),
+        ("2007", 1, 2, "07"),
+        ("2000", 1, 2, "00"),
+        ("2000", 1, 0, "0"),
+        ("12.34", 0, 2, "02.34"),
+        ("12.34", -1, 0, "0.34"),
+        ("-1999", 1, 2, "-99"),
+        ("00123", 4, 0, "123"),
+        ("00123", 1, 3, "023"),
+    ];
+    for &(input, magnitude, digits, expected) in &cases {
+        let dec = FixedDecimal::from_str(input)
+            .unwrap()
+            .truncated_left(magnitude)
+            .padded_left(digits);
+        assert_eq!(
+            expected,
+            dec.to_string(),
+            "{} {} {}",
+            input,
+            magnitude,
+            digits
+        );
+    }
+}
@@ -176,3 +176,58 @@ macro_rules! assert_writeable_eq {
         }
     };
 }
+
+/// Testing macro for types implementing Writeable whose length is always known in advance. The
+/// argument should be a `&dyn Writeable`.
+///
+/// The macro tests that the string length is [`LengthHint::Exact`] and equal to the length of
+/// the string content. Unlike [`assert_writeable_eq!`], it does not need the expected string,
+/// which makes it suitable for checking the length of many different outputs.
+///
+/// # Examples
+///
+/// ```
+/// use writeable::Writeable;
+/// use writeable::LengthHint;
+/// use writeable::assert_writeable_len_exact;
+/// use std::fmt;
+///
+/// struct Demo;
+/// impl Writeable for Demo {
+///     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+///         sink.write_str("foo")
+///     }
+///     fn write_len(&self) -> LengthHint {
+///         LengthHint::Exact(3)
+///     }
+/// }
+///
+/// assert_writeable_len_exact!(&Demo);
+/// assert_writeable_len_exact!(&Demo, "Message: {}", "Hello World");
+/// ```
+#[macro_export]
+macro_rules! assert_writeable_len_exact {
+    ($actual_writeable:expr $(,)?) => {
+        {
+            use $crate::Writeable;
+            let writeable = $actual_writeable;
+            let output = writeable.writeable_to_string();
+            assert_eq!($crate::LengthHint::Exact(output.len()), writeable.write_len(), "{:?}", output);
+        }
+    };
+
+    ($actual_writeable:expr, $($arg:tt)+) => {
+        {
+            use $crate::Writeable;
+            let writeable = $actual_writeable;
+            let output = writeable.writeable_to_string();
+            assert_eq!(
+                $crate::LengthHint::Exact(output.len()),
+                writeable.write_len(),
+                "{}: {:?}",
+                format_args!($($arg)+),
+                output
+            );
+        }
+    };
+}